@@ -9,7 +9,7 @@
 //! - Using utility functions for bit analysis
 
 use omron_fins::{Client, ClientConfig, MemoryArea};
-use omron_fins::utils::{print_bits, format_binary, format_hex, get_on_bits, word_to_bits};
+use omron_fins::utils::{as_i16_slice, print_bits, format_binary, format_hex, get_on_bits, word_to_bits};
 use std::net::Ipv4Addr;
 
 fn main() -> omron_fins::Result<()> {
@@ -93,6 +93,10 @@ fn main() -> omron_fins::Result<()> {
     let counter: i32 = client.read_i32(MemoryArea::DM, 220)?;
     println!("Counter (i32 from DM220-221): {}", counter);
     
+    // Read i16 (INT) array - 1 word each
+    let offsets: Vec<i16> = client.read_i16_slice(MemoryArea::DM, 224, 4)?;
+    println!("Offsets (i16 from DM224-227): {:?}", offsets);
+    
     // Read ASCII string - variable words (2 chars per word)
     let product_code: String = client.read_string(MemoryArea::DM, 230, 10)?;
     println!("Product code (string from DM230, 10 words): \"{}\"", product_code);
@@ -111,7 +115,7 @@ fn main() -> omron_fins::Result<()> {
     println!("As u16: {:?}", raw_words);
     
     // Interpret as signed integers
-    let signed: Vec<i16> = raw_words.iter().map(|&w| w as i16).collect();
+    let signed = as_i16_slice(&raw_words);
     println!("As i16: {:?}", signed);
     
     // Convert two words to u32 (big-endian)
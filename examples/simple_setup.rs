@@ -8,7 +8,7 @@
 //! - Network addressing configuration
 //! - Error handling patterns
 
-use omron_fins::{Client, ClientConfig, FinsError, PlcMode};
+use omron_fins::{Client, ClientConfig, EndCode, FinsError, PlcMode};
 use std::net::Ipv4Addr;
 use std::time::Duration;
 
@@ -151,9 +151,12 @@ fn main() -> omron_fins::Result<()> {
         match client.stop() {
             Ok(()) => println!("Stop successful"),
             Err(FinsError::Timeout) => println!("Timeout - check network connection"),
-            Err(FinsError::PlcError { main_code, sub_code }) => {
-                println!("PLC error: main=0x{:02X}, sub=0x{:02X}", main_code, sub_code);
-                // Check specific error codes here
+            Err(FinsError::PlcError {
+                end_code: EndCode::AccessRightHeld,
+            }) => println!("Another node holds the access right"),
+            Err(FinsError::PlcError { end_code }) => {
+                println!("PLC error: {}", end_code);
+                // Check other specific end codes here
             }
             Err(e) => println!("Other error: {}", e),
         }
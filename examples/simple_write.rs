@@ -92,17 +92,21 @@ fn main() -> omron_fins::Result<()> {
     println!("\n=== Type Conversions ===\n");
 
     // Write f32 (REAL) - automatically converts to 2 words
-    client.write_f32(MemoryArea::DM, 200, 3.14159)?;
-    println!("Wrote f32 3.14159 to DM200-201");
+    client.write_f32(MemoryArea::DM, 200, std::f32::consts::PI)?;
+    println!("Wrote f32 {} to DM200-201", std::f32::consts::PI);
 
     // Write f64 (LREAL) - automatically converts to 4 words
-    client.write_f64(MemoryArea::DM, 210, 3.141592653589793)?;
-    println!("Wrote f64 3.141592653589793 to DM210-213");
+    client.write_f64(MemoryArea::DM, 210, std::f64::consts::PI)?;
+    println!("Wrote f64 {} to DM210-213", std::f64::consts::PI);
 
     // Write i32 (DINT) - automatically converts to 2 words
     client.write_i32(MemoryArea::DM, 220, -123456)?;
     println!("Wrote i32 -123456 to DM220-221");
 
+    // Write i16 (INT) array - one word per value
+    client.write_i16_slice(MemoryArea::DM, 224, &[-10, -5, 5, 10])?;
+    println!("Wrote i16 [-10, -5, 5, 10] to DM224-227");
+
     // Write ASCII string - automatically converts to words (2 chars per word)
     client.write_string(MemoryArea::DM, 230, "PRODUCT-001")?;
     println!("Wrote string \"PRODUCT-001\" to DM230+");
@@ -0,0 +1,212 @@
+//! Derive macro for `omron_fins::FinsStruct`.
+//!
+//! Use it through the `derive` feature of `omron-fins`, which re-exports the
+//! macro next to the trait. See the `omron_fins::record` module for the
+//! field attributes.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitInt, Type};
+
+/// Derives `FinsStruct` for a struct with named fields.
+///
+/// Field attributes:
+///
+/// * `#[fins(offset = N)]` - Word offset of the field in the block; defaults
+///   to the word after the previous field
+/// * `#[fins(words = N)]` - Words occupied by a `String` field (required for
+///   `String`, not allowed on other types)
+#[proc_macro_derive(FinsStruct, attributes(fins))]
+pub fn derive_fins_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// A field with its resolved layout expressions.
+struct Field {
+    ident: syn::Ident,
+    ty: Type,
+    offset: TokenStream2,
+    words: TokenStream2,
+    is_string: bool,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "FinsStruct can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "FinsStruct can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut fields = Vec::with_capacity(named.len());
+    let mut next_offset = quote!(0u16);
+    for field in named {
+        let (offset, words) = parse_attributes(field)?;
+        let is_string = is_string(&field.ty);
+        let ty = &field.ty;
+
+        let words = match (words, is_string) {
+            (Some(words), true) => quote!(#words),
+            (None, false) => quote!(<#ty as ::omron_fins::FinsField>::WORDS),
+            (None, true) => {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "String fields need #[fins(words = N)]",
+                ))
+            }
+            (Some(words), false) => {
+                return Err(syn::Error::new(
+                    words.span(),
+                    "`words` is only allowed on String fields",
+                ))
+            }
+        };
+        let offset = match offset {
+            Some(offset) => quote!(#offset),
+            None => next_offset.clone(),
+        };
+        next_offset = quote!((#offset + #words));
+
+        fields.push(Field {
+            ident: field.ident.clone().expect("named field"),
+            ty: ty.clone(),
+            offset,
+            words,
+            is_string,
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let ends = fields.iter().map(|field| {
+        let (offset, words) = (&field.offset, &field.words);
+        quote!(#offset + #words)
+    });
+    let puts = fields.iter().map(|field| {
+        let Field {
+            ident,
+            offset,
+            words,
+            ..
+        } = field;
+        let range = quote!((#offset) as usize..(#offset + #words) as usize);
+        if field.is_string {
+            quote_spanned!(ident.span()=>
+                ::omron_fins::record::put_string(&self.#ident, &mut words[#range]);
+            )
+        } else {
+            quote_spanned!(ident.span()=>
+                ::omron_fins::FinsField::put(&self.#ident, &mut words[#range], order);
+            )
+        }
+    });
+    let gets = fields.iter().map(|field| {
+        let Field {
+            ident,
+            ty,
+            offset,
+            words,
+            ..
+        } = field;
+        let range = quote!((#offset) as usize..(#offset + #words) as usize);
+        if field.is_string {
+            quote_spanned!(ident.span()=>
+                #ident: ::omron_fins::record::get_string(&words[#range]),
+            )
+        } else {
+            quote_spanned!(ident.span()=>
+                #ident: <#ty as ::omron_fins::FinsField>::get(&words[#range], order),
+            )
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::omron_fins::FinsStruct for #name #ty_generics #where_clause {
+            const WORD_COUNT: u16 = {
+                let mut count = 0u16;
+                #(
+                    let end = #ends;
+                    if end > count {
+                        count = end;
+                    }
+                )*
+                count
+            };
+
+            fn to_words(&self, order: ::omron_fins::WordOrder) -> ::std::vec::Vec<u16> {
+                let mut words = ::std::vec![0u16; Self::WORD_COUNT as usize];
+                #(#puts)*
+                words
+            }
+
+            fn from_words(
+                words: &[u16],
+                order: ::omron_fins::WordOrder,
+            ) -> ::omron_fins::Result<Self> {
+                ::omron_fins::record::check_len::<Self>(words)?;
+                ::std::result::Result::Ok(Self {
+                    #(#gets)*
+                })
+            }
+        }
+    })
+}
+
+/// Reads the `offset` and `words` arguments of the `#[fins(...)]` attributes.
+fn parse_attributes(field: &syn::Field) -> syn::Result<(Option<LitInt>, Option<LitInt>)> {
+    let mut offset = None;
+    let mut words = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("fins"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("offset") {
+                offset = Some(parse_u16(meta.value()?.parse()?)?);
+                Ok(())
+            } else if meta.path.is_ident("words") {
+                words = Some(parse_u16(meta.value()?.parse()?)?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `offset` or `words`"))
+            }
+        })?;
+    }
+    Ok((offset, words))
+}
+
+/// Checks that a literal fits in a word address and gives it a `u16` suffix.
+fn parse_u16(lit: LitInt) -> syn::Result<LitInt> {
+    let value: u16 = lit.base10_parse()?;
+    Ok(LitInt::new(&format!("{}u16", value), lit.span()))
+}
+
+/// Returns `true` for `String` and `std::string::String`.
+fn is_string(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "String"),
+        _ => false,
+    }
+}
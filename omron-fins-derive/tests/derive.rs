@@ -0,0 +1,61 @@
+use omron_fins::{FinsStruct, WordOrder};
+
+#[derive(Debug, PartialEq, FinsStruct)]
+struct Recipe {
+    #[fins(offset = 0)]
+    batch: u16,
+    #[fins(offset = 2)]
+    temperature: f32,
+    setpoints: [i16; 3],
+    enabled: bool,
+    #[fins(offset = 10, words = 3)]
+    name: String,
+    #[fins(offset = 14)]
+    total: u32,
+}
+
+fn recipe() -> Recipe {
+    Recipe {
+        batch: 7,
+        temperature: 1.5,
+        setpoints: [-1, 0, 300],
+        enabled: true,
+        name: "PASTA".to_string(),
+        total: 0x0001_0002,
+    }
+}
+
+#[test]
+fn test_derive_layout() {
+    assert_eq!(Recipe::WORD_COUNT, 16);
+    assert_eq!(
+        recipe().to_words(WordOrder::LowFirst),
+        vec![
+            7, 0, 0x0000, 0x3FC0, 0xFFFF, 0, 300, 1, 0, 0, 0x4150, 0x5453, 0x0041, 0, 0x0002,
+            0x0001
+        ]
+    );
+    assert_eq!(
+        recipe().to_words(WordOrder::HighFirst)[14..],
+        [0x0001, 0x0002]
+    );
+}
+
+#[test]
+fn test_derive_round_trip() {
+    for order in [WordOrder::LowFirst, WordOrder::HighFirstByteSwap] {
+        let words = recipe().to_words(order);
+        assert_eq!(Recipe::from_words(&words, order).unwrap(), recipe());
+    }
+    assert!(Recipe::from_words(&[0; 15], WordOrder::LowFirst).is_err());
+}
+
+#[test]
+fn test_derive_truncates_strings() {
+    let mut long = recipe();
+    long.name = "SPAGHETTI".to_string();
+    let words = long.to_words(WordOrder::LowFirst);
+    let decoded = Recipe::from_words(&words, WordOrder::LowFirst).unwrap();
+    assert_eq!(decoded.name, "SPAGHE");
+    assert_eq!(decoded.total, 0x0001_0002);
+}
@@ -0,0 +1,458 @@
+//! Omron address notation.
+//!
+//! [`FinsAddress`] parses the address strings used by CX-Programmer and
+//! other FINS drivers, so configuration files can store addresses such as
+//! `"D100"` or `"CIO2.05"` instead of separate area/word/bit fields.
+//!
+//! | Notation | Area | Example |
+//! |----------|------|---------|
+//! | `CIO`, or no prefix | [`MemoryArea::CIO`] | `CIO2.05`, `2.05` |
+//! | `W`, `WR` | [`MemoryArea::WR`] | `W3.12` |
+//! | `H`, `HR` | [`MemoryArea::HR`] | `H10` |
+//! | `D`, `DM` | [`MemoryArea::DM`] | `D100` |
+//! | `A`, `AR` | [`MemoryArea::AR`] | `A500.01` |
+//! | `T`, `TIM` | [`MemoryArea::TIM`] | `T5` |
+//! | `C`, `CNT` | [`MemoryArea::CNT`] | `C12` |
+//! | `IR` | [`MemoryArea::IR`] | `IR0` |
+//! | `CF` | [`MemoryArea::CF`] | `CF4096.03` |
+//! | `E<bank>_` | EM bank, as [`MemoryArea::Custom`] | `E0_200` |
+//! | `Custom(<word code>[, <bit code>])` | Other [`MemoryArea::Custom`] areas | `Custom(0xF0) 7`, `Custom(0xF0, 0x30) 7.05` |
+//!
+//! Prefixes are case-insensitive and may be followed by a space. A `.NN`
+//! suffix selects a bit. [`Display`](std::fmt::Display) writes the
+//! canonical form (`"CIO 0.05"`, `"D100"`, `"E0_200"`, `"Custom(0xF0) 7"`),
+//! which parses back to the same address.
+//!
+//! [`AreaRange`] describes a run of consecutive words and splits itself into
+//! command-sized chunks.
+//!
+//! # Example
+//!
+//! ```
+//! use omron_fins::{FinsAddress, MemoryArea};
+//!
+//! let addr: FinsAddress = "CIO2.05".parse()?;
+//! assert_eq!(addr.area, MemoryArea::CIO);
+//! assert_eq!(addr.word, 2);
+//! assert_eq!(addr.bit, Some(5));
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::command::MAX_WORDS_PER_COMMAND;
+use crate::error::{FinsError, Result};
+use crate::memory::MemoryArea;
+
+/// Highest EM bank number accepted in `E<bank>_<word>` notation.
+const MAX_EM_BANK: u8 = 12;
+/// Word access code of EM bank 0; banks 1-12 follow consecutively.
+const EM_WORD_CODE: u8 = 0xA0;
+/// Bit access code of EM bank 0; banks 1-12 follow consecutively.
+const EM_BIT_CODE: u8 = 0x20;
+
+/// A memory location: area, word and optional bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FinsAddress {
+    /// Memory area.
+    pub area: MemoryArea,
+    /// Word address (timer/counter/register number for TIM, CNT and IR).
+    pub word: u16,
+    /// Bit position (0-15), or `None` for a word address.
+    pub bit: Option<u8>,
+}
+
+impl FinsAddress {
+    /// Creates a word address.
+    pub fn word(area: MemoryArea, word: u16) -> Self {
+        Self {
+            area,
+            word,
+            bit: None,
+        }
+    }
+
+    /// Creates a bit address.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `bit` is greater than 15.
+    pub fn bit(area: MemoryArea, word: u16, bit: u8) -> Result<Self> {
+        if bit > 15 {
+            return Err(FinsError::invalid_parameter("bit", "must be 0-15"));
+        }
+        Ok(Self {
+            area,
+            word,
+            bit: Some(bit),
+        })
+    }
+
+    /// Returns the area for EM bank `bank`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the bank is above 12.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{FinsAddress, MemoryArea};
+    ///
+    /// let em2 = FinsAddress::em_area(2)?;
+    /// assert_eq!(em2, MemoryArea::Custom { word_code: 0xA2, bit_code: Some(0x22) });
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn em_area(bank: u8) -> Result<MemoryArea> {
+        if bank > MAX_EM_BANK {
+            return Err(FinsError::invalid_parameter(
+                "bank",
+                format!("EM bank must be 0-{}", MAX_EM_BANK),
+            ));
+        }
+        Ok(MemoryArea::Custom {
+            word_code: EM_WORD_CODE + bank,
+            bit_code: Some(EM_BIT_CODE + bank),
+        })
+    }
+}
+
+impl FromStr for FinsAddress {
+    type Err = FinsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid =
+            |reason: &str| FinsError::invalid_parameter("address", format!("'{}': {}", s, reason));
+
+        let text = s.trim();
+        if let Some((area, location)) = parse_custom(text) {
+            let area = area.ok_or_else(|| invalid("invalid custom area codes"))?;
+            return Self::parse_location(area, location.trim_start(), invalid);
+        }
+        let digits = text
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or_else(|| invalid("missing word address"))?;
+        let (prefix, rest) = text.split_at(digits);
+        let prefix = prefix.trim_end().to_ascii_uppercase();
+
+        let (area, location) = match prefix.as_str() {
+            "" | "CIO" => (MemoryArea::CIO, rest),
+            "W" | "WR" => (MemoryArea::WR, rest),
+            "H" | "HR" => (MemoryArea::HR, rest),
+            "D" | "DM" => (MemoryArea::DM, rest),
+            "A" | "AR" => (MemoryArea::AR, rest),
+            "T" | "TIM" => (MemoryArea::TIM, rest),
+            "C" | "CNT" => (MemoryArea::CNT, rest),
+            "IR" => (MemoryArea::IR, rest),
+            "CF" => (MemoryArea::CF, rest),
+            "E" | "EM" => {
+                let (bank, word) = rest
+                    .split_once('_')
+                    .ok_or_else(|| invalid("EM addresses are written E<bank>_<word>"))?;
+                let bank = bank.parse().map_err(|_| invalid("invalid EM bank"))?;
+                (Self::em_area(bank)?, word)
+            }
+            _ => return Err(invalid("unknown area prefix")),
+        };
+        Self::parse_location(area, location, invalid)
+    }
+}
+
+impl FinsAddress {
+    /// Parses the `<word>[.<bit>]` part of an address in `area`.
+    fn parse_location(
+        area: MemoryArea,
+        location: &str,
+        invalid: impl Fn(&str) -> FinsError,
+    ) -> Result<Self> {
+        let (word, bit) = match location.split_once('.') {
+            Some((word, bit)) => (word, Some(bit)),
+            None => (location, None),
+        };
+        let word = word.parse().map_err(|_| invalid("invalid word address"))?;
+        match bit {
+            Some(bit) => {
+                let bit = bit.parse().map_err(|_| invalid("invalid bit number"))?;
+                Self::bit(area, word, bit)
+            }
+            None => Ok(Self::word(area, word)),
+        }
+    }
+}
+
+impl fmt::Display for FinsAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.area {
+            MemoryArea::CIO => write!(f, "CIO {}", self.word)?,
+            MemoryArea::WR => write!(f, "W{}", self.word)?,
+            MemoryArea::HR => write!(f, "H{}", self.word)?,
+            MemoryArea::DM => write!(f, "D{}", self.word)?,
+            MemoryArea::AR => write!(f, "A{}", self.word)?,
+            MemoryArea::TIM => write!(f, "T{}", self.word)?,
+            MemoryArea::CNT => write!(f, "C{}", self.word)?,
+            MemoryArea::IR => write!(f, "IR{}", self.word)?,
+            MemoryArea::CF => write!(f, "CF{}", self.word)?,
+            MemoryArea::Custom { word_code, .. }
+                if word_code >= EM_WORD_CODE
+                    && Self::em_area(word_code - EM_WORD_CODE).ok() == Some(self.area) =>
+            {
+                write!(f, "E{}_{}", word_code - EM_WORD_CODE, self.word)?
+            }
+            MemoryArea::Custom {
+                word_code,
+                bit_code: None,
+            } => write!(f, "Custom(0x{:02X}) {}", word_code, self.word)?,
+            MemoryArea::Custom {
+                word_code,
+                bit_code: Some(bit_code),
+            } => write!(
+                f,
+                "Custom(0x{:02X}, 0x{:02X}) {}",
+                word_code, bit_code, self.word
+            )?,
+        }
+        if let Some(bit) = self.bit {
+            write!(f, ".{:02}", bit)?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits `Custom(<word code>[, <bit code>])<location>` into its area and
+/// location. Returns `None` if `text` doesn't use this notation, and a
+/// `None` area if the codes are malformed.
+fn parse_custom(text: &str) -> Option<(Option<MemoryArea>, &str)> {
+    let prefix = text.get(..7)?;
+    if !prefix.eq_ignore_ascii_case("custom(") {
+        return None;
+    }
+    let Some((codes, location)) = text[7..].split_once(')') else {
+        return Some((None, ""));
+    };
+    let code = |code: &str| {
+        let code = code.trim();
+        let hex = code
+            .strip_prefix("0x")
+            .or_else(|| code.strip_prefix("0X"))
+            .unwrap_or(code);
+        u8::from_str_radix(hex, 16).ok()
+    };
+    let area = match codes.split_once(',') {
+        Some((word_code, bit_code)) => {
+            code(word_code)
+                .zip(code(bit_code))
+                .map(|(word_code, bit_code)| MemoryArea::Custom {
+                    word_code,
+                    bit_code: Some(bit_code),
+                })
+        }
+        None => code(codes).map(|word_code| MemoryArea::Custom {
+            word_code,
+            bit_code: None,
+        }),
+    };
+    Some((area, location))
+}
+
+/// A run of consecutive words in one memory area.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::{AreaRange, MemoryArea};
+///
+/// let range = AreaRange::new(MemoryArea::DM, 0, 2500);
+/// let chunks: Vec<AreaRange> = range.chunks(999).collect();
+/// assert_eq!(chunks.len(), 3);
+/// assert_eq!(chunks[2], AreaRange::new(MemoryArea::DM, 1998, 502));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AreaRange {
+    /// Memory area.
+    pub area: MemoryArea,
+    /// First word address.
+    pub start: u16,
+    /// Number of words.
+    pub len: u16,
+}
+
+impl AreaRange {
+    /// Creates a range of `len` words starting at `start`.
+    pub fn new(area: MemoryArea, start: u16, len: u16) -> Self {
+        Self { area, start, len }
+    }
+
+    /// Returns `true` if the range covers no words.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the address one past the last word (may exceed `u16::MAX`).
+    pub fn end(&self) -> u32 {
+        self.start as u32 + self.len as u32
+    }
+
+    /// Returns `true` if `word` lies within the range.
+    pub fn contains(&self, word: u16) -> bool {
+        word >= self.start && (word as u32) < self.end()
+    }
+
+    /// Splits the range into consecutive subranges of at most `max_len`
+    /// words (a `max_len` of 0 is treated as 1).
+    pub fn chunks(self, max_len: u16) -> impl Iterator<Item = AreaRange> {
+        let max_len = max_len.max(1);
+        let mut next = self;
+        std::iter::from_fn(move || {
+            if next.is_empty() {
+                return None;
+            }
+            let len = next.len.min(max_len);
+            let chunk = AreaRange::new(next.area, next.start, len);
+            next.start = next.start.wrapping_add(len);
+            next.len -= len;
+            Some(chunk)
+        })
+    }
+
+    /// Splits the range into chunks that fit in one read/write command
+    /// ([`MAX_WORDS_PER_COMMAND`] words).
+    pub fn command_chunks(self) -> impl Iterator<Item = AreaRange> {
+        self.chunks(MAX_WORDS_PER_COMMAND)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> FinsAddress {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_areas() {
+        assert_eq!(parse("D100"), FinsAddress::word(MemoryArea::DM, 100));
+        assert_eq!(parse("DM 100"), FinsAddress::word(MemoryArea::DM, 100));
+        assert_eq!(
+            parse("CIO2.05"),
+            FinsAddress::bit(MemoryArea::CIO, 2, 5).unwrap()
+        );
+        assert_eq!(
+            parse("0.05"),
+            FinsAddress::bit(MemoryArea::CIO, 0, 5).unwrap()
+        );
+        assert_eq!(
+            parse("w3.12"),
+            FinsAddress::bit(MemoryArea::WR, 3, 12).unwrap()
+        );
+        assert_eq!(parse("H10"), FinsAddress::word(MemoryArea::HR, 10));
+        assert_eq!(parse("A500"), FinsAddress::word(MemoryArea::AR, 500));
+        assert_eq!(parse("T5"), FinsAddress::word(MemoryArea::TIM, 5));
+        assert_eq!(parse("C12"), FinsAddress::word(MemoryArea::CNT, 12));
+        assert_eq!(parse("IR3"), FinsAddress::word(MemoryArea::IR, 3));
+        assert_eq!(
+            parse("E0_200"),
+            FinsAddress::word(FinsAddress::em_area(0).unwrap(), 200)
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for (text, canonical) in [
+            ("cio0.5", "CIO 0.05"),
+            ("D100", "D100"),
+            ("WR3.12", "W3.12"),
+            ("HR 10", "H10"),
+            ("A500.01", "A500.01"),
+            ("TIM5", "T5"),
+            ("CNT12", "C12"),
+            ("IR3", "IR3"),
+            ("CF4096.03", "CF4096.03"),
+            ("EM2_200", "E2_200"),
+        ] {
+            let address = parse(text);
+            assert_eq!(address.to_string(), canonical);
+            assert_eq!(parse(canonical), address);
+        }
+
+        let custom = MemoryArea::Custom {
+            word_code: 0xF0,
+            bit_code: None,
+        };
+        let with_bits = MemoryArea::Custom {
+            word_code: 0xF0,
+            bit_code: Some(0x30),
+        };
+        for (address, canonical) in [
+            (FinsAddress::word(custom, 7), "Custom(0xF0) 7"),
+            (FinsAddress::word(with_bits, 7), "Custom(0xF0, 0x30) 7"),
+            (
+                FinsAddress::bit(with_bits, 7, 5).unwrap(),
+                "Custom(0xF0, 0x30) 7.05",
+            ),
+        ] {
+            assert_eq!(address.to_string(), canonical);
+            assert_eq!(parse(canonical), address);
+        }
+        assert_eq!(parse("custom(f0)7"), FinsAddress::word(custom, 7));
+
+        // EM word codes without the EM bit code aren't EM notation
+        let em_words_only = MemoryArea::Custom {
+            word_code: 0xA0,
+            bit_code: None,
+        };
+        let address = FinsAddress::word(em_words_only, 7);
+        assert_eq!(address.to_string(), "Custom(0xA0) 7");
+        assert_eq!(parse("Custom(0xA0) 7"), address);
+    }
+
+    #[test]
+    fn test_area_range_chunks() {
+        let range = AreaRange::new(MemoryArea::DM, 100, 1500);
+        assert!(range.contains(100));
+        assert!(range.contains(1599));
+        assert!(!range.contains(1600));
+        assert_eq!(range.end(), 1600);
+
+        let chunks: Vec<_> = range.command_chunks().collect();
+        assert_eq!(
+            chunks,
+            vec![
+                AreaRange::new(MemoryArea::DM, 100, MAX_WORDS_PER_COMMAND),
+                AreaRange::new(MemoryArea::DM, 800, MAX_WORDS_PER_COMMAND),
+                AreaRange::new(MemoryArea::DM, 1500, 100),
+            ]
+        );
+
+        assert_eq!(AreaRange::new(MemoryArea::DM, 0, 0).chunks(10).count(), 0);
+        assert_eq!(AreaRange::new(MemoryArea::DM, 0, 3).chunks(0).count(), 3);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for text in [
+            "",
+            "D",
+            "X100",
+            "D100.16",
+            "D1.x",
+            "E0200",
+            "E13_0",
+            "D70000",
+            "Custom(0xF0 7",
+            "Custom(0x100) 7",
+            "Custom(0xF0,) 7",
+            "Custom(0xF0)",
+        ] {
+            assert!(
+                matches!(
+                    text.parse::<FinsAddress>(),
+                    Err(FinsError::InvalidParameter { .. })
+                ),
+                "{}",
+                text
+            );
+        }
+    }
+}
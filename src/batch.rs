@@ -0,0 +1,313 @@
+//! Batched reads of scattered values.
+//!
+//! A [`BatchRead`] collects heterogeneous read requests and
+//! [`Client::read_batch`](crate::Client::read_batch) executes them with as
+//! few FINS commands as it can: bits and values of up to two words go into
+//! Multiple Memory Area Read commands (up to
+//! [`MAX_MULTI_READ_ITEMS`](crate::MAX_MULTI_READ_ITEMS) words or bits
+//! each), longer blocks into plain reads. Each request returns a
+//! [`BatchHandle`] used to look its value up in the [`BatchResults`].
+//!
+//! # Example
+//!
+//! ```
+//! use omron_fins::{BatchRead, MemoryArea};
+//!
+//! let mut batch = BatchRead::new();
+//! let speed = batch.f32(MemoryArea::DM, 100);
+//! let running = batch.bit(MemoryArea::CIO, 0, 5);
+//! let recipe = batch.string(MemoryArea::DM, 500, 10);
+//!
+//! // speed and running share one multiple read, the string is a plain read
+//! assert_eq!(batch.command_count(), 2);
+//! ```
+
+use crate::command::{MultiReadSpec, MAX_MULTI_READ_ITEMS};
+use crate::error::{FinsError, Result};
+use crate::memory::MemoryArea;
+use crate::types::WordOrder;
+use crate::utils;
+
+/// Items of up to this many words are read through multiple reads.
+const MULTI_READ_MAX_WORDS: u16 = 2;
+
+/// Identifies one request in a [`BatchRead`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BatchHandle(usize);
+
+/// A value returned by a batch read.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchValue {
+    /// Words from [`BatchRead::words`].
+    Words(Vec<u16>),
+    /// Bit from [`BatchRead::bit`].
+    Bit(bool),
+    /// REAL from [`BatchRead::f32`], decoded with the client's word order.
+    F32(f32),
+    /// String from [`BatchRead::string`], with trailing NULs trimmed.
+    String(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Words,
+    Bit(u8),
+    F32,
+    String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Item {
+    area: MemoryArea,
+    address: u16,
+    count: u16,
+    kind: Kind,
+}
+
+impl Item {
+    fn multi_read(&self) -> bool {
+        matches!(self.kind, Kind::Bit(_)) || self.count <= MULTI_READ_MAX_WORDS
+    }
+
+    fn specs(&self) -> Vec<MultiReadSpec> {
+        match self.kind {
+            Kind::Bit(bit) => vec![MultiReadSpec {
+                area: self.area,
+                address: self.address,
+                bit: Some(bit),
+            }],
+            _ => (0..self.count)
+                .map(|offset| MultiReadSpec {
+                    area: self.area,
+                    address: self.address.wrapping_add(offset),
+                    bit: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn decode(&self, words: Vec<u16>, order: WordOrder) -> BatchValue {
+        match self.kind {
+            Kind::Words => BatchValue::Words(words),
+            Kind::Bit(_) => BatchValue::Bit(words.first().is_some_and(|&word| word != 0)),
+            Kind::F32 => {
+                let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_be_bytes()).collect();
+                BatchValue::F32(f32::from_be_bytes(order.native(&bytes)))
+            }
+            Kind::String => {
+                let bytes = utils::unpack_string_bytes(&words);
+                BatchValue::String(String::from_utf8_lossy(&bytes).to_string())
+            }
+        }
+    }
+}
+
+/// How a batch is split into commands.
+#[derive(Debug, Default)]
+pub(crate) struct BatchPlan {
+    /// Multiple read commands, each a list of (item index, spec).
+    pub(crate) multi_reads: Vec<Vec<(usize, MultiReadSpec)>>,
+    /// Plain reads, as (item index, area, address, count).
+    pub(crate) reads: Vec<(usize, MemoryArea, u16, u16)>,
+}
+
+/// A set of read requests executed together.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchRead {
+    items: Vec<Item>,
+}
+
+impl BatchRead {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests `count` words starting at `address`.
+    pub fn words(&mut self, area: MemoryArea, address: u16, count: u16) -> BatchHandle {
+        self.push(area, address, count, Kind::Words)
+    }
+
+    /// Requests one bit.
+    pub fn bit(&mut self, area: MemoryArea, address: u16, bit: u8) -> BatchHandle {
+        self.push(area, address, 1, Kind::Bit(bit))
+    }
+
+    /// Requests a REAL stored at `address` and `address + 1`.
+    pub fn f32(&mut self, area: MemoryArea, address: u16) -> BatchHandle {
+        self.push(area, address, 2, Kind::F32)
+    }
+
+    /// Requests a string stored in `word_count` words, 2 characters per
+    /// word as written by [`Client::write_string`](crate::Client::write_string).
+    pub fn string(&mut self, area: MemoryArea, address: u16, word_count: u16) -> BatchHandle {
+        self.push(area, address, word_count, Kind::String)
+    }
+
+    /// Returns the number of requests.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if no request has been added.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the number of FINS commands the batch takes, not counting
+    /// the extra commands of plain reads longer than
+    /// [`MAX_WORDS_PER_COMMAND`](crate::MAX_WORDS_PER_COMMAND).
+    pub fn command_count(&self) -> usize {
+        let plan = self.plan();
+        plan.multi_reads.len() + plan.reads.len()
+    }
+
+    fn push(&mut self, area: MemoryArea, address: u16, count: u16, kind: Kind) -> BatchHandle {
+        self.items.push(Item {
+            area,
+            address,
+            count,
+            kind,
+        });
+        BatchHandle(self.items.len() - 1)
+    }
+
+    pub(crate) fn plan(&self) -> BatchPlan {
+        let mut plan = BatchPlan::default();
+        let mut specs = Vec::new();
+        for (index, item) in self.items.iter().enumerate() {
+            if item.multi_read() {
+                specs.extend(item.specs().into_iter().map(|spec| (index, spec)));
+            } else {
+                plan.reads
+                    .push((index, item.area, item.address, item.count));
+            }
+        }
+        while !specs.is_empty() {
+            let rest = specs.split_off(specs.len().min(MAX_MULTI_READ_ITEMS));
+            plan.multi_reads.push(std::mem::replace(&mut specs, rest));
+        }
+        plan
+    }
+
+    /// Decodes the words collected for each request.
+    pub(crate) fn decode(&self, words: Vec<Vec<u16>>, order: WordOrder) -> Result<BatchResults> {
+        let values = self
+            .items
+            .iter()
+            .zip(words)
+            .map(|(item, words)| {
+                if words.len() != item.count as usize {
+                    return Err(FinsError::invalid_response(format!(
+                        "expected {} words for batch item, got {}",
+                        item.count,
+                        words.len()
+                    )));
+                }
+                Ok(item.decode(words, order))
+            })
+            .collect::<Result<_>>()?;
+        Ok(BatchResults { values })
+    }
+}
+
+/// Values returned by [`Client::read_batch`](crate::Client::read_batch).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchResults {
+    values: Vec<BatchValue>,
+}
+
+impl BatchResults {
+    /// Returns the value of a request, or `None` if the handle belongs to
+    /// another batch.
+    pub fn get(&self, handle: BatchHandle) -> Option<&BatchValue> {
+        self.values.get(handle.0)
+    }
+
+    /// Returns the words of a [`BatchRead::words`] request.
+    pub fn words(&self, handle: BatchHandle) -> Option<&[u16]> {
+        match self.get(handle)? {
+            BatchValue::Words(words) => Some(words),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [`BatchRead::bit`] request.
+    pub fn bit(&self, handle: BatchHandle) -> Option<bool> {
+        match self.get(handle)? {
+            BatchValue::Bit(bit) => Some(*bit),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [`BatchRead::f32`] request.
+    pub fn f32(&self, handle: BatchHandle) -> Option<f32> {
+        match self.get(handle)? {
+            BatchValue::F32(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [`BatchRead::string`] request.
+    pub fn string(&self, handle: BatchHandle) -> Option<&str> {
+        match self.get(handle)? {
+            BatchValue::String(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_plan() {
+        let mut batch = BatchRead::new();
+        batch.words(MemoryArea::DM, 0, 1);
+        batch.f32(MemoryArea::DM, 10);
+        batch.words(MemoryArea::DM, 100, 50);
+        batch.bit(MemoryArea::CIO, 0, 5);
+
+        let plan = batch.plan();
+        assert_eq!(plan.reads, vec![(2, MemoryArea::DM, 100, 50)]);
+        assert_eq!(plan.multi_reads.len(), 1);
+        let items: Vec<usize> = plan.multi_reads[0]
+            .iter()
+            .map(|(index, _)| *index)
+            .collect();
+        assert_eq!(items, vec![0, 1, 1, 3]);
+        assert_eq!(plan.multi_reads[0][2].1.address, 11);
+        assert_eq!(plan.multi_reads[0][3].1.bit, Some(5));
+
+        let mut large = BatchRead::new();
+        for address in 0..MAX_MULTI_READ_ITEMS as u16 + 1 {
+            large.bit(MemoryArea::WR, address, 0);
+        }
+        assert_eq!(large.command_count(), 2);
+        assert_eq!(BatchRead::new().command_count(), 0);
+    }
+
+    #[test]
+    fn test_batch_decode() {
+        let mut batch = BatchRead::new();
+        let speed = batch.f32(MemoryArea::DM, 0);
+        let name = batch.string(MemoryArea::DM, 2, 2);
+        let flag = batch.bit(MemoryArea::CIO, 0, 0);
+
+        let results = batch
+            .decode(
+                vec![vec![0x0000, 0x3FC0], vec![0x4241, 0x0043], vec![1]],
+                WordOrder::LowFirst,
+            )
+            .unwrap();
+        assert_eq!(results.f32(speed), Some(1.5));
+        assert_eq!(results.string(name), Some("ABC"));
+        assert_eq!(results.bit(flag), Some(true));
+        assert_eq!(results.words(flag), None);
+
+        assert!(batch
+            .decode(vec![vec![0], vec![0, 0], vec![1]], WordOrder::LowFirst)
+            .is_err());
+    }
+}
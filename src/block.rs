@@ -0,0 +1,261 @@
+//! Mixed-type word blocks.
+//!
+//! Recipes and parameter sets usually mix integers, floats and text in one
+//! contiguous region. [`WordBlockBuilder`] places typed fields at word
+//! offsets so the whole region goes out in a single
+//! [`Client::write_block`](crate::Client::write_block) call, and
+//! [`WordBlock`] slices a block returned by
+//! [`Client::read_block`](crate::Client::read_block) back into fields.
+//!
+//! Multi-word fields use the client's [`WordOrder`].
+//!
+//! # Example
+//!
+//! ```
+//! use omron_fins::{WordBlock, WordBlockBuilder, WordOrder};
+//!
+//! let block = WordBlockBuilder::new()
+//!     .with_u16(0, 7)
+//!     .with_f32(2, 12.5)
+//!     .with_string(4, "MIX-01");
+//! let words = block.to_words(WordOrder::LowFirst);
+//! assert_eq!(words.len(), 7);
+//!
+//! let fields = WordBlock::new(words, WordOrder::LowFirst);
+//! assert_eq!(fields.u16_at(0)?, 7);
+//! assert_eq!(fields.f32_at(2)?, 12.5);
+//! assert_eq!(fields.string_at(4, 3)?, "MIX-01");
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use crate::error::{FinsError, Result};
+use crate::types::WordOrder;
+use crate::utils;
+
+/// A typed field waiting to be laid out.
+#[derive(Debug, Clone, PartialEq)]
+enum Field {
+    U16(u16),
+    I32(i32),
+    F32(f32),
+    Text(Vec<u16>),
+}
+
+impl Field {
+    fn word_len(&self) -> usize {
+        match self {
+            Field::U16(_) => 1,
+            Field::I32(_) | Field::F32(_) => 2,
+            Field::Text(words) => words.len(),
+        }
+    }
+}
+
+/// Assembles typed fields at word offsets into one block.
+///
+/// Gaps between fields are zero-filled. A later field overwrites any
+/// earlier field it overlaps.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WordBlockBuilder {
+    fields: Vec<(u16, Field)>,
+}
+
+impl WordBlockBuilder {
+    /// Creates an empty block.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Places a u16 (UINT/WORD) at `offset`.
+    pub fn with_u16(mut self, offset: u16, value: u16) -> Self {
+        self.fields.push((offset, Field::U16(value)));
+        self
+    }
+
+    /// Places an i16 (INT) at `offset`.
+    pub fn with_i16(self, offset: u16, value: i16) -> Self {
+        self.with_u16(offset, value as u16)
+    }
+
+    /// Places an i32 (DINT) at `offset` and `offset + 1`.
+    pub fn with_i32(mut self, offset: u16, value: i32) -> Self {
+        self.fields.push((offset, Field::I32(value)));
+        self
+    }
+
+    /// Places an f32 (REAL) at `offset` and `offset + 1`.
+    pub fn with_f32(mut self, offset: u16, value: f32) -> Self {
+        self.fields.push((offset, Field::F32(value)));
+        self
+    }
+
+    /// Places an ASCII string at `offset`, 2 characters per word, in the
+    /// same layout as [`Client::write_string`](crate::Client::write_string).
+    pub fn with_string(mut self, offset: u16, value: &str) -> Self {
+        let words = utils::pack_string_bytes(value.as_bytes());
+        self.fields.push((offset, Field::Text(words)));
+        self
+    }
+
+    /// Returns the block length in words (end of the last field).
+    pub fn len(&self) -> usize {
+        self.fields
+            .iter()
+            .map(|(offset, field)| *offset as usize + field.word_len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if no field has been added.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Lays the fields out as PLC words, using `order` for 32-bit fields.
+    pub fn to_words(&self, order: WordOrder) -> Vec<u16> {
+        let mut words = vec![0u16; self.len()];
+        for (offset, field) in &self.fields {
+            let start = *offset as usize;
+            match field {
+                Field::U16(value) => words[start] = *value,
+                Field::I32(value) => place_native(&mut words[start..], value.to_be_bytes(), order),
+                Field::F32(value) => place_native(&mut words[start..], value.to_be_bytes(), order),
+                Field::Text(text) => words[start..start + text.len()].copy_from_slice(text),
+            }
+        }
+        words
+    }
+}
+
+/// Writes a 32-bit value in PLC layout to the first two words of `words`.
+fn place_native(words: &mut [u16], mut bytes: [u8; 4], order: WordOrder) {
+    order.arrange(&mut bytes);
+    words[0] = u16::from_be_bytes([bytes[0], bytes[1]]);
+    words[1] = u16::from_be_bytes([bytes[2], bytes[3]]);
+}
+
+/// A block of words read from the PLC, decoded field by field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordBlock {
+    words: Vec<u16>,
+    order: WordOrder,
+}
+
+impl WordBlock {
+    /// Wraps `words`, decoding 32-bit fields with `order`.
+    pub fn new(words: Vec<u16>, order: WordOrder) -> Self {
+        Self { words, order }
+    }
+
+    /// Returns the raw words.
+    pub fn words(&self) -> &[u16] {
+        &self.words
+    }
+
+    /// Returns the u16 (UINT/WORD) at `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `offset` is past the block.
+    pub fn u16_at(&self, offset: u16) -> Result<u16> {
+        Ok(self.slice(offset, 1)?[0])
+    }
+
+    /// Returns the i16 (INT) at `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `offset` is past the block.
+    pub fn i16_at(&self, offset: u16) -> Result<i16> {
+        self.u16_at(offset).map(|word| word as i16)
+    }
+
+    /// Returns the i32 (DINT) at `offset` and `offset + 1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the field runs past the block.
+    pub fn i32_at(&self, offset: u16) -> Result<i32> {
+        self.native(offset).map(i32::from_be_bytes)
+    }
+
+    /// Returns the f32 (REAL) at `offset` and `offset + 1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the field runs past the block.
+    pub fn f32_at(&self, offset: u16) -> Result<f32> {
+        self.native(offset).map(f32::from_be_bytes)
+    }
+
+    /// Returns the ASCII string stored in `word_count` words at `offset`,
+    /// with trailing NULs trimmed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the field runs past the block.
+    pub fn string_at(&self, offset: u16, word_count: u16) -> Result<String> {
+        let bytes = utils::unpack_string_bytes(self.slice(offset, word_count)?);
+        Ok(String::from_utf8_lossy(&bytes).to_string())
+    }
+
+    fn native(&self, offset: u16) -> Result<[u8; 4]> {
+        let words = self.slice(offset, 2)?;
+        let mut bytes = [0u8; 4];
+        bytes[..2].copy_from_slice(&words[0].to_be_bytes());
+        bytes[2..].copy_from_slice(&words[1].to_be_bytes());
+        self.order.arrange(&mut bytes);
+        Ok(bytes)
+    }
+
+    fn slice(&self, offset: u16, len: u16) -> Result<&[u16]> {
+        let start = offset as usize;
+        self.words.get(start..start + len as usize).ok_or_else(|| {
+            FinsError::invalid_parameter(
+                "offset",
+                format!(
+                    "field at {}+{} runs past the {}-word block",
+                    offset,
+                    len,
+                    self.words.len()
+                ),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_layout() {
+        let builder = WordBlockBuilder::new()
+            .with_i16(0, -1)
+            .with_i32(2, 0x1122_3344)
+            .with_string(5, "ABC");
+        assert_eq!(builder.len(), 7);
+        assert_eq!(
+            builder.to_words(WordOrder::LowFirst),
+            vec![0xFFFF, 0x0000, 0x3344, 0x1122, 0x0000, 0x4241, 0x0043]
+        );
+        assert_eq!(
+            builder.to_words(WordOrder::HighFirst)[2..4],
+            [0x1122, 0x3344]
+        );
+        assert!(WordBlockBuilder::new().is_empty());
+    }
+
+    #[test]
+    fn test_block_fields() {
+        let block = WordBlock::new(vec![0xFFFE, 0x0000, 0x3FC0, 0x4241], WordOrder::LowFirst);
+        assert_eq!(block.i16_at(0).unwrap(), -2);
+        assert_eq!(block.f32_at(1).unwrap(), 1.5);
+        assert_eq!(block.string_at(3, 1).unwrap(), "AB");
+        assert!(matches!(
+            block.i32_at(3),
+            Err(FinsError::InvalidParameter { .. })
+        ));
+        assert!(block.u16_at(4).is_err());
+    }
+}
@@ -41,7 +41,7 @@
 //!
 //! The [`ClientConfig`] struct allows customization of:
 //! - PLC IP address and port
-//! - Communication timeout
+//! - Communication timeouts (send and receive)
 //! - Source and destination node addresses
 //! - Network addressing for multi-network setups
 //!
@@ -50,36 +50,181 @@
 //! The `Client` uses an atomic counter for Service IDs, making it safe to share
 //! between threads. However, the underlying UDP socket operations are synchronous
 //! and will block.
+//!
+//! By default concurrent callers contend for the same socket and may read each
+//! other's responses. Enable [`ClientConfig::with_pipelining`] to let several
+//! threads keep requests in flight at once: a background receiver routes each
+//! response to its caller by Service ID.
 
-use std::net::SocketAddr;
+use std::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::atomic::{AtomicU8, Ordering};
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
 
+use crate::address::{AreaRange, FinsAddress};
+use crate::batch::{BatchRead, BatchResults};
+use crate::block::{WordBlock, WordBlockBuilder};
 use crate::command::{
-    FillCommand, ForcedBit, ForcedSetResetCancelCommand, ForcedSetResetCommand, MultiReadSpec,
-    MultipleReadCommand, PlcMode, ReadBitCommand, ReadWordCommand, RunCommand, StopCommand,
-    TransferCommand, WriteBitCommand, WriteWordCommand, MAX_WORDS_PER_COMMAND,
+    AccessRightAction, AccessRightCommand, ConnectionDataReadCommand, EchoTestCommand,
+    ErrorLogClearCommand, ErrorLogReadCommand, FileDisk, FileRenameCommand, FileTransferDirection,
+    FileWriteCommand, FileWriteMode, FillCommand, ForcedBit, ForcedSetResetCancelCommand,
+    ForcedSetResetCommand, MemoryFileTransferCommand, MessageClearCommand, MessageReadCommand,
+    MultiReadSpec, MultipleReadCommand, PlcMode, ProgramFileTransferCommand, RawCommand,
+    ReadBitCommand, ReadWordCommand, ResetCommand, RunCommand, StopCommand, TransferCommand,
+    WriteBitCommand, WriteWordCommand, MAX_CONNECTION_DATA_UNITS, MAX_FILE_CHUNK,
+    MAX_WORDS_PER_COMMAND,
 };
-use crate::error::Result;
-use crate::header::NodeAddress;
+use crate::datetime::TimeFormat;
+use crate::error::{EndCode, RequestContext, Result};
+use crate::header::{HeaderOptions, NodeAddress, BROADCAST_NODE, FINS_HEADER_SIZE};
+#[cfg(feature = "serde")]
+use crate::image::ImageLayout;
+use crate::logging::{log_debug, log_trace, log_warn, Hex};
 use crate::memory::MemoryArea;
-use crate::response::FinsResponse;
-use crate::transport::{UdpTransport, DEFAULT_FINS_PORT, DEFAULT_TIMEOUT};
-use crate::types::{DataType, PlcValue};
+use crate::metrics::MetricsSink;
+use crate::observer::{Direction, FrameObserver};
+use crate::pipeline::Demux;
+use crate::profile::CpuProfile;
+use crate::record::FinsStruct;
+use crate::recorder::{SessionEntry, SessionRecorder};
+use crate::resolver::{resolve_ipv4, Resolver, SystemResolver};
+use crate::response::{
+    AccessRight, EndCodeWarnings, ErrorLogEntry, FinsResponse, MultiReadResult, PlcDateTime,
+    UnitInfo,
+};
+use crate::route::Route;
+use crate::scale::Scale;
+use crate::stats::{ClientStats, StatsCounters};
+use crate::tag::{TagKind, TagTable, TagValue};
+use crate::tcp::TcpTransport;
+use crate::transport::{
+    SocketOptions, Transport, TransportProtocol, UdpTransport, DEFAULT_FINS_PORT, DEFAULT_TIMEOUT,
+};
+use crate::types::{DataType, LengthPrefix, PlcValue, StringEncoding, WordOrder};
+use crate::utils::{self, native_to_words, words_to_native};
 
 /// Configuration for creating a FINS client.
+///
+/// With the `serde` feature it can be loaded from application configuration
+/// files. Durations are written as strings such as `"2s"`, `"250ms"` or
+/// `"1m30s"`, the PLC address with or without port, CPU profiles by preset
+/// name and tags as a table from name to address and type. Everything but
+/// the address and the two nodes may be left out and takes the value of
+/// [`ClientConfig::new`]:
+///
+/// ```toml
+/// plc_addr = "192.168.1.250"
+/// source = { node = 1 }
+/// destination = { node = 10 }
+/// timeout = "500ms"
+/// word_order = "high_first"
+/// cpu_profile = "CJ2M"
+///
+/// [tags]
+/// LineSpeed = { address = "D100", type = "REAL" }
+/// EStop = { address = "CIO0.05", type = "bit" }
+/// Operator = { address = "D200", type = "string", words = 10 }
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClientConfig {
     /// PLC IP address or hostname.
+    #[cfg_attr(feature = "serde", serde(with = "crate::config_serde::plc_addr"))]
     pub plc_addr: SocketAddr,
     /// Source node address (this client).
     pub source: NodeAddress,
     /// Destination node address (the PLC).
     pub destination: NodeAddress,
-    /// Communication timeout.
+    /// Receive timeout: how long to wait for a response.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default = "crate::config_serde::default_timeout",
+            with = "crate::config_serde::duration"
+        )
+    )]
     pub timeout: Duration,
+    /// Send timeout: how long a blocked send may take.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default = "crate::config_serde::default_timeout",
+            with = "crate::config_serde::duration"
+        )
+    )]
+    pub send_timeout: Duration,
+    /// FINS/TCP connect and handshake timeout (unused over UDP).
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default = "crate::config_serde::default_timeout",
+            with = "crate::config_serde::duration"
+        )
+    )]
+    pub connect_timeout: Duration,
+    /// Idle time before FINS/TCP keep-alive probes (zero disables them).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::config_serde::duration")
+    )]
+    pub tcp_keep_alive: Duration,
+    /// Transport protocol (UDP by default).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub protocol: TransportProtocol,
+    /// Socket tuning (buffers, TTL, TOS, broadcast).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub socket_options: SocketOptions,
+    /// Route responses to concurrent callers by SID (see [`with_pipelining`](Self::with_pipelining)).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pipelining: bool,
+    /// Gateway count and ICF flags applied to every command header.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub header_options: HeaderOptions,
+    /// Minimum delay between consecutive requests (zero disables pacing).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::config_serde::duration")
+    )]
+    pub min_request_interval: Duration,
+    /// Allow [`Client::reset_unit`] (off by default).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub allow_unit_reset: bool,
+    /// Allow bit access to DM (CS/CJ-series and newer CPUs; off by default).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dm_bit_access: bool,
+    /// CPU model whose area capacities replace the generic limits.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::config_serde::cpu_profile")
+    )]
+    pub cpu_profile: Option<CpuProfile>,
+    /// Layout of 32- and 64-bit values used by the typed helpers, or `None`
+    /// for each helper's default (see [`ClientConfig::with_word_order`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub word_order: Option<WordOrder>,
+    /// Named tags for [`Client::read_tag`] and [`Client::write_tag`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tags: TagTable,
+    /// Attach the failed request to read and write errors (off by default).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub error_context: bool,
+    /// Reject the 0x0040 routing warning instead of accepting it (off by default).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strict_end_codes: bool,
+    /// Retransmissions after a response with the wrong SID or command code
+    /// (see [`DEFAULT_SID_RETRIES`]).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::config_serde::default_sid_retries")
+    )]
+    pub sid_retries: u8,
 }
 
+/// Retransmissions after a SID or command mismatch unless
+/// [`ClientConfig::with_sid_retries`] says otherwise.
+pub const DEFAULT_SID_RETRIES: u8 = 3;
+
 impl ClientConfig {
     /// Creates a new client configuration with minimal required parameters.
     ///
@@ -105,9 +250,84 @@ impl ClientConfig {
             source: NodeAddress::new(0, source_node, 0),
             destination: NodeAddress::new(0, dest_node, 0),
             timeout: DEFAULT_TIMEOUT,
+            send_timeout: DEFAULT_TIMEOUT,
+            connect_timeout: DEFAULT_TIMEOUT,
+            tcp_keep_alive: Duration::ZERO,
+            protocol: TransportProtocol::Udp,
+            socket_options: SocketOptions::default(),
+            pipelining: false,
+            header_options: HeaderOptions::default(),
+            min_request_interval: Duration::ZERO,
+            allow_unit_reset: false,
+            dm_bit_access: false,
+            cpu_profile: None,
+            word_order: None,
+            tags: TagTable::new(),
+            error_context: false,
+            strict_end_codes: false,
+            sid_retries: DEFAULT_SID_RETRIES,
         }
     }
 
+    /// Creates a new client configuration from a hostname.
+    ///
+    /// The name is resolved once, using the system resolver, and the first
+    /// IPv4 address is used. This lets DHCP/DNS-driven deployments refer to
+    /// the PLC by name instead of a hard-coded IP.
+    ///
+    /// Only the resolved address is kept: [`Client::reconnect`] reuses it and
+    /// does not look the name up again. If the PLC can move to a new IP,
+    /// call `from_host` again and build a new client.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - PLC hostname or IP literal (port defaults to 9600)
+    /// * `source_node` - Source node number (this client)
+    /// * `dest_node` - Destination node number (the PLC)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The lookup fails (`FinsError::Io`)
+    /// - The host has no IPv4 address (`FinsError::InvalidParameter`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::ClientConfig;
+    ///
+    /// let config = ClientConfig::from_host("plc-line3.factory.local", 1, 0)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn from_host(host: &str, source_node: u8, dest_node: u8) -> Result<Self> {
+        Self::from_host_with_resolver(host, source_node, dest_node, &SystemResolver)
+    }
+
+    /// Creates a new client configuration from a hostname using a custom resolver.
+    ///
+    /// See [`from_host`](Self::from_host) for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::ClientConfig;
+    /// use std::net::{IpAddr, Ipv4Addr};
+    ///
+    /// let resolver = |_host: &str| Ok(vec![IpAddr::V4(Ipv4Addr::new(10, 0, 3, 20))]);
+    /// let config = ClientConfig::from_host_with_resolver("plc-line3", 1, 0, &resolver)?;
+    /// assert_eq!(config.plc_addr.ip(), Ipv4Addr::new(10, 0, 3, 20));
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn from_host_with_resolver(
+        host: &str,
+        source_node: u8,
+        dest_node: u8,
+        resolver: &dyn Resolver,
+    ) -> Result<Self> {
+        let ip = resolve_ipv4(host, resolver)?;
+        Ok(Self::new(ip, source_node, dest_node))
+    }
+
     /// Sets a custom PLC port (default is 9600).
     ///
     /// # Example
@@ -124,7 +344,7 @@ impl ClientConfig {
         self
     }
 
-    /// Sets a custom timeout (default is 2 seconds).
+    /// Sets both the send and receive timeouts (default is 2 seconds).
     ///
     /// # Example
     ///
@@ -138,163 +358,137 @@ impl ClientConfig {
     /// ```
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
+        self.send_timeout = timeout;
         self
     }
 
-    /// Sets custom source network/unit addresses.
+    /// Sets the receive (response) timeout only.
     ///
     /// # Example
     ///
     /// ```
     /// use omron_fins::ClientConfig;
     /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
     ///
     /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
-    ///     .with_source_network(1)
-    ///     .with_source_unit(0);
+    ///     .with_receive_timeout(Duration::from_millis(500))
+    ///     .with_send_timeout(Duration::from_millis(100));
     /// ```
-    pub fn with_source_network(mut self, network: u8) -> Self {
-        self.source.network = network;
+    pub fn with_receive_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
         self
     }
 
-    /// Sets custom source unit address.
-    pub fn with_source_unit(mut self, unit: u8) -> Self {
-        self.source.unit = unit;
+    /// Sets the send timeout only.
+    pub fn with_send_timeout(mut self, timeout: Duration) -> Self {
+        self.send_timeout = timeout;
         self
     }
 
-    /// Sets custom destination network/unit addresses.
-    pub fn with_dest_network(mut self, network: u8) -> Self {
-        self.destination.network = network;
+    /// Sets the FINS/TCP connect and handshake timeout.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
         self
     }
 
-    /// Sets custom destination unit address.
-    pub fn with_dest_unit(mut self, unit: u8) -> Self {
-        self.destination.unit = unit;
+    /// Sends FINS/TCP keep-alive probes after `interval` without traffic.
+    ///
+    /// A PLC that disappears while the client is idle is then detected
+    /// before the next request. Either way, a request that finds the
+    /// connection lost reconnects and fails with
+    /// `FinsError::ConnectionReset`. Ignored over UDP.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::ClientConfig;
+    /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
+    ///
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 0, 0)
+    ///     .with_tcp()
+    ///     .with_tcp_keep_alive(Duration::from_secs(30));
+    /// assert_eq!(config.tcp_keep_alive, Duration::from_secs(30));
+    /// ```
+    pub fn with_tcp_keep_alive(mut self, interval: Duration) -> Self {
+        self.tcp_keep_alive = interval;
         self
     }
-}
-
-/// FINS client for communicating with Omron PLCs.
-///
-/// Provides a simple API for reading and writing PLC memory.
-/// Each operation produces exactly 1 request and 1 response.
-/// No automatic retries, caching, or reconnection.
-///
-/// # Example
-///
-/// ```no_run
-/// use omron_fins::{Client, ClientConfig, MemoryArea};
-/// use std::net::Ipv4Addr;
-///
-/// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0);
-/// let client = Client::new(config).unwrap();
-///
-/// // Read 10 words from DM100
-/// let data = client.read(MemoryArea::DM, 100, 10).unwrap();
-///
-/// // Write values to DM200
-/// client.write(MemoryArea::DM, 200, &[0x1234, 0x5678]).unwrap();
-///
-/// // Read a single bit
-/// let bit = client.read_bit(MemoryArea::CIO, 0, 5).unwrap();
-///
-/// // Write a single bit
-/// client.write_bit(MemoryArea::CIO, 0, 5, true).unwrap();
-/// ```
-pub struct Client {
-    transport: UdpTransport,
-    source: NodeAddress,
-    destination: NodeAddress,
-    sid_counter: AtomicU8,
-}
 
-impl Client {
-    /// Creates a new FINS client with the given configuration.
-    ///
-    /// # Errors
+    /// Uses FINS/TCP instead of UDP.
     ///
-    /// Returns an error if the UDP transport cannot be created.
+    /// On connect the client performs the FINS/TCP node-address handshake
+    /// and uses the node number the PLC confirms as its source node. Pass
+    /// source node 0 to have the PLC assign a free node automatically.
     ///
     /// # Example
     ///
     /// ```no_run
     /// use omron_fins::{Client, ClientConfig};
     /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
     ///
-    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0);
-    /// let client = Client::new(config).unwrap();
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 0, 0)
+    ///     .with_tcp()
+    ///     .with_connect_timeout(Duration::from_secs(10));
+    /// let client = Client::new(config)?;
+    /// println!("assigned node {}", client.source().node);
+    /// # Ok::<(), omron_fins::FinsError>(())
     /// ```
-    pub fn new(config: ClientConfig) -> Result<Self> {
-        let transport = UdpTransport::new(config.plc_addr, config.timeout)?;
-
-        // Drain any stale packets from previous sessions
-        transport.drain_pending();
-
-        Ok(Self {
-            transport,
-            source: config.source,
-            destination: config.destination,
-            sid_counter: AtomicU8::new(0),
-        })
+    pub fn with_tcp(self) -> Self {
+        self.with_protocol(TransportProtocol::Tcp)
     }
 
-    /// Generates the next Service ID.
-    fn next_sid(&self) -> u8 {
-        self.sid_counter.fetch_add(1, Ordering::Relaxed)
+    /// Sets the transport protocol.
+    pub fn with_protocol(mut self, protocol: TransportProtocol) -> Self {
+        self.protocol = protocol;
+        self
     }
 
-    /// Sends a command and receives the response, with SID validation and retry.
+    /// Sets socket tuning options for the underlying UDP socket.
     ///
-    /// If the received response has a mismatched SID (stale packet), it will
-    /// drain pending packets and retry up to MAX_SID_RETRIES times.
-    fn send_receive_with_sid(&self, data: &[u8], expected_sid: u8) -> Result<FinsResponse> {
-        use crate::error::FinsError;
-        const MAX_SID_RETRIES: usize = 3;
-
-        for attempt in 0..=MAX_SID_RETRIES {
-            // On retry, drain any stale packets first
-            if attempt > 0 {
-                self.transport.drain_pending();
-            }
-
-            let response_bytes = self.transport.send_receive(data)?;
-            let response = FinsResponse::from_bytes(&response_bytes)?;
-
-            if response.header.sid == expected_sid {
-                return Ok(response);
-            }
-
-            // Log mismatch on first attempt only (for debugging)
-            if attempt == 0 {
-                // SID mismatch - stale packet detected, will retry
-            }
-        }
-
-        // All retries failed - return error with last received SID
-        // Drain and try one more time to get the actual received SID for error message
-        self.transport.drain_pending();
-        let response_bytes = self.transport.send_receive(data)?;
-        let response = FinsResponse::from_bytes(&response_bytes)?;
-        Err(FinsError::sid_mismatch(expected_sid, response.header.sid))
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{ClientConfig, SocketOptions};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_socket_options(SocketOptions::new().with_dscp(46));
+    /// ```
+    pub fn with_socket_options(mut self, options: SocketOptions) -> Self {
+        self.socket_options = options;
+        self
     }
 
-    /// Reads words from PLC memory.
+    /// Binds the local socket to port 9600 with `SO_REUSEADDR`.
     ///
-    /// # Arguments
+    /// Needed for Ethernet units that only reply to source port 9600.
+    /// See [`SocketOptions::with_fins_source_port`] for how this interacts
+    /// with several clients on one host.
     ///
-    /// * `area` - Memory area to read from
-    /// * `address` - Starting word address
-    /// * `count` - Number of words to read (1-999)
+    /// # Example
     ///
-    /// # Errors
+    /// ```
+    /// use omron_fins::ClientConfig;
+    /// use std::net::Ipv4Addr;
     ///
-    /// Returns an error if:
-    /// - Count is 0 or > 999
-    /// - Communication fails
-    /// - PLC returns an error
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_fins_source_port();
+    /// assert_eq!(config.socket_options.local_port, Some(9600));
+    /// ```
+    pub fn with_fins_source_port(mut self) -> Self {
+        self.socket_options = self.socket_options.with_fins_source_port();
+        self
+    }
+
+    /// Enables concurrent in-flight requests (default is off).
+    ///
+    /// When enabled, the client starts a background thread that receives
+    /// all responses and hands each to the request with the matching
+    /// Service ID, so threads sharing one client no longer serialize on the
+    /// socket. At most 256 requests can be outstanding at a time.
     ///
     /// # Example
     ///
@@ -302,508 +496,617 @@ impl Client {
     /// use omron_fins::{Client, ClientConfig, MemoryArea};
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
-    ///
-    /// let data = client.read(MemoryArea::DM, 100, 10).unwrap();
-    /// println!("Read {} words: {:?}", data.len(), data);
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_pipelining(true);
+    /// let client = Client::new(config)?;
+    ///
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| client.read(MemoryArea::DM, 0, 10));
+    ///     s.spawn(|| client.read(MemoryArea::DM, 100, 10));
+    /// });
+    /// # Ok::<(), omron_fins::FinsError>(())
     /// ```
-    pub fn read(&self, area: MemoryArea, mut address: u16, mut count: u16) -> Result<Vec<u16>> {
-        area.check_bounds(address, count)?;
-
-        let mut result = Vec::with_capacity(count as usize);
-
-        while count > 0 {
-            let chunk_size = std::cmp::min(count, MAX_WORDS_PER_COMMAND);
-
-            let sid = self.next_sid();
-            let cmd = ReadWordCommand::new(
-                self.destination,
-                self.source,
-                sid,
-                area,
-                address,
-                chunk_size,
-            )?;
-            let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
-            response.check_error()?;
-
-            let words = response.to_words()?;
-            result.extend(words);
-
-            address += chunk_size;
-            count -= chunk_size;
-
-            if count > 0 {
-                std::thread::sleep(std::time::Duration::from_millis(1));
-            }
-        }
-
-        Ok(result)
+    pub fn with_pipelining(mut self, enabled: bool) -> Self {
+        self.pipelining = enabled;
+        self
     }
 
-    /// Writes words to PLC memory.
+    /// Sets the gateway count and ICF flags used in command headers.
     ///
-    /// # Arguments
+    /// Multi-level Controller Link routes may need a specific gateway
+    /// count. Clearing the "response required" flag only changes the ICF
+    /// byte; the client still waits for a reply, so use it only with
+    /// nodes that answer regardless.
     ///
-    /// * `area` - Memory area to write to
-    /// * `address` - Starting word address
-    /// * `data` - Words to write (1-999 words)
+    /// # Example
     ///
-    /// # Errors
+    /// ```
+    /// use omron_fins::{ClientConfig, HeaderOptions};
+    /// use std::net::Ipv4Addr;
     ///
-    /// Returns an error if:
-    /// - Data is empty or > 999 words
-    /// - Communication fails
-    /// - PLC returns an error
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_dest_network(2)
+    ///     .with_header_options(HeaderOptions::new().with_gct(0x02));
+    /// assert_eq!(config.header_options.gct, 0x02);
+    /// ```
+    pub fn with_header_options(mut self, options: HeaderOptions) -> Self {
+        self.header_options = options;
+        self
+    }
+
+    /// Sets a minimum delay between the start of consecutive requests.
+    ///
+    /// Some older Ethernet option boards (e.g., on CP1L) drop frames that
+    /// arrive back-to-back. With pacing enabled the client waits before
+    /// sending whenever the previous request started less than `interval`
+    /// ago, so callers don't need their own `sleep()` calls.
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// ```
+    /// use omron_fins::ClientConfig;
     /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
-    ///
-    /// client.write(MemoryArea::DM, 100, &[0x1234, 0x5678]).unwrap();
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_min_request_interval(Duration::from_millis(20));
     /// ```
-    pub fn write(&self, area: MemoryArea, mut address: u16, data: &[u16]) -> Result<()> {
-        area.check_bounds(address, data.len() as u16)?;
-
-        let mut data_index = 0;
-        let mut count = data.len() as u16;
-
-        while count > 0 {
-            let chunk_size = std::cmp::min(count, MAX_WORDS_PER_COMMAND);
-            let chunk_data = &data[data_index..(data_index + chunk_size as usize)];
-
-            let sid = self.next_sid();
-            let cmd = WriteWordCommand::new(
-                self.destination,
-                self.source,
-                sid,
-                area,
-                address,
-                chunk_data,
-            )?;
-            let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
-            response.check_error()?;
-
-            address += chunk_size;
-            data_index += chunk_size as usize;
-            count -= chunk_size;
-
-            if count > 0 {
-                std::thread::sleep(std::time::Duration::from_millis(1));
-            }
-        }
-
-        Ok(())
+    pub fn with_min_request_interval(mut self, interval: Duration) -> Self {
+        self.min_request_interval = interval;
+        self
     }
 
-    /// Reads a single bit from PLC memory.
-    ///
-    /// # Arguments
+    /// Limits the client to at most `rate` requests per second.
     ///
-    /// * `area` - Memory area to read from (must support bit access)
-    /// * `address` - Word address
-    /// * `bit` - Bit position (0-15)
+    /// Shorthand for [`with_min_request_interval`](Self::with_min_request_interval)
+    /// with an interval of `1s / rate`. A rate of 0 disables pacing.
     ///
-    /// # Errors
+    /// # Example
     ///
-    /// Returns an error if:
-    /// - Area doesn't support bit access (DM)
-    /// - Bit position > 15
-    /// - Communication fails
-    /// - PLC returns an error
+    /// ```
+    /// use omron_fins::ClientConfig;
+    /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
+    ///
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_max_requests_per_second(50);
+    /// assert_eq!(config.min_request_interval, Duration::from_millis(20));
+    /// ```
+    pub fn with_max_requests_per_second(mut self, rate: u32) -> Self {
+        self.min_request_interval = if rate == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs(1) / rate
+        };
+        self
+    }
+
+    /// Allows [`Client::reset_unit`] to send Reset commands.
+    ///
+    /// Resetting a unit interrupts all communication through it and, for a
+    /// CPU unit, stops the running program. The flag exists so a reset
+    /// cannot be issued by accident.
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// ```
+    /// use omron_fins::ClientConfig;
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
-    ///
-    /// let bit = client.read_bit(MemoryArea::CIO, 0, 5).unwrap();
-    /// println!("CIO 0.05 = {}", bit);
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_unit_reset_allowed(true);
     /// ```
-    pub fn read_bit(&self, area: MemoryArea, address: u16, bit: u8) -> Result<bool> {
-        let sid = self.next_sid();
-        let cmd = ReadBitCommand::new(self.destination, self.source, sid, area, address, bit)?;
-
-        let response = self.send_receive_with_sid(&cmd.to_bytes()?, sid)?;
-        response.check_error()?;
-        response.to_bit()
+    pub fn with_unit_reset_allowed(mut self, allowed: bool) -> Self {
+        self.allow_unit_reset = allowed;
+        self
     }
 
-    /// Writes a single bit to PLC memory.
+    /// Enables bit access to the DM area (e.g., D100.05).
     ///
-    /// # Arguments
+    /// CS/CJ-series and newer CPUs support DM bit addressing; older
+    /// families reject it, so [`Client::read_bit`] and [`Client::write_bit`]
+    /// refuse DM unless this is set.
     ///
-    /// * `area` - Memory area to write to (must support bit access)
-    /// * `address` - Word address
-    /// * `bit` - Bit position (0-15)
-    /// * `value` - Bit value to write
+    /// # Example
     ///
-    /// # Errors
+    /// ```
+    /// use omron_fins::ClientConfig;
+    /// use std::net::Ipv4Addr;
     ///
-    /// Returns an error if:
-    /// - Area doesn't support bit access (DM)
-    /// - Bit position > 15
-    /// - Communication fails
-    /// - PLC returns an error
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_dm_bit_access(true);
+    /// assert!(config.dm_bit_access);
+    /// ```
+    pub fn with_dm_bit_access(mut self, enabled: bool) -> Self {
+        self.dm_bit_access = enabled;
+        self
+    }
+
+    /// Wraps read and write errors in `FinsError::WithContext`, naming the
+    /// operation, area, address, word count and SID of the failed command.
+    ///
+    /// Off by default so existing matches on the bare error keep working;
+    /// when enabled,
+    /// [`FinsError::without_context`](crate::FinsError::without_context)
+    /// recovers it.
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// ```
+    /// use omron_fins::ClientConfig;
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
-    ///
-    /// client.write_bit(MemoryArea::CIO, 0, 5, true).unwrap();
+    /// // Errors read e.g. "... while reading DM 32760 len 20 (SID 0x05)"
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_error_context(true);
+    /// assert!(config.error_context);
     /// ```
-    pub fn write_bit(&self, area: MemoryArea, address: u16, bit: u8, value: bool) -> Result<()> {
-        let sid = self.next_sid();
-        let cmd = WriteBitCommand::new(
-            self.destination,
-            self.source,
-            sid,
-            area,
-            address,
-            bit,
-            value,
-        )?;
-
-        let response = self.send_receive_with_sid(&cmd.to_bytes()?, sid)?;
-        response.check_error()?;
-        Ok(())
+    pub fn with_error_context(mut self, enabled: bool) -> Self {
+        self.error_context = enabled;
+        self
     }
 
-    /// Fills a memory area with a single value.
+    /// Treats end code 0x0040 as an error.
     ///
-    /// # Arguments
+    /// By default the routing warning 0x0040 is accepted like a normal
+    /// completion, as most Omron tools do (see
+    /// [`FinsResponse::check_error`]). In strict mode every client method
+    /// fails with `FinsError::PlcError` instead, so no warning passes
+    /// unnoticed. [`Client::read_with_warnings`] and
+    /// [`Client::write_with_warnings`] still report it as a warning, and
+    /// [`Client::execute_raw`] never checks end codes.
     ///
-    /// * `area` - Memory area to fill
-    /// * `address` - Starting word address
-    /// * `count` - Number of words to fill (1-999)
-    /// * `value` - Value to fill with
+    /// # Example
     ///
-    /// # Errors
+    /// ```
+    /// use omron_fins::ClientConfig;
+    /// use std::net::Ipv4Addr;
     ///
-    /// Returns an error if:
-    /// - Count is 0 or > 999
-    /// - Communication fails
-    /// - PLC returns an error
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_strict_end_codes(true);
+    /// assert!(config.strict_end_codes);
+    /// ```
+    pub fn with_strict_end_codes(mut self, strict: bool) -> Self {
+        self.strict_end_codes = strict;
+        self
+    }
+
+    /// Sets how often a command is resent after a response with the wrong
+    /// SID arrives (default [`DEFAULT_SID_RETRIES`]).
+    ///
+    /// A response with the right SID that answers a different command is a
+    /// late response from before the SID counter wrapped, and is retried the
+    /// same way. Each retry first drains stale packets from the socket, but
+    /// a retry sends the command again, so a write the PLC already carried
+    /// out may run twice. With 0 the first mismatch fails with
+    /// `FinsError::SidMismatch` or `FinsError::CommandMismatch` and every
+    /// command is sent exactly once.
+    ///
+    /// Unused over FINS/TCP, which discards responses with another SID
+    /// without resending, and in pipelined mode, where responses are routed
+    /// by SID.
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// ```
+    /// use omron_fins::ClientConfig;
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
-    ///
-    /// // Zero out DM100-DM149
-    /// client.fill(MemoryArea::DM, 100, 50, 0x0000).unwrap();
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_sid_retries(0);
+    /// assert_eq!(config.sid_retries, 0);
     /// ```
-    pub fn fill(
-        &self,
-        area: MemoryArea,
-        mut address: u16,
-        mut count: u16,
-        value: u16,
-    ) -> Result<()> {
-        area.check_bounds(address, count)?;
-
-        while count > 0 {
-            let chunk_size = std::cmp::min(count, MAX_WORDS_PER_COMMAND);
-            let sid = self.next_sid();
-            let cmd = FillCommand::new(
-                self.destination,
-                self.source,
-                sid,
-                area,
-                address,
-                chunk_size,
-                value,
-            )?;
-
-            let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
-            response.check_error()?;
-
-            address += chunk_size;
-            count -= chunk_size;
-
-            if count > 0 {
-                std::thread::sleep(std::time::Duration::from_millis(1));
-            }
-        }
+    pub fn with_sid_retries(mut self, retries: u8) -> Self {
+        self.sid_retries = retries;
+        self
+    }
 
-        Ok(())
+    /// Validates addresses against the area sizes of a CPU model.
+    ///
+    /// Reads and writes past the end of an area then fail locally with
+    /// `FinsError::InvalidAddressing` instead of an end code from the PLC.
+    /// Without a profile the generic [`MemoryArea::max_words`] limits apply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{ClientConfig, CpuProfile};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_cpu_profile(CpuProfile::CJ2M);
+    /// assert_eq!(config.cpu_profile, Some(CpuProfile::CJ2M));
+    /// ```
+    pub fn with_cpu_profile(mut self, profile: CpuProfile) -> Self {
+        self.cpu_profile = Some(profile);
+        self
     }
 
-    /// Puts the PLC into run mode.
+    /// Sets how the typed helpers lay out 32- and 64-bit values.
     ///
-    /// # Arguments
+    /// Applies to [`Client::read_f32`], [`Client::read_i32`],
+    /// [`Client::read_f64`], [`Client::read_u64`], their write counterparts,
+    /// the BCD double-word helpers and [`Client::read_struct`]/
+    /// [`Client::write_struct`]. Without a word order the helpers use
+    /// [`WordOrder::LowFirst`], the layout of Omron CPUs, except
+    /// [`Client::read_i32`], [`Client::write_i32`] and their slice variants,
+    /// which keep storing the high word first as in earlier releases.
     ///
-    /// * `mode` - PLC operating mode (Debug, Monitor, or Run)
+    /// # Example
     ///
-    /// # Errors
+    /// ```
+    /// use omron_fins::{ClientConfig, WordOrder};
+    /// use std::net::Ipv4Addr;
     ///
-    /// Returns an error if communication fails or PLC returns an error.
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_word_order(WordOrder::HighFirst);
+    /// assert_eq!(config.word_order, Some(WordOrder::HighFirst));
+    /// ```
+    pub fn with_word_order(mut self, order: WordOrder) -> Self {
+        self.word_order = Some(order);
+        self
+    }
+
+    /// Sets the named tags available to [`Client::read_tag`] and
+    /// [`Client::write_tag`].
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// use omron_fins::{Client, ClientConfig, PlcMode};
+    /// ```
+    /// use omron_fins::{ClientConfig, DataType, TagKind, TagTable};
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
+    /// let mut tags = TagTable::new();
+    /// tags.add("LineSpeed", "D100", TagKind::Value(DataType::REAL))?;
     ///
-    /// client.run(PlcMode::Monitor).unwrap();
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0).with_tags(tags);
+    /// assert_eq!(config.tags.len(), 1);
+    /// # Ok::<(), omron_fins::FinsError>(())
     /// ```
-    pub fn run(&self, mode: PlcMode) -> Result<()> {
-        let sid = self.next_sid();
-        let cmd = RunCommand::new(self.destination, self.source, sid, mode);
-
-        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
-        response.check_error()?;
-        Ok(())
+    pub fn with_tags(mut self, tags: TagTable) -> Self {
+        self.tags = tags;
+        self
     }
 
-    /// Stops the PLC.
+    /// Addresses commands to the destination of a validated [`Route`].
     ///
-    /// # Errors
+    /// # Example
     ///
-    /// Returns an error if communication fails or PLC returns an error.
+    /// ```
+    /// use omron_fins::{ClientConfig, NodeAddress, Route};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let route = Route::new(NodeAddress::new(2, 5, 0), &[NodeAddress::new(1, 10, 0)])?;
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 10).with_route(&route);
+    /// assert_eq!(config.destination.network, 2);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn with_route(mut self, route: &Route) -> Self {
+        self.destination = route.destination();
+        self
+    }
+
+    /// Sets custom source network/unit addresses.
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// use omron_fins::{Client, ClientConfig};
+    /// ```
+    /// use omron_fins::ClientConfig;
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
-    ///
-    /// client.stop().unwrap();
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_source_network(1)
+    ///     .with_source_unit(0);
     /// ```
-    pub fn stop(&self) -> Result<()> {
-        let sid = self.next_sid();
-        let cmd = StopCommand::new(self.destination, self.source, sid);
-
-        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
-        response.check_error()?;
-        Ok(())
+    pub fn with_source_network(mut self, network: u8) -> Self {
+        self.source.network = network;
+        self
     }
 
-    /// Transfers data from one memory area to another within the PLC.
-    ///
-    /// # Arguments
+    /// Sets the source node to the last octet of the local IP address used
+    /// to reach the PLC, following Omron's convention of matching FINS node
+    /// numbers to IP addresses.
     ///
-    /// * `src_area` - Source memory area
-    /// * `src_address` - Source starting address
-    /// * `dst_area` - Destination memory area
-    /// * `dst_address` - Destination starting address
-    /// * `count` - Number of words to transfer (1-999)
+    /// The interface is chosen by the operating system's routing table; no
+    /// packet is sent.
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - Count is 0 or > 999
-    /// - Communication fails
-    /// - PLC returns an error
+    /// - No local address can reach the PLC (`FinsError::Io`)
+    /// - The local address is not IPv4, or its last octet is 0 or 255 and
+    ///   cannot be a node number (`FinsError::InvalidParameter`)
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// ```
+    /// use omron_fins::ClientConfig;
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
-    ///
-    /// // Copy DM100-DM109 to DM200-DM209
-    /// client.transfer(MemoryArea::DM, 100, MemoryArea::DM, 200, 10).unwrap();
+    /// let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 0, 10).with_auto_source_node()?;
+    /// // Reached through 127.0.0.1
+    /// assert_eq!(config.source.node, 1);
+    /// # Ok::<(), omron_fins::FinsError>(())
     /// ```
-    pub fn transfer(
-        &self,
-        src_area: MemoryArea,
-        mut src_address: u16,
-        dst_area: MemoryArea,
-        mut dst_address: u16,
-        mut count: u16,
-    ) -> Result<()> {
-        src_area.check_bounds(src_address, count)?;
-        dst_area.check_bounds(dst_address, count)?;
+    pub fn with_auto_source_node(mut self) -> Result<Self> {
+        let socket = std::net::UdpSocket::bind((std::net::Ipv4Addr::UNSPECIFIED, 0))?;
+        socket.connect(self.plc_addr)?;
+        self.source.node = node_from_ip(socket.local_addr()?.ip())?;
+        Ok(self)
+    }
 
-        while count > 0 {
-            let chunk_size = std::cmp::min(count, MAX_WORDS_PER_COMMAND);
-            let sid = self.next_sid();
-            let cmd = TransferCommand::new(
-                self.destination,
-                self.source,
-                sid,
-                src_area,
-                src_address,
-                dst_area,
-                dst_address,
-                chunk_size,
-            )?;
+    /// Sets custom source unit address.
+    pub fn with_source_unit(mut self, unit: u8) -> Self {
+        self.source.unit = unit;
+        self
+    }
 
-            let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
-            response.check_error()?;
-
-            src_address += chunk_size;
-            dst_address += chunk_size;
-            count -= chunk_size;
-
-            if count > 0 {
-                std::thread::sleep(std::time::Duration::from_millis(1));
-            }
-        }
+    /// Sets custom destination network/unit addresses.
+    pub fn with_dest_network(mut self, network: u8) -> Self {
+        self.destination.network = network;
+        self
+    }
 
-        Ok(())
+    /// Sets custom destination unit address.
+    pub fn with_dest_unit(mut self, unit: u8) -> Self {
+        self.destination.unit = unit;
+        self
     }
 
-    /// Forces bits ON/OFF in the PLC, overriding normal program control.
-    ///
-    /// # Arguments
-    ///
-    /// * `specs` - List of bits to force with their specifications
+    /// Checks the configuration for values a PLC would reject.
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - Specs is empty
-    /// - Any area doesn't support bit access
-    /// - Any bit position > 15
-    /// - Communication fails
-    /// - PLC returns an error
+    /// Returns `FinsError::InvalidParameter` if:
+    /// - A node number is above 254 or a network number above 127
+    /// - A timeout is zero
+    /// - Source and destination are the same node on the same network,
+    ///   unless source node 0 asks a FINS/TCP PLC to assign one
+    /// - Pipelining is enabled over FINS/TCP
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// use omron_fins::{Client, ClientConfig, ForcedBit, ForceSpec, MemoryArea};
+    /// ```
+    /// use omron_fins::ClientConfig;
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
-    ///
-    /// client.forced_set_reset(&[
-    ///     ForcedBit { area: MemoryArea::CIO, address: 0, bit: 0, spec: ForceSpec::ForceOn },
-    ///     ForcedBit { area: MemoryArea::CIO, address: 0, bit: 1, spec: ForceSpec::ForceOff },
-    /// ]).unwrap();
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 10, 10);
+    /// assert!(config.validate().is_err());
     /// ```
-    pub fn forced_set_reset(&self, specs: &[ForcedBit]) -> Result<()> {
-        let sid = self.next_sid();
-        let cmd = ForcedSetResetCommand::new(self.destination, self.source, sid, specs.to_vec())?;
+    pub fn validate(&self) -> Result<()> {
+        use crate::error::FinsError;
 
-        let response = self.send_receive_with_sid(&cmd.to_bytes()?, sid)?;
-        response.check_error()?;
+        for (name, address) in [("source", self.source), ("destination", self.destination)] {
+            if address.node > 254 {
+                return Err(FinsError::invalid_parameter(
+                    name,
+                    format!("node {} is outside 0-254", address.node),
+                ));
+            }
+            if address.network > 127 {
+                return Err(FinsError::invalid_parameter(
+                    name,
+                    format!("network {} is outside 0-127", address.network),
+                ));
+            }
+        }
+        for (name, timeout) in [
+            ("timeout", self.timeout),
+            ("send_timeout", self.send_timeout),
+            ("connect_timeout", self.connect_timeout),
+        ] {
+            if timeout.is_zero() {
+                return Err(FinsError::invalid_parameter(
+                    name,
+                    "must be greater than zero",
+                ));
+            }
+        }
+        // Over FINS/TCP, source node 0 asks the PLC to assign a node
+        let auto_node = self.protocol == TransportProtocol::Tcp && self.source.node == 0;
+        if !auto_node
+            && self.source.network == self.destination.network
+            && self.source.node == self.destination.node
+        {
+            return Err(FinsError::invalid_parameter(
+                "source",
+                format!(
+                    "source and destination are both node {} on network {}",
+                    self.source.node, self.source.network
+                ),
+            ));
+        }
+        if self.pipelining && self.protocol == TransportProtocol::Tcp {
+            return Err(FinsError::invalid_parameter(
+                "pipelining",
+                "only supported over UDP",
+            ));
+        }
         Ok(())
     }
 
-    /// Cancels all forced bits in the PLC.
+    /// Finishes a `with_*` chain, returning the configuration once
+    /// [`validate`](Self::validate) accepts it.
     ///
     /// # Errors
     ///
-    /// Returns an error if communication fails or PLC returns an error.
+    /// Returns the errors of [`validate`](Self::validate).
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// use omron_fins::{Client, ClientConfig};
+    /// ```
+    /// use omron_fins::ClientConfig;
     /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 10)
+    ///     .with_timeout(Duration::from_millis(500))
+    ///     .with_dest_network(2)
+    ///     .build()?;
     ///
-    /// client.forced_set_reset_cancel().unwrap();
+    /// let invalid = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 10)
+    ///     .with_timeout(Duration::ZERO)
+    ///     .build();
+    /// assert!(invalid.is_err());
+    /// # Ok::<(), omron_fins::FinsError>(())
     /// ```
-    pub fn forced_set_reset_cancel(&self) -> Result<()> {
-        let sid = self.next_sid();
-        let cmd = ForcedSetResetCancelCommand::new(self.destination, self.source, sid);
+    pub fn build(self) -> Result<Self> {
+        self.validate()?;
+        Ok(self)
+    }
+}
 
-        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
-        response.check_error()?;
-        Ok(())
+/// Per-request overrides for a single client call.
+///
+/// # Example
+///
+/// ```no_run
+/// use omron_fins::{Client, ClientConfig, MemoryArea, RequestOptions};
+/// use std::net::Ipv4Addr;
+/// use std::time::Duration;
+///
+/// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+///
+/// // Allow a slow read more time than the client-wide timeout
+/// let options = RequestOptions::new().with_timeout(Duration::from_secs(30));
+/// let data = client.read_with_options(MemoryArea::DM, 0, 999, &options)?;
+/// # Ok::<(), omron_fins::FinsError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestOptions {
+    /// Response timeout for this request (`None` uses the client timeout).
+    pub timeout: Option<Duration>,
+}
+
+impl RequestOptions {
+    /// Creates options that use the client defaults.
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Reads from multiple memory areas in a single request.
-    ///
-    /// # Arguments
-    ///
-    /// * `specs` - List of read specifications
-    ///
-    /// # Returns
-    ///
-    /// A vector of u16 values in the same order as the specs.
-    /// For word reads, the full u16 value is returned.
-    /// For bit reads, 0x0000 (OFF) or 0x0001 (ON) is returned.
+    /// Sets the response timeout for this request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+/// FINS client for communicating with Omron PLCs.
+///
+/// Provides a simple API for reading and writing PLC memory.
+/// Each operation produces 1 request and 1 response, except that blocks
+/// larger than [`MAX_WORDS_PER_COMMAND`](crate::MAX_WORDS_PER_COMMAND) are
+/// split into sequential commands. Nothing is cached.
+///
+/// The only automatic retry is for a late answer to an earlier request,
+/// i.e. a response with the wrong SID or command code: over UDP it is
+/// discarded and the request sent again, up to
+/// [`ClientConfig::sid_retries`] times. FINS/TCP never resends; it skips
+/// responses with another SID. Other errors are returned to the caller.
+///
+/// Over UDP the client does not reconnect on its own; call
+/// [`reconnect`](Self::reconnect) after a network change. FINS/TCP
+/// re-runs its handshake when the PLC drops the connection and reports it
+/// as `FinsError::ConnectionReset`, and after a receive timeout. With
+/// [`ClientConfig::with_pipelining`], requests from several threads share
+/// the socket and are in flight at once.
+///
+/// # Example
+///
+/// ```no_run
+/// use omron_fins::{Client, ClientConfig, MemoryArea};
+/// use std::net::Ipv4Addr;
+///
+/// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0);
+/// let client = Client::new(config).unwrap();
+///
+/// // Read 10 words from DM100
+/// let data = client.read(MemoryArea::DM, 100, 10).unwrap();
+///
+/// // Write values to DM200
+/// client.write(MemoryArea::DM, 200, &[0x1234, 0x5678]).unwrap();
+///
+/// // Read a single bit
+/// let bit = client.read_bit(MemoryArea::CIO, 0, 5).unwrap();
+///
+/// // Write a single bit
+/// client.write_bit(MemoryArea::CIO, 0, 5, true).unwrap();
+/// ```
+pub struct Client {
+    transport: Transport,
+    source: NodeAddress,
+    destination: NodeAddress,
+    sid_counter: AtomicU8,
+    timeout: Duration,
+    demux: Option<Demux>,
+    config: ClientConfig,
+    frame_observer: Option<FrameObserver>,
+    recorder: Option<SessionRecorder>,
+    metrics: Option<Box<dyn MetricsSink>>,
+    stats: Arc<StatsCounters>,
+    next_request_at: Mutex<Option<Instant>>,
+}
+
+impl Client {
+    /// Creates a new FINS client with the given configuration.
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - Specs is empty
-    /// - Any bit area doesn't support bit access
-    /// - Any bit position > 15
-    /// - Communication fails
-    /// - PLC returns an error
+    /// Returns an error if the UDP transport cannot be created, or if the
+    /// FINS/TCP connection or node-address handshake fails.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use omron_fins::{Client, ClientConfig, MultiReadSpec, MemoryArea};
+    /// use omron_fins::{Client, ClientConfig};
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
-    ///
-    /// let values = client.read_multiple(&[
-    ///     MultiReadSpec { area: MemoryArea::DM, address: 100, bit: None },
-    ///     MultiReadSpec { area: MemoryArea::DM, address: 200, bit: None },
-    ///     MultiReadSpec { area: MemoryArea::CIO, address: 0, bit: Some(5) },
-    /// ]).unwrap();
-    /// // values[0] = DM100, values[1] = DM200, values[2] = CIO0.05 (0 or 1)
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0);
+    /// let client = Client::new(config).unwrap();
     /// ```
-    pub fn read_multiple(&self, specs: &[MultiReadSpec]) -> Result<Vec<u16>> {
-        let sid = self.next_sid();
-        let cmd = MultipleReadCommand::new(self.destination, self.source, sid, specs.to_vec())?;
+    pub fn new(config: ClientConfig) -> Result<Self> {
+        let stats = Arc::new(StatsCounters::default());
+        let (transport, demux) = Self::connect(&config, &stats)?;
+        let source = Self::effective_source(&config, &transport);
 
-        let response = self.send_receive_with_sid(&cmd.to_bytes()?, sid)?;
-        response.check_error()?;
-        response.to_words()
+        Ok(Self {
+            transport,
+            source,
+            destination: config.destination,
+            sid_counter: AtomicU8::new(0),
+            timeout: config.timeout,
+            demux,
+            config,
+            frame_observer: None,
+            recorder: None,
+            metrics: None,
+            stats,
+            next_request_at: Mutex::new(None),
+        })
     }
 
-    /// Reads an f32 (REAL) value from 2 consecutive words.
+    /// Creates an independent client with the same configuration, e.g. one
+    /// per worker thread.
     ///
-    /// # Arguments
+    /// The clone opens its own socket (or FINS/TCP connection) with its own
+    /// SID counter. Responses are matched on the socket that sent the
+    /// request, so clones never see each other's responses even when their
+    /// SIDs coincide. The clone starts with fresh statistics and without
+    /// the frame observer, session recorder or metrics sink of this client.
     ///
-    /// * `area` - Memory area to read from
-    /// * `address` - Starting word address
+    /// A client can also be shared between threads by reference; cloning
+    /// avoids contention on one socket and keeps each worker's timeouts
+    /// independent.
+    ///
+    /// A UDP client bound to a fixed local port (see
+    /// [`ClientConfig::with_fins_source_port`]) cannot be cloned: with
+    /// `SO_REUSEADDR` the clone could bind the same port, and both sockets
+    /// would then compete for the same PLC's responses.
     ///
     /// # Errors
     ///
-    /// Returns an error if communication fails or PLC returns an error.
+    /// Returns `FinsError::InvalidParameter` if the configuration binds a
+    /// fixed local port over UDP, or the errors of [`Client::new`].
     ///
     /// # Example
     ///
@@ -811,229 +1114,679 @@ impl Client {
     /// use omron_fins::{Client, ClientConfig, MemoryArea};
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
     ///
-    /// let temperature: f32 = client.read_f32(MemoryArea::DM, 100).unwrap();
+    /// let worker = client.try_clone()?;
+    /// let handle = std::thread::spawn(move || worker.read(MemoryArea::DM, 0, 10));
+    /// let status = client.read(MemoryArea::DM, 100, 1)?;
+    /// let data = handle.join().unwrap()?;
+    /// # Ok::<(), omron_fins::FinsError>(())
     /// ```
-    pub fn read_f32(&self, area: MemoryArea, address: u16) -> Result<f32> {
-        let words = self.read(area, address, 2)?;
-        // Omron uses word swap: low word first, high word second
-        let bytes = [
-            (words[1] >> 8) as u8,
-            (words[1] & 0xFF) as u8,
-            (words[0] >> 8) as u8,
-            (words[0] & 0xFF) as u8,
-        ];
-        Ok(f32::from_be_bytes(bytes))
+    pub fn try_clone(&self) -> Result<Self> {
+        if self.config.protocol == TransportProtocol::Udp
+            && self.config.socket_options.local_port.is_some()
+        {
+            return Err(crate::error::FinsError::invalid_parameter(
+                "socket_options",
+                "a client bound to a fixed local port cannot be cloned",
+            ));
+        }
+        Self::new(self.config.clone())
     }
 
-    /// Writes an f32 (REAL) value to 2 consecutive words.
-    ///
-    /// # Arguments
-    ///
-    /// * `area` - Memory area to write to
-    /// * `address` - Starting word address
-    /// * `value` - f32 value to write
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if communication fails or PLC returns an error.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// use omron_fins::{Client, ClientConfig, MemoryArea};
-    /// use std::net::Ipv4Addr;
-    ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
-    ///
-    /// client.write_f32(MemoryArea::DM, 100, 3.14159).unwrap();
-    /// ```
-    pub fn write_f32(&self, area: MemoryArea, address: u16, value: f32) -> Result<()> {
-        let bytes = value.to_be_bytes();
-        // Omron uses word swap: low word first, high word second
-        let words = [
-            u16::from_be_bytes([bytes[2], bytes[3]]),
-            u16::from_be_bytes([bytes[0], bytes[1]]),
-        ];
-        self.write(area, address, &words)
+    /// Creates the transport (and receiver, in pipelined mode) for `config`.
+    fn connect(
+        config: &ClientConfig,
+        stats: &Arc<StatsCounters>,
+    ) -> Result<(Transport, Option<Demux>)> {
+        use crate::error::FinsError;
+
+        if config.protocol == TransportProtocol::Tcp {
+            if config.pipelining {
+                return Err(FinsError::invalid_parameter(
+                    "pipelining",
+                    "only supported over UDP",
+                ));
+            }
+            let mut transport = TcpTransport::connect(
+                config.plc_addr,
+                config.connect_timeout,
+                config.timeout,
+                config.source.node,
+            )?;
+            transport.set_send_timeout(config.send_timeout)?;
+            transport.set_keep_alive(config.tcp_keep_alive)?;
+            return Ok((Transport::Tcp(transport), None));
+        }
+
+        let transport =
+            UdpTransport::with_options(config.plc_addr, config.timeout, &config.socket_options)?;
+        transport.set_send_timeout(config.send_timeout)?;
+
+        // Drain any stale packets from previous sessions
+        stats.record_stale(transport.drain_pending());
+
+        let demux = if config.pipelining {
+            Some(Demux::new(&transport, Arc::clone(stats))?)
+        } else {
+            None
+        };
+
+        Ok((Transport::Udp(transport), demux))
     }
 
-    /// Reads an f64 (LREAL) value from 4 consecutive words.
+    /// Returns the configured source address, with the node number the PLC
+    /// assigned during the FINS/TCP handshake.
+    fn effective_source(config: &ClientConfig, transport: &Transport) -> NodeAddress {
+        let mut source = config.source;
+        if let Transport::Tcp(tcp) = transport {
+            source.node = tcp.client_node();
+        }
+        source
+    }
+
+    /// Closes the socket and opens a new one from the stored configuration.
     ///
-    /// # Arguments
+    /// Use this to recover from I/O errors (e.g., a network interface
+    /// bounce) without rebuilding the client. The SID counter carries on,
+    /// so late responses to requests sent before the reconnect cannot be
+    /// mistaken for new ones. Over FINS/TCP the node-address handshake is
+    /// repeated and the newly assigned source node is used.
     ///
-    /// * `area` - Memory area to read from
-    /// * `address` - Starting word address
+    /// The PLC address in the config is reused as is. A config built with
+    /// [`ClientConfig::from_host`] is not resolved again, so a PLC that
+    /// changed its IP needs a new client.
     ///
     /// # Errors
     ///
-    /// Returns an error if communication fails or PLC returns an error.
+    /// Returns an error if the new transport cannot be created; the client
+    /// then keeps its previous socket.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use omron_fins::{Client, ClientConfig, FinsError, MemoryArea};
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
+    /// let mut client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
     ///
-    /// let value: f64 = client.read_f64(MemoryArea::DM, 100).unwrap();
+    /// if let Err(FinsError::Io(_)) = client.read(MemoryArea::DM, 100, 10) {
+    ///     client.reconnect()?;
+    /// }
+    /// # Ok::<(), FinsError>(())
     /// ```
-    pub fn read_f64(&self, area: MemoryArea, address: u16) -> Result<f64> {
-        let words = self.read(area, address, 4)?;
-        // Omron uses word swap: words in reverse order
-        let bytes = [
-            (words[3] >> 8) as u8,
-            (words[3] & 0xFF) as u8,
-            (words[2] >> 8) as u8,
-            (words[2] & 0xFF) as u8,
-            (words[1] >> 8) as u8,
-            (words[1] & 0xFF) as u8,
-            (words[0] >> 8) as u8,
-            (words[0] & 0xFF) as u8,
-        ];
-        Ok(f64::from_be_bytes(bytes))
+    pub fn reconnect(&mut self) -> Result<()> {
+        let (transport, demux) = Self::connect(&self.config, &self.stats)?;
+        // Stop the old receiver thread before its socket is closed
+        self.demux = demux;
+        self.source = Self::effective_source(&self.config, &transport);
+        self.transport = transport;
+        Ok(())
     }
 
-    /// Writes an f64 (LREAL) value to 4 consecutive words.
+    /// Checks an address range against the CPU profile, if one is set, or
+    /// the generic area capacity otherwise.
+    fn check_bounds(&self, area: MemoryArea, address: u16, count: u16) -> Result<()> {
+        match &self.config.cpu_profile {
+            Some(profile) => profile.check_range(area, address, count),
+            None => area.check_bounds(address, count),
+        }
+    }
+
+    /// Checks the range a write of `data` covers, like
+    /// [`check_bounds`](Self::check_bounds).
+    fn check_write_bounds(&self, area: MemoryArea, address: u16, data: &[u16]) -> Result<()> {
+        let count = data_word_count(data.len())?;
+        self.check_bounds(area, address, count / area.words_per_item())
+    }
+
+    /// Checks a block of up to the whole address space for the `_large`
+    /// methods. The generic area capacities are far below what larger CPUs
+    /// provide, so without a profile only the 16-bit address space limits
+    /// the block.
+    fn check_large_bounds(&self, area: MemoryArea, address: u16, count: u32) -> Result<u16> {
+        let count = large_block_count(address, count)?;
+        if let Some(profile) = &self.config.cpu_profile {
+            profile.check_range(area, address, count)?;
+        }
+        Ok(count)
+    }
+
+    /// Attaches the request context to a failed result if
+    /// [`ClientConfig::error_context`] is set.
+    fn in_context<T>(
+        &self,
+        result: Result<T>,
+        context: impl FnOnce() -> RequestContext,
+    ) -> Result<T> {
+        if self.config.error_context {
+            result.map_err(|error| error.with_context(context()))
+        } else {
+            result
+        }
+    }
+
+    /// Returns the configured word order, least significant word first if
+    /// none is set.
+    fn word_order(&self) -> WordOrder {
+        self.config.word_order.unwrap_or_default()
+    }
+
+    /// Returns the word order of the i32 helpers, which store the high word
+    /// first unless a word order is configured.
+    fn dint_word_order(&self) -> WordOrder {
+        self.config.word_order.unwrap_or(WordOrder::HighFirst)
+    }
+
+    /// Generates the next Service ID.
+    fn next_sid(&self) -> u8 {
+        self.sid_counter.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Sends a command and receives the response, with SID validation and retry.
     ///
-    /// # Arguments
+    /// If the received response has a mismatched SID or answers another
+    /// command (stale packet), it will drain pending packets and retry up to
+    /// `sid_retries` times.
+    /// In pipelined mode the response is routed by SID instead.
+    fn send_receive_with_sid(&self, data: &[u8], expected_sid: u8) -> Result<FinsResponse> {
+        self.send_receive_with_options(data, expected_sid, &RequestOptions::default())
+    }
+
+    /// Like [`send_receive_with_sid`](Self::send_receive_with_sid), honoring
+    /// per-request overrides.
+    fn send_receive_with_options(
+        &self,
+        data: &[u8],
+        expected_sid: u8,
+        options: &RequestOptions,
+    ) -> Result<FinsResponse> {
+        self.send_receive_retrying(data, expected_sid, options, self.config.sid_retries)
+    }
+
+    /// Like [`send_receive_with_sid`](Self::send_receive_with_sid), but
+    /// never sends the command twice, for commands that are not idempotent.
+    fn send_receive_once(&self, data: &[u8], expected_sid: u8) -> Result<FinsResponse> {
+        self.send_receive_retrying(data, expected_sid, &RequestOptions::default(), 0)
+    }
+
+    /// Sends a command, resending it at most `retries` times on a stale
+    /// response, and reports it to the metrics sink.
+    fn send_receive_retrying(
+        &self,
+        data: &[u8],
+        expected_sid: u8,
+        options: &RequestOptions,
+        retries: u8,
+    ) -> Result<FinsResponse> {
+        use crate::error::FinsError;
+
+        let Some(metrics) = &self.metrics else {
+            return self.send_receive_matched(data, expected_sid, options, retries);
+        };
+
+        let (mrc, src) = command_codes(data);
+        metrics.on_request(mrc, src);
+        let started = Instant::now();
+        let result = self.send_receive_matched(data, expected_sid, options, retries);
+        match &result {
+            Ok(response) => {
+                let end_code = EndCode::from_codes(response.main_code, response.sub_code);
+                metrics.on_response(mrc, src, end_code, started.elapsed());
+            }
+            Err(FinsError::Timeout) => metrics.on_timeout(mrc, src, started.elapsed()),
+            Err(error) => metrics.on_error(mrc, src, error),
+        }
+        result
+    }
+
+    /// Sends a command and returns the response carrying `expected_sid`.
+    fn send_receive_matched(
+        &self,
+        data: &[u8],
+        expected_sid: u8,
+        options: &RequestOptions,
+        retries: u8,
+    ) -> Result<FinsResponse> {
+        use crate::error::FinsError;
+
+        // Commands encode default header flags; patch them if configured
+        let patched;
+        let data = if self.config.header_options == HeaderOptions::default() {
+            data
+        } else {
+            let mut frame = data.to_vec();
+            self.config.header_options.apply(&mut frame);
+            patched = frame;
+            &patched
+        };
+
+        // FINS/TCP discards other SIDs itself and replaces the connection
+        // after a timeout, so a resend could only duplicate the command
+        if self.demux.is_some() || matches!(self.transport, Transport::Tcp(_)) {
+            let response_bytes = self.exchange(data, expected_sid, options)?;
+            return check_echo(data, FinsResponse::from_bytes(&response_bytes)?);
+        }
+
+        let send_receive = |data: &[u8]| self.exchange(data, expected_sid, options);
+
+        let mut attempt = 0;
+        loop {
+            // On retry, drain any stale packets first
+            if attempt > 0 {
+                self.drain_stale(expected_sid);
+                self.stats.record_retransmit();
+            }
+
+            let response_bytes = send_receive(data)?;
+            let response = FinsResponse::from_bytes(&response_bytes)?;
+
+            let error = if response.header.sid == expected_sid {
+                match check_echo(data, response) {
+                    // The right SID on another command's response is a late
+                    // answer from before the SID counter wrapped
+                    Err(error @ FinsError::CommandMismatch { .. }) => error,
+                    result => return result,
+                }
+            } else {
+                self.stats.record_sid_mismatch();
+                FinsError::sid_mismatch(expected_sid, response.header.sid)
+            };
+
+            // Log mismatch on first attempt only (for debugging)
+            if attempt == 0 {
+                log_warn!("{}", error);
+            }
+
+            // All retries failed - return the last mismatch
+            if attempt == retries {
+                return Err(error);
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Checks the end code of a response, honoring
+    /// [`ClientConfig::strict_end_codes`].
+    fn check_response(&self, response: &FinsResponse) -> Result<()> {
+        if self.config.strict_end_codes {
+            response.check_error_strict()
+        } else {
+            response.check_error()
+        }
+    }
+
+    /// Checks a response like [`check_response`](Self::check_response), or,
+    /// if the caller collects `warnings`, accepts flagged normal completions
+    /// and adds their flags.
+    fn check_collecting(
+        &self,
+        response: &FinsResponse,
+        warnings: Option<&mut EndCodeWarnings>,
+    ) -> Result<()> {
+        match warnings {
+            Some(collected) => {
+                if let Some(flags) = response.check_warnings()? {
+                    *collected |= flags;
+                }
+                Ok(())
+            }
+            None => self.check_response(response),
+        }
+    }
+
+    /// Discards packets queued on the socket before `sid` is resent.
+    fn drain_stale(&self, sid: u8) {
+        let drained = self.transport.drain_pending();
+        if drained > 0 {
+            log_warn!(
+                "drained {} stale packet(s) before resending SID 0x{:02X}",
+                drained,
+                sid
+            );
+        }
+        self.stats.record_stale(drained);
+    }
+
+    /// Performs one send/receive round trip, reporting both frames to the
+    /// frame observer.
+    fn exchange(&self, data: &[u8], sid: u8, options: &RequestOptions) -> Result<Vec<u8>> {
+        use crate::error::FinsError;
+
+        self.pace();
+        log_debug!(
+            "sending {} bytes to {} (SID 0x{:02X})",
+            data.len(),
+            self.config.plc_addr,
+            sid
+        );
+        log_trace!("sent:\n{}", Hex(data));
+        self.observe(Direction::Sent, data);
+        self.stats.record_sent(data.len());
+        let timestamp = SystemTime::now();
+        let started = Instant::now();
+
+        let result = match (&self.demux, options.timeout) {
+            (Some(demux), timeout) => {
+                demux.send_receive(data, sid, timeout.unwrap_or(self.timeout))
+            }
+            (None, Some(timeout)) => self.transport.send_receive_with_timeout(data, timeout),
+            (None, None) => self.transport.send_receive(data),
+        };
+
+        if let Some(recorder) = &self.recorder {
+            recorder.record(timestamp, sid, data, &result, started.elapsed());
+        }
+
+        match &result {
+            Ok(response_bytes) => {
+                log_debug!(
+                    "received {} bytes in {:?} (SID 0x{:02X})",
+                    response_bytes.len(),
+                    started.elapsed(),
+                    sid
+                );
+                log_trace!("received:\n{}", Hex(response_bytes));
+                self.stats.record_received(response_bytes.len());
+            }
+            Err(FinsError::Timeout) => {
+                log_debug!("no response to SID 0x{:02X} within the timeout", sid);
+                self.stats.record_timeout();
+            }
+            Err(error) => log_debug!("SID 0x{:02X} failed: {}", sid, error),
+        }
+
+        let response_bytes = result?;
+        self.observe(Direction::Received, &response_bytes);
+        Ok(response_bytes)
+    }
+
+    /// Waits until the configured minimum request interval has elapsed.
+    ///
+    /// Each caller reserves the next free send slot under the lock and
+    /// sleeps outside it, so pipelined requests are spaced out too.
+    fn pace(&self) {
+        let interval = self.config.min_request_interval;
+        if interval.is_zero() {
+            return;
+        }
+
+        let wait = {
+            let mut next = self
+                .next_request_at
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            let slot = next.map_or(now, |at| at.max(now));
+            *next = Some(slot + interval);
+            slot - now
+        };
+        if !wait.is_zero() {
+            std::thread::sleep(wait);
+        }
+    }
+
+    fn observe(&self, direction: Direction, frame: &[u8]) {
+        if let Some(observer) = &self.frame_observer {
+            observer(direction, frame);
+        }
+    }
+
+    /// Returns the current receive timeout.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Changes the receive timeout of the running client.
     ///
-    /// * `area` - Memory area to write to
-    /// * `address` - Starting word address
-    /// * `value` - f64 value to write
+    /// Unlike [`ClientConfig::with_timeout`], this leaves the send timeout
+    /// alone, so a [`ClientConfig::with_send_timeout`] setting survives and
+    /// is inherited by [`reconnect`](Self::reconnect) and
+    /// [`try_clone`](Self::try_clone); use
+    /// [`set_send_timeout`](Self::set_send_timeout) to change it.
+    ///
+    /// Useful to extend the timeout around a slow operation and restore the
+    /// fast default afterwards. For a single call,
+    /// [`RequestOptions::with_timeout`] needs no `&mut` access. In pipelined
+    /// mode the socket's read timeout stays at the receiver thread's poll
+    /// interval, and the new timeout applies to the wait for each response.
     ///
     /// # Errors
     ///
-    /// Returns an error if communication fails or PLC returns an error.
+    /// Returns `FinsError::InvalidParameter` if the timeout is zero, or
+    /// `FinsError::Io` if the socket rejects it.
     ///
     /// # Example
     ///
     /// ```no_run
     /// use omron_fins::{Client, ClientConfig, MemoryArea};
     /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
+    /// let mut client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    /// let fast = client.timeout();
     ///
-    /// client.write_f64(MemoryArea::DM, 100, 3.141592653589793).unwrap();
+    /// client.set_timeout(Duration::from_secs(30))?;
+    /// let program = client.read(MemoryArea::DM, 0, 700);
+    /// client.set_timeout(fast)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
     /// ```
-    pub fn write_f64(&self, area: MemoryArea, address: u16, value: f64) -> Result<()> {
-        let bytes = value.to_be_bytes();
-        // Omron uses word swap: words in reverse order
-        let words = [
-            u16::from_be_bytes([bytes[6], bytes[7]]),
-            u16::from_be_bytes([bytes[4], bytes[5]]),
-            u16::from_be_bytes([bytes[2], bytes[3]]),
-            u16::from_be_bytes([bytes[0], bytes[1]]),
-        ];
-        self.write(area, address, &words)
+    pub fn set_timeout(&mut self, timeout: Duration) -> Result<()> {
+        if timeout.is_zero() {
+            return Err(crate::error::FinsError::invalid_parameter(
+                "timeout",
+                "must be greater than zero",
+            ));
+        }
+        // UDP receives wait on their own deadline, so this leaves the
+        // socket's read timeout (and the receiver thread's poll) alone
+        self.transport.set_timeout(timeout)?;
+        self.timeout = timeout;
+        self.config.timeout = timeout;
+        Ok(())
     }
 
-    /// Reads a custom structure from PLC memory based on a set of data types.
+    /// Changes the send timeout of the running client, like
+    /// [`ClientConfig::with_send_timeout`] does at creation.
     ///
-    /// # Arguments
+    /// The send timeout bounds how long a send may block, e.g. on a full
+    /// socket buffer; the receive timeout ([`set_timeout`](Self::set_timeout))
+    /// bounds the wait for the response.
     ///
-    /// * `area` - Memory area to read from
-    /// * `address` - Starting word address
-    /// * `types` - List of data types to read in sequence
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the timeout is zero, or
+    /// `FinsError::Io` if the socket rejects it.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// # use omron_fins::{Client, ClientConfig, MemoryArea, DataType, PlcValue};
-    /// # use std::net::Ipv4Addr;
-    /// # let client = Client::new(ClientConfig::new(Ipv4Addr::new(127, 0, 0, 1), 1, 10)).unwrap();
-    /// let my_struct = client.read_struct(MemoryArea::DM, 100, vec![
-    ///     DataType::LINT, // 8 bytes
-    ///     DataType::INT,  // 2 bytes
-    ///     DataType::REAL, // 4 bytes
-    /// ]).unwrap();
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
+    ///
+    /// let mut client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    /// client.set_send_timeout(Duration::from_millis(100))?;
+    /// # Ok::<(), omron_fins::FinsError>(())
     /// ```
-    pub fn read_struct(
-        &self,
-        area: MemoryArea,
-        address: u16,
-        types: Vec<DataType>,
-    ) -> Result<Vec<PlcValue>> {
-        let total_bytes: usize = types.iter().map(|t| (t.size() + 1) & !1).sum(); // Align to 2-byte words
-        let word_count = (total_bytes / 2) as u16;
-
-        let words = self.read(area, address, word_count)?;
-        let mut bytes = Vec::with_capacity(words.len() * 2);
-        for word in words {
-            bytes.extend_from_slice(&word.to_be_bytes());
+    pub fn set_send_timeout(&mut self, timeout: Duration) -> Result<()> {
+        if timeout.is_zero() {
+            return Err(crate::error::FinsError::invalid_parameter(
+                "send_timeout",
+                "must be greater than zero",
+            ));
         }
+        self.transport.set_send_timeout(timeout)?;
+        self.config.send_timeout = timeout;
+        Ok(())
+    }
 
-        let mut results = Vec::with_capacity(types.len());
-        let mut offset = 0;
-        for data_type in types {
-            let size = data_type.size();
-            let chunk = &bytes[offset..offset + size];
-            results.push(PlcValue::from_plc_bytes(data_type, chunk)?);
-            offset += (size + 1) & !1; // Advance by even bytes
-        }
+    /// Installs a callback invoked with every raw frame sent and received.
+    ///
+    /// The observer runs synchronously on the thread issuing the request,
+    /// so keep it fast. Useful for audit logging of every command sent to
+    /// a PLC.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, Direction};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let mut client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    /// client.set_frame_observer(|direction, frame| {
+    ///     if direction == Direction::Sent {
+    ///         println!("-> {:02X?}", frame);
+    ///     }
+    /// });
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn set_frame_observer<F>(&mut self, observer: F)
+    where
+        F: Fn(Direction, &[u8]) + Send + Sync + 'static,
+    {
+        self.frame_observer = Some(Box::new(observer));
+    }
 
-        Ok(results)
+    /// Removes the frame observer, if any.
+    pub fn clear_frame_observer(&mut self) {
+        self.frame_observer = None;
     }
 
-    /// Writes a custom structure to PLC memory.
+    /// Installs a recorder that captures every request/response exchange.
     ///
-    /// # Arguments
+    /// Replaces any previously installed recorder.
     ///
-    /// * `area` - Memory area to write to
-    /// * `address` - Starting word address
-    /// * `values` - List of values to write in sequence
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, SessionRecorder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let mut client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    /// client.set_session_recorder(SessionRecorder::new(1000));
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn set_session_recorder(&mut self, recorder: SessionRecorder) {
+        self.recorder = Some(recorder);
+    }
+
+    /// Removes and returns the session recorder, if any.
+    pub fn take_session_recorder(&mut self) -> Option<SessionRecorder> {
+        self.recorder.take()
+    }
+
+    /// Installs a sink that is told about every command and its outcome.
+    ///
+    /// Replaces any previously installed sink. To share one sink between
+    /// clients, install an `Arc` of it.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// # use omron_fins::{Client, ClientConfig, MemoryArea, PlcValue};
-    /// # use std::net::Ipv4Addr;
-    /// # let client = Client::new(ClientConfig::new(Ipv4Addr::new(127, 0, 0, 1), 1, 10)).unwrap();
-    /// client.write_struct(MemoryArea::DM, 100, vec![
-    ///     PlcValue::Lint(123456789),
-    ///     PlcValue::Int(100),
-    ///     PlcValue::Real(3.14159),
-    /// ]).unwrap();
+    /// use omron_fins::{Client, ClientConfig, MetricsSink};
+    /// use std::net::Ipv4Addr;
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// struct Timeouts;
+    ///
+    /// impl MetricsSink for Timeouts {
+    ///     fn on_timeout(&self, mrc: u8, src: u8, elapsed: Duration) {
+    ///         eprintln!("command {:02X}{:02X} timed out after {:?}", mrc, src, elapsed);
+    ///     }
+    /// }
+    ///
+    /// let sink = Arc::new(Timeouts);
+    /// let mut client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    /// client.set_metrics_sink(sink.clone());
+    /// # Ok::<(), omron_fins::FinsError>(())
     /// ```
-    pub fn write_struct(&self, area: MemoryArea, address: u16, values: Vec<PlcValue>) -> Result<()> {
-        let mut bytes = Vec::new();
-        for value in values {
-            let val_bytes = value.to_plc_bytes();
-            bytes.extend_from_slice(&val_bytes);
-            // Ensure 16-bit alignment (even bytes)
-            if val_bytes.len() % 2 != 0 {
-                bytes.push(0);
-            }
-        }
+    pub fn set_metrics_sink(&mut self, sink: impl MetricsSink + 'static) {
+        self.metrics = Some(Box::new(sink));
+    }
 
-        let words: Vec<u16> = bytes
-            .chunks_exact(2)
-            .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]))
-            .collect();
+    /// Removes the metrics sink, if any.
+    pub fn clear_metrics_sink(&mut self) {
+        self.metrics = None;
+    }
 
-        self.write(area, address, &words)
+    /// Returns a snapshot of the communication counters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    /// let _ = client.read(MemoryArea::DM, 100, 10);
+    ///
+    /// let stats = client.stats();
+    /// println!("sent {} bytes, {} timeouts", stats.bytes_sent, stats.timeouts);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn stats(&self) -> ClientStats {
+        self.stats.snapshot()
     }
 
-    /// Reads an i32 (DINT) value from 2 consecutive words.
+    /// Resets all communication counters to zero.
+    pub fn reset_stats(&self) {
+        self.stats.reset();
+    }
+
+    /// Discards any datagrams waiting on the socket.
+    ///
+    /// Use this to flush the socket explicitly when recovering from an
+    /// error storm. Returns the number of datagrams discarded; the total
+    /// is also counted in [`ClientStats::stale_packets`]. In pipelined
+    /// mode the receiver thread already discards unclaimed responses, so
+    /// this returns 0.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    /// let discarded = client.drain_pending();
+    /// println!("discarded {} stale datagrams", discarded);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn drain_pending(&self) -> usize {
+        if self.demux.is_some() {
+            return 0;
+        }
+        let drained = self.transport.drain_pending();
+        self.stats.record_stale(drained);
+        drained
+    }
+
+    /// Returns the exchanges captured by the session recorder, oldest first.
+    ///
+    /// Empty if no recorder is installed.
+    pub fn session_log(&self) -> Vec<SessionEntry> {
+        self.recorder
+            .as_ref()
+            .map(SessionRecorder::entries)
+            .unwrap_or_default()
+    }
+
+    /// Reads words from PLC memory.
     ///
     /// # Arguments
     ///
     /// * `area` - Memory area to read from
     /// * `address` - Starting word address
+    /// * `count` - Number of words to read (1-999)
     ///
     /// # Errors
     ///
-    /// Returns an error if communication fails or PLC returns an error.
+    /// Returns an error if:
+    /// - Count is 0 or > 999
+    /// - Communication fails
+    /// - PLC returns an error
     ///
     /// # Example
     ///
@@ -1045,70 +1798,70 @@ impl Client {
     ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
     /// )).unwrap();
     ///
-    /// let counter: i32 = client.read_i32(MemoryArea::DM, 100).unwrap();
+    /// let data = client.read(MemoryArea::DM, 100, 10).unwrap();
+    /// println!("Read {} words: {:?}", data.len(), data);
     /// ```
-    pub fn read_i32(&self, area: MemoryArea, address: u16) -> Result<i32> {
-        let words = self.read(area, address, 2)?;
-        let bytes = [
-            (words[0] >> 8) as u8,
-            (words[0] & 0xFF) as u8,
-            (words[1] >> 8) as u8,
-            (words[1] & 0xFF) as u8,
-        ];
-        Ok(i32::from_be_bytes(bytes))
+    pub fn read(&self, area: MemoryArea, address: u16, count: u16) -> Result<Vec<u16>> {
+        self.read_with_options(area, address, count, &RequestOptions::default())
     }
 
-    /// Writes an i32 (DINT) value to 2 consecutive words.
-    ///
-    /// # Arguments
+    /// Reads words from PLC memory with per-request options.
     ///
-    /// * `area` - Memory area to write to
-    /// * `address` - Starting word address
-    /// * `value` - i32 value to write
+    /// Behaves like [`read`](Self::read); `options` applies to every
+    /// command the read is split into.
     ///
     /// # Errors
     ///
-    /// Returns an error if communication fails or PLC returns an error.
+    /// Same as [`read`](Self::read).
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, RequestOptions};
     /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
     ///
     /// let client = Client::new(ClientConfig::new(
     ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
     /// )).unwrap();
     ///
-    /// client.write_i32(MemoryArea::DM, 100, -123456).unwrap();
+    /// let options = RequestOptions::new().with_timeout(Duration::from_secs(10));
+    /// let data = client.read_with_options(MemoryArea::DM, 0, 999, &options).unwrap();
     /// ```
-    pub fn write_i32(&self, area: MemoryArea, address: u16, value: i32) -> Result<()> {
-        let bytes = value.to_be_bytes();
-        let words = [
-            u16::from_be_bytes([bytes[0], bytes[1]]),
-            u16::from_be_bytes([bytes[2], bytes[3]]),
-        ];
-        self.write(area, address, &words)
+    pub fn read_with_options(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        count: u16,
+        options: &RequestOptions,
+    ) -> Result<Vec<u16>> {
+        self.check_bounds(area, address, count)?;
+        let mut result = Vec::with_capacity(count as usize);
+        self.read_chunks(area, address, count, options, None, |response| {
+            result.extend(response.to_words()?);
+            Ok(())
+        })?;
+        Ok(result)
     }
 
-    /// Writes an ASCII string to consecutive words.
+    /// Reads a large block of words, e.g. a whole recipe area.
     ///
-    /// Each word stores 2 ASCII characters (big-endian). If the string has an
-    /// odd number of characters, the last byte is padded with 0x00.
+    /// The block is split into sequential commands of at most
+    /// [`MAX_WORDS_PER_COMMAND`](crate::MAX_WORDS_PER_COMMAND) words. These
+    /// are separate FINS transactions: the PLC program may change memory
+    /// between them, so the result is not a consistent snapshot.
     ///
     /// # Arguments
     ///
-    /// * `area` - Memory area to write to
+    /// * `area` - Memory area to read from
     /// * `address` - Starting word address
-    /// * `value` - String to write (ASCII only)
+    /// * `count` - Number of words to read, up to the end of the address space
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - String is empty
-    /// - String exceeds 1998 characters (999 words)
-    /// - Communication fails
-    /// - PLC returns an error
+    /// Returns an error if the block runs past the 65535-word address space
+    /// or, with a [`CpuProfile`], the end of the area, communication fails
+    /// or PLC returns an error. Words read before the failure are discarded.
     ///
     /// # Example
     ///
@@ -1116,67 +1869,193 @@ impl Client {
     /// use omron_fins::{Client, ClientConfig, MemoryArea};
     /// use std::net::Ipv4Addr;
     ///
-    /// let client = Client::new(ClientConfig::new(
-    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
-    /// )).unwrap();
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
     ///
-    /// // Write a product code to DM100
-    /// client.write_string(MemoryArea::DM, 100, "PRODUCT-001").unwrap();
+    /// // D10000-D15999 in 9 commands
+    /// let recipes = client.read_large(MemoryArea::DM, 10000, 6000)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
     /// ```
-    pub fn write_string(&self, area: MemoryArea, address: u16, value: &str) -> Result<()> {
-        use crate::command::MAX_WORDS_PER_COMMAND;
-        use crate::error::FinsError;
+    pub fn read_large(&self, area: MemoryArea, address: u16, count: u32) -> Result<Vec<u16>> {
+        let count = self.check_large_bounds(area, address, count)?;
+        let mut result = Vec::with_capacity(count as usize);
+        let options = RequestOptions::default();
+        self.read_chunks(area, address, count, &options, None, |response| {
+            result.extend(response.to_words()?);
+            Ok(())
+        })?;
+        Ok(result)
+    }
 
-        if value.is_empty() {
-            return Err(FinsError::InvalidParameter {
-                parameter: "value".to_string(),
-                reason: "string cannot be empty".to_string(),
-            });
+    /// Reads words into a caller-provided buffer instead of allocating.
+    ///
+    /// Reads `buf.len()` words, in as many commands as
+    /// [`read`](Self::read) would use.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    /// * `buf` - Buffer to fill, one element per word
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer is longer than 65535 words, the PLC
+    /// returns fewer words than requested, communication fails or PLC
+    /// returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let mut words = [0u16; 32];
+    /// loop {
+    ///     client.read_into(MemoryArea::DM, 100, &mut words)?;
+    ///     // ... use words ...
+    /// #   break;
+    /// }
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_into(&self, area: MemoryArea, address: u16, buf: &mut [u16]) -> Result<()> {
+        let count = buffer_word_count(buf.len())?;
+        self.check_bounds(area, address, count)?;
+        let mut filled = 0;
+        self.read_chunks(
+            area,
+            address,
+            count,
+            &RequestOptions::default(),
+            None,
+            |response| {
+                let data = chunk_data(response, buf.len() - filled)?;
+                for (word, pair) in buf[filled..].iter_mut().zip(data.chunks_exact(2)) {
+                    *word = u16::from_be_bytes([pair[0], pair[1]]);
+                }
+                filled += data.len() / 2;
+                Ok(())
+            },
+        )?;
+        check_filled(filled, buf.len())
+    }
+
+    /// Reads raw memory bytes into a caller-provided buffer.
+    ///
+    /// Each word fills 2 bytes, high byte first, exactly as sent by the PLC.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    /// * `buf` - Buffer to fill; its length must be even
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer length is odd or above 65535 words,
+    /// the PLC returns fewer words than requested, communication fails or
+    /// PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let mut image = [0u8; 64];
+    /// client.read_bytes_into(MemoryArea::DM, 100, &mut image)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_bytes_into(&self, area: MemoryArea, address: u16, buf: &mut [u8]) -> Result<()> {
+        if buf.len() % 2 != 0 {
+            return Err(crate::error::FinsError::invalid_parameter(
+                "buf",
+                "length must be even (2 bytes per word)",
+            ));
         }
+        let count = buffer_word_count(buf.len() / 2)?;
+        self.check_bounds(area, address, count)?;
+        let mut filled = 0;
+        self.read_chunks(
+            area,
+            address,
+            count,
+            &RequestOptions::default(),
+            None,
+            |response| {
+                let data = chunk_data(response, (buf.len() - filled) / 2)?;
+                buf[filled..filled + data.len()].copy_from_slice(data);
+                filled += data.len();
+                Ok(())
+            },
+        )?;
+        check_filled(filled, buf.len())
+    }
 
-        let bytes = value.as_bytes();
-        let word_count = (bytes.len() + 1) / 2;
+    /// Reads `count` words in chunks of at most [`MAX_WORDS_PER_COMMAND`]
+    /// and hands each successful response to `sink`, in order. With
+    /// `warnings`, flagged normal completions are accepted and their flags
+    /// collected. Callers check the bounds.
+    ///
+    /// [`MAX_WORDS_PER_COMMAND`]: crate::MAX_WORDS_PER_COMMAND
+    fn read_chunks(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        count: u16,
+        options: &RequestOptions,
+        mut warnings: Option<&mut EndCodeWarnings>,
+        mut sink: impl FnMut(&FinsResponse) -> Result<()>,
+    ) -> Result<()> {
+        let range = AreaRange::new(area, address, count);
+        for (index, chunk) in range.command_chunks().enumerate() {
+            if index > 0 {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
 
-        if word_count > MAX_WORDS_PER_COMMAND as usize {
-            return Err(FinsError::InvalidParameter {
-                parameter: "value".to_string(),
-                reason: format!(
-                    "string too long: {} bytes requires {} words, max is {}",
-                    bytes.len(),
-                    word_count,
-                    MAX_WORDS_PER_COMMAND
-                ),
-            });
+            let sid = self.next_sid();
+            let cmd = ReadWordCommand::new(
+                self.destination,
+                self.source,
+                sid,
+                area,
+                chunk.start,
+                chunk.len,
+            )?;
+            let result = self
+                .send_receive_with_options(&cmd.to_bytes(), sid, options)
+                .and_then(|response| {
+                    self.check_collecting(&response, warnings.as_deref_mut())?;
+                    Ok(response)
+                });
+            let response = self.in_context(result, || RequestContext {
+                operation: "reading",
+                area,
+                address: chunk.start,
+                count: chunk.len,
+                sid,
+            })?;
+            sink(&response)?;
         }
 
-        // Omron uses byte swap within words: first char in low byte, second char in high byte
-        let words: Vec<u16> = bytes
-            .chunks(2)
-            .map(|chunk| {
-                let low = chunk[0] as u16;
-                let high = if chunk.len() > 1 { chunk[1] as u16 } else { 0 };
-                (high << 8) | low
-            })
-            .collect();
-
-        self.write(area, address, &words)
+        Ok(())
     }
 
-    /// Reads an ASCII string from consecutive words.
-    ///
-    /// Each word contains 2 ASCII characters (big-endian). Null bytes (0x00)
-    /// at the end of the string are trimmed.
+    /// Writes words to PLC memory.
     ///
     /// # Arguments
     ///
-    /// * `area` - Memory area to read from
+    /// * `area` - Memory area to write to
     /// * `address` - Starting word address
-    /// * `word_count` - Number of words to read (1-999)
+    /// * `data` - Words to write (1-999 words)
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - Word count is 0 or > 999
+    /// - Data is empty or > 999 words
     /// - Communication fails
     /// - PLC returns an error
     ///
@@ -1190,106 +2069,6799 @@ impl Client {
     ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
     /// )).unwrap();
     ///
-    /// // Read a product code from DM100 (up to 20 characters = 10 words)
-    /// let code = client.read_string(MemoryArea::DM, 100, 10).unwrap();
-    /// println!("Product code: {}", code);
+    /// client.write(MemoryArea::DM, 100, &[0x1234, 0x5678]).unwrap();
     /// ```
-    pub fn read_string(&self, area: MemoryArea, address: u16, word_count: u16) -> Result<String> {
-        let words = self.read(area, address, word_count)?;
+    pub fn write(&self, area: MemoryArea, address: u16, data: &[u16]) -> Result<()> {
+        self.write_with_options(area, address, data, &RequestOptions::default())
+    }
 
-        // Omron uses byte swap within words: first char in low byte, second char in high byte
-        let mut bytes: Vec<u8> = Vec::with_capacity(words.len() * 2);
-        for word in &words {
-            bytes.push((word & 0xFF) as u8); // low byte first
-            bytes.push((word >> 8) as u8); // high byte second
-        }
+    /// Writes words to PLC memory with per-request options.
+    ///
+    /// Behaves like [`write`](Self::write); `options` applies to every
+    /// command the write is split into.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`write`](Self::write).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, RequestOptions};
+    /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// let options = RequestOptions::new().with_timeout(Duration::from_secs(10));
+    /// client.write_with_options(MemoryArea::DM, 0, &[0; 999], &options).unwrap();
+    /// ```
+    pub fn write_with_options(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        data: &[u16],
+        options: &RequestOptions,
+    ) -> Result<()> {
+        self.check_write_bounds(area, address, data)?;
+        self.write_chunks(area, address, data, options, None)
+    }
 
-        // Trim null bytes from the end
-        while bytes.last() == Some(&0) {
-            bytes.pop();
+    /// Writes `data` in chunks of at most [`MAX_WORDS_PER_COMMAND`] words,
+    /// collecting warning flags like [`read_chunks`](Self::read_chunks).
+    /// Callers check the bounds.
+    ///
+    /// [`MAX_WORDS_PER_COMMAND`]: crate::MAX_WORDS_PER_COMMAND
+    fn write_chunks(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        data: &[u16],
+        options: &RequestOptions,
+        mut warnings: Option<&mut EndCodeWarnings>,
+    ) -> Result<()> {
+        let range = AreaRange::new(area, address, data_word_count(data.len())?);
+        for (index, chunk) in range.command_chunks().enumerate() {
+            if index > 0 {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+
+            let offset = (chunk.start - address) as usize;
+            let chunk_data = &data[offset..offset + chunk.len as usize];
+
+            let sid = self.next_sid();
+            let cmd = WriteWordCommand::new(
+                self.destination,
+                self.source,
+                sid,
+                area,
+                chunk.start,
+                chunk_data,
+            )?;
+            let result = self
+                .send_receive_with_options(&cmd.to_bytes(), sid, options)
+                .and_then(|response| self.check_collecting(&response, warnings.as_deref_mut()));
+            self.in_context(result, || RequestContext {
+                operation: "writing",
+                area,
+                address: chunk.start,
+                count: chunk.len,
+                sid,
+            })?;
         }
 
-        Ok(String::from_utf8_lossy(&bytes).to_string())
+        Ok(())
     }
 
-    /// Returns the source node address.
-    pub fn source(&self) -> NodeAddress {
-        self.source
+    /// Reads words like [`read`](Self::read), returning the warning flags
+    /// of the responses alongside the data.
+    ///
+    /// A PLC can complete a command while flagging a relay error or a CPU
+    /// Unit error in the end code. [`read`](Self::read) accepts only the
+    /// 0x0040 flag and drops it; this method accepts every flagged normal
+    /// completion and reports the flags of all chunks, or `None` if no flag
+    /// was set.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`read`](Self::read), except that flagged normal completions
+    /// are not errors.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let (words, warnings) = client.read_with_warnings(MemoryArea::DM, 100, 10)?;
+    /// if let Some(warnings) = warnings {
+    ///     eprintln!("read succeeded with warnings: {}", warnings);
+    /// }
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_with_warnings(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        count: u16,
+    ) -> Result<(Vec<u16>, Option<EndCodeWarnings>)> {
+        self.check_bounds(area, address, count)?;
+        let mut warnings = EndCodeWarnings::default();
+        let mut result = Vec::with_capacity(count as usize);
+        self.read_chunks(
+            area,
+            address,
+            count,
+            &RequestOptions::default(),
+            Some(&mut warnings),
+            |response| {
+                result.extend(response.to_words()?);
+                Ok(())
+            },
+        )?;
+        Ok((result, (!warnings.is_empty()).then_some(warnings)))
     }
 
-    /// Returns the destination node address.
-    pub fn destination(&self) -> NodeAddress {
-        self.destination
+    /// Writes words like [`write`](Self::write), returning the warning
+    /// flags of the responses, or `None` if no flag was set.
+    ///
+    /// See [`read_with_warnings`](Self::read_with_warnings).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`write`](Self::write), except that flagged normal
+    /// completions are not errors.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// if let Some(warnings) = client.write_with_warnings(MemoryArea::DM, 100, &[1, 2])? {
+    ///     eprintln!("written, but the PLC reports: {}", warnings);
+    /// }
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_with_warnings(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        data: &[u16],
+    ) -> Result<Option<EndCodeWarnings>> {
+        self.check_write_bounds(area, address, data)?;
+        let mut warnings = EndCodeWarnings::default();
+        self.write_chunks(
+            area,
+            address,
+            data,
+            &RequestOptions::default(),
+            Some(&mut warnings),
+        )?;
+        Ok((!warnings.is_empty()).then_some(warnings))
     }
-}
 
-impl std::fmt::Debug for Client {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Client")
-            .field("transport", &self.transport)
-            .field("source", &self.source)
-            .field("destination", &self.destination)
-            .finish()
+    /// Writes words, then reads the same range back and compares it.
+    ///
+    /// For validated processes where every write must be confirmed. The
+    /// read-back is a separate command, so a word the PLC program changes
+    /// in between is reported as a mismatch.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `data` - Words to write
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::VerifyMismatch` listing every word that read back
+    /// differently, or the errors of [`write`](Self::write) and
+    /// [`read`](Self::read).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, FinsError, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// match client.write_verify(MemoryArea::DM, 100, &[10, 20, 30]) {
+    ///     Err(FinsError::VerifyMismatch { mismatches }) => {
+    ///         for m in mismatches {
+    ///             eprintln!("D{}: wrote {}, read {}", m.address, m.written, m.read);
+    ///         }
+    ///     }
+    ///     other => other?,
+    /// }
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_verify(&self, area: MemoryArea, address: u16, data: &[u16]) -> Result<()> {
+        self.check_write_bounds(area, address, data)?;
+        self.write_and_compare(area, address, data)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::net::Ipv4Addr;
 
-    #[test]
-    fn test_client_config_new() {
-        let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0);
+    /// Writes `data` and compares it with a read-back, like
+    /// [`write_verify`](Self::write_verify). Callers check the bounds.
+    fn write_and_compare(&self, area: MemoryArea, address: u16, data: &[u16]) -> Result<()> {
+        use crate::error::{FinsError, WordMismatch};
 
-        assert_eq!(config.plc_addr.ip(), Ipv4Addr::new(192, 168, 1, 250));
-        assert_eq!(config.plc_addr.port(), DEFAULT_FINS_PORT);
-        assert_eq!(config.source.node, 1);
-        assert_eq!(config.destination.node, 0);
-        assert_eq!(config.timeout, DEFAULT_TIMEOUT);
+        let options = RequestOptions::default();
+        self.write_chunks(area, address, data, &options, None)?;
+        let mut read_back = Vec::with_capacity(data.len());
+        self.read_chunks(
+            area,
+            address,
+            data.len() as u16,
+            &options,
+            None,
+            |response| {
+                read_back.extend(response.to_words()?);
+                Ok(())
+            },
+        )?;
+        if read_back.len() != data.len() {
+            return Err(FinsError::invalid_response(format!(
+                "expected {} words, got {}",
+                data.len(),
+                read_back.len()
+            )));
+        }
+        let mismatches: Vec<WordMismatch> = data
+            .iter()
+            .zip(read_back)
+            .enumerate()
+            .filter(|(_, (&written, read))| written != *read)
+            .map(|(offset, (&written, read))| WordMismatch {
+                address: address.wrapping_add(offset as u16),
+                written,
+                read,
+            })
+            .collect();
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(FinsError::verify_mismatch(mismatches))
+        }
     }
 
-    #[test]
-    fn test_client_config_with_port() {
+    /// Writes a large block of words, e.g. a recipe download or lookup table.
+    ///
+    /// The block is written in sequential commands of at most
+    /// [`MAX_WORDS_PER_COMMAND`](crate::MAX_WORDS_PER_COMMAND) words; these
+    /// are separate FINS transactions. With `verify` set, each chunk is read
+    /// back and compared before the next one is sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `data` - Words to write, up to the end of the address space
+    /// * `verify` - Read back and compare every chunk
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::TransferIncomplete` with the number of words
+    /// written (and verified) so far if a chunk fails or reads back
+    /// differently, or `FinsError::InvalidParameter` if the block runs past
+    /// the 65535-word address space or, with a [`CpuProfile`], the end of
+    /// the area.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, FinsError, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let table = vec![0u16; 4000];
+    /// match client.write_large(MemoryArea::DM, 20000, &table, true) {
+    ///     Err(FinsError::TransferIncomplete { words_done, source }) => {
+    ///         eprintln!("stopped at D{}: {}", 20000 + words_done, source);
+    ///     }
+    ///     other => other?,
+    /// }
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_large(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        data: &[u16],
+        verify: bool,
+    ) -> Result<()> {
+        use crate::command::MAX_WORDS_PER_COMMAND;
+        use crate::error::FinsError;
+
+        let count = u32::try_from(data.len()).unwrap_or(u32::MAX);
+        self.check_large_bounds(area, address, count)?;
+        let options = RequestOptions::default();
+        let mut words_done = 0;
+        for chunk in data.chunks(MAX_WORDS_PER_COMMAND as usize) {
+            let start = address + words_done as u16;
+            let result = if verify {
+                self.write_and_compare(area, start, chunk)
+            } else {
+                self.write_chunks(area, start, chunk, &options, None)
+            };
+            if let Err(error) = result {
+                return Err(FinsError::transfer_incomplete(words_done, error));
+            }
+            words_done += chunk.len();
+        }
+        Ok(())
+    }
+
+    /// Reads a single bit from PLC memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from (must support bit access)
+    /// * `address` - Word address
+    /// * `bit` - Bit position (0-15)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Area doesn't support bit access (DM, unless
+    ///   [`ClientConfig::with_dm_bit_access`] is set)
+    /// - Bit position > 15
+    /// - Communication fails
+    /// - PLC returns an error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// let bit = client.read_bit(MemoryArea::CIO, 0, 5).unwrap();
+    /// println!("CIO 0.05 = {}", bit);
+    /// ```
+    pub fn read_bit(&self, area: MemoryArea, address: u16, bit: u8) -> Result<bool> {
+        if let Some(profile) = &self.config.cpu_profile {
+            profile.check_range(area, address, 1)?;
+        }
+        let sid = self.next_sid();
+        let cmd = ReadBitCommand::new_with_dm_bits(
+            self.destination,
+            self.source,
+            sid,
+            area,
+            address,
+            bit,
+            self.config.dm_bit_access,
+        )?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes()?, sid)?;
+        self.check_response(&response)?;
+        response.to_bit()
+    }
+
+    /// Reads consecutive bits in a single request.
+    ///
+    /// Bits continue into the following words after bit 15, so a whole
+    /// input word, or several, can be scanned in one round trip.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from (must support bit access)
+    /// * `address` - Word address of the first bit
+    /// * `start_bit` - Position of the first bit (0-15)
+    /// * `count` - Number of bits to read (1-[`MAX_BITS_PER_COMMAND`](crate::MAX_BITS_PER_COMMAND))
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Area doesn't support bit access
+    /// - Bit position > 15 or count out of range
+    /// - Communication fails
+    /// - PLC returns an error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // All 16 flags of CIO 0
+    /// let inputs: Vec<bool> = client.read_bits(MemoryArea::CIO, 0, 0, 16)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_bits(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        start_bit: u8,
+        count: u16,
+    ) -> Result<Vec<bool>> {
+        let sid = self.next_sid();
+        let cmd = ReadBitCommand::new_with_dm_bits(
+            self.destination,
+            self.source,
+            sid,
+            area,
+            address,
+            start_bit,
+            self.config.dm_bit_access,
+        )?
+        .with_count(count)?;
+        let words = (start_bit as u16 + count).div_ceil(16);
+        if let Some(profile) = &self.config.cpu_profile {
+            profile.check_range(area, address, words)?;
+        }
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes()?, sid)?;
+        self.check_response(&response)?;
+        let bits = response.to_bits();
+        if bits.len() != count as usize {
+            return Err(crate::error::FinsError::invalid_response(format!(
+                "expected {} bits, got {}",
+                count,
+                bits.len()
+            )));
+        }
+        Ok(bits)
+    }
+
+    /// Writes a single bit to PLC memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to (must support bit access)
+    /// * `address` - Word address
+    /// * `bit` - Bit position (0-15)
+    /// * `value` - Bit value to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Area doesn't support bit access (DM, unless
+    ///   [`ClientConfig::with_dm_bit_access`] is set)
+    /// - Bit position > 15
+    /// - Communication fails
+    /// - PLC returns an error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// client.write_bit(MemoryArea::CIO, 0, 5, true).unwrap();
+    /// ```
+    pub fn write_bit(&self, area: MemoryArea, address: u16, bit: u8, value: bool) -> Result<()> {
+        if let Some(profile) = &self.config.cpu_profile {
+            profile.check_range(area, address, 1)?;
+        }
+        let sid = self.next_sid();
+        let cmd = WriteBitCommand::new_with_dm_bits(
+            self.destination,
+            self.source,
+            sid,
+            area,
+            address,
+            bit,
+            value,
+            self.config.dm_bit_access,
+        )?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes()?, sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Writes consecutive bits in a single request.
+    ///
+    /// All bits are set by one frame, so a bank of command bits changes
+    /// together. Bits continue into the following words after bit 15.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to (must support bit access)
+    /// * `address` - Word address of the first bit
+    /// * `start_bit` - Position of the first bit (0-15)
+    /// * `values` - Bit values to write, in order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Area doesn't support bit access
+    /// - Bit position > 15, or `values` is empty or too long
+    /// - Communication fails
+    /// - PLC returns an error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // W10.00-W10.03
+    /// client.write_bits(MemoryArea::WR, 10, 0, &[true, false, false, true])?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_bits(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        start_bit: u8,
+        values: &[bool],
+    ) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd = WriteBitCommand::new_with_dm_bits(
+            self.destination,
+            self.source,
+            sid,
+            area,
+            address,
+            start_bit,
+            false,
+            self.config.dm_bit_access,
+        )?
+        .with_values(values)?;
+        let words = (start_bit as u16 + values.len() as u16).div_ceil(16);
+        if let Some(profile) = &self.config.cpu_profile {
+            profile.check_range(area, address, words)?;
+        }
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes()?, sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Reads one word, applies `f` and writes the result back.
+    ///
+    /// This is how individual bits of DM and other word-only areas are
+    /// changed. The write is skipped when `f` returns the word unchanged.
+    ///
+    /// The read and the write are separate commands: if the PLC program
+    /// writes the same word in between, that change is lost. Use it on
+    /// words the program only reads, or hold the access right (see
+    /// [`acquire_access_right`](Self::acquire_access_right)) around it.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area
+    /// * `address` - Word address
+    /// * `f` - Computes the new word from the current one
+    ///
+    /// # Returns
+    ///
+    /// The word before and after the change, as `(old, new)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // Clear the low byte of D100, keeping the high byte
+    /// let (old, new) = client.modify_word(MemoryArea::DM, 100, |word| word & 0xFF00)?;
+    /// println!("D100: 0x{:04X} -> 0x{:04X}", old, new);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn modify_word(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        f: impl FnOnce(u16) -> u16,
+    ) -> Result<(u16, u16)> {
+        let old = self
+            .read(area, address, 1)?
+            .first()
+            .copied()
+            .ok_or_else(|| {
+                crate::error::FinsError::invalid_response("expected 1 word, got none")
+            })?;
+        let new = f(old);
+        if new != old {
+            self.write(area, address, &[new])?;
+        }
+        Ok((old, new))
+    }
+
+    /// Sets or clears one bit of a word with [`modify_word`](Self::modify_word).
+    ///
+    /// Works in areas without bit access, such as DM on older CPUs; the
+    /// same caveat about concurrent PLC writes applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `bit` is greater than 15,
+    /// otherwise the same errors as [`modify_word`](Self::modify_word).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // D200.03 ON
+    /// client.set_bit_in_word(MemoryArea::DM, 200, 3, true)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn set_bit_in_word(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        bit: u8,
+        value: bool,
+    ) -> Result<(u16, u16)> {
+        if bit > 15 {
+            return Err(crate::error::FinsError::invalid_parameter(
+                "bit",
+                "must be 0-15",
+            ));
+        }
+        self.modify_word(area, address, |word| utils::set_bit(word, bit, value))
+    }
+
+    /// Reads words starting at an address given in Omron notation.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Word address such as `"D100"` or `"W3"` (see
+    ///   [`FinsAddress`])
+    /// * `count` - Number of words to read
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the address does not parse
+    /// or names a bit, otherwise the same errors as [`read`](Self::read).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let data = client.read_addr("D100", 10)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_addr(&self, address: &str, count: u16) -> Result<Vec<u16>> {
+        let address = parse_word_address(address)?;
+        self.read(address.area, address.word, count)
+    }
+
+    /// Writes words starting at an address given in Omron notation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the address does not parse
+    /// or names a bit, otherwise the same errors as [`write`](Self::write).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_addr("E0_200", &[0x1234, 0x5678])?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_addr(&self, address: &str, data: &[u16]) -> Result<()> {
+        let address = parse_word_address(address)?;
+        self.write(address.area, address.word, data)
+    }
+
+    /// Reads a bit at an address given in Omron notation (e.g., `"CIO2.05"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the address does not parse
+    /// or has no bit, otherwise the same errors as
+    /// [`read_bit`](Self::read_bit).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let running = client.read_bit_addr("W3.12")?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_bit_addr(&self, address: &str) -> Result<bool> {
+        let (address, bit) = parse_bit_address(address)?;
+        self.read_bit(address.area, address.word, bit)
+    }
+
+    /// Writes a bit at an address given in Omron notation (e.g., `"CIO2.05"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the address does not parse
+    /// or has no bit, otherwise the same errors as
+    /// [`write_bit`](Self::write_bit).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_bit_addr("CIO2.05", true)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_bit_addr(&self, address: &str, value: bool) -> Result<()> {
+        let (address, bit) = parse_bit_address(address)?;
+        self.write_bit(address.area, address.word, bit, value)
+    }
+
+    /// Reads a named tag from the configured [`TagTable`].
+    ///
+    /// Bit tags are read with one bit read, value and string tags with one
+    /// word read. Multi-word values use the configured word order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the tag is unknown, otherwise
+    /// the same errors as the underlying read.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, DataType, TagKind, TagTable, TagValue};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let mut tags = TagTable::new();
+    /// tags.add("LineSpeed", "D100", TagKind::Value(DataType::REAL))?;
+    /// tags.add("EStop", "CIO0.05", TagKind::Bit)?;
+    ///
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0).with_tags(tags);
+    /// let client = Client::new(config)?;
+    ///
+    /// if client.read_tag("EStop")? == TagValue::Bit(true) {
+    ///     println!("speed: {:?}", client.read_tag("LineSpeed")?);
+    /// }
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_tag(&self, name: &str) -> Result<TagValue> {
+        let tag = self.config.tags.lookup(name)?;
+        let address = tag.address();
+        match tag.kind() {
+            TagKind::Bit => {
+                let bit = address.bit.unwrap_or_default();
+                self.read_bit(address.area, address.word, bit)
+                    .map(TagValue::Bit)
+            }
+            TagKind::Value(data_type) => {
+                let words = self.read(
+                    address.area,
+                    address.word,
+                    data_type.size().div_ceil(2) as u16,
+                )?;
+                let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_be_bytes()).collect();
+                PlcValue::from_plc_bytes_with(data_type, &bytes, self.word_order())
+                    .map(TagValue::Value)
+            }
+            TagKind::String { words } => self
+                .read_string(address.area, address.word, words)
+                .map(TagValue::String),
+        }
+    }
+
+    /// Writes a named tag from the configured [`TagTable`].
+    ///
+    /// Strings are zero-padded to the tag length.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the tag is unknown, the
+    /// value does not match the tag's kind or data type, or a string is
+    /// longer than the tag; otherwise the same errors as the underlying
+    /// write.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, DataType, PlcValue, TagKind, TagTable};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let mut tags = TagTable::new();
+    /// tags.add("LineSpeed", "D100", TagKind::Value(DataType::REAL))?;
+    /// tags.add("Reset", "W0.00", TagKind::Bit)?;
+    ///
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0).with_tags(tags);
+    /// let client = Client::new(config)?;
+    ///
+    /// client.write_tag("LineSpeed", PlcValue::Real(12.5))?;
+    /// client.write_tag("Reset", true)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_tag(&self, name: &str, value: impl Into<TagValue>) -> Result<()> {
+        let tag = self.config.tags.lookup(name)?;
+        let address = tag.address();
+        match (tag.kind(), value.into()) {
+            (TagKind::Bit, TagValue::Bit(value)) => {
+                let bit = address.bit.unwrap_or_default();
+                self.write_bit(address.area, address.word, bit, value)
+            }
+            (TagKind::String { words }, TagValue::String(text)) => {
+                if text.len() > words as usize * 2 {
+                    return Err(crate::error::FinsError::invalid_parameter(
+                        "value",
+                        format!("'{}' does not fit tag '{}' of {} words", text, name, words),
+                    ));
+                }
+                self.write_string_fixed(address.area, address.word, &text, words, 0)
+            }
+            (TagKind::Value(data_type), TagValue::Value(value))
+                if value.data_type() == data_type =>
+            {
+                let words: Vec<u16> = value
+                    .to_plc_bytes_with(self.word_order())
+                    .chunks(2)
+                    .map(|pair| u16::from_be_bytes([pair[0], pair.get(1).copied().unwrap_or(0)]))
+                    .collect();
+                self.write(address.area, address.word, &words)
+            }
+            (_, value) => Err(tag.mismatch(&value)),
+        }
+    }
+
+    /// Fills a memory area with a single value.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to fill
+    /// * `address` - Starting word address
+    /// * `count` - Number of words to fill (1-999)
+    /// * `value` - Value to fill with
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Count is 0 or > 999
+    /// - Communication fails
+    /// - PLC returns an error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// // Zero out DM100-DM149
+    /// client.fill(MemoryArea::DM, 100, 50, 0x0000).unwrap();
+    /// ```
+    pub fn fill(&self, area: MemoryArea, address: u16, count: u16, value: u16) -> Result<()> {
+        self.check_bounds(area, address, count)?;
+        self.fill_chunks(area, address, count, value)
+    }
+
+    /// Fills `count` words in chunks of at most
+    /// [`MAX_WORDS_PER_COMMAND`](crate::MAX_WORDS_PER_COMMAND). Callers
+    /// check the bounds.
+    fn fill_chunks(&self, area: MemoryArea, address: u16, count: u16, value: u16) -> Result<()> {
+        let range = AreaRange::new(area, address, count);
+        for (index, chunk) in range.command_chunks().enumerate() {
+            if index > 0 {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+
+            let sid = self.next_sid();
+            let cmd = FillCommand::new(
+                self.destination,
+                self.source,
+                sid,
+                area,
+                chunk.start,
+                chunk.len,
+                value,
+            )?;
+
+            let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+            self.check_response(&response)?;
+        }
+
+        Ok(())
+    }
+
+    /// Fills a large block of memory with a single value.
+    ///
+    /// Like [`read_large`](Self::read_large), the count may cover the whole
+    /// address space; it is split into sequential Memory Area Fill commands,
+    /// each a separate FINS transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to fill
+    /// * `address` - Starting word address
+    /// * `count` - Number of words to fill
+    /// * `value` - Value to write to all words
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the block runs past the 65535-word address space
+    /// or, with a [`CpuProfile`], the end of the area, communication fails
+    /// or PLC returns an error. Commands already completed are not undone.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // Zero the whole 32K-word DM area
+    /// client.fill_large(MemoryArea::DM, 0, 32768, 0)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn fill_large(&self, area: MemoryArea, address: u16, count: u32, value: u16) -> Result<()> {
+        let count = self.check_large_bounds(area, address, count)?;
+        self.fill_chunks(area, address, count, value)
+    }
+
+    /// Reads every word in `range`, split into command-sized chunks.
+    ///
+    /// Equivalent to [`read`](Self::read) with the range's area, start and
+    /// length.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`read`](Self::read).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{AreaRange, Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let recipe = AreaRange::new(MemoryArea::DM, 1000, 2500);
+    /// let words = client.read_range(&recipe)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_range(&self, range: &AreaRange) -> Result<Vec<u16>> {
+        self.read(range.area, range.start, range.len)
+    }
+
+    /// Writes `data` to `range`, split into command-sized chunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `data` does not have exactly
+    /// `range.len` words, otherwise the same errors as [`write`](Self::write).
+    pub fn write_range(&self, range: &AreaRange, data: &[u16]) -> Result<()> {
+        if data.len() != range.len as usize {
+            return Err(crate::error::FinsError::invalid_parameter(
+                "data",
+                format!("range has {} words, got {}", range.len, data.len()),
+            ));
+        }
+        self.write(range.area, range.start, data)
+    }
+
+    /// Fills every word in `range` with `value`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`fill`](Self::fill).
+    pub fn fill_range(&self, range: &AreaRange, value: u16) -> Result<()> {
+        self.fill(range.area, range.start, range.len, value)
+    }
+
+    /// Puts the PLC into run mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - PLC operating mode (Debug, Monitor, or Run)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, PlcMode};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// client.run(PlcMode::Monitor).unwrap();
+    /// ```
+    pub fn run(&self, mode: PlcMode) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd = RunCommand::new(self.destination, self.source, sid, mode);
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Stops the PLC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// client.stop().unwrap();
+    /// ```
+    pub fn stop(&self) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd = StopCommand::new(self.destination, self.source, sid);
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Stops the PLC, sending an explicit program number.
+    ///
+    /// Use this instead of [`stop`](Self::stop) for controller families
+    /// that reject the STOP command without a program number. Pass
+    /// `0xFFFF` for the current program.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.stop_program(0xFFFF)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn stop_program(&self, program_number: u16) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd = StopCommand::new(self.destination, self.source, sid)
+            .with_program_number(program_number);
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Resets a unit of the destination node (Reset, 0x04 0x03).
+    ///
+    /// **Warning:** this restarts the unit. Resetting an Ethernet unit drops
+    /// every connection through it, including this one, and resetting the
+    /// CPU unit stops the PLC program. Use it to recover hung option boards
+    /// only, and only with [`ClientConfig::with_unit_reset_allowed`] set.
+    ///
+    /// Units may restart before replying, so a timeout is treated as
+    /// success.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Unit address to reset (0x10 + unit number for CPU Bus
+    ///   Units such as Ethernet units)
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if resets are not enabled, or
+    /// an error if communication fails or the unit returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+    ///     .with_unit_reset_allowed(true);
+    /// let client = Client::new(config)?;
+    ///
+    /// // Reset the Ethernet unit with unit number 0
+    /// client.reset_unit(0x10)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn reset_unit(&self, unit: u8) -> Result<()> {
+        use crate::error::FinsError;
+
+        if !self.config.allow_unit_reset {
+            return Err(FinsError::invalid_parameter(
+                "unit",
+                "unit reset is disabled; enable it with ClientConfig::with_unit_reset_allowed",
+            ));
+        }
+
+        let sid = self.next_sid();
+        let destination = NodeAddress {
+            unit,
+            ..self.destination
+        };
+        let cmd = ResetCommand::new(destination, self.source, sid);
+
+        match self.send_receive_with_sid(&cmd.to_bytes(), sid) {
+            Ok(response) => self.check_response(&response),
+            Err(FinsError::Timeout) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Runs an internode echo test (0x08 0x01), the FINS equivalent of ping.
+    ///
+    /// Sends `payload` to the destination, checks that the same bytes come
+    /// back and returns the round-trip time. Useful for diagnosing routing
+    /// problems without touching PLC memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidResponse` if the echoed data differs, or
+    /// an error if the payload is too long, communication fails, or the
+    /// destination returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let rtt = client.echo_test(b"0123456789")?;
+    /// println!("round trip: {:?}", rtt);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn echo_test(&self, payload: &[u8]) -> Result<Duration> {
+        use crate::error::FinsError;
+
+        let sid = self.next_sid();
+        let cmd = EchoTestCommand::new(self.destination, self.source, sid, payload)?;
+
+        let started = Instant::now();
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        let elapsed = started.elapsed();
+        self.check_response(&response)?;
+
+        if response.data != payload {
+            return Err(FinsError::invalid_response(format!(
+                "echo test data mismatch: sent {} bytes, received {} bytes",
+                payload.len(),
+                response.data.len()
+            )));
+        }
+        Ok(elapsed)
+    }
+
+    /// Reads the unit composition of the destination node
+    /// (Connection Data Read, 0x05 0x02).
+    ///
+    /// Lists every unit with its unit address and model, e.g. to find the
+    /// Ethernet unit's address for [`reset_unit`](Self::reset_unit) or for
+    /// [`ClientConfig::with_dest_unit`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or the PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let ethernet = client
+    ///     .read_connection_data()?
+    ///     .into_iter()
+    ///     .find(|unit| unit.model.contains("ETN"));
+    /// if let Some(unit) = ethernet {
+    ///     println!("Ethernet unit at 0x{:02X}", unit.unit_address);
+    /// }
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_connection_data(&self) -> Result<Vec<UnitInfo>> {
+        let sid = self.next_sid();
+        let cmd = ConnectionDataReadCommand::new(
+            self.destination,
+            self.source,
+            sid,
+            0x00,
+            MAX_CONNECTION_DATA_UNITS,
+        )?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        response.to_connection_data()
+    }
+
+    /// Acquires the access right for program and parameter area writes.
+    ///
+    /// Returns [`AccessRight::HeldBy`] instead of an error when another
+    /// device (e.g., a CX-Programmer session) already holds it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or the PLC returns an error
+    /// other than "access right held by another device".
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{AccessRight, Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// match client.acquire_access_right()? {
+    ///     AccessRight::Acquired => {
+    ///         // ... write program or parameter areas ...
+    ///         client.release_access_right()?;
+    ///     }
+    ///     AccessRight::HeldBy(holder) => println!("held by node {}", holder.node),
+    /// }
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn acquire_access_right(&self) -> Result<AccessRight> {
+        let sid = self.next_sid();
+        let cmd = AccessRightCommand::new(
+            self.destination,
+            self.source,
+            sid,
+            AccessRightAction::Acquire,
+        );
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        response.to_access_right()
+    }
+
+    /// Acquires the access right even if another device holds it.
+    ///
+    /// The previous holder is not notified; use with care while an
+    /// engineer is online with the PLC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    pub fn forced_acquire_access_right(&self) -> Result<()> {
+        self.access_right(AccessRightAction::ForcedAcquire)
+    }
+
+    /// Releases the access right.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    pub fn release_access_right(&self) -> Result<()> {
+        self.access_right(AccessRightAction::Release)
+    }
+
+    fn access_right(&self, action: AccessRightAction) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd = AccessRightCommand::new(self.destination, self.source, sid, action);
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Reads MSG instruction messages.
+    ///
+    /// # Arguments
+    ///
+    /// * `numbers` - Message numbers to read (0-7)
+    ///
+    /// # Returns
+    ///
+    /// A map from message number to its text (empty if no message is set).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a message number is invalid, communication
+    /// fails, or the PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// for (number, text) in client.read_messages(&[0, 1, 2, 3, 4, 5, 6, 7])? {
+    ///     if !text.is_empty() {
+    ///         println!("MSG {}: {}", number, text);
+    ///     }
+    /// }
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_messages(&self, numbers: &[u8]) -> Result<BTreeMap<u8, String>> {
+        let sid = self.next_sid();
+        let cmd = MessageReadCommand::new(self.destination, self.source, sid, numbers)?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        response.to_messages()
+    }
+
+    /// Clears MSG instruction messages.
+    ///
+    /// # Arguments
+    ///
+    /// * `numbers` - Message numbers to clear (0-7)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a message number is invalid, communication
+    /// fails, or the PLC returns an error.
+    pub fn clear_messages(&self, numbers: &[u8]) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd = MessageClearCommand::new(self.destination, self.source, sid, numbers)?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Reads records from the controller error log.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - First record number to read (0 = oldest)
+    /// * `count` - Number of records to read (1-20)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if count is out of range, communication fails,
+    /// or the PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// for entry in client.read_error_log(0, 20)? {
+    ///     println!("{} error 0x{:04X}", entry.timestamp, entry.error_code);
+    /// }
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_error_log(&self, start: u16, count: u16) -> Result<Vec<ErrorLogEntry>> {
+        let sid = self.next_sid();
+        let cmd = ErrorLogReadCommand::new(self.destination, self.source, sid, start, count)?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        response.to_error_log()
+    }
+
+    /// Clears the controller error log.
+    ///
+    /// Typically called after exporting the log with
+    /// [`read_error_log`](Self::read_error_log).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or the PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let log = client.read_error_log(0, 20)?;
+    /// // ... export log ...
+    /// client.clear_error_log()?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn clear_error_log(&self) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd = ErrorLogClearCommand::new(self.destination, self.source, sid);
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Transfers data from one memory area to another within the PLC.
+    ///
+    /// # Arguments
+    ///
+    /// * `src_area` - Source memory area
+    /// * `src_address` - Source starting address
+    /// * `dst_area` - Destination memory area
+    /// * `dst_address` - Destination starting address
+    /// * `count` - Number of words to transfer (1-999)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Count is 0 or > 999
+    /// - Communication fails
+    /// - PLC returns an error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// // Copy DM100-DM109 to DM200-DM209
+    /// client.transfer(MemoryArea::DM, 100, MemoryArea::DM, 200, 10).unwrap();
+    /// ```
+    pub fn transfer(
+        &self,
+        src_area: MemoryArea,
+        src_address: u16,
+        dst_area: MemoryArea,
+        dst_address: u16,
+        count: u16,
+    ) -> Result<()> {
+        self.check_bounds(src_area, src_address, count)?;
+        self.check_bounds(dst_area, dst_address, count)?;
+        self.transfer_chunks(src_area, src_address, dst_area, dst_address, count)
+    }
+
+    /// Transfers `count` words in chunks of at most
+    /// [`MAX_WORDS_PER_COMMAND`](crate::MAX_WORDS_PER_COMMAND). Callers
+    /// check the bounds.
+    fn transfer_chunks(
+        &self,
+        src_area: MemoryArea,
+        mut src_address: u16,
+        dst_area: MemoryArea,
+        mut dst_address: u16,
+        mut count: u16,
+    ) -> Result<()> {
+        while count > 0 {
+            let chunk_size = std::cmp::min(count, MAX_WORDS_PER_COMMAND);
+            let sid = self.next_sid();
+            let cmd = TransferCommand::new(
+                self.destination,
+                self.source,
+                sid,
+                src_area,
+                src_address,
+                dst_area,
+                dst_address,
+                chunk_size,
+            )?;
+
+            let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+            self.check_response(&response)?;
+
+            src_address += chunk_size;
+            dst_address += chunk_size;
+            count -= chunk_size;
+
+            if count > 0 {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Transfers a large block between memory areas within the PLC.
+    ///
+    /// The count may cover the whole address space; it is split into
+    /// sequential Memory Area Transfer commands, each a separate FINS
+    /// transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `src_area` - Source memory area
+    /// * `src_address` - Source starting address
+    /// * `dst_area` - Destination memory area
+    /// * `dst_address` - Destination starting address
+    /// * `count` - Number of words to transfer
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either block runs past the 65535-word address
+    /// space or, with a [`CpuProfile`], the end of its area, communication
+    /// fails or PLC returns an error. Commands already completed are not
+    /// undone.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // Back up D0-D15999 to D16000-D31999
+    /// client.transfer_large(MemoryArea::DM, 0, MemoryArea::DM, 16000, 16000)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn transfer_large(
+        &self,
+        src_area: MemoryArea,
+        src_address: u16,
+        dst_area: MemoryArea,
+        dst_address: u16,
+        count: u32,
+    ) -> Result<()> {
+        self.check_large_bounds(dst_area, dst_address, count)?;
+        let count = self.check_large_bounds(src_area, src_address, count)?;
+        self.transfer_chunks(src_area, src_address, dst_area, dst_address, count)
+    }
+
+    /// Writes a file to the memory card or EM file memory.
+    ///
+    /// Data longer than [`MAX_FILE_CHUNK`](crate::MAX_FILE_CHUNK) bytes is
+    /// sent in several Single File Write commands (0x22 0x03): the first
+    /// one applies `mode`, the rest append to the file. Appended chunks are
+    /// never resent after a stale response, since the PLC would append them
+    /// twice; the write fails with `FinsError::SidMismatch` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `disk` - File device to write to
+    /// * `name` - File name in 8.3 format (e.g., "RECIPE.CSV")
+    /// * `data` - File contents
+    /// * `mode` - Create, overwrite or append
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is invalid, communication fails, or
+    /// the PLC returns an error (e.g., no card or file already exists). A
+    /// failure after the first chunk leaves a partially written file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, FileDisk, FileWriteMode};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let recipe = std::fs::read("recipe.csv")?;
+    /// client.write_file(FileDisk::MemoryCard, "RECIPE.CSV", &recipe, FileWriteMode::Overwrite)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_file(
+        &self,
+        disk: FileDisk,
+        name: &str,
+        data: &[u8],
+        mode: FileWriteMode,
+    ) -> Result<()> {
+        let mut position = 0usize;
+        let mut chunk_mode = mode;
+
+        loop {
+            let chunk_end = (position + MAX_FILE_CHUNK).min(data.len());
+            let chunk = &data[position..chunk_end];
+
+            let sid = self.next_sid();
+            let cmd = FileWriteCommand::new(
+                self.destination,
+                self.source,
+                sid,
+                disk,
+                name,
+                chunk_mode,
+                position as u32,
+                chunk,
+            )?;
+            // A resent append chunk would be appended twice
+            let response = if position == 0 {
+                self.send_receive_with_sid(&cmd.to_bytes(), sid)?
+            } else {
+                self.send_receive_once(&cmd.to_bytes(), sid)?
+            };
+            self.check_response(&response)?;
+
+            position = chunk_end;
+            if position >= data.len() {
+                return Ok(());
+            }
+            chunk_mode = FileWriteMode::Append;
+        }
+    }
+
+    /// Renames a file on the memory card or EM file memory.
+    ///
+    /// Writing to a temporary name and renaming it afterwards lets the PLC
+    /// never see a half-written recipe file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a name is invalid, communication fails, or the
+    /// PLC returns an error (e.g., file not found or new name in use).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, FileDisk, FileWriteMode};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let recipe = b"1,2,3\r\n";
+    /// client.write_file(FileDisk::MemoryCard, "RECIPE.TMP", recipe, FileWriteMode::Overwrite)?;
+    /// client.rename_file(FileDisk::MemoryCard, "RECIPE.TMP", "RECIPE.CSV")?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn rename_file(&self, disk: FileDisk, old_name: &str, new_name: &str) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd =
+            FileRenameCommand::new(self.destination, self.source, sid, disk, old_name, new_name)?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Transfers words between PLC memory and a file on the PLC side
+    /// (Memory Area-File Transfer, 0x22 0x0A).
+    ///
+    /// Backing up a large DM block this way takes one command instead of
+    /// many reads; the resulting file can then be retrieved separately.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - Memory to file, or file to memory
+    /// * `area` - Memory area
+    /// * `address` - Starting word address
+    /// * `count` - Number of words to transfer
+    /// * `disk` - File device holding the file
+    /// * `name` - File name in 8.3 format
+    ///
+    /// # Returns
+    ///
+    /// The number of words the PLC reports as transferred.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parameters are invalid, communication fails, or
+    /// the PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, FileDisk, FileTransferDirection, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // Back up DM0-DM4095 to the memory card
+    /// client.transfer_memory_file(
+    ///     FileTransferDirection::ToFile,
+    ///     MemoryArea::DM,
+    ///     0,
+    ///     4096,
+    ///     FileDisk::MemoryCard,
+    ///     "DMBACKUP.IOM",
+    /// )?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn transfer_memory_file(
+        &self,
+        direction: FileTransferDirection,
+        area: MemoryArea,
+        address: u16,
+        count: u16,
+        disk: FileDisk,
+        name: &str,
+    ) -> Result<u16> {
+        use crate::error::FinsError;
+
+        let sid = self.next_sid();
+        let cmd = MemoryFileTransferCommand::new(
+            self.destination,
+            self.source,
+            sid,
+            direction,
+            area,
+            address,
+            count,
+            disk,
+            name,
+        )?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        match response.data.get(..2) {
+            Some(transferred) => Ok(u16::from_be_bytes([transferred[0], transferred[1]])),
+            None => Err(FinsError::invalid_response(
+                "memory-file transfer response missing word count",
+            )),
+        }
+    }
+
+    /// Transfers the user program between the program area and a file on
+    /// the PLC side (Program Area-File Transfer, 0x22 0x0C).
+    ///
+    /// Loading a program from a file ([`FileTransferDirection::FromFile`])
+    /// requires the PLC to be in PROGRAM mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is invalid, communication fails, or the
+    /// PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, FileDisk, FileTransferDirection};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // Dump a ladder backup to the memory card
+    /// client.transfer_program_file(
+    ///     FileTransferDirection::ToFile,
+    ///     FileDisk::MemoryCard,
+    ///     "BACKUP.OBJ",
+    /// )?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn transfer_program_file(
+        &self,
+        direction: FileTransferDirection,
+        disk: FileDisk,
+        name: &str,
+    ) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd = ProgramFileTransferCommand::new(
+            self.destination,
+            self.source,
+            sid,
+            direction,
+            disk,
+            name,
+        )?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Forces bits ON/OFF in the PLC, overriding normal program control.
+    ///
+    /// # Arguments
+    ///
+    /// * `specs` - List of bits to force with their specifications
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Specs is empty
+    /// - Any area doesn't support bit access
+    /// - Any bit position > 15
+    /// - Communication fails
+    /// - PLC returns an error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, ForcedBit, ForceSpec, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// client.forced_set_reset(&[
+    ///     ForcedBit { area: MemoryArea::CIO, address: 0, bit: 0, spec: ForceSpec::ForceOn },
+    ///     ForcedBit { area: MemoryArea::CIO, address: 0, bit: 1, spec: ForceSpec::ForceOff },
+    /// ]).unwrap();
+    /// ```
+    pub fn forced_set_reset(&self, specs: &[ForcedBit]) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd = ForcedSetResetCommand::new_with_dm_bits(
+            self.destination,
+            self.source,
+            sid,
+            specs.to_vec(),
+            self.config.dm_bit_access,
+        )?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes()?, sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Cancels all forced bits in the PLC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// client.forced_set_reset_cancel().unwrap();
+    /// ```
+    pub fn forced_set_reset_cancel(&self) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd = ForcedSetResetCancelCommand::new(self.destination, self.source, sid);
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes(), sid)?;
+        self.check_response(&response)?;
+        Ok(())
+    }
+
+    /// Reads from multiple memory areas in a single request.
+    ///
+    /// # Arguments
+    ///
+    /// * `specs` - List of read specifications
+    ///
+    /// # Returns
+    ///
+    /// A vector of u16 values in the same order as the specs.
+    /// For word reads, the full u16 value is returned.
+    /// For bit reads, 0x0000 (OFF) or 0x0001 (ON) is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Specs is empty
+    /// - Any bit area doesn't support bit access
+    /// - Any bit position > 15
+    /// - Communication fails
+    /// - PLC returns an error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MultiReadSpec, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// let values = client.read_multiple(&[
+    ///     MultiReadSpec { area: MemoryArea::DM, address: 100, bit: None },
+    ///     MultiReadSpec { area: MemoryArea::DM, address: 200, bit: None },
+    ///     MultiReadSpec { area: MemoryArea::CIO, address: 0, bit: Some(5) },
+    /// ]).unwrap();
+    /// // values[0] = DM100, values[1] = DM200, values[2] = CIO0.05 (0 or 1)
+    /// ```
+    pub fn read_multiple(&self, specs: &[MultiReadSpec]) -> Result<Vec<u16>> {
+        let sid = self.next_sid();
+        let cmd = MultipleReadCommand::new_with_dm_bits(
+            self.destination,
+            self.source,
+            sid,
+            specs.to_vec(),
+            self.config.dm_bit_access,
+        )?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes()?, sid)?;
+        self.check_response(&response)?;
+        Ok(response
+            .to_multi_read_with(specs, self.config.dm_bit_access)?
+            .into_iter()
+            .map(MultiReadResult::as_u16)
+            .collect())
+    }
+
+    /// Reads from multiple memory areas in a single request, returning a
+    /// typed value for each spec.
+    ///
+    /// Like [`read_multiple`](Self::read_multiple), but word specs yield
+    /// [`MultiReadResult::Word`] and bit specs [`MultiReadResult::Bit`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`read_multiple`](Self::read_multiple).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, MultiReadResult, MultiReadSpec};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let values = client.read_multiple_typed(&[
+    ///     MultiReadSpec { area: MemoryArea::DM, address: 100, bit: None },
+    ///     MultiReadSpec { area: MemoryArea::CIO, address: 0, bit: Some(5) },
+    /// ])?;
+    /// if let MultiReadResult::Bit(running) = values[1] {
+    ///     println!("running: {}", running);
+    /// }
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_multiple_typed(&self, specs: &[MultiReadSpec]) -> Result<Vec<MultiReadResult>> {
+        let sid = self.next_sid();
+        let cmd = MultipleReadCommand::new_with_dm_bits(
+            self.destination,
+            self.source,
+            sid,
+            specs.to_vec(),
+            self.config.dm_bit_access,
+        )?;
+
+        let response = self.send_receive_with_sid(&cmd.to_bytes()?, sid)?;
+        self.check_response(&response)?;
+        response.to_multi_read_with(specs, self.config.dm_bit_access)
+    }
+
+    /// Executes a [`BatchRead`] with as few commands as possible.
+    ///
+    /// Bits and values of up to two words are combined into Multiple Memory
+    /// Area Read commands; longer blocks use [`read`](Self::read). The
+    /// commands are separate FINS transactions, so values from different
+    /// commands are not a consistent snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any command fails; no partial results are
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{BatchRead, Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let mut batch = BatchRead::new();
+    /// let speed = batch.f32(MemoryArea::DM, 100);
+    /// let running = batch.bit(MemoryArea::CIO, 0, 5);
+    /// let log = batch.words(MemoryArea::DM, 1000, 200);
+    ///
+    /// let results = client.read_batch(&batch)?;
+    /// println!("speed {:?}, running {:?}", results.f32(speed), results.bit(running));
+    /// println!("{} log words", results.words(log).unwrap_or_default().len());
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_batch(&self, batch: &BatchRead) -> Result<BatchResults> {
+        let plan = batch.plan();
+        let mut words = vec![Vec::new(); batch.len()];
+
+        for command in plan.multi_reads {
+            let specs: Vec<MultiReadSpec> = command.iter().map(|(_, spec)| spec.clone()).collect();
+            let values = self.read_multiple_typed(&specs)?;
+            for ((index, _), value) in command.iter().zip(values) {
+                words[*index].push(value.as_u16());
+            }
+        }
+        for (index, area, address, count) in plan.reads {
+            words[index] = self.read(area, address, count)?;
+        }
+
+        batch.decode(words, self.word_order())
+    }
+
+    /// Sends an arbitrary FINS command and returns the raw response.
+    ///
+    /// The header and Service ID are filled in by the client and the
+    /// exchange goes through the normal SID-validated send/receive, so
+    /// observers, recording and stats see it like any other request. The
+    /// response end code is not checked; call
+    /// [`FinsResponse::check_error`] if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mrc` - Main request code
+    /// * `src` - Sub request code
+    /// * `payload` - Command data following the command code
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is too large or communication fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // Clock Read (0x07 0x01)
+    /// let response = client.execute_raw(0x07, 0x01, &[])?;
+    /// response.check_error()?;
+    /// println!("clock bytes: {:02X?}", response.data);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn execute_raw(&self, mrc: u8, src: u8, payload: &[u8]) -> Result<FinsResponse> {
+        let sid = self.next_sid();
+        let cmd = RawCommand::new(self.destination, self.source, sid, mrc, src, payload)?;
+
+        self.send_receive_with_sid(&cmd.to_bytes(), sid)
+    }
+
+    /// Sends a FINS command with the "response not required" ICF bit set.
+    ///
+    /// Returns as soon as the frame is sent; the destination does not
+    /// reply, so there is no way to tell whether it executed the command.
+    /// The configured Gateway Count is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Node to send the command to
+    /// * `mrc` - Main request code
+    /// * `src` - Sub request code
+    /// * `payload` - Command data following the command code
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is too large or the send fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, NodeAddress};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // STOP (0x04 0x02) node 12 without waiting for the reply
+    /// client.send_no_response(NodeAddress::new(0, 12, 0), 0x04, 0x02, &[])?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn send_no_response(
+        &self,
+        destination: NodeAddress,
+        mrc: u8,
+        src: u8,
+        payload: &[u8],
+    ) -> Result<()> {
+        let sid = self.next_sid();
+        let cmd = RawCommand::new(destination, self.source, sid, mrc, src, payload)?;
+
+        let mut frame = cmd.to_bytes();
+        self.config
+            .header_options
+            .with_response_required(false)
+            .apply(&mut frame);
+
+        self.pace();
+        if let Some(metrics) = &self.metrics {
+            metrics.on_request(mrc, src);
+        }
+        self.observe(Direction::Sent, &frame);
+        self.stats.record_sent(frame.len());
+        self.transport.send(&frame)
+    }
+
+    /// Broadcasts a FINS command to every node on the destination network.
+    ///
+    /// The command is addressed to node [`BROADCAST_NODE`] on the network
+    /// and unit of the configured destination and sent without waiting for
+    /// responses, e.g. to stop every PLC on a segment or set all clocks at
+    /// once. Over UDP the socket must also be allowed to broadcast and the
+    /// client pointed at the subnet broadcast address (see
+    /// [`SocketOptions::with_broadcast`](crate::SocketOptions::with_broadcast)).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is too large or the send fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, SocketOptions};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 255), 1, 0)
+    ///     .with_socket_options(SocketOptions::new().with_broadcast(true));
+    /// let client = Client::new(config)?;
+    ///
+    /// // STOP every PLC on the segment
+    /// client.broadcast(0x04, 0x02, &[])?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn broadcast(&self, mrc: u8, src: u8, payload: &[u8]) -> Result<()> {
+        let destination = NodeAddress::new(
+            self.destination.network,
+            BROADCAST_NODE,
+            self.destination.unit,
+        );
+        self.send_no_response(destination, mrc, src, payload)
+    }
+
+    /// Reads an f32 (REAL) value from 2 consecutive words.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// let temperature: f32 = client.read_f32(MemoryArea::DM, 100).unwrap();
+    /// ```
+    pub fn read_f32(&self, area: MemoryArea, address: u16) -> Result<f32> {
+        self.read_f32_with_order(area, address, self.word_order())
+    }
+
+    /// Reads an f32 (REAL) value with an explicit word order.
+    ///
+    /// For regions laid out differently from the rest of the PLC, e.g.
+    /// written by a third-party gateway; [`read_f32`](Self::read_f32) uses the
+    /// configured [`WordOrder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, WordOrder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let value = client.read_f32_with_order(MemoryArea::DM, 5000, WordOrder::HighFirst)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_f32_with_order(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        order: WordOrder,
+    ) -> Result<f32> {
+        let words = self.read(area, address, 2)?;
+        Ok(f32::from_be_bytes(words_to_native(&words, order)))
+    }
+
+    /// Writes an f32 (REAL) value to 2 consecutive words.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `value` - f32 value to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// client.write_f32(MemoryArea::DM, 100, 3.14159).unwrap();
+    /// ```
+    pub fn write_f32(&self, area: MemoryArea, address: u16, value: f32) -> Result<()> {
+        self.write_f32_with_order(area, address, value, self.word_order())
+    }
+
+    /// Writes an f32 (REAL) value with an explicit word order.
+    ///
+    /// The counterpart of [`read_f32_with_order`](Self::read_f32_with_order).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, WordOrder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_f32_with_order(MemoryArea::DM, 5000, 3.5, WordOrder::HighFirst)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_f32_with_order(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        value: f32,
+        order: WordOrder,
+    ) -> Result<()> {
+        let words = native_to_words(value.to_be_bytes(), order);
+        self.write(area, address, &words)
+    }
+
+    /// Reads an f64 (LREAL) value from 4 consecutive words.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// let value: f64 = client.read_f64(MemoryArea::DM, 100).unwrap();
+    /// ```
+    pub fn read_f64(&self, area: MemoryArea, address: u16) -> Result<f64> {
+        self.read_f64_with_order(area, address, self.word_order())
+    }
+
+    /// Reads an f64 (LREAL) value with an explicit word order.
+    ///
+    /// For regions laid out differently from the rest of the PLC, e.g.
+    /// written by a third-party gateway; [`read_f64`](Self::read_f64) uses the
+    /// configured [`WordOrder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, WordOrder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let value = client.read_f64_with_order(MemoryArea::DM, 5000, WordOrder::HighFirst)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_f64_with_order(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        order: WordOrder,
+    ) -> Result<f64> {
+        let words = self.read(area, address, 4)?;
+        Ok(f64::from_be_bytes(words_to_native(&words, order)))
+    }
+
+    /// Writes an f64 (LREAL) value to 4 consecutive words.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `value` - f64 value to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// client.write_f64(MemoryArea::DM, 100, 3.141592653589793).unwrap();
+    /// ```
+    pub fn write_f64(&self, area: MemoryArea, address: u16, value: f64) -> Result<()> {
+        self.write_f64_with_order(area, address, value, self.word_order())
+    }
+
+    /// Writes an f64 (LREAL) value with an explicit word order.
+    ///
+    /// The counterpart of [`read_f64_with_order`](Self::read_f64_with_order).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, WordOrder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_f64_with_order(MemoryArea::DM, 5000, 3.5, WordOrder::HighFirst)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_f64_with_order(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        value: f64,
+        order: WordOrder,
+    ) -> Result<()> {
+        let words = native_to_words(value.to_be_bytes(), order);
+        self.write(area, address, &words)
+    }
+
+    /// Reads a u64 (ULINT) value from 4 consecutive words.
+    ///
+    /// Uses the configured [`WordOrder`] (least significant word first by
+    /// default), like [`read_f64`](Self::read_f64).
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let energy_wh: u64 = client.read_u64(MemoryArea::DM, 100)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_u64(&self, area: MemoryArea, address: u16) -> Result<u64> {
+        self.read_u64_with_order(area, address, self.word_order())
+    }
+
+    /// Reads a u64 (ULINT) value with an explicit word order.
+    ///
+    /// For regions laid out differently from the rest of the PLC, e.g.
+    /// written by a third-party gateway; [`read_u64`](Self::read_u64) uses the
+    /// configured [`WordOrder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, WordOrder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let value = client.read_u64_with_order(MemoryArea::DM, 5000, WordOrder::HighFirst)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_u64_with_order(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        order: WordOrder,
+    ) -> Result<u64> {
+        let words = self.read(area, address, 4)?;
+        Ok(u64::from_be_bytes(words_to_native(&words, order)))
+    }
+
+    /// Reads an i64 (LINT) value from 4 consecutive words.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let position: i64 = client.read_i64(MemoryArea::DM, 100)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_i64(&self, area: MemoryArea, address: u16) -> Result<i64> {
+        self.read_i64_with_order(area, address, self.word_order())
+    }
+
+    /// Reads an i64 (LINT) value with an explicit word order.
+    ///
+    /// For regions laid out differently from the rest of the PLC, e.g.
+    /// written by a third-party gateway; [`read_i64`](Self::read_i64) uses the
+    /// configured [`WordOrder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, WordOrder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let value = client.read_i64_with_order(MemoryArea::DM, 5000, WordOrder::HighFirst)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_i64_with_order(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        order: WordOrder,
+    ) -> Result<i64> {
+        self.read_u64_with_order(area, address, order)
+            .map(|value| value as i64)
+    }
+
+    /// Writes a u64 (ULINT) value to 4 consecutive words.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `value` - Value to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_u64(MemoryArea::DM, 100, 0)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_u64(&self, area: MemoryArea, address: u16, value: u64) -> Result<()> {
+        self.write_u64_with_order(area, address, value, self.word_order())
+    }
+
+    /// Writes a u64 (ULINT) value with an explicit word order.
+    ///
+    /// The counterpart of [`read_u64_with_order`](Self::read_u64_with_order).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, WordOrder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_u64_with_order(MemoryArea::DM, 5000, 7, WordOrder::HighFirst)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_u64_with_order(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        value: u64,
+        order: WordOrder,
+    ) -> Result<()> {
+        let words = native_to_words(value.to_be_bytes(), order);
+        self.write(area, address, &words)
+    }
+
+    /// Writes an i64 (LINT) value to 4 consecutive words.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `value` - Value to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_i64(MemoryArea::DM, 100, -1)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_i64(&self, area: MemoryArea, address: u16, value: i64) -> Result<()> {
+        self.write_i64_with_order(area, address, value, self.word_order())
+    }
+
+    /// Writes an i64 (LINT) value with an explicit word order.
+    ///
+    /// The counterpart of [`read_i64_with_order`](Self::read_i64_with_order).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, WordOrder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_i64_with_order(MemoryArea::DM, 5000, -7, WordOrder::HighFirst)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_i64_with_order(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        value: i64,
+        order: WordOrder,
+    ) -> Result<()> {
+        self.write_u64_with_order(area, address, value as u64, order)
+    }
+
+    /// Reads a date and time stored in the given format.
+    ///
+    /// See [`TimeFormat`] for the layouts; binary values
+    /// use the configured word order.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    /// * `format` - BCD (CS/CJ/CP) or binary (NJ/NX) layout
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails, PLC returns an error or the
+    /// words are not a valid date and time.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, TimeFormat};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // CPU clock in A351-A353
+    /// let now = client.read_date_time(MemoryArea::AR, 351, TimeFormat::Bcd)?;
+    /// println!("PLC time: {}", now);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_date_time(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        format: TimeFormat,
+    ) -> Result<PlcDateTime> {
+        let words = self.read(area, address, format.date_time_words())?;
+        format.decode_date_time(&words, self.word_order())
+    }
+
+    /// Writes a date and time in the given format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the value cannot be stored
+    /// in `format` (see [`TimeFormat::encode_date_time`]),
+    /// or an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, PlcDateTime, TimeFormat};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let start = PlcDateTime { year: 2024, month: 3, day: 15, hour: 6, minute: 0, second: 0 };
+    /// client.write_date_time(MemoryArea::DM, 100, TimeFormat::Binary, &start)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_date_time(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        format: TimeFormat,
+        value: &PlcDateTime,
+    ) -> Result<()> {
+        let words = format.encode_date_time(value, self.word_order())?;
+        self.write(area, address, &words)
+    }
+
+    /// Reads a duration: a `TIME` variable, or hours-minutes-seconds for BCD.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails, PLC returns an error or the
+    /// words are not a valid duration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, TimeFormat};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let runtime = client.read_time(MemoryArea::DM, 200, TimeFormat::Bcd)?;
+    /// println!("running for {} h", runtime.as_secs() / 3600);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_time(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        format: TimeFormat,
+    ) -> Result<Duration> {
+        let words = self.read(area, address, format.time_words())?;
+        format.decode_time(&words, self.word_order())
+    }
+
+    /// Writes a duration. BCD drops fractions of a second.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the duration cannot be
+    /// stored in `format`, or an error if communication fails or PLC returns
+    /// an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, TimeFormat};
+    /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_time(MemoryArea::DM, 200, TimeFormat::Binary, Duration::from_millis(1500))?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_time(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        format: TimeFormat,
+        value: Duration,
+    ) -> Result<()> {
+        let words = format.encode_time(value, self.word_order())?;
+        self.write(area, address, &words)
+    }
+
+    /// Reads a time of day as the duration since midnight.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails, PLC returns an error or the
+    /// words are not a valid time of day.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, TimeFormat};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let shift_start = client.read_time_of_day(MemoryArea::DM, 300, TimeFormat::Binary)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_time_of_day(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        format: TimeFormat,
+    ) -> Result<Duration> {
+        let words = self.read(area, address, format.time_words())?;
+        format.decode_time_of_day(&words, self.word_order())
+    }
+
+    /// Writes a time of day given as the duration since midnight.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the value is 24 hours or
+    /// more, or an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, TimeFormat};
+    /// use std::net::Ipv4Addr;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // 06:30:00
+    /// client.write_time_of_day(MemoryArea::DM, 300, TimeFormat::Bcd, Duration::from_secs(23400))?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_time_of_day(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        format: TimeFormat,
+        value: Duration,
+    ) -> Result<()> {
+        let words = format.encode_time_of_day(value, self.word_order())?;
+        self.write(area, address, &words)
+    }
+
+    /// Reads a block of words for field-by-field decoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    /// * `count` - Number of words in the block
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let recipe = client.read_block(MemoryArea::DM, 500, 12)?;
+    /// let batch = recipe.u16_at(0)?;
+    /// let setpoint = recipe.f32_at(2)?;
+    /// let name = recipe.string_at(4, 8)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_block(&self, area: MemoryArea, address: u16, count: u16) -> Result<WordBlock> {
+        let words = self.read(area, address, count)?;
+        Ok(WordBlock::new(words, self.word_order()))
+    }
+
+    /// Writes a block assembled with [`WordBlockBuilder`] in one call.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address (offset 0 of the block)
+    /// * `block` - Fields to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, WordBlockBuilder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let recipe = WordBlockBuilder::new()
+    ///     .with_u16(0, 42)
+    ///     .with_f32(2, 180.5)
+    ///     .with_string(4, "PASTA-01");
+    /// client.write_block(MemoryArea::DM, 500, &recipe)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_block(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        block: &WordBlockBuilder,
+    ) -> Result<()> {
+        let words = block.to_words(self.word_order());
+        self.write(area, address, &words)
+    }
+
+    /// Reads a custom structure from PLC memory based on a set of data types.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    /// * `types` - List of data types to read in sequence
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use omron_fins::{Client, ClientConfig, MemoryArea, DataType, PlcValue};
+    /// # use std::net::Ipv4Addr;
+    /// # let client = Client::new(ClientConfig::new(Ipv4Addr::new(127, 0, 0, 1), 1, 10)).unwrap();
+    /// let my_struct = client.read_struct(MemoryArea::DM, 100, vec![
+    ///     DataType::LINT, // 8 bytes
+    ///     DataType::INT,  // 2 bytes
+    ///     DataType::REAL, // 4 bytes
+    /// ]).unwrap();
+    /// ```
+    pub fn read_struct(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        types: Vec<DataType>,
+    ) -> Result<Vec<PlcValue>> {
+        let total_bytes: usize = types.iter().map(|t| (t.size() + 1) & !1).sum(); // Align to 2-byte words
+        let word_count = (total_bytes / 2) as u16;
+
+        let words = self.read(area, address, word_count)?;
+        let mut bytes = Vec::with_capacity(words.len() * 2);
+        for word in words {
+            bytes.extend_from_slice(&word.to_be_bytes());
+        }
+
+        let mut results = Vec::with_capacity(types.len());
+        let mut offset = 0;
+        for data_type in types {
+            let size = data_type.size();
+            let chunk = &bytes[offset..offset + size];
+            results.push(PlcValue::from_plc_bytes_with(
+                data_type,
+                chunk,
+                self.word_order(),
+            )?);
+            offset += (size + 1) & !1; // Advance by even bytes
+        }
+
+        Ok(results)
+    }
+
+    /// Writes a custom structure to PLC memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `values` - List of values to write in sequence
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use omron_fins::{Client, ClientConfig, MemoryArea, PlcValue};
+    /// # use std::net::Ipv4Addr;
+    /// # let client = Client::new(ClientConfig::new(Ipv4Addr::new(127, 0, 0, 1), 1, 10)).unwrap();
+    /// client.write_struct(MemoryArea::DM, 100, vec![
+    ///     PlcValue::Lint(123456789),
+    ///     PlcValue::Int(100),
+    ///     PlcValue::Real(3.14159),
+    /// ]).unwrap();
+    /// ```
+    pub fn write_struct(&self, area: MemoryArea, address: u16, values: Vec<PlcValue>) -> Result<()> {
+        let mut bytes = Vec::new();
+        for value in values {
+            let val_bytes = value.to_plc_bytes_with(self.word_order());
+            bytes.extend_from_slice(&val_bytes);
+            // Ensure 16-bit alignment (even bytes)
+            if val_bytes.len() % 2 != 0 {
+                bytes.push(0);
+            }
+        }
+
+        let words: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]))
+            .collect();
+
+        self.write(area, address, &words)
+    }
+
+    /// Reads a [`FinsStruct`] from PLC memory in one read.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address of the struct
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, FinsField, FinsStruct, MemoryArea, WordOrder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// struct Setpoint(f32);
+    ///
+    /// impl FinsStruct for Setpoint {
+    ///     const WORD_COUNT: u16 = 2;
+    ///
+    ///     fn to_words(&self, order: WordOrder) -> Vec<u16> {
+    ///         let mut words = vec![0; 2];
+    ///         self.0.put(&mut words, order);
+    ///         words
+    ///     }
+    ///
+    ///     fn from_words(words: &[u16], order: WordOrder) -> omron_fins::Result<Self> {
+    ///         omron_fins::record::check_len::<Self>(words)?;
+    ///         Ok(Setpoint(f32::get(&words[..2], order)))
+    ///     }
+    /// }
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let setpoint: Setpoint = client.read_typed(MemoryArea::DM, 100)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_typed<T: FinsStruct>(&self, area: MemoryArea, address: u16) -> Result<T> {
+        let words = self.read(area, address, T::WORD_COUNT)?;
+        T::from_words(&words, self.word_order())
+    }
+
+    /// Writes a [`FinsStruct`] to PLC memory in one write.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address of the struct
+    /// * `value` - Struct to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    pub fn write_typed<T: FinsStruct>(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        value: &T,
+    ) -> Result<()> {
+        let words = value.to_words(self.word_order());
+        self.write(area, address, &words)
+    }
+
+    /// Reads a serde-deserializable value from a word image.
+    ///
+    /// The value's size comes from [`ImageLayout::word_count`]; the layout
+    /// convention is described in the [`image`](crate::image) module.
+    /// Requires the `serde` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    /// * `layout` - String length and word order; the client's word order
+    ///   is used unless the layout sets one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `T` has no fixed size, communication fails, PLC
+    /// returns an error or the words do not decode as a `T`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::image::ImageLayout;
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use serde::Deserialize;
+    /// use std::net::Ipv4Addr;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Status {
+    ///     speed: f32,
+    ///     alarms: u16,
+    /// }
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let status: Status = client.read_image(MemoryArea::DM, 300, &ImageLayout::new())?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn read_image<T: serde::de::DeserializeOwned>(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        layout: &ImageLayout,
+    ) -> Result<T> {
+        let layout = self.image_layout(layout);
+        let words = self.read(area, address, layout.word_count::<T>()?)?;
+        crate::image::from_image(&words, &layout)
+    }
+
+    /// Writes a serde-serializable value as a word image.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `value` - Value to write
+    /// * `layout` - String length and word order; the client's word order
+    ///   is used unless the layout sets one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value does not fit the layout (see
+    /// [`image::to_image`](crate::image::to_image)), communication fails or
+    /// PLC returns an error.
+    #[cfg(feature = "serde")]
+    pub fn write_image<T: serde::Serialize + ?Sized>(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        value: &T,
+        layout: &ImageLayout,
+    ) -> Result<()> {
+        let words = crate::image::to_image(value, &self.image_layout(layout))?;
+        self.write(area, address, &words)
+    }
+
+    /// Fills in the client's word order unless `layout` sets one.
+    #[cfg(feature = "serde")]
+    fn image_layout(&self, layout: &ImageLayout) -> ImageLayout {
+        match layout.word_order() {
+            Some(_) => *layout,
+            None => layout.with_word_order(self.word_order()),
+        }
+    }
+
+    /// Reads an index register (IR0-IR15).
+    ///
+    /// # Arguments
+    ///
+    /// * `register` - Register number (0-15)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the register number is out of range,
+    /// communication fails, or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let ir0 = client.read_ir(0)?;
+    /// println!("IR0 = 0x{:08X}", ir0);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_ir(&self, register: u16) -> Result<u32> {
+        let words = self.read(MemoryArea::IR, register, 1)?;
+        if words.len() != 2 {
+            return Err(crate::error::FinsError::invalid_response(format!(
+                "expected 2 words for IR{}, got {}",
+                register,
+                words.len()
+            )));
+        }
+        Ok(((words[0] as u32) << 16) | words[1] as u32)
+    }
+
+    /// Writes an index register (IR0-IR15).
+    ///
+    /// # Arguments
+    ///
+    /// * `register` - Register number (0-15)
+    /// * `value` - 32-bit register value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the register number is out of range,
+    /// communication fails, or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_ir(1, 0x0001_0000)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_ir(&self, register: u16, value: u32) -> Result<()> {
+        let words = [(value >> 16) as u16, (value & 0xFFFF) as u16];
+        self.write(MemoryArea::IR, register, &words)
+    }
+
+    /// Reads an i16 (INT) value from one word.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Word address
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let offset: i16 = client.read_i16(MemoryArea::DM, 100)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_i16(&self, area: MemoryArea, address: u16) -> Result<i16> {
+        let words = self.read(area, address, 1)?;
+        Ok(words[0] as i16)
+    }
+
+    /// Reads `count` consecutive i16 (INT) values.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    /// * `count` - Number of values to read
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let temperatures: Vec<i16> = client.read_i16_slice(MemoryArea::DM, 100, 8)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_i16_slice(&self, area: MemoryArea, address: u16, count: u16) -> Result<Vec<i16>> {
+        let words = self.read(area, address, count)?;
+        Ok(words.into_iter().map(|word| word as i16).collect())
+    }
+
+    /// Writes an i16 (INT) value to one word.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Word address
+    /// * `value` - Value to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_i16(MemoryArea::DM, 100, -250)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_i16(&self, area: MemoryArea, address: u16, value: i16) -> Result<()> {
+        self.write(area, address, &[value as u16])
+    }
+
+    /// Writes consecutive i16 (INT) values.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `values` - Values to write, one word each
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_i16_slice(MemoryArea::DM, 100, &[-1, 0, 1])?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_i16_slice(&self, area: MemoryArea, address: u16, values: &[i16]) -> Result<()> {
+        let words: Vec<u16> = values.iter().map(|&value| value as u16).collect();
+        self.write(area, address, &words)
+    }
+
+    /// Reads one INT word and converts it to engineering units.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Word address
+    /// * `scale` - Mapping from raw counts to engineering units
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if either range of `scale` is
+    /// empty, or an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, Scale};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // 4-20 mA transmitter, 0-4000 counts for 0-150 °C
+    /// let temperature = client.read_scaled(MemoryArea::CIO, 2001, &Scale::new(0, 4000, 0.0, 150.0))?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_scaled(&self, area: MemoryArea, address: u16, scale: &Scale) -> Result<f64> {
+        scale.check()?;
+        Ok(scale.to_eu(self.read_i16(area, address)?))
+    }
+
+    /// Converts an engineering value to raw counts and writes it as one INT
+    /// word.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if either range of `scale` is
+    /// empty or the raw value does not fit in an i16, or an error if
+    /// communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, Scale};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // Valve position output, 0-4000 counts for 0-100 %
+    /// client.write_scaled(MemoryArea::CIO, 2011, &Scale::new(0, 4000, 0.0, 100.0), 42.5)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_scaled(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        scale: &Scale,
+        value: f64,
+    ) -> Result<()> {
+        scale.check()?;
+        let raw = scale.to_raw(value).ok_or_else(|| {
+            crate::error::FinsError::invalid_parameter(
+                "value",
+                format!("{} is outside the raw range of {:?}", value, scale),
+            )
+        })?;
+        self.write_i16(area, address, raw)
+    }
+
+    /// Reads an i32 (DINT) value from 2 consecutive words.
+    ///
+    /// The high word comes first unless a [`WordOrder`] is configured with
+    /// [`ClientConfig::with_word_order`].
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// let counter: i32 = client.read_i32(MemoryArea::DM, 100).unwrap();
+    /// ```
+    pub fn read_i32(&self, area: MemoryArea, address: u16) -> Result<i32> {
+        self.read_i32_with_order(area, address, self.dint_word_order())
+    }
+
+    /// Reads an i32 (DINT) value with an explicit word order.
+    ///
+    /// For regions laid out differently from the rest of the PLC, e.g.
+    /// written by a third-party gateway; [`read_i32`](Self::read_i32) uses the
+    /// configured [`WordOrder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, WordOrder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let value = client.read_i32_with_order(MemoryArea::DM, 5000, WordOrder::HighFirst)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_i32_with_order(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        order: WordOrder,
+    ) -> Result<i32> {
+        let words = self.read(area, address, 2)?;
+        Ok(i32::from_be_bytes(words_to_native(&words, order)))
+    }
+
+    /// Writes an i32 (DINT) value to 2 consecutive words.
+    ///
+    /// The high word comes first unless a [`WordOrder`] is configured with
+    /// [`ClientConfig::with_word_order`].
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `value` - i32 value to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// client.write_i32(MemoryArea::DM, 100, -123456).unwrap();
+    /// ```
+    pub fn write_i32(&self, area: MemoryArea, address: u16, value: i32) -> Result<()> {
+        self.write_i32_with_order(area, address, value, self.dint_word_order())
+    }
+
+    /// Writes an i32 (DINT) value with an explicit word order.
+    ///
+    /// The counterpart of [`read_i32_with_order`](Self::read_i32_with_order).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, WordOrder};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_i32_with_order(MemoryArea::DM, 5000, -7, WordOrder::HighFirst)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_i32_with_order(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        value: i32,
+        order: WordOrder,
+    ) -> Result<()> {
+        let words = native_to_words(value.to_be_bytes(), order);
+        self.write(area, address, &words)
+    }
+
+    /// Reads `count` consecutive f32 (REAL) values.
+    ///
+    /// All values are fetched with as few read commands as possible (one per
+    /// 350 values) instead of one request each.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    /// * `count` - Number of values to read (2 words each)
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `count` values do not fit in
+    /// a 16-bit word count, or an error if communication fails or PLC
+    /// returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // 16 analog inputs in D100-D131
+    /// let inputs: Vec<f32> = client.read_f32_slice(MemoryArea::DM, 100, 16)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_f32_slice(&self, area: MemoryArea, address: u16, count: u16) -> Result<Vec<f32>> {
+        let words = self.read(area, address, double_word_count(count)?)?;
+        Ok(words
+            .chunks_exact(2)
+            .map(|pair| f32::from_be_bytes(words_to_native(pair, self.word_order())))
+            .collect())
+    }
+
+    /// Writes consecutive f32 (REAL) values, 2 words each.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `values` - Values to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_f32_slice(MemoryArea::DM, 100, &[0.0, 12.5, 100.0])?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_f32_slice(&self, area: MemoryArea, address: u16, values: &[f32]) -> Result<()> {
+        let words: Vec<u16> = values
+            .iter()
+            .flat_map(|value| native_to_words(value.to_be_bytes(), self.word_order()))
+            .collect();
+        self.write(area, address, &words)
+    }
+
+    /// Reads `count` consecutive i32 (DINT) values.
+    ///
+    /// The high word comes first unless a [`WordOrder`] is configured with
+    /// [`ClientConfig::with_word_order`].
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    /// * `count` - Number of values to read (2 words each)
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `count` values do not fit in
+    /// a 16-bit word count, or an error if communication fails or PLC
+    /// returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let counters: Vec<i32> = client.read_i32_slice(MemoryArea::DM, 100, 8)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_i32_slice(&self, area: MemoryArea, address: u16, count: u16) -> Result<Vec<i32>> {
+        let words = self.read(area, address, double_word_count(count)?)?;
+        Ok(words
+            .chunks_exact(2)
+            .map(|pair| i32::from_be_bytes(words_to_native(pair, self.dint_word_order())))
+            .collect())
+    }
+
+    /// Writes consecutive i32 (DINT) values, 2 words each.
+    ///
+    /// The high word comes first unless a [`WordOrder`] is configured with
+    /// [`ClientConfig::with_word_order`].
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `values` - Values to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_i32_slice(MemoryArea::DM, 100, &[0, -1, 100000])?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_i32_slice(&self, area: MemoryArea, address: u16, values: &[i32]) -> Result<()> {
+        let words: Vec<u16> = values
+            .iter()
+            .flat_map(|value| native_to_words(value.to_be_bytes(), self.dint_word_order()))
+            .collect();
+        self.write(area, address, &words)
+    }
+
+    /// Reads a 4-digit BCD value from one word.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Word address
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidResponse` if the word holds a nibble above 9,
+    /// or an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // D100 = 0x1234 -> 1234
+    /// let preset: u16 = client.read_bcd16(MemoryArea::DM, 100)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_bcd16(&self, area: MemoryArea, address: u16) -> Result<u16> {
+        let word = self.read(area, address, 1)?[0];
+        utils::bcd_to_u16(word).ok_or_else(|| {
+            crate::error::FinsError::invalid_response(format!(
+                "{} {} holds invalid BCD 0x{:04X}",
+                area, address, word
+            ))
+        })
+    }
+
+    /// Writes a value from 0 to 9999 as 4-digit BCD to one word.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Word address
+    /// * `value` - Value to write (0-9999)
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `value` is above 9999,
+    /// or an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // Writes 0x1234 to D100
+    /// client.write_bcd16(MemoryArea::DM, 100, 1234)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_bcd16(&self, area: MemoryArea, address: u16, value: u16) -> Result<()> {
+        let word = utils::u16_to_bcd(value).ok_or_else(|| {
+            crate::error::FinsError::invalid_parameter("value", "BCD words hold at most 9999")
+        })?;
+        self.write(area, address, &[word])
+    }
+
+    /// Reads an 8-digit BCD value from 2 consecutive words.
+    ///
+    /// Uses the configured [`WordOrder`] (least significant word first by
+    /// default).
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidResponse` if either word holds a nibble
+    /// above 9, or an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // D100 = 0x5678, D101 = 0x1234 -> 12345678
+    /// let total: u32 = client.read_bcd32(MemoryArea::DM, 100)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_bcd32(&self, area: MemoryArea, address: u16) -> Result<u32> {
+        let words = self.read(area, address, 2)?;
+        let raw = u32::from_be_bytes(words_to_native(&words, self.word_order()));
+        utils::bcd_to_u32(raw).ok_or_else(|| {
+            crate::error::FinsError::invalid_response(format!(
+                "{} {} holds invalid BCD 0x{:08X}",
+                area, address, raw
+            ))
+        })
+    }
+
+    /// Writes a value from 0 to 99999999 as 8-digit BCD to 2 consecutive
+    /// words, in the configured [`WordOrder`].
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `value` - Value to write (0-99999999)
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `value` is above 99999999,
+    /// or an error if communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_bcd32(MemoryArea::DM, 100, 12345678)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_bcd32(&self, area: MemoryArea, address: u16, value: u32) -> Result<()> {
+        let raw = utils::u32_to_bcd(value).ok_or_else(|| {
+            crate::error::FinsError::invalid_parameter(
+                "value",
+                "BCD double words hold at most 99999999",
+            )
+        })?;
+        let words = native_to_words(raw.to_be_bytes(), self.word_order());
+        self.write(area, address, &words)
+    }
+
+    /// Writes an ASCII string to consecutive words.
+    ///
+    /// Each word stores 2 ASCII characters (big-endian). If the string has an
+    /// odd number of characters, the last byte is padded with 0x00.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `value` - String to write (ASCII only)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - String is empty
+    /// - String exceeds 1998 characters (999 words)
+    /// - Communication fails
+    /// - PLC returns an error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// // Write a product code to DM100
+    /// client.write_string(MemoryArea::DM, 100, "PRODUCT-001").unwrap();
+    /// ```
+    pub fn write_string(&self, area: MemoryArea, address: u16, value: &str) -> Result<()> {
+        self.write_string_encoded(area, address, value, StringEncoding::Utf8)
+    }
+
+    /// Writes a string in the given encoding to consecutive words.
+    ///
+    /// Uses the same layout and limits as [`write_string`](Self::write_string),
+    /// counted in encoded bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `value` - String to write
+    /// * `encoding` - Encoding stored in PLC memory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - String is empty or too long
+    /// - String has characters `encoding` cannot represent
+    /// - Communication fails
+    /// - PLC returns an error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, StringEncoding};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_string_encoded(MemoryArea::DM, 100, "LINE-A", StringEncoding::Ascii)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_string_encoded(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        value: &str,
+        encoding: StringEncoding,
+    ) -> Result<()> {
+        use crate::command::MAX_WORDS_PER_COMMAND;
+        use crate::error::FinsError;
+
+        if value.is_empty() {
+            return Err(FinsError::InvalidParameter {
+                parameter: "value".to_string(),
+                reason: "string cannot be empty".to_string(),
+            });
+        }
+
+        let bytes = encoding.encode(value)?;
+        let word_count = bytes.len().div_ceil(2);
+
+        if word_count > MAX_WORDS_PER_COMMAND as usize {
+            return Err(FinsError::InvalidParameter {
+                parameter: "value".to_string(),
+                reason: format!(
+                    "string too long: {} bytes requires {} words, max is {}",
+                    bytes.len(),
+                    word_count,
+                    MAX_WORDS_PER_COMMAND
+                ),
+            });
+        }
+
+        // Omron uses byte swap within words: first char in low byte, second char in high byte
+        let words = utils::pack_string_bytes(&bytes);
+
+        self.write(area, address, &words)
+    }
+
+    /// Writes a string padded or truncated to exactly `word_len` words.
+    ///
+    /// Unlike [`write_string`](Self::write_string), the whole field is
+    /// always overwritten, so a shorter value leaves no trailing characters
+    /// of a previous longer one. Truncation cuts at a byte boundary; keep
+    /// values ASCII if they may be truncated.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Starting word address
+    /// * `value` - String to write (may be empty to clear the field)
+    /// * `word_len` - Field length in words (2 characters each)
+    /// * `pad` - Padding byte, usually `b' '` or `0x00`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `word_len` is 0, communication fails or PLC
+    /// returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // D100-D109 = "P-7" followed by 17 spaces
+    /// client.write_string_fixed(MemoryArea::DM, 100, "P-7", 10, b' ')?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_string_fixed(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        value: &str,
+        word_len: u16,
+        pad: u8,
+    ) -> Result<()> {
+        if word_len == 0 {
+            return Err(crate::error::FinsError::invalid_parameter(
+                "word_len",
+                "must be at least 1",
+            ));
+        }
+
+        let mut bytes = value.as_bytes().to_vec();
+        bytes.resize(word_len as usize * 2, pad);
+        let words = utils::pack_string_bytes(&bytes);
+
+        self.write(area, address, &words)
+    }
+
+    /// Reads an ASCII string from consecutive words.
+    ///
+    /// Each word contains 2 ASCII characters (big-endian). Null bytes (0x00)
+    /// at the end of the string are trimmed. Invalid UTF-8 is replaced with
+    /// U+FFFD; use [`read_string_strict`](Self::read_string_strict) to get
+    /// an error instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    /// * `word_count` - Number of words to read (1-999)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Word count is 0 or > 999
+    /// - Communication fails
+    /// - PLC returns an error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(
+    ///     Ipv4Addr::new(192, 168, 1, 250), 1, 0
+    /// )).unwrap();
+    ///
+    /// // Read a product code from DM100 (up to 20 characters = 10 words)
+    /// let code = client.read_string(MemoryArea::DM, 100, 10).unwrap();
+    /// println!("Product code: {}", code);
+    /// ```
+    pub fn read_string(&self, area: MemoryArea, address: u16, word_count: u16) -> Result<String> {
+        self.read_string_encoded(area, address, word_count, StringEncoding::Utf8, false)
+    }
+
+    /// Reads a UTF-8 string like [`read_string`](Self::read_string), but
+    /// fails on invalid bytes instead of replacing them.
+    ///
+    /// Use it where a silently altered value is worse than an error, e.g.
+    /// batch or lot IDs kept for traceability.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidResponse` naming the offset of the first
+    /// invalid byte (byte `n` is in word `address + n / 2`), or an error if
+    /// communication fails or PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let batch_id = client.read_string_strict(MemoryArea::DM, 100, 10)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_string_strict(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        word_count: u16,
+    ) -> Result<String> {
+        self.read_string_encoded(area, address, word_count, StringEncoding::Utf8, true)
+    }
+
+    /// Reads a string in the given encoding from consecutive words.
+    ///
+    /// Uses the same layout as [`read_string`](Self::read_string). Invalid
+    /// byte sequences are replaced with U+FFFD unless `strict` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Starting word address
+    /// * `word_count` - Number of words to read
+    /// * `encoding` - Encoding stored in PLC memory
+    /// * `strict` - Fail on invalid bytes instead of replacing them
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidResponse` in strict mode if the bytes are
+    /// not valid in `encoding`, or an error if communication fails or PLC
+    /// returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, MemoryArea, StringEncoding};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// let code = client.read_string_encoded(MemoryArea::DM, 100, 10, StringEncoding::Ascii, true)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_string_encoded(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        word_count: u16,
+        encoding: StringEncoding,
+        strict: bool,
+    ) -> Result<String> {
+        let words = self.read(area, address, word_count)?;
+
+        // Omron uses byte swap within words: first char in low byte, second char in high byte
+        let bytes = utils::unpack_string_bytes(&words);
+
+        encoding.decode(&bytes, strict)
+    }
+
+    /// Writes a length-prefixed string, as used by NJ/NX programs and some
+    /// function block libraries.
+    ///
+    /// Only the prefix and the characters are written; words after them
+    /// keep their contents.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to write to
+    /// * `address` - Address of the length prefix
+    /// * `value` - String to write, stored as UTF-8
+    /// * `prefix` - Size of the length prefix
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the string is longer than
+    /// the prefix can count, or an error if communication fails or PLC
+    /// returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, LengthPrefix, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// client.write_omron_string(MemoryArea::DM, 100, "LOT-4711", LengthPrefix::Word)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn write_omron_string(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        value: &str,
+        prefix: LengthPrefix,
+    ) -> Result<()> {
+        let words = prefix.pack(value.as_bytes())?;
+        self.write(area, address, &words)
+    }
+
+    /// Reads a length-prefixed string.
+    ///
+    /// `word_count` is the size of the whole field, prefix included; only
+    /// the characters the prefix counts are returned. Invalid UTF-8 is
+    /// replaced as in [`read_string`](Self::read_string).
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Memory area to read from
+    /// * `address` - Address of the length prefix
+    /// * `word_count` - Words to read, prefix included
+    /// * `prefix` - Size of the length prefix
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidResponse` if the prefix counts more bytes
+    /// than `word_count` words hold, or an error if communication fails or
+    /// PLC returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{Client, ClientConfig, LengthPrefix, MemoryArea};
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+    ///
+    /// // Count word plus up to 40 characters
+    /// let lot = client.read_omron_string(MemoryArea::DM, 100, 21, LengthPrefix::Word)?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_omron_string(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        word_count: u16,
+        prefix: LengthPrefix,
+    ) -> Result<String> {
+        let words = self.read(area, address, word_count)?;
+        let bytes = prefix.unpack(&words)?;
+        StringEncoding::Utf8.decode(&bytes, false)
+    }
+
+    /// Returns the source node address.
+    pub fn source(&self) -> NodeAddress {
+        self.source
+    }
+
+    /// Returns the destination node address.
+    pub fn destination(&self) -> NodeAddress {
+        self.destination
+    }
+}
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("transport", &self.transport)
+            .field("source", &self.source)
+            .field("destination", &self.destination)
+            .field("pipelining", &self.demux.is_some())
+            .field("frame_observer", &self.frame_observer.is_some())
+            .field("recorder", &self.recorder)
+            .field("metrics", &self.metrics.is_some())
+            .finish()
+    }
+}
+
+/// Checks that a response echoes the command code of the command it answers.
+///
+/// After the SID wraps around, a late response to an earlier command can
+/// carry the current SID; the command code tells them apart.
+fn check_echo(command: &[u8], response: FinsResponse) -> Result<FinsResponse> {
+    let sent = command_codes(command);
+    if (response.mrc, response.src) != sent {
+        return Err(crate::error::FinsError::command_mismatch(
+            sent,
+            (response.mrc, response.src),
+        ));
+    }
+    Ok(response)
+}
+
+/// Returns the MRC and SRC of a command frame, or zeros if it is too short.
+fn command_codes(frame: &[u8]) -> (u8, u8) {
+    match frame.get(FINS_HEADER_SIZE..FINS_HEADER_SIZE + 2) {
+        Some(&[mrc, src]) => (mrc, src),
+        _ => (0, 0),
+    }
+}
+
+/// Returns the FINS node number matching a local IP address: its last octet.
+fn node_from_ip(ip: IpAddr) -> Result<u8> {
+    let octet = match ip {
+        IpAddr::V4(ip) => ip.octets()[3],
+        IpAddr::V6(_) => {
+            return Err(crate::error::FinsError::invalid_parameter(
+                "source",
+                format!("local address {} is not IPv4", ip),
+            ))
+        }
+    };
+    if octet == 0 || octet == BROADCAST_NODE {
+        return Err(crate::error::FinsError::invalid_parameter(
+            "source",
+            format!("local address {} does not map to a node number", ip),
+        ));
+    }
+    Ok(octet)
+}
+
+/// Parses an Omron address string that must name a word.
+fn parse_word_address(text: &str) -> Result<FinsAddress> {
+    let address: FinsAddress = text.parse()?;
+    if address.bit.is_some() {
+        return Err(crate::error::FinsError::invalid_parameter(
+            "address",
+            format!("'{}' is a bit address, expected a word", text),
+        ));
+    }
+    Ok(address)
+}
+
+/// Parses an Omron address string that must name a bit.
+fn parse_bit_address(text: &str) -> Result<(FinsAddress, u8)> {
+    let address: FinsAddress = text.parse()?;
+    match address.bit {
+        Some(bit) => Ok((address, bit)),
+        None => Err(crate::error::FinsError::invalid_parameter(
+            "address",
+            format!("'{}' is a word address, expected a bit", text),
+        )),
+    }
+}
+
+/// Converts the count of a large block to a command count, checking that
+/// the block fits in the 65535-word address space.
+fn large_block_count(address: u16, count: u32) -> Result<u16> {
+    if address as u64 + count as u64 > u16::MAX as u64 {
+        return Err(crate::error::FinsError::invalid_parameter(
+            "count",
+            format!(
+                "{} words from {} run past the 65535-word address space",
+                count, address
+            ),
+        ));
+    }
+    Ok(count as u16)
+}
+
+/// Converts the length of the data to write to a word count.
+fn data_word_count(words: usize) -> Result<u16> {
+    u16::try_from(words).map_err(|_| {
+        crate::error::FinsError::invalid_parameter(
+            "data",
+            format!("{} words exceed the 65535-word limit", words),
+        )
+    })
+}
+
+/// Converts a caller buffer length in words to a read count.
+fn buffer_word_count(words: usize) -> Result<u16> {
+    u16::try_from(words).map_err(|_| {
+        crate::error::FinsError::invalid_parameter(
+            "buf",
+            format!("{} words exceed the 65535-word limit", words),
+        )
+    })
+}
+
+/// Returns the data of one read response, which must hold whole words and
+/// no more than `room` of them.
+fn chunk_data(response: &FinsResponse, room: usize) -> Result<&[u8]> {
+    if response.data.len() % 2 != 0 || response.data.len() / 2 > room {
+        return Err(crate::error::FinsError::invalid_response(format!(
+            "unexpected read data length {}",
+            response.data.len()
+        )));
+    }
+    Ok(&response.data)
+}
+
+/// Checks that a caller buffer was completely filled.
+fn check_filled(filled: usize, expected: usize) -> Result<()> {
+    if filled != expected {
+        return Err(crate::error::FinsError::invalid_response(format!(
+            "PLC returned {} of {} requested units",
+            filled, expected
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the word count of `count` 32-bit values.
+fn double_word_count(count: u16) -> Result<u16> {
+    count.checked_mul(2).ok_or_else(|| {
+        crate::error::FinsError::invalid_parameter(
+            "count",
+            format!("{} double words exceed the 65535-word limit", count),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn test_client_config_new() {
+        let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0);
+
+        assert_eq!(config.plc_addr.ip(), Ipv4Addr::new(192, 168, 1, 250));
+        assert_eq!(config.plc_addr.port(), DEFAULT_FINS_PORT);
+        assert_eq!(config.source.node, 1);
+        assert_eq!(config.destination.node, 0);
+        assert_eq!(config.timeout, DEFAULT_TIMEOUT);
+        assert_eq!(config.send_timeout, DEFAULT_TIMEOUT);
+        assert_eq!(config.connect_timeout, DEFAULT_TIMEOUT);
+        assert_eq!(config.protocol, TransportProtocol::Udp);
+        assert_eq!(config.socket_options, SocketOptions::default());
+        assert!(!config.pipelining);
+        assert_eq!(config.header_options, HeaderOptions::default());
+        assert_eq!(config.min_request_interval, Duration::ZERO);
+        assert!(!config.allow_unit_reset);
+        assert!(!config.dm_bit_access);
+        assert_eq!(config.cpu_profile, None);
+        assert_eq!(config.word_order, None);
+    }
+
+    #[test]
+    fn test_client_config_with_port() {
         let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0).with_port(9601);
 
-        assert_eq!(config.plc_addr.port(), 9601);
+        assert_eq!(config.plc_addr.port(), 9601);
+    }
+
+    #[test]
+    fn test_client_config_with_timeout() {
+        let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+            .with_timeout(Duration::from_secs(5));
+
+        assert_eq!(config.timeout, Duration::from_secs(5));
+        assert_eq!(config.send_timeout, Duration::from_secs(5));
+
+        let config = config
+            .with_receive_timeout(Duration::from_millis(500))
+            .with_send_timeout(Duration::from_millis(100));
+        assert_eq!(config.timeout, Duration::from_millis(500));
+        assert_eq!(config.send_timeout, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_client_config_with_network() {
+        let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+            .with_source_network(1)
+            .with_dest_network(2);
+
+        assert_eq!(config.source.network, 1);
+        assert_eq!(config.destination.network, 2);
+    }
+
+    #[test]
+    fn test_client_config_from_host() {
+        let config = ClientConfig::from_host("127.0.0.1", 1, 0).unwrap();
+        assert_eq!(config.plc_addr.ip(), Ipv4Addr::LOCALHOST);
+        assert_eq!(config.plc_addr.port(), DEFAULT_FINS_PORT);
+
+        let resolver = |_: &str| Ok(vec![std::net::IpAddr::V4(Ipv4Addr::new(10, 0, 3, 20))]);
+        let config = ClientConfig::from_host_with_resolver("plc-line3", 1, 0, &resolver)
+            .unwrap()
+            .with_port(9601);
+        assert_eq!(config.plc_addr.ip(), Ipv4Addr::new(10, 0, 3, 20));
+        assert_eq!(config.plc_addr.port(), 9601);
+    }
+
+    #[test]
+    fn test_client_creation() {
+        // Note: This creates a socket but doesn't actually connect to a PLC
+        let config = ClientConfig::new(Ipv4Addr::new(127, 0, 0, 1), 1, 10);
+        let client = Client::new(config);
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn test_client_sid_increment() {
+        let config = ClientConfig::new(Ipv4Addr::new(127, 0, 0, 1), 1, 10);
+        let client = Client::new(config).unwrap();
+
+        assert_eq!(client.next_sid(), 0);
+        assert_eq!(client.next_sid(), 1);
+        assert_eq!(client.next_sid(), 2);
+    }
+
+    #[test]
+    fn test_client_pipelined_concurrent_reads() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+
+        // Fake PLC: collect both requests, then answer in reverse order
+        // with the requested start address as the word value.
+        let responder = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            let mut buf = [0u8; 64];
+            for _ in 0..2 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                requests.push((buf[..len].to_vec(), from));
+            }
+            for (request, from) in requests.iter().rev() {
+                let mut response = request[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00, request[13], request[14]]);
+                plc.send_to(&response, from).unwrap();
+            }
+        });
+
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_pipelining(true);
+        let client = Client::new(config).unwrap();
+
+        std::thread::scope(|s| {
+            let a = s.spawn(|| client.read(MemoryArea::DM, 100, 1));
+            let b = s.spawn(|| client.read(MemoryArea::DM, 200, 1));
+            assert_eq!(a.join().unwrap().unwrap(), vec![100]);
+            assert_eq!(b.join().unwrap().unwrap(), vec![200]);
+        });
+        responder.join().unwrap();
+    }
+
+    #[test]
+    fn test_client_pipelined_set_timeout_drop() {
+        let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(silent.local_addr().unwrap().port())
+            .with_timeout(Duration::from_millis(100))
+            .with_pipelining(true);
+        let mut client = Client::new(config).unwrap();
+
+        client.set_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(client.timeout(), Duration::from_secs(5));
+        // Let the receiver thread start a receive with the new timeout
+        std::thread::sleep(Duration::from_millis(200));
+
+        let start = Instant::now();
+        drop(client);
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_client_read_with_options_timeout() {
+        let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = silent.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_timeout(Duration::from_secs(5));
+        let client = Client::new(config).unwrap();
+
+        let options = RequestOptions::new().with_timeout(Duration::from_millis(50));
+        let start = std::time::Instant::now();
+        let result = client.read_with_options(MemoryArea::DM, 0, 1, &options);
+        assert!(matches!(result, Err(crate::FinsError::Timeout)));
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_client_reconnect() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_timeout(Duration::from_secs(1));
+        let mut client = Client::new(config).unwrap();
+
+        client.reconnect().unwrap();
+
+        // The rebuilt socket still reaches the PLC
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00, 0x12, 0x34]);
+            assert_eq!(len, 18);
+            plc.send_to(&response, from).unwrap();
+        });
+        assert_eq!(client.read(MemoryArea::DM, 0, 1).unwrap(), vec![0x1234]);
+        responder.join().unwrap();
+        assert_eq!(client.next_sid(), 1);
+    }
+
+    #[test]
+    fn test_client_pipelined_reconnect_failure() {
+        use crate::server::{FinsServer, Reply};
+        use crate::transport::SocketOptions;
+
+        // Slower than the receiver thread's poll interval, so only a
+        // running demux sees the response
+        let server = FinsServer::bind("127.0.0.1:0")
+            .unwrap()
+            .with_handler(0x01, 0x01, |_, _| {
+                std::thread::sleep(Duration::from_millis(200));
+                Reply::ok(vec![0x12, 0x34])
+            })
+            .spawn()
+            .unwrap();
+        let local_port = std::net::UdpSocket::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        // Without SO_REUSEADDR the new socket cannot bind the port the
+        // current one holds, so the reconnect fails
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(server.local_addr().port())
+            .with_socket_options(SocketOptions::new().with_local_port(local_port))
+            .with_pipelining(true);
+        let mut client = Client::new(config).unwrap();
+
+        assert!(client.reconnect().is_err());
+        assert_eq!(client.read(MemoryArea::DM, 0, 1).unwrap(), vec![0x1234]);
+    }
+
+    #[test]
+    fn test_client_frame_observer() {
+        use std::sync::{Arc, Mutex};
+
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let mut client = Client::new(config).unwrap();
+
+        let frames = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&frames);
+        client.set_frame_observer(move |direction, frame| {
+            sink.lock().unwrap().push((direction, frame.to_vec()));
+        });
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+            plc.send_to(&response, from).unwrap();
+            buf[..len].to_vec()
+        });
+        client.write(MemoryArea::DM, 0, &[0xBEEF]).unwrap();
+        let request = responder.join().unwrap();
+
+        let frames = frames.lock().unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0], (Direction::Sent, request));
+        assert_eq!(frames[1].0, Direction::Received);
+        assert_eq!(frames[1].1[0], 0xC0);
+    }
+
+    #[test]
+    fn test_client_header_options_applied() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_header_options(HeaderOptions::new().with_gct(0x02));
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (_, from) = plc.recv_from(&mut buf).unwrap();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+            plc.send_to(&response, from).unwrap();
+            (buf[0], buf[2])
+        });
+        client.write(MemoryArea::DM, 0, &[0x0001]).unwrap();
+
+        assert_eq!(responder.join().unwrap(), (0x80, 0x02));
+    }
+
+    #[test]
+    fn test_client_min_request_interval() {
+        let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = silent.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_timeout(Duration::from_millis(10))
+            .with_min_request_interval(Duration::from_millis(200));
+        let client = Client::new(config).unwrap();
+
+        let started = Instant::now();
+        for _ in 0..3 {
+            assert!(client.read(MemoryArea::DM, 0, 1).is_err());
+        }
+        // The second and third requests each wait for their slot
+        assert!(started.elapsed() >= Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_client_read_error_log() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[
+                0x21, 0x02, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00, 0x01, // counts
+                0x00, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x01, 0x12, 0x25, 0x06, // record
+            ]);
+            plc.send_to(&response, from).unwrap();
+            buf[10..len].to_vec()
+        });
+        let log = client.read_error_log(0, 5).unwrap();
+        let request = responder.join().unwrap();
+
+        assert_eq!(request, vec![0x21, 0x02, 0x00, 0x00, 0x00, 0x05]);
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].error_code, 0x000A);
+        assert_eq!(log[0].timestamp.to_string(), "2025-06-01 12:00:00");
+        assert!(client.read_error_log(0, 0).is_err());
+    }
+
+    #[test]
+    fn test_client_acquire_access_right_held() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x0C, 0x01, 0x30, 0x01, 0x00, 0x05, 0x00]);
+            plc.send_to(&response, from).unwrap();
+            buf[10..len].to_vec()
+        });
+        let result = client.acquire_access_right().unwrap();
+
+        assert_eq!(responder.join().unwrap(), vec![0x0C, 0x01, 0xFF, 0xFF]);
+        assert_eq!(result, AccessRight::HeldBy(NodeAddress::new(0, 5, 0)));
+    }
+
+    #[test]
+    fn test_client_reset_unit_requires_opt_in() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_timeout(Duration::from_millis(100));
+
+        let client = Client::new(config.clone()).unwrap();
+        assert!(matches!(
+            client.reset_unit(0x10),
+            Err(crate::error::FinsError::InvalidParameter { .. })
+        ));
+
+        // The unit restarts without answering
+        let client = Client::new(config.with_unit_reset_allowed(true)).unwrap();
+        client.reset_unit(0x10).unwrap();
+        let mut buf = [0u8; 64];
+        let len = plc.recv(&mut buf).unwrap();
+        assert_eq!(buf[5], 0x10);
+        assert_eq!(&buf[10..len], &[0x04, 0x03]);
+    }
+
+    #[test]
+    fn test_client_write_file_no_append_resend() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 2048];
+            for stale in [false, true] {
+                let (_, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                if stale {
+                    response[9] = response[9].wrapping_sub(5);
+                }
+                response.extend_from_slice(&[0x22, 0x03, 0x00, 0x00]);
+                plc.send_to(&response, from).unwrap();
+            }
+            // The append chunk is not sent again
+            plc.set_read_timeout(Some(Duration::from_millis(200)))
+                .unwrap();
+            plc.recv_from(&mut buf).is_err()
+        });
+        let data = vec![0x5A; MAX_FILE_CHUNK + 10];
+        let result = client.write_file(
+            FileDisk::MemoryCard,
+            "DATA.BIN",
+            &data,
+            FileWriteMode::Overwrite,
+        );
+        assert!(matches!(
+            result,
+            Err(crate::error::FinsError::SidMismatch { .. })
+        ));
+        assert!(responder.join().unwrap());
+    }
+
+    #[test]
+    fn test_client_write_file_chunks() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            let mut buf = [0u8; 2048];
+            for _ in 0..3 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x22, 0x03, 0x00, 0x00]);
+                plc.send_to(&response, from).unwrap();
+                requests.push(buf[..len].to_vec());
+            }
+            requests
+        });
+        let data = vec![0x5A; MAX_FILE_CHUNK * 2 + 10];
+        client
+            .write_file(
+                FileDisk::MemoryCard,
+                "DATA.BIN",
+                &data,
+                FileWriteMode::Overwrite,
+            )
+            .unwrap();
+        let requests = responder.join().unwrap();
+
+        // Mode codes: overwrite first, then append
+        let modes: Vec<&[u8]> = requests.iter().map(|r| &r[14..16]).collect();
+        assert_eq!(modes, vec![&[0x00, 0x01][..], &[0x00, 0x02], &[0x00, 0x02]]);
+        let lengths: Vec<usize> = requests.iter().map(|r| r.len() - 34).collect();
+        assert_eq!(lengths, vec![MAX_FILE_CHUNK, MAX_FILE_CHUNK, 10]);
+        assert_eq!(
+            &requests[2][28..32],
+            &((MAX_FILE_CHUNK * 2) as u32).to_be_bytes()
+        );
+    }
+
+    #[test]
+    fn test_client_read_multiple_parses_items() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (_, from) = plc.recv_from(&mut buf).unwrap();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x04, 0x00, 0x00, 0x82, 0x12, 0x34, 0x30, 0x01]);
+            plc.send_to(&response, from).unwrap();
+        });
+        let values = client
+            .read_multiple(&[
+                MultiReadSpec {
+                    area: MemoryArea::DM,
+                    address: 100,
+                    bit: None,
+                },
+                MultiReadSpec {
+                    area: MemoryArea::CIO,
+                    address: 0,
+                    bit: Some(5),
+                },
+            ])
+            .unwrap();
+        responder.join().unwrap();
+
+        assert_eq!(values, vec![0x1234, 0x0001]);
+    }
+
+    #[test]
+    fn test_client_execute_raw() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            // Error end codes are passed through unchecked
+            response.extend_from_slice(&[buf[10], buf[11], 0x04, 0x01, 0x99]);
+            plc.send_to(&response, from).unwrap();
+            buf[10..len].to_vec()
+        });
+        let response = client.execute_raw(0x27, 0x10, &[0x01, 0x02]).unwrap();
+
+        assert_eq!(responder.join().unwrap(), vec![0x27, 0x10, 0x01, 0x02]);
+        assert_eq!((response.mrc, response.src), (0x27, 0x10));
+        assert_eq!((response.main_code, response.sub_code), (0x04, 0x01));
+        assert_eq!(response.data, vec![0x99]);
+    }
+
+    #[test]
+    fn test_client_echo_test() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            for corrupt in [false, true] {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x08, 0x01, 0x00, 0x00]);
+                response.extend_from_slice(&buf[12..len]);
+                if corrupt {
+                    *response.last_mut().unwrap() ^= 0xFF;
+                }
+                plc.send_to(&response, from).unwrap();
+            }
+        });
+
+        assert!(client.echo_test(b"PING").is_ok());
+        assert!(matches!(
+            client.echo_test(b"PING"),
+            Err(crate::error::FinsError::InvalidResponse { .. })
+        ));
+        responder.join().unwrap();
+    }
+
+    #[test]
+    fn test_client_broadcast_skips_response() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        // Returns immediately although the PLC never answers
+        client.broadcast(0x04, 0x02, &[]).unwrap();
+
+        let mut buf = [0u8; 64];
+        let (len, _) = plc.recv_from(&mut buf).unwrap();
+        assert_eq!(buf[0], 0x81); // ICF: response not required
+        assert_eq!(buf[4], BROADCAST_NODE);
+        assert_eq!(&buf[10..len], &[0x04, 0x02]);
+        assert_eq!(client.stats().responses_received, 0);
+    }
+
+    #[test]
+    fn test_client_index_registers() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            // Read IR2: one register, four data bytes
+            assert_eq!(
+                &buf[10..len],
+                &[0x01, 0x01, 0xDC, 0x01, 0x02, 0x00, 0x00, 0x01]
+            );
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00, 0x00, 0x01, 0x86, 0xA0]);
+            plc.send_to(&response, from).unwrap();
+
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            assert_eq!(
+                &buf[10..len],
+                &[0x01, 0x02, 0xDC, 0x01, 0x0F, 0x00, 0x00, 0x01, 0xDE, 0xAD, 0xBE, 0xEF]
+            );
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+            plc.send_to(&response, from).unwrap();
+        });
+
+        assert_eq!(client.read_ir(2).unwrap(), 100_000);
+        client.write_ir(15, 0xDEAD_BEEF).unwrap();
+        assert!(client.read_ir(16).is_err());
+        responder.join().unwrap();
+    }
+
+    #[test]
+    fn test_client_cpu_profile_bounds() {
+        // Nothing listens here: out-of-range requests must fail before sending
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(1)
+            .with_cpu_profile(CpuProfile::CJ2M);
+        let client = Client::new(config).unwrap();
+
+        for result in [
+            client.read(MemoryArea::DM, 32760, 9).map(|_| ()),
+            client.write(MemoryArea::HR, 511, &[0, 0]),
+            client.read_bit(MemoryArea::AR, 960, 0).map(|_| ()),
+        ] {
+            assert!(matches!(
+                result,
+                Err(crate::error::FinsError::InvalidAddressing { .. })
+            ));
+        }
+        assert_eq!(client.stats().requests_sent, 0);
+    }
+
+    #[test]
+    fn test_client_string_addresses() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            assert_eq!(
+                &buf[10..len],
+                &[0x01, 0x01, 0x82, 0x00, 0x64, 0x00, 0x00, 0x01]
+            );
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00, 0x12, 0x34]);
+            plc.send_to(&response, from).unwrap();
+        });
+
+        assert_eq!(client.read_addr("D100", 1).unwrap(), vec![0x1234]);
+        responder.join().unwrap();
+
+        // Word/bit mismatches and bad notation fail before sending
+        assert!(client.read_addr("D100.01", 1).is_err());
+        assert!(client.read_bit_addr("D100").is_err());
+        assert!(client.write_addr("Q5", &[1]).is_err());
+        assert_eq!(client.stats().requests_sent, 1);
+    }
+
+    #[test]
+    fn test_client_read_range_chunks() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                requests.push(buf[12..len].to_vec());
+                let count = u16::from_be_bytes([buf[16], buf[17]]) as usize;
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+                response.extend(std::iter::repeat(0xAB).take(count * 2));
+                plc.send_to(&response, from).unwrap();
+            }
+            requests
+        });
+
+        let range = AreaRange::new(MemoryArea::DM, 100, 701);
+        assert_eq!(client.read_range(&range).unwrap(), vec![0xABAB; 701]);
+
+        let requests = responder.join().unwrap();
+        // DM100 x700, then DM800 x1
+        assert_eq!(requests[0], vec![0x82, 0x00, 0x64, 0x00, 0x02, 0xBC]);
+        assert_eq!(requests[1], vec![0x82, 0x03, 0x20, 0x00, 0x00, 0x01]);
+
+        assert!(client.write_range(&range, &[0; 10]).is_err());
+    }
+
+    #[test]
+    fn test_client_64_bit_word_order() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let written = buf[18..len].to_vec();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+            plc.send_to(&response, from).unwrap();
+
+            let (_, from) = plc.recv_from(&mut buf).unwrap();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+            response.extend_from_slice(&[0xFF, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+            plc.send_to(&response, from).unwrap();
+            written
+        });
+
+        client
+            .write_u64(MemoryArea::DM, 0, 0x0001_0002_0003_0004)
+            .unwrap();
+        assert_eq!(client.read_i64(MemoryArea::DM, 0).unwrap(), -2);
+
+        // Least significant word first
+        assert_eq!(
+            responder.join().unwrap(),
+            vec![0x00, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x01]
+        );
+    }
+
+    #[test]
+    fn test_client_i16_helpers() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let written = buf[18..len].to_vec();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+            plc.send_to(&response, from).unwrap();
+
+            let (_, from) = plc.recv_from(&mut buf).unwrap();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+            response.extend_from_slice(&[0xFF, 0x06, 0x00, 0x05, 0x80, 0x00]);
+            plc.send_to(&response, from).unwrap();
+            written
+        });
+
+        client
+            .write_i16_slice(MemoryArea::DM, 0, &[-1, 250, i16::MIN])
+            .unwrap();
+        assert_eq!(
+            client.read_i16_slice(MemoryArea::DM, 0, 3).unwrap(),
+            vec![-250, 5, i16::MIN]
+        );
+
+        assert_eq!(
+            responder.join().unwrap(),
+            vec![0xFF, 0xFF, 0x00, 0xFA, 0x80, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_client_bcd_helpers() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let written = buf[18..len].to_vec();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+            plc.send_to(&response, from).unwrap();
+
+            for data in [[0x56, 0x78, 0x12, 0x34], [0x00, 0x0A, 0x00, 0x00]] {
+                let (_, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+                response.extend_from_slice(&data);
+                plc.send_to(&response, from).unwrap();
+            }
+            written
+        });
+
+        client.write_bcd32(MemoryArea::DM, 0, 12345678).unwrap();
+        assert_eq!(client.read_bcd32(MemoryArea::DM, 0).unwrap(), 12345678);
+        assert!(matches!(
+            client.read_bcd32(MemoryArea::DM, 0),
+            Err(crate::error::FinsError::InvalidResponse { .. })
+        ));
+
+        // Least significant word first
+        assert_eq!(responder.join().unwrap(), vec![0x56, 0x78, 0x12, 0x34]);
+
+        // Out-of-range values fail before anything is sent
+        assert!(client.write_bcd16(MemoryArea::DM, 0, 10000).is_err());
+        assert!(client.write_bcd32(MemoryArea::DM, 0, 100_000_000).is_err());
+    }
+
+    #[test]
+    fn test_client_word_order() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config.clone()).unwrap();
+        let low_first = Client::new(config.clone().with_word_order(WordOrder::LowFirst)).unwrap();
+        let swapped = Client::new(config.with_word_order(WordOrder::HighFirstByteSwap)).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut written = Vec::new();
+            for _ in 0..3 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                written.push(buf[18..len].to_vec());
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+                plc.send_to(&response, from).unwrap();
+            }
+
+            let (_, from) = plc.recv_from(&mut buf).unwrap();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+            response.extend_from_slice(&[0x80, 0x3F, 0x00, 0x00]);
+            plc.send_to(&response, from).unwrap();
+            written
+        });
+
+        client.write_i32(MemoryArea::DM, 0, 0x1122_3344).unwrap();
+        low_first.write_i32(MemoryArea::DM, 0, 0x1122_3344).unwrap();
+        swapped.write_i32(MemoryArea::DM, 0, 0x1122_3344).unwrap();
+        assert_eq!(swapped.read_f32(MemoryArea::DM, 0).unwrap(), 1.0);
+
+        let written = responder.join().unwrap();
+        // Without a configured order, i32 keeps the high word first
+        assert_eq!(written[0], vec![0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(written[1], vec![0x33, 0x44, 0x11, 0x22]);
+        assert_eq!(written[2], vec![0x22, 0x11, 0x44, 0x33]);
+    }
+
+    #[test]
+    fn test_client_f32_and_i32_slices() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut requests = Vec::new();
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            requests.push(buf[12..len].to_vec());
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+            // 1.0, -2.5
+            response.extend_from_slice(&[0x00, 0x00, 0x3F, 0x80, 0x00, 0x00, 0xC0, 0x20]);
+            plc.send_to(&response, from).unwrap();
+
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            requests.push(buf[12..len].to_vec());
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+            plc.send_to(&response, from).unwrap();
+            requests
+        });
+
+        assert_eq!(
+            client.read_f32_slice(MemoryArea::DM, 100, 2).unwrap(),
+            vec![1.0, -2.5]
+        );
+        client
+            .write_i32_slice(MemoryArea::DM, 100, &[1, -2])
+            .unwrap();
+
+        let requests = responder.join().unwrap();
+        // One read of DM100 x4 words
+        assert_eq!(requests[0], vec![0x82, 0x00, 0x64, 0x00, 0x00, 0x04]);
+        // i32 values keep the high word first, like write_i32
+        assert_eq!(
+            requests[1][6..],
+            [0x00, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFF, 0xFE]
+        );
+
+        assert!(client.read_i32_slice(MemoryArea::DM, 0, 40000).is_err());
+    }
+
+    #[test]
+    fn test_client_word_blocks() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_word_order(WordOrder::HighFirst);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let written = buf[12..len].to_vec();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+            plc.send_to(&response, from).unwrap();
+
+            let (_, from) = plc.recv_from(&mut buf).unwrap();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+            response.extend_from_slice(&written[6..]);
+            plc.send_to(&response, from).unwrap();
+            written
+        });
+
+        let recipe = WordBlockBuilder::new()
+            .with_u16(0, 3)
+            .with_i32(1, -2)
+            .with_string(3, "OK");
+        client.write_block(MemoryArea::DM, 500, &recipe).unwrap();
+        let block = client.read_block(MemoryArea::DM, 500, 4).unwrap();
+        assert_eq!(block.u16_at(0).unwrap(), 3);
+        assert_eq!(block.i32_at(1).unwrap(), -2);
+        assert_eq!(block.string_at(3, 1).unwrap(), "OK");
+
+        // DM500 x4 in one command, DINT high word first
+        assert_eq!(
+            responder.join().unwrap(),
+            vec![
+                0x82, 0x01, 0xF4, 0x00, 0x00, 0x04, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xFE, 0x4B, 0x4F
+            ]
+        );
+    }
+
+    #[test]
+    fn test_client_string_encoding() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            for _ in 0..2 {
+                let (_, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+                // "O", 0xFF
+                response.extend_from_slice(&[0xFF, 0x4F]);
+                plc.send_to(&response, from).unwrap();
+            }
+        });
+
+        assert_eq!(
+            client.read_string(MemoryArea::DM, 0, 1).unwrap(),
+            "O\u{FFFD}"
+        );
+        assert!(matches!(
+            client.read_string_encoded(MemoryArea::DM, 0, 1, StringEncoding::Utf8, true),
+            Err(crate::error::FinsError::InvalidResponse { .. })
+        ));
+        responder.join().unwrap();
+
+        // Unencodable text fails before anything is sent
+        assert!(client
+            .write_string_encoded(MemoryArea::DM, 0, "25°C", StringEncoding::Ascii)
+            .is_err());
+    }
+
+    #[test]
+    fn test_client_write_string_fixed() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut written = Vec::new();
+            for _ in 0..2 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                written.push(buf[18..len].to_vec());
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+                plc.send_to(&response, from).unwrap();
+            }
+            written
+        });
+
+        client
+            .write_string_fixed(MemoryArea::DM, 0, "ABC", 3, b' ')
+            .unwrap();
+        client
+            .write_string_fixed(MemoryArea::DM, 0, "ABCDEFGH", 2, 0)
+            .unwrap();
+
+        let written = responder.join().unwrap();
+        // Low byte first: "AB", "C ", "  "
+        assert_eq!(written[0], vec![0x42, 0x41, 0x20, 0x43, 0x20, 0x20]);
+        assert_eq!(written[1], vec![0x42, 0x41, 0x44, 0x43]);
+
+        assert!(client
+            .write_string_fixed(MemoryArea::DM, 0, "A", 0, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_client_read_bits() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let request = buf[12..len].to_vec();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01]);
+            plc.send_to(&response, from).unwrap();
+            request
+        });
+
+        assert_eq!(
+            client.read_bits(MemoryArea::CIO, 0, 14, 4).unwrap(),
+            vec![true, false, false, true]
+        );
+        // CIO 0.14, 4 bits
+        assert_eq!(
+            responder.join().unwrap(),
+            vec![0x30, 0x00, 0x00, 0x0E, 0x00, 0x04]
+        );
+
+        assert!(client.read_bits(MemoryArea::CIO, 0, 0, 0).is_err());
+        assert!(client.read_bits(MemoryArea::DM, 0, 0, 16).is_err());
+    }
+
+    #[test]
+    fn test_client_write_bits() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let request = buf[12..len].to_vec();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+            plc.send_to(&response, from).unwrap();
+            request
+        });
+
+        client
+            .write_bits(MemoryArea::WR, 10, 0, &[true, false, true])
+            .unwrap();
+        assert_eq!(
+            responder.join().unwrap(),
+            vec![0x31, 0x00, 0x0A, 0x00, 0x00, 0x03, 0x01, 0x00, 0x01]
+        );
+
+        assert!(client.write_bits(MemoryArea::WR, 10, 0, &[]).is_err());
+    }
+
+    #[test]
+    fn test_client_read_into() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            for data in [
+                &[0x12, 0x34, 0xAB, 0xCD][..],
+                &[0x00, 0x01, 0x00, 0x02],
+                &[0x00, 0x01],
+            ] {
+                let (_, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+                response.extend_from_slice(data);
+                plc.send_to(&response, from).unwrap();
+            }
+        });
+
+        let mut words = [0u16; 2];
+        client.read_into(MemoryArea::DM, 0, &mut words).unwrap();
+        assert_eq!(words, [0x1234, 0xABCD]);
+
+        let mut bytes = [0u8; 4];
+        client
+            .read_bytes_into(MemoryArea::DM, 0, &mut bytes)
+            .unwrap();
+        assert_eq!(bytes, [0x00, 0x01, 0x00, 0x02]);
+
+        // Short response
+        assert!(matches!(
+            client.read_into(MemoryArea::DM, 0, &mut words),
+            Err(crate::error::FinsError::InvalidResponse { .. })
+        ));
+        responder.join().unwrap();
+
+        assert!(client
+            .read_bytes_into(MemoryArea::DM, 0, &mut [0u8; 3])
+            .is_err());
+    }
+
+    #[test]
+    fn test_client_read_large() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let custom = MemoryArea::Custom {
+            word_code: 0xA0,
+            bit_code: None,
+        };
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut counts = Vec::new();
+            for _ in 0..3 {
+                let (_, from) = plc.recv_from(&mut buf).unwrap();
+                let count = u16::from_be_bytes([buf[16], buf[17]]);
+                counts.push(count);
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+                response.extend(std::iter::repeat(0x11).take(count as usize * 2));
+                plc.send_to(&response, from).unwrap();
+            }
+            counts
+        });
+
+        let words = client.read_large(custom, 100, 1500).unwrap();
+        assert_eq!(words, vec![0x1111; 1500]);
+        assert_eq!(responder.join().unwrap(), vec![700, 700, 100]);
+
+        assert!(client.read_large(custom, 1, 65536).is_err());
+    }
+
+    #[test]
+    fn test_client_read_large_past_area_capacity() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut chunks = Vec::new();
+            for _ in 0..9 {
+                let (_, from) = plc.recv_from(&mut buf).unwrap();
+                let address = u16::from_be_bytes([buf[13], buf[14]]);
+                let count = u16::from_be_bytes([buf[16], buf[17]]);
+                chunks.push((address, count));
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+                response.extend(std::iter::repeat(0x22).take(count as usize * 2));
+                plc.send_to(&response, from).unwrap();
+            }
+            chunks
+        });
+
+        // The documented example: D10000-D15999 in 9 commands, beyond the
+        // generic 4096-word DM capacity
+        let words = client.read_large(MemoryArea::DM, 10000, 6000).unwrap();
+        assert_eq!(words, vec![0x2222; 6000]);
+        let chunks = responder.join().unwrap();
+        assert_eq!(chunks[0], (10000, 700));
+        assert_eq!(chunks[8], (15600, 400));
+
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_cpu_profile(CpuProfile::CJ2M);
+        let client = Client::new(config).unwrap();
+        assert!(client.read_large(MemoryArea::DM, 30000, 6000).is_err());
+    }
+
+    #[test]
+    fn test_client_write_large_verify() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 2048];
+            let mut last_written = Vec::new();
+            for round in 0..4 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                if round % 2 == 0 {
+                    last_written = buf[18..len].to_vec();
+                    response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+                } else {
+                    response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+                    response.extend_from_slice(&last_written);
+                    if round == 3 {
+                        // Second chunk reads back with its first word changed
+                        response[14] ^= 0xFF;
+                    }
+                }
+                plc.send_to(&response, from).unwrap();
+            }
+        });
+
+        let data = vec![0x5A5A; 800];
+        match client.write_large(MemoryArea::DM, 0, &data, true) {
+            Err(crate::error::FinsError::TransferIncomplete { words_done, source }) => {
+                assert_eq!(words_done, 700);
+                assert!(source.to_string().contains("word 700"));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        responder.join().unwrap();
+
+        assert!(client
+            .write_large(MemoryArea::DM, 65000, &[0; 600], false)
+            .is_err());
+    }
+
+    #[test]
+    fn test_client_write_large_past_area_capacity() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 2048];
+            let mut memory = vec![0u8; 65536 * 2];
+            let mut writes = Vec::new();
+            // 6 chunks, each written and read back
+            for _ in 0..12 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                let offset = u16::from_be_bytes([buf[13], buf[14]]) as usize * 2;
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                if buf[11] == 0x02 {
+                    writes.push(offset / 2);
+                    memory[offset..offset + len - 18].copy_from_slice(&buf[18..len]);
+                } else {
+                    let count = u16::from_be_bytes([buf[16], buf[17]]) as usize;
+                    response.extend_from_slice(&memory[offset..offset + count * 2]);
+                }
+                plc.send_to(&response, from).unwrap();
+            }
+            writes
+        });
+
+        // The documented example: D20000 onwards, beyond the generic
+        // 4096-word DM capacity
+        let table = vec![0x1234; 4000];
+        client
+            .write_large(MemoryArea::DM, 20000, &table, true)
+            .unwrap();
+        assert_eq!(
+            responder.join().unwrap(),
+            vec![20000, 20700, 21400, 22100, 22800, 23500]
+        );
+
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_cpu_profile(CpuProfile::CJ2M);
+        let client = Client::new(config).unwrap();
+        assert!(client
+            .write_large(MemoryArea::DM, 30000, &table, false)
+            .is_err());
     }
 
     #[test]
-    fn test_client_config_with_timeout() {
-        let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
-            .with_timeout(Duration::from_secs(5));
+    fn test_client_write_rejects_oversized_data() {
+        let custom = MemoryArea::Custom {
+            word_code: 0xA0,
+            bit_code: None,
+        };
+        let client = Client::new(ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)).unwrap();
 
-        assert_eq!(config.timeout, Duration::from_secs(5));
+        // 65536 words used to wrap to a count of 0 and succeed unsent
+        let data = vec![0; 65536];
+        for result in [
+            client.write(custom, 0, &data),
+            client.write_with_warnings(custom, 0, &data).map(|_| ()),
+            client.write_verify(custom, 0, &data),
+        ] {
+            match result {
+                Err(crate::error::FinsError::InvalidParameter { parameter, .. }) => {
+                    assert_eq!(parameter, "data");
+                }
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
     }
 
     #[test]
-    fn test_client_config_with_network() {
-        let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
-            .with_source_network(1)
-            .with_dest_network(2);
+    fn test_client_reads_condition_flags() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_cpu_profile(CpuProfile::CJ2M);
+        let client = Client::new(config).unwrap();
 
-        assert_eq!(config.source.network, 1);
-        assert_eq!(config.destination.network, 2);
+        let responder = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            let mut buf = [0u8; 64];
+            for _ in 0..2 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00, 0x01]);
+                plc.send_to(&response, from).unwrap();
+                requests.push(buf[12..len].to_vec());
+            }
+            requests
+        });
+
+        // A condition flag and a clock pulse at their PLC addresses
+        assert!(client.read_bit(MemoryArea::CF, 0x1001, 3).unwrap());
+        assert!(client.read_bit(MemoryArea::CF, 0, 2).unwrap());
+        assert!(client.read_bit(MemoryArea::CF, 1, 0).is_err());
+        assert_eq!(
+            responder.join().unwrap(),
+            vec![
+                vec![0x07, 0x10, 0x01, 0x03, 0x00, 0x01],
+                vec![0x07, 0x00, 0x00, 0x02, 0x00, 0x01],
+            ]
+        );
     }
 
     #[test]
-    fn test_client_creation() {
-        // Note: This creates a socket but doesn't actually connect to a PLC
-        let config = ClientConfig::new(Ipv4Addr::new(127, 0, 0, 1), 1, 10);
-        let client = Client::new(config);
-        assert!(client.is_ok());
+    fn test_client_rejects_cf_writes() {
+        let client = Client::new(ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)).unwrap();
+
+        for result in [
+            client.write_bit(MemoryArea::CF, 0, 3, true),
+            client.write(MemoryArea::CF, 0, &[1]),
+            client.fill(MemoryArea::CF, 0, 1, 0),
+            client.transfer(MemoryArea::DM, 0, MemoryArea::CF, 0, 1),
+        ] {
+            match result {
+                Err(crate::error::FinsError::InvalidParameter { parameter, .. }) => {
+                    assert_eq!(parameter, "area");
+                }
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
     }
 
     #[test]
-    fn test_client_sid_increment() {
-        let config = ClientConfig::new(Ipv4Addr::new(127, 0, 0, 1), 1, 10);
+    fn test_client_write_verify() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
         let client = Client::new(config).unwrap();
 
-        assert_eq!(client.next_sid(), 0);
-        assert_eq!(client.next_sid(), 1);
-        assert_eq!(client.next_sid(), 2);
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut memory = Vec::new();
+            for round in 0..4 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                if buf[11] == 0x02 {
+                    memory = buf[18..len].to_vec();
+                    if round == 2 {
+                        // The second write only sticks for its first word
+                        memory[2..].fill(0);
+                    }
+                } else {
+                    response.extend_from_slice(&memory);
+                }
+                plc.send_to(&response, from).unwrap();
+            }
+        });
+
+        client
+            .write_verify(MemoryArea::DM, 100, &[1, 2, 3])
+            .unwrap();
+        match client.write_verify(MemoryArea::DM, 100, &[1, 2, 3]) {
+            Err(crate::error::FinsError::VerifyMismatch { mismatches }) => {
+                assert_eq!(
+                    mismatches,
+                    vec![
+                        crate::error::WordMismatch {
+                            address: 101,
+                            written: 2,
+                            read: 0
+                        },
+                        crate::error::WordMismatch {
+                            address: 102,
+                            written: 3,
+                            read: 0
+                        },
+                    ]
+                );
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        responder.join().unwrap();
+    }
+
+    #[test]
+    fn test_client_fill_and_transfer_large() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let custom = MemoryArea::Custom {
+            word_code: 0xA0,
+            bit_code: None,
+        };
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut requests = Vec::new();
+            loop {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                requests.push(buf[10..len].to_vec());
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                plc.send_to(&response, from).unwrap();
+                if buf[11] == 0x05 {
+                    return requests;
+                }
+            }
+        });
+
+        client.fill_large(custom, 0, 65535, 0).unwrap();
+        client
+            .transfer_large(MemoryArea::DM, 0, MemoryArea::DM, 1000, 10)
+            .unwrap();
+
+        let requests = responder.join().unwrap();
+        // 65535 words: 93 fills of 700 and one of 435, then one transfer
+        assert_eq!(requests.len(), 95);
+        assert_eq!(&requests[93][..2], &[0x01, 0x03]);
+        assert_eq!(&requests[93][6..8], &[0x01, 0xB3]);
+        assert_eq!(&requests[94][..2], &[0x01, 0x05]);
+
+        assert!(client.fill_large(custom, 1, 65535, 0).is_err());
+        assert!(client.transfer_large(custom, 0, custom, 10, 65530).is_err());
+    }
+
+    #[test]
+    fn test_client_fill_and_transfer_large_past_area_capacity() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut requests = Vec::new();
+            // 47 fills and 23 transfers
+            for _ in 0..70 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                requests.push(buf[10..len].to_vec());
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                plc.send_to(&response, from).unwrap();
+            }
+            requests
+        });
+
+        // The documented examples, beyond the generic 4096-word DM capacity
+        client.fill_large(MemoryArea::DM, 0, 32768, 0).unwrap();
+        client
+            .transfer_large(MemoryArea::DM, 0, MemoryArea::DM, 16000, 16000)
+            .unwrap();
+
+        let requests = responder.join().unwrap();
+        // Last fill: D32200, 568 words
+        assert_eq!(&requests[46][..2], &[0x01, 0x03]);
+        assert_eq!(&requests[46][3..5], &32200u16.to_be_bytes());
+        assert_eq!(&requests[46][6..8], &568u16.to_be_bytes());
+        // Last transfer: D15400 to D31400, 600 words
+        assert_eq!(&requests[69][..2], &[0x01, 0x05]);
+        assert_eq!(&requests[69][3..5], &15400u16.to_be_bytes());
+        assert_eq!(&requests[69][7..9], &31400u16.to_be_bytes());
+        assert_eq!(&requests[69][10..12], &600u16.to_be_bytes());
+
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_cpu_profile(CpuProfile::CJ2M);
+        let client = Client::new(config).unwrap();
+        assert!(client.fill_large(MemoryArea::DM, 0, 40000, 0).is_err());
+        assert!(client
+            .transfer_large(MemoryArea::DM, 0, MemoryArea::DM, 30000, 6000)
+            .is_err());
+    }
+
+    #[test]
+    fn test_client_typed_round_trip() {
+        struct Pair {
+            count: u16,
+            total: i32,
+        }
+
+        impl FinsStruct for Pair {
+            const WORD_COUNT: u16 = 3;
+
+            fn to_words(&self, order: WordOrder) -> Vec<u16> {
+                let mut words = vec![self.count, 0, 0];
+                crate::FinsField::put(&self.total, &mut words[1..], order);
+                words
+            }
+
+            fn from_words(words: &[u16], order: WordOrder) -> Result<Self> {
+                crate::record::check_len::<Self>(words)?;
+                Ok(Self {
+                    count: words[0],
+                    total: crate::FinsField::get(&words[1..3], order),
+                })
+            }
+        }
+
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        // Echo the written words back on the following read
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let written = buf[18..len].to_vec();
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+            plc.send_to(&response, from).unwrap();
+
+            let (_, from) = plc.recv_from(&mut buf).unwrap();
+            let count = &buf[16..18];
+            assert_eq!(count, &[0x00, 0x03]);
+            let mut response = buf[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00]);
+            response.extend_from_slice(&written);
+            plc.send_to(&response, from).unwrap();
+            written
+        });
+
+        let pair = Pair {
+            count: 5,
+            total: -2,
+        };
+        client.write_typed(MemoryArea::DM, 100, &pair).unwrap();
+        let read: Pair = client.read_typed(MemoryArea::DM, 100).unwrap();
+        assert_eq!(
+            responder.join().unwrap(),
+            vec![0x00, 0x05, 0xFF, 0xFE, 0xFF, 0xFF]
+        );
+        assert_eq!(read.count, 5);
+        assert_eq!(read.total, -2);
+    }
+
+    #[test]
+    fn test_client_tags() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let mut tags = TagTable::new();
+        tags.add("Speed", "D100", TagKind::Value(DataType::REAL))
+            .unwrap();
+        tags.add("EStop", "CIO0.05", TagKind::Bit).unwrap();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_tags(tags);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut requests = Vec::new();
+            for data in [&[][..], &[0x01]] {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                requests.push(buf[10..len].to_vec());
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                response.extend_from_slice(data);
+                plc.send_to(&response, from).unwrap();
+            }
+            requests
+        });
+
+        client.write_tag("Speed", PlcValue::Real(1.5)).unwrap();
+        assert_eq!(client.read_tag("EStop").unwrap(), TagValue::Bit(true));
+
+        let requests = responder.join().unwrap();
+        // D100-D101 = 1.5, low word first
+        assert_eq!(
+            requests[0],
+            vec![0x01, 0x02, 0x82, 0x00, 0x64, 0x00, 0x00, 0x02, 0x00, 0x00, 0x3F, 0xC0]
+        );
+        assert_eq!(&requests[1][2..6], &[0x30, 0x00, 0x00, 0x05]);
+
+        assert!(client.read_tag("Missing").is_err());
+        assert!(client.write_tag("Speed", PlcValue::Dint(1)).is_err());
+        assert!(client.write_tag("EStop", "on").is_err());
+    }
+
+    #[test]
+    fn test_client_read_batch() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut requests = Vec::new();
+            // Multiple read of D0, D1 and CIO0.05, then a plain read of D10-D12
+            let answers: [&[u8]; 2] = [
+                &[0x82, 0x00, 0x00, 0x82, 0x3F, 0xC0, 0x30, 0x01],
+                &[0x42, 0x41, 0x00, 0x43, 0x00, 0x00],
+            ];
+            for data in answers {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                requests.push(buf[10..len].to_vec());
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                response.extend_from_slice(data);
+                plc.send_to(&response, from).unwrap();
+            }
+            requests
+        });
+
+        let mut batch = BatchRead::new();
+        let speed = batch.f32(MemoryArea::DM, 0);
+        let name = batch.string(MemoryArea::DM, 10, 3);
+        let running = batch.bit(MemoryArea::CIO, 0, 5);
+        let results = client.read_batch(&batch).unwrap();
+
+        let requests = responder.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(&requests[0][..2], &[0x01, 0x04]);
+        assert_eq!(&requests[1][..2], &[0x01, 0x01]);
+        assert_eq!(results.f32(speed), Some(1.5));
+        assert_eq!(results.string(name), Some("ABC"));
+        assert_eq!(results.bit(running), Some(true));
+    }
+
+    #[test]
+    fn test_client_dm_bits_in_multi_commands() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let plain = Client::new(config.clone()).unwrap();
+        let client = Client::new(config.with_dm_bit_access(true)).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut requests = Vec::new();
+            for _ in 0..3 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                requests.push(buf[10..len].to_vec());
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                if buf[11] == 0x04 {
+                    response.extend_from_slice(&[0x02, 0x01]);
+                }
+                plc.send_to(&response, from).unwrap();
+            }
+            requests
+        });
+
+        let specs = [MultiReadSpec {
+            area: MemoryArea::DM,
+            address: 100,
+            bit: Some(3),
+        }];
+        assert!(plain.read_multiple(&specs).is_err());
+        assert_eq!(client.read_multiple(&specs).unwrap(), vec![1]);
+
+        let mut batch = BatchRead::new();
+        let flag = batch.bit(MemoryArea::DM, 100, 3);
+        assert_eq!(client.read_batch(&batch).unwrap().bit(flag), Some(true));
+
+        let forces = [ForcedBit {
+            area: MemoryArea::DM,
+            address: 100,
+            bit: 3,
+            spec: crate::command::ForceSpec::ForceOn,
+        }];
+        assert!(plain.forced_set_reset(&forces).is_err());
+        client.forced_set_reset(&forces).unwrap();
+
+        let requests = responder.join().unwrap();
+        assert_eq!(requests[0], vec![0x01, 0x04, 0x02, 0x00, 0x64, 0x03]);
+        assert_eq!(requests[1], vec![0x01, 0x04, 0x02, 0x00, 0x64, 0x03]);
+        assert_eq!(&requests[2][..4], &[0x23, 0x01, 0x00, 0x01]);
+        assert_eq!(&requests[2][6..], &[0x02, 0x00, 0x64, 0x03]);
+    }
+
+    #[test]
+    fn test_client_modify_word() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        // D100 holds 0x00F0 until it is written
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut word = [0x00, 0xF0];
+            let mut writes = Vec::new();
+            for _ in 0..3 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                if buf[11] == 0x01 {
+                    response.extend_from_slice(&word);
+                } else {
+                    word = [buf[len - 2], buf[len - 1]];
+                    writes.push(word);
+                }
+                plc.send_to(&response, from).unwrap();
+            }
+            writes
+        });
+
+        assert_eq!(
+            client
+                .set_bit_in_word(MemoryArea::DM, 100, 0, true)
+                .unwrap(),
+            (0x00F0, 0x00F1)
+        );
+        // Already set: read only, no write
+        assert_eq!(
+            client
+                .set_bit_in_word(MemoryArea::DM, 100, 4, true)
+                .unwrap(),
+            (0x00F1, 0x00F1)
+        );
+        assert_eq!(responder.join().unwrap(), vec![[0x00, 0xF1]]);
+        assert!(client
+            .set_bit_in_word(MemoryArea::DM, 100, 16, true)
+            .is_err());
+    }
+
+    #[test]
+    fn test_client_date_time() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                requests.push(buf[..len].to_vec());
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                if buf[11] == 0x01 {
+                    // A351-A353: 2024-03-15 08:30:05
+                    response.extend_from_slice(&[0x30, 0x05, 0x15, 0x08, 0x24, 0x03]);
+                }
+                plc.send_to(&response, from).unwrap();
+            }
+            requests
+        });
+
+        let now = client
+            .read_date_time(MemoryArea::AR, 351, TimeFormat::Bcd)
+            .unwrap();
+        assert_eq!(now.to_string(), "2024-03-15 08:30:05");
+        client
+            .write_time(
+                MemoryArea::DM,
+                0,
+                TimeFormat::Binary,
+                Duration::from_nanos(0x0102_0304),
+            )
+            .unwrap();
+
+        let requests = responder.join().unwrap();
+        assert_eq!(&requests[0][16..18], &[0x00, 0x03]);
+        // LINT nanoseconds, low word first
+        assert_eq!(
+            &requests[1][18..],
+            &[0x03, 0x04, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00]
+        );
+        assert!(client
+            .write_time_of_day(
+                MemoryArea::DM,
+                0,
+                TimeFormat::Bcd,
+                Duration::from_secs(86400)
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_client_scaled() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut written = Vec::new();
+            for _ in 0..2 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                if buf[11] == 0x01 {
+                    // 1000 counts
+                    response.extend_from_slice(&[0x03, 0xE8]);
+                } else {
+                    written = buf[18..len].to_vec();
+                }
+                plc.send_to(&response, from).unwrap();
+            }
+            written
+        });
+
+        let scale = Scale::new(0, 4000, 0.0, 10.0);
+        assert_eq!(client.read_scaled(MemoryArea::DM, 0, &scale).unwrap(), 2.5);
+        client.write_scaled(MemoryArea::DM, 0, &scale, 7.5).unwrap();
+        assert_eq!(responder.join().unwrap(), vec![0x0B, 0xB8]);
+
+        assert!(client
+            .write_scaled(MemoryArea::DM, 0, &scale, 1000.0)
+            .is_err());
+        assert!(client
+            .read_scaled(MemoryArea::DM, 0, &Scale::new(0, 0, 0.0, 10.0))
+            .is_err());
+    }
+
+    #[test]
+    fn test_client_omron_string() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut written = Vec::new();
+            for _ in 0..2 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                if buf[11] == 0x01 {
+                    // Count 3, "LOT", then stale characters
+                    response.extend_from_slice(&[0x00, 0x03, 0x4F, 0x4C, 0x58, 0x54]);
+                } else {
+                    written = buf[18..len].to_vec();
+                }
+                plc.send_to(&response, from).unwrap();
+            }
+            written
+        });
+
+        assert_eq!(
+            client
+                .read_omron_string(MemoryArea::DM, 0, 3, LengthPrefix::Word)
+                .unwrap(),
+            "LOT"
+        );
+        client
+            .write_omron_string(MemoryArea::DM, 0, "AB", LengthPrefix::Byte)
+            .unwrap();
+        assert_eq!(responder.join().unwrap(), vec![0x41, 0x02, 0x00, 0x42]);
+    }
+
+    #[test]
+    fn test_client_word_order_override() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut written = Vec::new();
+            for _ in 0..3 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                if buf[11] == 0x01 {
+                    // 1.5 as ABCD
+                    response.extend_from_slice(&[0x3F, 0xC0, 0x00, 0x00]);
+                } else {
+                    written = buf[18..len].to_vec();
+                }
+                plc.send_to(&response, from).unwrap();
+            }
+            written
+        });
+
+        assert_eq!(
+            client
+                .read_f32_with_order(MemoryArea::DM, 0, WordOrder::HighFirst)
+                .unwrap(),
+            1.5
+        );
+        // The configured CDAB order reads the same words differently
+        assert_ne!(client.read_f32(MemoryArea::DM, 0).unwrap(), 1.5);
+        client
+            .write_i32_with_order(MemoryArea::DM, 0, 0x0102_0304, WordOrder::LowFirstByteSwap)
+            .unwrap();
+        assert_eq!(responder.join().unwrap(), vec![0x04, 0x03, 0x02, 0x01]);
+    }
+
+    #[test]
+    fn test_auto_source_node() {
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 0, 10)
+            .with_auto_source_node()
+            .unwrap();
+        assert_eq!(config.source.node, 1);
+
+        assert_eq!(
+            node_from_ip(Ipv4Addr::new(192, 168, 1, 42).into()).unwrap(),
+            42
+        );
+        assert!(node_from_ip(Ipv4Addr::new(10, 0, 1, 0).into()).is_err());
+        assert!(node_from_ip(Ipv4Addr::new(10, 0, 1, 255).into()).is_err());
+        assert!(node_from_ip(std::net::Ipv6Addr::LOCALHOST.into()).is_err());
+    }
+
+    #[test]
+    fn test_config_validate() {
+        let config = || ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 10);
+        assert!(config().build().is_ok());
+        // Same node on another network is fine
+        assert!(config().with_dest_network(1).build().is_ok());
+        assert!(ClientConfig::new(Ipv4Addr::LOCALHOST, 0, 0)
+            .build()
+            .is_err());
+        // FINS/TCP assigns the source node, as in the `with_tcp` example
+        assert!(ClientConfig::new(Ipv4Addr::LOCALHOST, 0, 0)
+            .with_tcp()
+            .validate()
+            .is_ok());
+        assert!(ClientConfig::new(Ipv4Addr::LOCALHOST, 5, 5)
+            .with_tcp()
+            .validate()
+            .is_err());
+
+        let invalid = [
+            config().with_source_network(128),
+            ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 255),
+            config().with_timeout(Duration::ZERO),
+            config().with_connect_timeout(Duration::ZERO),
+            ClientConfig::new(Ipv4Addr::LOCALHOST, 5, 5),
+            config().with_tcp().with_pipelining(true),
+        ];
+        for config in invalid {
+            assert!(
+                matches!(
+                    config.validate(),
+                    Err(crate::error::FinsError::InvalidParameter { .. })
+                ),
+                "{:?}",
+                config
+            );
+        }
+    }
+
+    #[test]
+    fn test_client_set_timeout() {
+        let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = silent.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let mut client = Client::new(config).unwrap();
+        assert_eq!(client.timeout(), DEFAULT_TIMEOUT);
+
+        client.set_timeout(Duration::from_millis(30)).unwrap();
+        assert_eq!(client.timeout(), Duration::from_millis(30));
+        let started = Instant::now();
+        assert!(matches!(
+            client.read(MemoryArea::DM, 0, 1),
+            Err(crate::FinsError::Timeout)
+        ));
+        assert!(started.elapsed() < DEFAULT_TIMEOUT);
+
+        assert!(client.set_timeout(Duration::ZERO).is_err());
+        assert_eq!(client.timeout(), Duration::from_millis(30));
+
+        // The send timeout is separate and survives a reconnect
+        assert_eq!(client.config.send_timeout, DEFAULT_TIMEOUT);
+        client.set_send_timeout(Duration::from_millis(100)).unwrap();
+        assert!(client.set_send_timeout(Duration::ZERO).is_err());
+        client.reconnect().unwrap();
+        assert_eq!(client.config.timeout, Duration::from_millis(30));
+        assert_eq!(client.config.send_timeout, Duration::from_millis(100));
+        let Transport::Udp(udp) = &client.transport else {
+            unreachable!()
+        };
+        assert_eq!(
+            udp.socket().write_timeout().unwrap(),
+            Some(Duration::from_millis(100))
+        );
+    }
+
+    #[test]
+    fn test_client_try_clone() {
+        let server = crate::server::FinsServer::bind("127.0.0.1:0")
+            .unwrap()
+            .spawn()
+            .unwrap();
+        let config =
+            ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(server.local_addr().port());
+        let client = Client::new(config.clone()).unwrap();
+        let clone = client.try_clone().unwrap();
+
+        let worker = std::thread::spawn(move || {
+            for value in 0..20 {
+                clone.write(MemoryArea::DM, 0, &[value]).unwrap();
+                assert_eq!(clone.read(MemoryArea::DM, 0, 1).unwrap(), vec![value]);
+            }
+            clone.stats().requests_sent
+        });
+        for value in 0..20 {
+            client.write(MemoryArea::DM, 1, &[value]).unwrap();
+            assert_eq!(client.read(MemoryArea::DM, 1, 1).unwrap(), vec![value]);
+        }
+        assert_eq!(worker.join().unwrap(), 40);
+        assert_eq!(client.stats().requests_sent, 40);
+
+        // A clone on the same fixed port would steal this client's responses
+        let local_port = std::net::UdpSocket::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let options = SocketOptions::new()
+            .with_local_port(local_port)
+            .with_reuse_address(true);
+        let bound = Client::new(config.with_socket_options(options)).unwrap();
+        assert!(matches!(
+            bound.try_clone(),
+            Err(crate::error::FinsError::InvalidParameter { .. })
+        ));
+    }
+
+    #[test]
+    fn test_client_error_context() {
+        use crate::server::{FinsServer, Reply};
+
+        let server = FinsServer::bind("127.0.0.1:0")
+            .unwrap()
+            .with_handler(0x01, 0x01, |_, _| Reply::error(0x11, 0x04))
+            .with_handler(0x01, 0x02, |_, _| Reply::error(0x21, 0x01))
+            .spawn()
+            .unwrap();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(server.local_addr().port())
+            .with_timeout(Duration::from_millis(200));
+
+        let plain = Client::new(config.clone()).unwrap();
+        assert!(plain
+            .write(MemoryArea::DM, 0, &[1])
+            .unwrap_err()
+            .context()
+            .is_none());
+
+        let client = Client::new(config.with_error_context(true)).unwrap();
+        let err = client.write(MemoryArea::DM, 100, &[1, 2]).unwrap_err();
+        let context = err.context().unwrap();
+        assert_eq!(context.operation, "writing");
+        assert_eq!(
+            (context.area, context.address, context.count),
+            (MemoryArea::DM, 100, 2)
+        );
+        assert!(err.is_permission_error());
+
+        // Chunks carry their own address and count
+        let err = client.read(MemoryArea::DM, 0, 1000).unwrap_err();
+        let context = err.context().unwrap();
+        assert_eq!(
+            (context.operation, context.address, context.count),
+            ("reading", 0, 700)
+        );
+        assert_eq!(
+            err.end_code(),
+            Some(crate::error::EndCode::AreaRangeExceeded)
+        );
+        assert!(err.to_string().ends_with(&format!(
+            "while reading DM 0 len 700 (SID 0x{:02X})",
+            context.sid
+        )));
+    }
+
+    #[test]
+    fn test_client_metrics_sink() {
+        use crate::server::{FinsServer, Reply};
+
+        #[derive(Default)]
+        struct Events(Mutex<Vec<String>>);
+
+        impl MetricsSink for Events {
+            fn on_request(&self, mrc: u8, src: u8) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("request {:02X}{:02X}", mrc, src));
+            }
+
+            fn on_response(&self, mrc: u8, src: u8, end_code: EndCode, _: Duration) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("response {:02X}{:02X} {:?}", mrc, src, end_code));
+            }
+
+            fn on_timeout(&self, mrc: u8, src: u8, _: Duration) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("timeout {:02X}{:02X}", mrc, src));
+            }
+        }
+
+        let server = FinsServer::bind("127.0.0.1:0")
+            .unwrap()
+            .with_handler(0x01, 0x02, |_, _| Reply::error(0x21, 0x01))
+            .with_handler(0x05, 0x01, |_, _| {
+                std::thread::sleep(Duration::from_millis(300));
+                Reply::ok(Vec::new())
+            })
+            .spawn()
+            .unwrap();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(server.local_addr().port())
+            .with_timeout(Duration::from_millis(100));
+        let mut client = Client::new(config).unwrap();
+        let events = Arc::new(Events::default());
+        client.set_metrics_sink(events.clone());
+
+        client.read(MemoryArea::DM, 0, 1).unwrap();
+        assert!(client.write(MemoryArea::DM, 0, &[1]).is_err());
+        assert!(client.execute_raw(0x05, 0x01, &[]).is_err());
+
+        assert_eq!(
+            *events.0.lock().unwrap(),
+            [
+                "request 0101",
+                "response 0101 NormalCompletion",
+                "request 0102",
+                "response 0102 ReadOnly",
+                "request 0501",
+                "timeout 0501",
+            ]
+        );
+
+        client.clear_metrics_sink();
+        let _ = client.read(MemoryArea::DM, 0, 1);
+        assert_eq!(events.0.lock().unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_client_sid_retries() {
+        // Answers every request with the wrong SID
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            while let Ok((_, from)) = plc.recv_from(&mut buf) {
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response[9] = response[9].wrapping_add(0x80);
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00, 0x00, 0x2A]);
+                let _ = plc.send_to(&response, from);
+            }
+        });
+
+        for retries in [0, 2] {
+            let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+                .with_port(port)
+                .with_sid_retries(retries);
+            let client = Client::new(config).unwrap();
+            assert!(matches!(
+                client.read(MemoryArea::DM, 0, 1),
+                Err(crate::error::FinsError::SidMismatch {
+                    expected: 0x00,
+                    received: 0x80
+                })
+            ));
+            let stats = client.stats();
+            assert_eq!(stats.requests_sent, 1 + retries as u64);
+            assert_eq!(stats.retransmits, retries as u64);
+        }
+    }
+
+    #[test]
+    fn test_client_strict_end_codes() {
+        use crate::server::{FinsServer, Reply};
+
+        let server = FinsServer::bind("127.0.0.1:0")
+            .unwrap()
+            .with_handler(0x01, 0x01, |_, _| Reply::new(0x00, 0x40, [0x00, 0x07]))
+            .spawn()
+            .unwrap();
+        let config =
+            ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(server.local_addr().port());
+
+        let lenient = Client::new(config.clone()).unwrap();
+        assert_eq!(lenient.read(MemoryArea::DM, 0, 1).unwrap(), vec![7]);
+
+        let strict = Client::new(config.with_strict_end_codes(true)).unwrap();
+        assert!(matches!(
+            strict.read(MemoryArea::DM, 0, 1),
+            Err(crate::error::FinsError::PlcError {
+                end_code: EndCode::Other {
+                    main_code: 0x00,
+                    sub_code: 0x40
+                }
+            })
+        ));
+        // Raw commands leave the check to the caller
+        assert!(strict.execute_raw(0x01, 0x01, &[]).is_ok());
+    }
+
+    #[test]
+    fn test_client_warnings() {
+        use crate::server::{FinsServer, Reply};
+
+        let server = FinsServer::bind("127.0.0.1:0")
+            .unwrap()
+            .with_handler(0x01, 0x01, |_, _| Reply::new(0x00, 0x80, [0x00, 0x07]))
+            .with_handler(0x01, 0x02, |_, _| Reply::new(0x80, 0x40, []))
+            .spawn()
+            .unwrap();
+        let config =
+            ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(server.local_addr().port());
+        let client = Client::new(config).unwrap();
+
+        // The plain methods fail on flags other than 0x0040
+        assert!(client.read(MemoryArea::DM, 0, 1).is_err());
+        assert!(client.write(MemoryArea::DM, 0, &[1]).is_err());
+
+        let (words, warnings) = client.read_with_warnings(MemoryArea::DM, 0, 1).unwrap();
+        assert_eq!(words, vec![7]);
+        assert!(warnings.unwrap().fatal_cpu_error);
+
+        let warnings = client
+            .write_with_warnings(MemoryArea::DM, 0, &[1])
+            .unwrap()
+            .unwrap();
+        assert!(warnings.relay_error && warnings.non_fatal_cpu_error);
+        assert!(!warnings.fatal_cpu_error);
+    }
+
+    #[test]
+    fn test_client_command_echo() {
+        // Answers every command as if it were a read (0x0101)
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            while let Ok((_, from)) = plc.recv_from(&mut buf) {
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00, 0x00, 0x2A]);
+                let _ = plc.send_to(&response, from);
+            }
+        });
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        assert_eq!(client.read(MemoryArea::DM, 0, 1).unwrap(), vec![0x2A]);
+        let err = client.write(MemoryArea::DM, 0, &[1]).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error::FinsError::CommandMismatch {
+                expected: (0x01, 0x02),
+                received: (0x01, 0x01)
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Command mismatch: sent 0x0102, response is for 0x0101"
+        );
+    }
+
+    #[test]
+    fn test_client_command_mismatch_retry() {
+        // Answers the first request as a write (0x0102) with the right SID,
+        // like a late response to an earlier command with the same SID
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let mut stale = true;
+            while let Ok((_, from)) = plc.recv_from(&mut buf) {
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                if stale {
+                    response.extend_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+                    stale = false;
+                } else {
+                    response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00, 0x00, 0x2A]);
+                }
+                let _ = plc.send_to(&response, from);
+            }
+        });
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+
+        assert_eq!(client.read(MemoryArea::DM, 0, 1).unwrap(), vec![0x2A]);
+        let stats = client.stats();
+        assert_eq!(stats.requests_sent, 2);
+        assert_eq!(stats.retransmits, 1);
+        assert_eq!(stats.sid_mismatches, 0);
+    }
+
+    #[test]
+    fn test_client_session_log() {
+        let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = silent.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_timeout(Duration::from_millis(50));
+        let mut client = Client::new(config).unwrap();
+        assert!(client.session_log().is_empty());
+
+        client.set_session_recorder(SessionRecorder::new(16));
+        assert!(client.read(MemoryArea::DM, 100, 1).is_err());
+
+        let log = client.session_log();
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].sid, 0);
+        assert_eq!(log[0].response, None);
+        assert_eq!(log[0].summary, "cmd 0101: timeout");
+        assert!(client.take_session_recorder().is_some());
+        assert!(client.session_log().is_empty());
+    }
+
+    #[test]
+    fn test_client_stats() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10)
+            .with_port(port)
+            .with_timeout(Duration::from_millis(200));
+        let client = Client::new(config).unwrap();
+
+        // First answer carries a stale SID, the retry is answered correctly,
+        // then the PLC goes silent.
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            for stale in [true, false] {
+                let (_, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                if stale {
+                    response[9] = response[9].wrapping_sub(1);
+                }
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00, 0x00, 0x2A]);
+                plc.send_to(&response, from).unwrap();
+            }
+            plc
+        });
+        assert_eq!(client.read(MemoryArea::DM, 0, 1).unwrap(), vec![0x2A]);
+        let _plc = responder.join().unwrap();
+        assert!(matches!(
+            client.read(MemoryArea::DM, 0, 1),
+            Err(crate::FinsError::Timeout)
+        ));
+
+        let stats = client.stats();
+        assert_eq!(stats.requests_sent, 3);
+        assert_eq!(stats.responses_received, 2);
+        assert_eq!(stats.sid_mismatches, 1);
+        assert_eq!(stats.retransmits, 1);
+        assert_eq!(stats.timeouts, 1);
+        assert_eq!(stats.bytes_sent, 3 * 18);
+        assert_eq!(stats.bytes_received, 2 * 16);
+
+        client.reset_stats();
+        assert_eq!(client.stats(), ClientStats::default());
+    }
+
+    #[test]
+    fn test_client_drain_pending() {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(port);
+        let client = Client::new(config).unwrap();
+        let local = match &client.transport {
+            Transport::Udp(udp) => udp.socket().local_addr().unwrap(),
+            Transport::Tcp(_) => unreachable!(),
+        };
+
+        plc.send_to(&[0xC0; 14], local).unwrap();
+        plc.send_to(&[0xC0; 14], local).unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(client.drain_pending(), 2);
+        assert_eq!(client.stats().stale_packets, 2);
+    }
+
+    #[test]
+    fn test_client_tcp_node_auto_assignment() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut handshake = [0u8; 20];
+            stream.read_exact(&mut handshake).unwrap();
+            assert_eq!(&handshake[16..], &[0, 0, 0, 0]);
+            let mut reply = b"FINS".to_vec();
+            reply.extend_from_slice(&[0, 0, 0, 16, 0, 0, 0, 1, 0, 0, 0, 0]);
+            reply.extend_from_slice(&[0, 0, 0, 0x33, 0, 0, 0, 0x0A]);
+            stream.write_all(&reply).unwrap();
+
+            let mut request = [0u8; 16 + 18];
+            stream.read_exact(&mut request).unwrap();
+            let fins = &request[16..];
+            let mut response = fins[..10].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00, 0xAB, 0xCD]);
+            let mut frame = b"FINS".to_vec();
+            frame.extend_from_slice(&((8 + response.len()) as u32).to_be_bytes());
+            frame.extend_from_slice(&[0, 0, 0, 2, 0, 0, 0, 0]);
+            frame.extend_from_slice(&response);
+            stream.write_all(&frame).unwrap();
+            fins.to_vec()
+        });
+
+        // Node 0 for both ends, as in the `with_tcp` example
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 0, 0)
+            .with_port(port)
+            .with_tcp();
+        let client = Client::new(config).unwrap();
+        assert_eq!(client.source().node, 0x33);
+        assert_eq!(client.read(MemoryArea::DM, 0, 1).unwrap(), vec![0xABCD]);
+
+        // The request header carries the assigned node as SA1
+        let request = server.join().unwrap();
+        assert_eq!(request[7], 0x33);
+    }
+
+    #[test]
+    fn test_client_tcp_connection_reset() {
+        use std::io::{Read, Write};
+
+        fn handshake(stream: &mut std::net::TcpStream) {
+            let mut handshake = [0u8; 20];
+            stream.read_exact(&mut handshake).unwrap();
+            let mut reply = b"FINS".to_vec();
+            reply.extend_from_slice(&[0, 0, 0, 16, 0, 0, 0, 1, 0, 0, 0, 0]);
+            reply.extend_from_slice(&[0, 0, 0, 0x33, 0, 0, 0, 0x0A]);
+            stream.write_all(&reply).unwrap();
+        }
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            handshake(&mut stream);
+            let mut request = [0u8; 16 + 18];
+            stream.read_exact(&mut request).unwrap();
+            drop(stream);
+
+            let (mut stream, _) = listener.accept().unwrap();
+            handshake(&mut stream);
+            stream.read_exact(&mut request).unwrap();
+            let mut response = request[16..26].to_vec();
+            response[0] = 0xC0;
+            response.extend_from_slice(&[0x01, 0x01, 0x00, 0x00, 0x12, 0x34]);
+            let mut frame = b"FINS".to_vec();
+            frame.extend_from_slice(&((8 + response.len()) as u32).to_be_bytes());
+            frame.extend_from_slice(&[0, 0, 0, 2, 0, 0, 0, 0]);
+            frame.extend_from_slice(&response);
+            stream.write_all(&frame).unwrap();
+        });
+
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 0, 10)
+            .with_port(port)
+            .with_tcp()
+            .with_tcp_keep_alive(Duration::from_secs(30));
+        let client = Client::new(config).unwrap();
+        assert!(matches!(
+            client.read(MemoryArea::DM, 0, 1),
+            Err(crate::error::FinsError::ConnectionReset)
+        ));
+        assert_eq!(client.read(MemoryArea::DM, 0, 1).unwrap(), vec![0x1234]);
+        assert_eq!(client.source().node, 0x33);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_client_tcp_rejects_pipelining() {
+        let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 0, 10)
+            .with_tcp()
+            .with_pipelining(true);
+        assert!(matches!(
+            Client::new(config),
+            Err(crate::FinsError::InvalidParameter { .. })
+        ));
     }
 
     #[test]
@@ -1391,6 +8963,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_float32_to_bytes() {
         let value: f32 = 3.14159;
         let bytes = value.to_be_bytes();
@@ -20,6 +20,35 @@
 //! - [`RunCommand`] - Put PLC into run mode
 //! - [`StopCommand`] - Stop the PLC
 //!
+//! - [`ResetCommand`] - Reset a unit (e.g., a hung Ethernet unit)
+//!
+//! ## Controller Information
+//! - [`ControllerDataReadCommand`] - Read the controller model and version
+//! - [`ConnectionDataReadCommand`] - Read the unit composition (models per unit address)
+//!
+//! ## Diagnostics
+//! - [`EchoTestCommand`] - Internode echo test (FINS "ping")
+//!
+//! ## Access Right
+//! - [`AccessRightCommand`] - Acquire, force-acquire or release the access right
+//!
+//! ## Messages
+//! - [`MessageReadCommand`] - Read MSG instruction messages
+//! - [`MessageClearCommand`] - Clear MSG instruction messages
+//!
+//! ## Maintenance
+//! - [`ErrorLogReadCommand`] - Read records from the controller error log
+//! - [`ErrorLogClearCommand`] - Clear the controller error log
+//!
+//! ## File Memory
+//! - [`FileWriteCommand`] - Write or create a file on the memory card or EM file memory
+//! - [`FileRenameCommand`] - Rename a file
+//! - [`MemoryFileTransferCommand`] - Transfer between I/O memory and a file
+//! - [`ProgramFileTransferCommand`] - Transfer between the program area and a file
+//!
+//! ## Raw Commands
+//! - [`RawCommand`] - Any MRC/SRC with a caller-supplied payload
+//!
 //! ## Forced I/O
 //! - [`ForcedSetResetCommand`] - Force bits ON/OFF
 //! - [`ForcedSetResetCancelCommand`] - Cancel all forced bits
@@ -43,10 +72,12 @@
 //! # Constants
 //!
 //! - [`MAX_WORDS_PER_COMMAND`] - Maximum number of words (999) used historically for some Omron models.
+//! - [`MAX_BITS_PER_COMMAND`] - Maximum number of bits per bit read or write.
 
 use crate::error::{FinsError, Result};
 use crate::header::{FinsHeader, NodeAddress, FINS_HEADER_SIZE};
 use crate::memory::MemoryArea;
+use crate::transport::MAX_PACKET_SIZE;
 
 /// Memory Read command code (MRC).
 pub(crate) const MRC_MEMORY_READ: u8 = 0x01;
@@ -68,6 +99,40 @@ pub(crate) const MRC_RUN: u8 = 0x04;
 pub(crate) const SRC_RUN: u8 = 0x01;
 /// Stop command sub-code (SRC).
 pub(crate) const SRC_STOP: u8 = 0x02;
+/// Reset command sub-code (SRC).
+pub(crate) const SRC_RESET: u8 = 0x03;
+/// Controller Data command code (MRC).
+pub(crate) const MRC_CONTROLLER_DATA: u8 = 0x05;
+/// Controller Data Read command sub-code (SRC).
+pub(crate) const SRC_CONTROLLER_DATA_READ: u8 = 0x01;
+/// Connection Data Read command sub-code (SRC).
+pub(crate) const SRC_CONNECTION_DATA_READ: u8 = 0x02;
+/// Internode Echo Test command code (MRC).
+pub(crate) const MRC_ECHO_TEST: u8 = 0x08;
+/// Internode Echo Test command sub-code (SRC).
+pub(crate) const SRC_ECHO_TEST: u8 = 0x01;
+/// Access Right command code (MRC).
+pub(crate) const MRC_ACCESS_RIGHT: u8 = 0x0C;
+/// Message Read/Clear command code (MRC).
+pub(crate) const MRC_MESSAGE: u8 = 0x09;
+/// Message Read/Clear command sub-code (SRC).
+pub(crate) const SRC_MESSAGE: u8 = 0x20;
+/// Error Log command code (MRC).
+pub(crate) const MRC_ERROR_LOG: u8 = 0x21;
+/// Error Log Read command sub-code (SRC).
+pub(crate) const SRC_ERROR_LOG_READ: u8 = 0x02;
+/// Error Log Clear command sub-code (SRC).
+pub(crate) const SRC_ERROR_LOG_CLEAR: u8 = 0x03;
+/// File Memory command code (MRC).
+pub(crate) const MRC_FILE: u8 = 0x22;
+/// Single File Write command sub-code (SRC).
+pub(crate) const SRC_FILE_WRITE: u8 = 0x03;
+/// File Name Change command sub-code (SRC).
+pub(crate) const SRC_FILE_RENAME: u8 = 0x08;
+/// Memory Area-File Transfer command sub-code (SRC).
+pub(crate) const SRC_MEMORY_FILE_TRANSFER: u8 = 0x0A;
+/// Program Area-File Transfer command sub-code (SRC).
+pub(crate) const SRC_PROGRAM_FILE_TRANSFER: u8 = 0x0C;
 /// Forced Set/Reset command code (MRC).
 pub(crate) const MRC_FORCED: u8 = 0x23;
 /// Forced Set/Reset command sub-code (SRC).
@@ -75,6 +140,24 @@ pub(crate) const SRC_FORCED_SET_RESET: u8 = 0x01;
 /// Forced Set/Reset Cancel command sub-code (SRC).
 pub(crate) const SRC_FORCED_CANCEL: u8 = 0x02;
 
+/// Number of MSG instruction message buffers (messages 0-7).
+pub const MESSAGE_COUNT: u8 = 8;
+
+/// Maximum number of file data bytes sent in one Single File Write command.
+pub const MAX_FILE_CHUNK: usize = 1000;
+
+/// Size of an encoded file name (8.3 format, space padded).
+pub(crate) const FILE_NAME_SIZE: usize = 12;
+
+/// Maximum number of test data bytes in an Internode Echo Test.
+pub const MAX_ECHO_DATA: usize = 1998;
+
+/// Maximum number of units returned by one Connection Data Read.
+pub const MAX_CONNECTION_DATA_UNITS: u8 = 64;
+
+/// Maximum number of error log records returned by one Error Log Read.
+pub const MAX_ERROR_LOG_RECORDS: u16 = 20;
+
 /// Maximum number of words that can be read/written in a single command on older models or standard UDP limits.
 ///
 /// Note: The library chunks user requests automatically into blocks of this size or lower
@@ -82,6 +165,18 @@ pub(crate) const SRC_FORCED_CANCEL: u8 = 0x02;
 /// 700 words = 1400 bytes, which fits safely inside the 1472 byte UDP payload max.
 pub const MAX_WORDS_PER_COMMAND: u16 = 700;
 
+/// Maximum number of bits read or written in a single command.
+///
+/// Each bit takes one byte in the response, so this matches the payload of
+/// [`MAX_WORDS_PER_COMMAND`] words.
+pub const MAX_BITS_PER_COMMAND: u16 = MAX_WORDS_PER_COMMAND * 2;
+
+/// Maximum number of words or bits in one Multiple Memory Area Read sent by
+/// [`Client::read_batch`](crate::Client::read_batch).
+///
+/// Kept at 128 so a single command is accepted by every CPU series.
+pub const MAX_MULTI_READ_ITEMS: usize = 128;
+
 /// Address specification for FINS commands.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Address {
@@ -133,6 +228,14 @@ impl Address {
     pub(crate) fn to_bytes(self) -> [u8; 3] {
         [(self.word >> 8) as u8, (self.word & 0xFF) as u8, self.bit]
     }
+
+    /// Serializes the address as the PLC sees it in `area`, applying the
+    /// area's address offset (counters start at 0x8000, index registers at
+    /// 0x0100).
+    pub(crate) fn encode(self, area: MemoryArea) -> [u8; 3] {
+        let word = self.word.wrapping_add(area.address_offset());
+        Self { word, ..self }.to_bytes()
+    }
 }
 
 /// Command for reading words from PLC memory.
@@ -188,6 +291,7 @@ impl ReadWordCommand {
                 "must be greater than 0",
             ));
         }
+        area.check_word_access()?;
         if count > area.max_words() {
             return Err(FinsError::invalid_parameter(
                 "count",
@@ -218,7 +322,7 @@ impl ReadWordCommand {
         bytes.push(MRC_MEMORY_READ);
         bytes.push(SRC_MEMORY_READ);
         bytes.push(self.area.word_code());
-        bytes.extend_from_slice(&self.address.to_bytes());
+        bytes.extend_from_slice(&self.address.encode(self.area));
         bytes.push((self.count >> 8) as u8);
         bytes.push((self.count & 0xFF) as u8);
         bytes
@@ -275,7 +379,16 @@ impl WriteWordCommand {
         if data.is_empty() {
             return Err(FinsError::invalid_parameter("data", "must not be empty"));
         }
-        if data.len() > area.max_words() as usize {
+        area.check_writable()?;
+        area.check_word_access()?;
+        let words_per_item = area.words_per_item() as usize;
+        if data.len() % words_per_item != 0 {
+            return Err(FinsError::invalid_parameter(
+                "data",
+                format!("{} items are {} words each", area, words_per_item),
+            ));
+        }
+        if data.len() / words_per_item > area.max_words() as usize {
             return Err(FinsError::invalid_parameter(
                 "data",
                 format!(
@@ -305,9 +418,10 @@ impl WriteWordCommand {
         bytes.push(MRC_MEMORY_WRITE);
         bytes.push(SRC_MEMORY_WRITE);
         bytes.push(self.area.word_code());
-        bytes.extend_from_slice(&self.address.to_bytes());
-        bytes.push((self.data.len() >> 8) as u8);
-        bytes.push((self.data.len() & 0xFF) as u8);
+        bytes.extend_from_slice(&self.address.encode(self.area));
+        let count = self.data.len() / self.area.words_per_item() as usize;
+        bytes.push((count >> 8) as u8);
+        bytes.push((count & 0xFF) as u8);
         for word in &self.data {
             bytes.push((word >> 8) as u8);
             bytes.push((word & 0xFF) as u8);
@@ -321,7 +435,9 @@ impl WriteWordCommand {
 pub struct ReadBitCommand {
     header: FinsHeader,
     area: MemoryArea,
+    bit_code: u8,
     address: Address,
+    count: u16,
 }
 
 impl ReadBitCommand {
@@ -363,17 +479,69 @@ impl ReadBitCommand {
         area: MemoryArea,
         word_address: u16,
         bit: u8,
+    ) -> Result<Self> {
+        Self::new_with_dm_bits(destination, source, sid, area, word_address, bit, false)
+    }
+
+    /// Like [`new`](Self::new), optionally allowing bit access to DM.
+    pub(crate) fn new_with_dm_bits(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        area: MemoryArea,
+        word_address: u16,
+        bit: u8,
+        dm_bit_access: bool,
     ) -> Result<Self> {
         // Validate bit access is supported
-        area.bit_code()?;
+        let bit_code = area.bit_code_with(dm_bit_access)?;
 
         Ok(Self {
             header: FinsHeader::new_command(destination, source, sid),
             area,
+            bit_code,
             address: Address::bit(word_address, bit)?,
+            count: 1,
         })
     }
 
+    /// Reads `count` consecutive bits instead of one.
+    ///
+    /// The response holds one byte per bit, continuing into the following
+    /// words after bit 15.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `count` is 0 or exceeds [`MAX_BITS_PER_COMMAND`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{ReadBitCommand, MemoryArea, NodeAddress};
+    ///
+    /// let cmd = ReadBitCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     MemoryArea::CIO,
+    ///     0,
+    ///     0,
+    /// )?
+    /// .with_count(16)?;
+    /// assert_eq!(&cmd.to_bytes()?[16..], &[0x00, 0x10]);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn with_count(mut self, count: u16) -> Result<Self> {
+        if count == 0 || count > MAX_BITS_PER_COMMAND {
+            return Err(FinsError::invalid_parameter(
+                "count",
+                format!("must be between 1 and {}", MAX_BITS_PER_COMMAND),
+            ));
+        }
+        self.count = count;
+        Ok(self)
+    }
+
     /// Returns the service ID.
     pub fn sid(&self) -> u8 {
         self.header.sid
@@ -385,10 +553,9 @@ impl ReadBitCommand {
         bytes.extend_from_slice(&self.header.to_bytes());
         bytes.push(MRC_MEMORY_READ);
         bytes.push(SRC_MEMORY_READ);
-        bytes.push(self.area.bit_code()?);
-        bytes.extend_from_slice(&self.address.to_bytes());
-        bytes.push(0x00); // Count high byte (always 1 bit)
-        bytes.push(0x01); // Count low byte
+        bytes.push(self.bit_code);
+        bytes.extend_from_slice(&self.address.encode(self.area));
+        bytes.extend_from_slice(&self.count.to_be_bytes());
         Ok(bytes)
     }
 }
@@ -398,8 +565,9 @@ impl ReadBitCommand {
 pub struct WriteBitCommand {
     header: FinsHeader,
     area: MemoryArea,
+    bit_code: u8,
     address: Address,
-    value: bool,
+    values: Vec<bool>,
 }
 
 impl WriteBitCommand {
@@ -445,17 +613,83 @@ impl WriteBitCommand {
         bit: u8,
         value: bool,
     ) -> Result<Self> {
+        Self::new_with_dm_bits(
+            destination,
+            source,
+            sid,
+            area,
+            word_address,
+            bit,
+            value,
+            false,
+        )
+    }
+
+    /// Like [`new`](Self::new), optionally allowing bit access to DM.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new_with_dm_bits(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        area: MemoryArea,
+        word_address: u16,
+        bit: u8,
+        value: bool,
+        dm_bit_access: bool,
+    ) -> Result<Self> {
+        area.check_writable()?;
         // Validate bit access is supported
-        area.bit_code()?;
+        let bit_code = area.bit_code_with(dm_bit_access)?;
 
         Ok(Self {
             header: FinsHeader::new_command(destination, source, sid),
             area,
+            bit_code,
             address: Address::bit(word_address, bit)?,
-            value,
+            values: vec![value],
         })
     }
 
+    /// Writes consecutive bits starting at the command's bit instead of
+    /// one, replacing the single value.
+    ///
+    /// Bits continue into the following words after bit 15; all of them
+    /// are written by one frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `values` is empty or longer than
+    /// [`MAX_BITS_PER_COMMAND`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{WriteBitCommand, MemoryArea, NodeAddress};
+    ///
+    /// let cmd = WriteBitCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     MemoryArea::CIO,
+    ///     100,
+    ///     0,
+    ///     false,
+    /// )?
+    /// .with_values(&[true, false, true])?;
+    /// assert_eq!(&cmd.to_bytes()?[16..], &[0x00, 0x03, 0x01, 0x00, 0x01]);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn with_values(mut self, values: &[bool]) -> Result<Self> {
+        if values.is_empty() || values.len() > MAX_BITS_PER_COMMAND as usize {
+            return Err(FinsError::invalid_parameter(
+                "values",
+                format!("must hold between 1 and {} bits", MAX_BITS_PER_COMMAND),
+            ));
+        }
+        self.values = values.to_vec();
+        Ok(self)
+    }
+
     /// Returns the service ID.
     pub fn sid(&self) -> u8 {
         self.header.sid
@@ -463,15 +697,14 @@ impl WriteBitCommand {
 
     /// Serializes the command to bytes for transmission.
     pub fn to_bytes(&self) -> Result<Vec<u8>> {
-        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 9);
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 8 + self.values.len());
         bytes.extend_from_slice(&self.header.to_bytes());
         bytes.push(MRC_MEMORY_WRITE);
         bytes.push(SRC_MEMORY_WRITE);
-        bytes.push(self.area.bit_code()?);
-        bytes.extend_from_slice(&self.address.to_bytes());
-        bytes.push(0x00); // Count high byte (always 1 bit)
-        bytes.push(0x01); // Count low byte
-        bytes.push(if self.value { 0x01 } else { 0x00 });
+        bytes.push(self.bit_code);
+        bytes.extend_from_slice(&self.address.encode(self.area));
+        bytes.extend_from_slice(&(self.values.len() as u16).to_be_bytes());
+        bytes.extend(self.values.iter().map(|&value| value as u8));
         Ok(bytes)
     }
 }
@@ -533,6 +766,8 @@ impl FillCommand {
                 "must be greater than 0",
             ));
         }
+        area.check_writable()?;
+        area.check_word_access()?;
         if count > area.max_words() {
             return Err(FinsError::invalid_parameter(
                 "count",
@@ -564,7 +799,7 @@ impl FillCommand {
         bytes.push(MRC_MEMORY_READ); // Memory commands use 0x01
         bytes.push(SRC_MEMORY_FILL);
         bytes.push(self.area.word_code());
-        bytes.extend_from_slice(&self.address.to_bytes());
+        bytes.extend_from_slice(&self.address.encode(self.area));
         bytes.push((self.count >> 8) as u8);
         bytes.push((self.count & 0xFF) as u8);
         bytes.push((self.value >> 8) as u8);
@@ -575,6 +810,8 @@ impl FillCommand {
 
 /// PLC operating mode for Run command.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum PlcMode {
     /// Debug mode - step execution.
     Debug,
@@ -653,6 +890,7 @@ impl RunCommand {
 #[derive(Debug, Clone)]
 pub struct StopCommand {
     header: FinsHeader,
+    program_number: Option<u16>,
 }
 
 impl StopCommand {
@@ -678,9 +916,34 @@ impl StopCommand {
     pub fn new(destination: NodeAddress, source: NodeAddress, sid: u8) -> Self {
         Self {
             header: FinsHeader::new_command(destination, source, sid),
+            program_number: None,
         }
     }
 
+    /// Adds the program number field to the command.
+    ///
+    /// Most CPUs accept the short form without parameters; some controller
+    /// families require the program number (0xFFFF for the current
+    /// program).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{StopCommand, NodeAddress};
+    ///
+    /// let cmd = StopCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    /// )
+    /// .with_program_number(0xFFFF);
+    /// assert_eq!(cmd.to_bytes().len(), 14);
+    /// ```
+    pub fn with_program_number(mut self, program_number: u16) -> Self {
+        self.program_number = Some(program_number);
+        self
+    }
+
     /// Returns the service ID.
     pub fn sid(&self) -> u8 {
         self.header.sid
@@ -688,92 +951,51 @@ impl StopCommand {
 
     /// Serializes the command to bytes for transmission.
     pub fn to_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 2);
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 4);
         bytes.extend_from_slice(&self.header.to_bytes());
         bytes.push(MRC_RUN);
         bytes.push(SRC_STOP);
+        if let Some(program_number) = self.program_number {
+            bytes.extend_from_slice(&program_number.to_be_bytes());
+        }
         bytes
     }
 }
 
-/// Command for transferring memory from one area to another.
+/// Command for resetting a unit.
+///
+/// The unit to reset is selected by the destination unit address (e.g.,
+/// 0x10 + unit number for a CPU Bus Unit such as an Ethernet unit).
 #[derive(Debug, Clone)]
-pub struct TransferCommand {
+pub struct ResetCommand {
     header: FinsHeader,
-    src_area: MemoryArea,
-    src_address: Address,
-    dst_area: MemoryArea,
-    dst_address: Address,
-    count: u16,
 }
 
-impl TransferCommand {
-    /// Creates a new transfer command.
+impl ResetCommand {
+    /// Creates a new reset command.
     ///
     /// # Arguments
     ///
-    /// * `destination` - Destination node address
+    /// * `destination` - Address of the unit to reset
     /// * `source` - Source node address
     /// * `sid` - Service ID for request/response matching
-    /// * `src_area` - Source memory area
-    /// * `src_address` - Source starting address
-    /// * `dst_area` - Destination memory area
-    /// * `dst_address` - Destination starting address
-    /// * `count` - Number of words to transfer (1 to maximum area capacity of the smallest area)
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if count is 0 or exceeds the available capacity of either source or destination.
     ///
     /// # Example
     ///
     /// ```
-    /// use omron_fins::{TransferCommand, MemoryArea, NodeAddress};
+    /// use omron_fins::{NodeAddress, ResetCommand};
     ///
-    /// let cmd = TransferCommand::new(
-    ///     NodeAddress::new(0, 10, 0),
+    /// // Ethernet unit with unit number 0
+    /// let cmd = ResetCommand::new(
+    ///     NodeAddress::new(0, 10, 0x10),
     ///     NodeAddress::new(0, 1, 0),
     ///     0x01,
-    ///     MemoryArea::DM,
-    ///     100,
-    ///     MemoryArea::DM,
-    ///     200,
-    ///     10,
-    /// ).unwrap();
+    /// );
     /// ```
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(
-        destination: NodeAddress,
-        source: NodeAddress,
-        sid: u8,
-        src_area: MemoryArea,
-        src_address: u16,
-        dst_area: MemoryArea,
-        dst_address: u16,
-        count: u16,
-    ) -> Result<Self> {
-        if count == 0 {
-            return Err(FinsError::invalid_parameter(
-                "count",
-                "must be greater than 0",
-            ));
-        }
-        let max_transfer = std::cmp::min(src_area.max_words(), dst_area.max_words());
-        if count > max_transfer {
-            return Err(FinsError::invalid_parameter(
-                "count",
-                format!("must not exceed area capacity of {} words", max_transfer),
-            ));
-        }
-
-        Ok(Self {
+    pub fn new(destination: NodeAddress, source: NodeAddress, sid: u8) -> Self {
+        Self {
             header: FinsHeader::new_command(destination, source, sid),
-            src_area,
-            src_address: Address::word(src_address),
-            dst_area,
-            dst_address: Address::word(dst_address),
-            count,
-        })
+        }
     }
 
     /// Returns the service ID.
@@ -783,96 +1005,1298 @@ impl TransferCommand {
 
     /// Serializes the command to bytes for transmission.
     pub fn to_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 12);
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 2);
         bytes.extend_from_slice(&self.header.to_bytes());
-        bytes.push(MRC_MEMORY_READ); // Memory commands use 0x01
-        bytes.push(SRC_MEMORY_TRANSFER);
-        bytes.push(self.src_area.word_code());
-        bytes.extend_from_slice(&self.src_address.to_bytes());
-        bytes.push(self.dst_area.word_code());
-        bytes.extend_from_slice(&self.dst_address.to_bytes());
-        bytes.push((self.count >> 8) as u8);
-        bytes.push((self.count & 0xFF) as u8);
+        bytes.push(MRC_RUN);
+        bytes.push(SRC_RESET);
         bytes
     }
 }
 
-/// Specification for forcing a bit.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ForceSpec {
-    /// Force the bit OFF.
-    ForceOff,
-    /// Force the bit ON.
-    ForceOn,
-    /// Release the forced state.
-    Release,
+/// Command for reading the controller model and version.
+#[derive(Debug, Clone)]
+pub struct ControllerDataReadCommand {
+    header: FinsHeader,
 }
 
-impl ForceSpec {
-    /// Returns the FINS code for this spec.
-    pub(crate) fn code(self) -> u16 {
-        match self {
-            ForceSpec::ForceOff => 0x0000,
-            ForceSpec::ForceOn => 0x0001,
-            ForceSpec::Release => 0x8000,
+impl ControllerDataReadCommand {
+    /// Creates a new controller data read command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{ControllerDataReadCommand, NodeAddress};
+    ///
+    /// let cmd = ControllerDataReadCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    /// );
+    /// ```
+    pub fn new(destination: NodeAddress, source: NodeAddress, sid: u8) -> Self {
+        Self {
+            header: FinsHeader::new_command(destination, source, sid),
         }
     }
-}
 
-/// A bit to be forced.
-#[derive(Debug, Clone)]
-pub struct ForcedBit {
-    /// Memory area of the bit.
-    pub area: MemoryArea,
-    /// Word address of the bit.
-    pub address: u16,
-    /// Bit position (0-15).
-    pub bit: u8,
-    /// Force specification.
-    pub spec: ForceSpec,
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 3);
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_CONTROLLER_DATA);
+        bytes.push(SRC_CONTROLLER_DATA_READ);
+        bytes.push(0x00); // Controller model and version only
+        bytes
+    }
 }
 
-/// Command for forcing bits ON/OFF.
+/// Command for reading which unit models occupy which unit addresses.
 #[derive(Debug, Clone)]
-pub struct ForcedSetResetCommand {
+pub struct ConnectionDataReadCommand {
     header: FinsHeader,
-    specs: Vec<ForcedBit>,
+    start_unit: u8,
+    count: u8,
 }
 
-impl ForcedSetResetCommand {
-    /// Creates a new forced set/reset command.
+impl ConnectionDataReadCommand {
+    /// Creates a new connection data read command.
     ///
     /// # Arguments
     ///
     /// * `destination` - Destination node address
     /// * `source` - Source node address
     /// * `sid` - Service ID for request/response matching
-    /// * `specs` - List of bits to force
+    /// * `start_unit` - First unit address to report
+    /// * `count` - Maximum number of units to report (1-64)
     ///
     /// # Errors
     ///
-    /// Returns an error if specs is empty, any area doesn't support bit access,
-    /// or any bit position is > 15.
+    /// Returns `FinsError::InvalidParameter` if count is 0 or greater than
+    /// [`MAX_CONNECTION_DATA_UNITS`].
     ///
     /// # Example
     ///
     /// ```
-    /// use omron_fins::{ForcedSetResetCommand, ForcedBit, ForceSpec, MemoryArea, NodeAddress};
+    /// use omron_fins::{ConnectionDataReadCommand, NodeAddress};
     ///
-    /// let cmd = ForcedSetResetCommand::new(
+    /// let cmd = ConnectionDataReadCommand::new(
     ///     NodeAddress::new(0, 10, 0),
     ///     NodeAddress::new(0, 1, 0),
     ///     0x01,
-    ///     vec![
-    ///         ForcedBit { area: MemoryArea::CIO, address: 0, bit: 0, spec: ForceSpec::ForceOn },
-    ///     ],
+    ///     0x00,
+    ///     64,
     /// ).unwrap();
     /// ```
     pub fn new(
         destination: NodeAddress,
         source: NodeAddress,
         sid: u8,
-        specs: Vec<ForcedBit>,
+        start_unit: u8,
+        count: u8,
+    ) -> Result<Self> {
+        if count == 0 || count > MAX_CONNECTION_DATA_UNITS {
+            return Err(FinsError::invalid_parameter(
+                "count",
+                format!("must be between 1 and {}", MAX_CONNECTION_DATA_UNITS),
+            ));
+        }
+
+        Ok(Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            start_unit,
+            count,
+        })
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 4);
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_CONTROLLER_DATA);
+        bytes.push(SRC_CONNECTION_DATA_READ);
+        bytes.push(self.start_unit);
+        bytes.push(self.count);
+        bytes
+    }
+}
+
+/// Command for the internode echo test.
+#[derive(Debug, Clone)]
+pub struct EchoTestCommand {
+    header: FinsHeader,
+    data: Vec<u8>,
+}
+
+impl EchoTestCommand {
+    /// Creates a new internode echo test command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `data` - Test data the destination echoes back
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the data is longer than
+    /// [`MAX_ECHO_DATA`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{EchoTestCommand, NodeAddress};
+    ///
+    /// let cmd = EchoTestCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     b"PING",
+    /// ).unwrap();
+    /// ```
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        data: &[u8],
+    ) -> Result<Self> {
+        if data.len() > MAX_ECHO_DATA {
+            return Err(FinsError::invalid_parameter(
+                "data",
+                format!("must not exceed {} bytes", MAX_ECHO_DATA),
+            ));
+        }
+
+        Ok(Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            data: data.to_vec(),
+        })
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 2 + self.data.len());
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_ECHO_TEST);
+        bytes.push(SRC_ECHO_TEST);
+        bytes.extend_from_slice(&self.data);
+        bytes
+    }
+}
+
+/// Access right operation for [`AccessRightCommand`].
+///
+/// The access right serializes program and parameter area writes between
+/// devices; while another device (e.g., CX-Programmer) holds it, those
+/// writes fail with end code 0x3001.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessRightAction {
+    /// Acquire the access right if no other device holds it.
+    Acquire,
+    /// Take the access right even if another device holds it.
+    ForcedAcquire,
+    /// Release the access right.
+    Release,
+}
+
+impl AccessRightAction {
+    /// Returns the FINS sub-request code (SRC) for this action.
+    pub(crate) fn code(self) -> u8 {
+        match self {
+            AccessRightAction::Acquire => 0x01,
+            AccessRightAction::ForcedAcquire => 0x02,
+            AccessRightAction::Release => 0x03,
+        }
+    }
+}
+
+/// Command for acquiring or releasing the access right.
+#[derive(Debug, Clone)]
+pub struct AccessRightCommand {
+    header: FinsHeader,
+    action: AccessRightAction,
+}
+
+impl AccessRightCommand {
+    /// Creates a new access right command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `action` - Acquire, forced acquire or release
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{AccessRightAction, AccessRightCommand, NodeAddress};
+    ///
+    /// let cmd = AccessRightCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     AccessRightAction::Acquire,
+    /// );
+    /// ```
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        action: AccessRightAction,
+    ) -> Self {
+        Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            action,
+        }
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 4);
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_ACCESS_RIGHT);
+        bytes.push(self.action.code());
+        bytes.push(0xFF); // Program number: always 0xFFFF
+        bytes.push(0xFF);
+        bytes
+    }
+}
+
+/// Builds the message number bit mask, validating each number.
+fn message_mask(numbers: &[u8]) -> Result<u8> {
+    if numbers.is_empty() {
+        return Err(FinsError::invalid_parameter(
+            "numbers",
+            "at least one message number is required",
+        ));
+    }
+
+    let mut mask = 0u8;
+    for &number in numbers {
+        if number >= MESSAGE_COUNT {
+            return Err(FinsError::invalid_parameter(
+                "numbers",
+                format!(
+                    "message number {} exceeds maximum {}",
+                    number,
+                    MESSAGE_COUNT - 1
+                ),
+            ));
+        }
+        mask |= 1 << number;
+    }
+    Ok(mask)
+}
+
+/// Command for reading MSG instruction messages.
+#[derive(Debug, Clone)]
+pub struct MessageReadCommand {
+    header: FinsHeader,
+    mask: u8,
+}
+
+impl MessageReadCommand {
+    /// Creates a new message read command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `numbers` - Message numbers to read (0-7)
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `numbers` is empty or a
+    /// number is greater than 7.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{MessageReadCommand, NodeAddress};
+    ///
+    /// let cmd = MessageReadCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     &[0, 3],
+    /// ).unwrap();
+    /// ```
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        numbers: &[u8],
+    ) -> Result<Self> {
+        Ok(Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            mask: message_mask(numbers)?,
+        })
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 4);
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_MESSAGE);
+        bytes.push(SRC_MESSAGE);
+        bytes.push(0x00); // Bits 14-15 = 00: message read
+        bytes.push(self.mask);
+        bytes
+    }
+}
+
+/// Command for clearing MSG instruction messages.
+#[derive(Debug, Clone)]
+pub struct MessageClearCommand {
+    header: FinsHeader,
+    mask: u8,
+}
+
+impl MessageClearCommand {
+    /// Creates a new message clear command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `numbers` - Message numbers to clear (0-7)
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `numbers` is empty or a
+    /// number is greater than 7.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{MessageClearCommand, NodeAddress};
+    ///
+    /// let cmd = MessageClearCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     &[0, 1, 2, 3, 4, 5, 6, 7],
+    /// ).unwrap();
+    /// ```
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        numbers: &[u8],
+    ) -> Result<Self> {
+        Ok(Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            mask: message_mask(numbers)?,
+        })
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 4);
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_MESSAGE);
+        bytes.push(SRC_MESSAGE);
+        bytes.push(0x40); // Bits 14-15 = 01: message clear
+        bytes.push(self.mask);
+        bytes
+    }
+}
+
+/// Command for reading records from the controller error log.
+#[derive(Debug, Clone)]
+pub struct ErrorLogReadCommand {
+    header: FinsHeader,
+    start: u16,
+    count: u16,
+}
+
+impl ErrorLogReadCommand {
+    /// Creates a new error log read command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `start` - First record number to read (0 = oldest)
+    /// * `count` - Number of records to read (1-20)
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if count is 0 or greater than
+    /// [`MAX_ERROR_LOG_RECORDS`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{ErrorLogReadCommand, NodeAddress};
+    ///
+    /// let cmd = ErrorLogReadCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     0,
+    ///     10,
+    /// ).unwrap();
+    /// ```
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        start: u16,
+        count: u16,
+    ) -> Result<Self> {
+        if count == 0 || count > MAX_ERROR_LOG_RECORDS {
+            return Err(FinsError::invalid_parameter(
+                "count",
+                format!("must be between 1 and {}", MAX_ERROR_LOG_RECORDS),
+            ));
+        }
+
+        Ok(Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            start,
+            count,
+        })
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 6);
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_ERROR_LOG);
+        bytes.push(SRC_ERROR_LOG_READ);
+        bytes.push((self.start >> 8) as u8);
+        bytes.push((self.start & 0xFF) as u8);
+        bytes.push((self.count >> 8) as u8);
+        bytes.push((self.count & 0xFF) as u8);
+        bytes
+    }
+}
+
+/// Command for clearing the controller error log.
+#[derive(Debug, Clone)]
+pub struct ErrorLogClearCommand {
+    header: FinsHeader,
+}
+
+impl ErrorLogClearCommand {
+    /// Creates a new error log clear command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{ErrorLogClearCommand, NodeAddress};
+    ///
+    /// let cmd = ErrorLogClearCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    /// );
+    /// ```
+    pub fn new(destination: NodeAddress, source: NodeAddress, sid: u8) -> Self {
+        Self {
+            header: FinsHeader::new_command(destination, source, sid),
+        }
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 2);
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_ERROR_LOG);
+        bytes.push(SRC_ERROR_LOG_CLEAR);
+        bytes
+    }
+}
+
+/// Command for transferring memory from one area to another.
+#[derive(Debug, Clone)]
+pub struct TransferCommand {
+    header: FinsHeader,
+    src_area: MemoryArea,
+    src_address: Address,
+    dst_area: MemoryArea,
+    dst_address: Address,
+    count: u16,
+}
+
+impl TransferCommand {
+    /// Creates a new transfer command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `src_area` - Source memory area
+    /// * `src_address` - Source starting address
+    /// * `dst_area` - Destination memory area
+    /// * `dst_address` - Destination starting address
+    /// * `count` - Number of words to transfer (1 to maximum area capacity of the smallest area)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if count is 0 or exceeds the available capacity of either source or destination.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{TransferCommand, MemoryArea, NodeAddress};
+    ///
+    /// let cmd = TransferCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     MemoryArea::DM,
+    ///     100,
+    ///     MemoryArea::DM,
+    ///     200,
+    ///     10,
+    /// ).unwrap();
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        src_area: MemoryArea,
+        src_address: u16,
+        dst_area: MemoryArea,
+        dst_address: u16,
+        count: u16,
+    ) -> Result<Self> {
+        if count == 0 {
+            return Err(FinsError::invalid_parameter(
+                "count",
+                "must be greater than 0",
+            ));
+        }
+        dst_area.check_writable()?;
+        src_area.check_word_access()?;
+        dst_area.check_word_access()?;
+        let max_transfer = std::cmp::min(src_area.max_words(), dst_area.max_words());
+        if count > max_transfer {
+            return Err(FinsError::invalid_parameter(
+                "count",
+                format!("must not exceed area capacity of {} words", max_transfer),
+            ));
+        }
+
+        Ok(Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            src_area,
+            src_address: Address::word(src_address),
+            dst_area,
+            dst_address: Address::word(dst_address),
+            count,
+        })
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 12);
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_MEMORY_READ); // Memory commands use 0x01
+        bytes.push(SRC_MEMORY_TRANSFER);
+        bytes.push(self.src_area.word_code());
+        bytes.extend_from_slice(&self.src_address.encode(self.src_area));
+        bytes.push(self.dst_area.word_code());
+        bytes.extend_from_slice(&self.dst_address.encode(self.dst_area));
+        bytes.push((self.count >> 8) as u8);
+        bytes.push((self.count & 0xFF) as u8);
+        bytes
+    }
+}
+
+/// File device addressed by file memory commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileDisk {
+    /// Memory card.
+    MemoryCard,
+    /// EM file memory.
+    EmFileMemory,
+}
+
+impl FileDisk {
+    /// Returns the FINS disk number for this device.
+    pub(crate) fn code(self) -> u16 {
+        match self {
+            FileDisk::MemoryCard => 0x8000,
+            FileDisk::EmFileMemory => 0x8001,
+        }
+    }
+}
+
+/// How a Single File Write treats an existing file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileWriteMode {
+    /// Create a new file; fails if the file already exists.
+    Create,
+    /// Create the file, replacing it if it already exists.
+    Overwrite,
+    /// Append data to the end of an existing file.
+    Append,
+}
+
+impl FileWriteMode {
+    /// Returns the FINS parameter code for this mode.
+    pub(crate) fn code(self) -> u16 {
+        match self {
+            FileWriteMode::Create => 0x0000,
+            FileWriteMode::Overwrite => 0x0001,
+            FileWriteMode::Append => 0x0002,
+        }
+    }
+}
+
+/// Encodes a file name into the 12-byte FINS 8.3 format.
+///
+/// The base name is padded to 8 characters and the extension to 3, e.g.
+/// `"recipe.csv"` becomes `"RECIPE  .CSV"`. Names are upper-cased, as
+/// the CPU stores them.
+pub(crate) fn encode_file_name(name: &str) -> Result<[u8; FILE_NAME_SIZE]> {
+    let invalid = |reason: &str| FinsError::invalid_parameter("name", reason);
+
+    let (base, ext) = name.split_once('.').unwrap_or((name, ""));
+    if base.is_empty() || base.len() > 8 || ext.len() > 3 || ext.contains('.') {
+        return Err(invalid("must be an 8.3 file name (e.g., RECIPE.CSV)"));
+    }
+    if !name
+        .bytes()
+        .all(|b| b.is_ascii_graphic() && !b"\\/:*?\"<>|".contains(&b))
+    {
+        return Err(invalid("contains characters not allowed in file names"));
+    }
+
+    let mut bytes = [b' '; FILE_NAME_SIZE];
+    bytes[..base.len()].copy_from_slice(base.as_bytes());
+    bytes[8] = b'.';
+    bytes[9..9 + ext.len()].copy_from_slice(ext.as_bytes());
+    bytes.make_ascii_uppercase();
+    Ok(bytes)
+}
+
+/// Command for writing data to a file (Single File Write).
+#[derive(Debug, Clone)]
+pub struct FileWriteCommand {
+    header: FinsHeader,
+    disk: FileDisk,
+    mode: FileWriteMode,
+    name: [u8; FILE_NAME_SIZE],
+    position: u32,
+    data: Vec<u8>,
+}
+
+impl FileWriteCommand {
+    /// Creates a new single file write command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `disk` - File device to write to
+    /// * `name` - File name in 8.3 format (e.g., "RECIPE.CSV")
+    /// * `mode` - How an existing file is treated
+    /// * `position` - Byte offset in the file where the data is written
+    /// * `data` - File data (at most [`MAX_FILE_CHUNK`] bytes)
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the name is not a valid 8.3
+    /// file name or the data is longer than [`MAX_FILE_CHUNK`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{FileDisk, FileWriteCommand, FileWriteMode, NodeAddress};
+    ///
+    /// let cmd = FileWriteCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     FileDisk::MemoryCard,
+    ///     "RECIPE.CSV",
+    ///     FileWriteMode::Overwrite,
+    ///     0,
+    ///     b"1,2,3\r\n",
+    /// ).unwrap();
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        disk: FileDisk,
+        name: &str,
+        mode: FileWriteMode,
+        position: u32,
+        data: &[u8],
+    ) -> Result<Self> {
+        if data.len() > MAX_FILE_CHUNK {
+            return Err(FinsError::invalid_parameter(
+                "data",
+                format!("must not exceed {} bytes per command", MAX_FILE_CHUNK),
+            ));
+        }
+
+        Ok(Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            disk,
+            mode,
+            name: encode_file_name(name)?,
+            position,
+            data: data.to_vec(),
+        })
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 24 + self.data.len());
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_FILE);
+        bytes.push(SRC_FILE_WRITE);
+        bytes.extend_from_slice(&self.disk.code().to_be_bytes());
+        bytes.extend_from_slice(&self.mode.code().to_be_bytes());
+        bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&self.position.to_be_bytes());
+        bytes.extend_from_slice(&(self.data.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(&self.data);
+        bytes
+    }
+}
+
+/// Command for renaming a file (File Name Change).
+#[derive(Debug, Clone)]
+pub struct FileRenameCommand {
+    header: FinsHeader,
+    disk: FileDisk,
+    old_name: [u8; FILE_NAME_SIZE],
+    new_name: [u8; FILE_NAME_SIZE],
+}
+
+impl FileRenameCommand {
+    /// Creates a new file name change command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `disk` - File device holding the file
+    /// * `old_name` - Current file name in 8.3 format
+    /// * `new_name` - New file name in 8.3 format
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if either name is not a valid
+    /// 8.3 file name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{FileDisk, FileRenameCommand, NodeAddress};
+    ///
+    /// let cmd = FileRenameCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     FileDisk::MemoryCard,
+    ///     "RECIPE.TMP",
+    ///     "RECIPE.CSV",
+    /// ).unwrap();
+    /// ```
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        disk: FileDisk,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            disk,
+            old_name: encode_file_name(old_name)?,
+            new_name: encode_file_name(new_name)?,
+        })
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 4 + FILE_NAME_SIZE * 2);
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_FILE);
+        bytes.push(SRC_FILE_RENAME);
+        bytes.extend_from_slice(&self.disk.code().to_be_bytes());
+        bytes.extend_from_slice(&self.old_name);
+        bytes.extend_from_slice(&self.new_name);
+        bytes
+    }
+}
+
+/// Direction of a PLC-side transfer between memory and a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileTransferDirection {
+    /// Copy PLC memory into the file.
+    ToFile,
+    /// Load the file into PLC memory.
+    FromFile,
+}
+
+impl FileTransferDirection {
+    /// Returns the FINS parameter code for this direction.
+    pub(crate) fn code(self) -> u16 {
+        match self {
+            FileTransferDirection::ToFile => 0x0000,
+            FileTransferDirection::FromFile => 0x0001,
+        }
+    }
+}
+
+/// Command for transferring between I/O memory and a file
+/// (Memory Area-File Transfer).
+///
+/// The transfer runs inside the PLC, so a large DM block can be backed up
+/// to the memory card and then retrieved as one file.
+#[derive(Debug, Clone)]
+pub struct MemoryFileTransferCommand {
+    header: FinsHeader,
+    direction: FileTransferDirection,
+    area: MemoryArea,
+    address: Address,
+    count: u16,
+    disk: FileDisk,
+    name: [u8; FILE_NAME_SIZE],
+}
+
+impl MemoryFileTransferCommand {
+    /// Creates a new memory area-file transfer command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `direction` - Memory to file, or file to memory
+    /// * `area` - Memory area
+    /// * `address` - Starting word address
+    /// * `count` - Number of words to transfer
+    /// * `disk` - File device holding the file
+    /// * `name` - File name in 8.3 format
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if count is 0 or exceeds the
+    /// area capacity, or the name is not a valid 8.3 file name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{
+    ///     FileDisk, FileTransferDirection, MemoryArea, MemoryFileTransferCommand, NodeAddress,
+    /// };
+    ///
+    /// let cmd = MemoryFileTransferCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     FileTransferDirection::ToFile,
+    ///     MemoryArea::DM,
+    ///     0,
+    ///     4096,
+    ///     FileDisk::MemoryCard,
+    ///     "DMBACKUP.IOM",
+    /// ).unwrap();
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        direction: FileTransferDirection,
+        area: MemoryArea,
+        address: u16,
+        count: u16,
+        disk: FileDisk,
+        name: &str,
+    ) -> Result<Self> {
+        if count == 0 {
+            return Err(FinsError::invalid_parameter(
+                "count",
+                "must be greater than 0",
+            ));
+        }
+        area.check_word_access()?;
+        if count > area.max_words() {
+            return Err(FinsError::invalid_parameter(
+                "count",
+                format!(
+                    "must not exceed area capacity of {} words",
+                    area.max_words()
+                ),
+            ));
+        }
+
+        Ok(Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            direction,
+            area,
+            address: Address::word(address),
+            count,
+            disk,
+            name: encode_file_name(name)?,
+        })
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 12 + FILE_NAME_SIZE);
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_FILE);
+        bytes.push(SRC_MEMORY_FILE_TRANSFER);
+        bytes.extend_from_slice(&self.direction.code().to_be_bytes());
+        bytes.push(self.area.word_code());
+        bytes.extend_from_slice(&self.address.encode(self.area));
+        bytes.extend_from_slice(&self.count.to_be_bytes());
+        bytes.extend_from_slice(&self.disk.code().to_be_bytes());
+        bytes.extend_from_slice(&self.name);
+        bytes
+    }
+}
+
+/// Command for transferring the user program between the program area and
+/// a file (Program Area-File Transfer).
+///
+/// The whole program is transferred. Dumping a ladder backup to the memory
+/// card this way is more robust than reading the program area over the
+/// network on a busy CPU.
+#[derive(Debug, Clone)]
+pub struct ProgramFileTransferCommand {
+    header: FinsHeader,
+    direction: FileTransferDirection,
+    disk: FileDisk,
+    name: [u8; FILE_NAME_SIZE],
+}
+
+impl ProgramFileTransferCommand {
+    /// Creates a new program area-file transfer command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `direction` - Program to file, or file to program
+    /// * `disk` - File device holding the file
+    /// * `name` - File name in 8.3 format (e.g., "BACKUP.OBJ")
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the name is not a valid 8.3
+    /// file name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{FileDisk, FileTransferDirection, NodeAddress, ProgramFileTransferCommand};
+    ///
+    /// let cmd = ProgramFileTransferCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     FileTransferDirection::ToFile,
+    ///     FileDisk::MemoryCard,
+    ///     "BACKUP.OBJ",
+    /// ).unwrap();
+    /// ```
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        direction: FileTransferDirection,
+        disk: FileDisk,
+        name: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            direction,
+            disk,
+            name: encode_file_name(name)?,
+        })
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 16 + FILE_NAME_SIZE);
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(MRC_FILE);
+        bytes.push(SRC_PROGRAM_FILE_TRANSFER);
+        bytes.extend_from_slice(&self.direction.code().to_be_bytes());
+        bytes.extend_from_slice(&[0xFF, 0xFF]); // Program number: always 0xFFFF
+        bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]); // Beginning word
+        bytes.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF]); // Word count: whole program
+        bytes.extend_from_slice(&self.disk.code().to_be_bytes());
+        bytes.extend_from_slice(&self.name);
+        bytes
+    }
+}
+
+/// Command with an arbitrary command code and payload.
+///
+/// Escape hatch for vendor-specific or not-yet-wrapped FINS commands.
+#[derive(Debug, Clone)]
+pub struct RawCommand {
+    header: FinsHeader,
+    mrc: u8,
+    src: u8,
+    payload: Vec<u8>,
+}
+
+impl RawCommand {
+    /// Creates a new raw command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `mrc` - Main request code
+    /// * `src` - Sub request code
+    /// * `payload` - Command data following the command code
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the frame would exceed
+    /// [`MAX_PACKET_SIZE`](crate::MAX_PACKET_SIZE).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{NodeAddress, RawCommand};
+    ///
+    /// // Controller Status Read (0x06 0x01)
+    /// let cmd = RawCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     0x06,
+    ///     0x01,
+    ///     &[],
+    /// ).unwrap();
+    /// assert_eq!(&cmd.to_bytes()[10..], &[0x06, 0x01]);
+    /// ```
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        mrc: u8,
+        src: u8,
+        payload: &[u8],
+    ) -> Result<Self> {
+        let max_payload = MAX_PACKET_SIZE - FINS_HEADER_SIZE - 2;
+        if payload.len() > max_payload {
+            return Err(FinsError::invalid_parameter(
+                "payload",
+                format!("must not exceed {} bytes", max_payload),
+            ));
+        }
+
+        Ok(Self {
+            header: FinsHeader::new_command(destination, source, sid),
+            mrc,
+            src,
+            payload: payload.to_vec(),
+        })
+    }
+
+    /// Returns the service ID.
+    pub fn sid(&self) -> u8 {
+        self.header.sid
+    }
+
+    /// Serializes the command to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FINS_HEADER_SIZE + 2 + self.payload.len());
+        bytes.extend_from_slice(&self.header.to_bytes());
+        bytes.push(self.mrc);
+        bytes.push(self.src);
+        bytes.extend_from_slice(&self.payload);
+        bytes
+    }
+}
+
+/// Specification for forcing a bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ForceSpec {
+    /// Force the bit OFF.
+    ForceOff,
+    /// Force the bit ON.
+    ForceOn,
+    /// Release the forced state.
+    Release,
+}
+
+impl ForceSpec {
+    /// Returns the FINS code for this spec.
+    pub(crate) fn code(self) -> u16 {
+        match self {
+            ForceSpec::ForceOff => 0x0000,
+            ForceSpec::ForceOn => 0x0001,
+            ForceSpec::Release => 0x8000,
+        }
+    }
+}
+
+/// A bit to be forced.
+#[derive(Debug, Clone)]
+pub struct ForcedBit {
+    /// Memory area of the bit.
+    pub area: MemoryArea,
+    /// Word address of the bit.
+    pub address: u16,
+    /// Bit position (0-15).
+    pub bit: u8,
+    /// Force specification.
+    pub spec: ForceSpec,
+}
+
+/// Command for forcing bits ON/OFF.
+#[derive(Debug, Clone)]
+pub struct ForcedSetResetCommand {
+    header: FinsHeader,
+    specs: Vec<ForcedBit>,
+    dm_bit_access: bool,
+}
+
+impl ForcedSetResetCommand {
+    /// Creates a new forced set/reset command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Destination node address
+    /// * `source` - Source node address
+    /// * `sid` - Service ID for request/response matching
+    /// * `specs` - List of bits to force
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if specs is empty, any area doesn't support bit access,
+    /// or any bit position is > 15.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{ForcedSetResetCommand, ForcedBit, ForceSpec, MemoryArea, NodeAddress};
+    ///
+    /// let cmd = ForcedSetResetCommand::new(
+    ///     NodeAddress::new(0, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     vec![
+    ///         ForcedBit { area: MemoryArea::CIO, address: 0, bit: 0, spec: ForceSpec::ForceOn },
+    ///     ],
+    /// ).unwrap();
+    /// ```
+    pub fn new(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        specs: Vec<ForcedBit>,
+    ) -> Result<Self> {
+        Self::new_with_dm_bits(destination, source, sid, specs, false)
+    }
+
+    /// Like [`new`](Self::new), optionally allowing bit access to DM.
+    pub(crate) fn new_with_dm_bits(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        specs: Vec<ForcedBit>,
+        dm_bit_access: bool,
     ) -> Result<Self> {
         if specs.is_empty() {
             return Err(FinsError::invalid_parameter("specs", "must not be empty"));
@@ -880,7 +2304,7 @@ impl ForcedSetResetCommand {
 
         // Validate all specs
         for spec in &specs {
-            spec.area.bit_code()?;
+            spec.area.bit_code_with(dm_bit_access)?;
             if spec.bit > 15 {
                 return Err(FinsError::invalid_parameter("bit", "must be 0-15"));
             }
@@ -889,6 +2313,7 @@ impl ForcedSetResetCommand {
         Ok(Self {
             header: FinsHeader::new_command(destination, source, sid),
             specs,
+            dm_bit_access,
         })
     }
 
@@ -910,10 +2335,8 @@ impl ForcedSetResetCommand {
             let code = spec.spec.code();
             bytes.push((code >> 8) as u8);
             bytes.push((code & 0xFF) as u8);
-            bytes.push(spec.area.bit_code()?);
-            bytes.push((spec.address >> 8) as u8);
-            bytes.push((spec.address & 0xFF) as u8);
-            bytes.push(spec.bit);
+            bytes.push(spec.area.bit_code_with(self.dm_bit_access)?);
+            bytes.extend_from_slice(&Address::bit(spec.address, spec.bit)?.encode(spec.area));
         }
 
         Ok(bytes)
@@ -983,6 +2406,7 @@ pub struct MultiReadSpec {
 pub struct MultipleReadCommand {
     header: FinsHeader,
     specs: Vec<MultiReadSpec>,
+    dm_bit_access: bool,
 }
 
 impl MultipleReadCommand {
@@ -1020,6 +2444,17 @@ impl MultipleReadCommand {
         source: NodeAddress,
         sid: u8,
         specs: Vec<MultiReadSpec>,
+    ) -> Result<Self> {
+        Self::new_with_dm_bits(destination, source, sid, specs, false)
+    }
+
+    /// Like [`new`](Self::new), optionally allowing bit access to DM.
+    pub(crate) fn new_with_dm_bits(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        specs: Vec<MultiReadSpec>,
+        dm_bit_access: bool,
     ) -> Result<Self> {
         if specs.is_empty() {
             return Err(FinsError::invalid_parameter("specs", "must not be empty"));
@@ -1028,16 +2463,19 @@ impl MultipleReadCommand {
         // Validate all specs
         for spec in &specs {
             if let Some(bit) = spec.bit {
-                spec.area.bit_code()?;
+                spec.area.bit_code_with(dm_bit_access)?;
                 if bit > 15 {
                     return Err(FinsError::invalid_parameter("bit", "must be 0-15"));
                 }
+            } else {
+                spec.area.check_word_access()?;
             }
         }
 
         Ok(Self {
             header: FinsHeader::new_command(destination, source, sid),
             specs,
+            dm_bit_access,
         })
     }
 
@@ -1055,15 +2493,11 @@ impl MultipleReadCommand {
 
         for spec in &self.specs {
             if let Some(bit) = spec.bit {
-                bytes.push(spec.area.bit_code()?);
-                bytes.push((spec.address >> 8) as u8);
-                bytes.push((spec.address & 0xFF) as u8);
-                bytes.push(bit);
+                bytes.push(spec.area.bit_code_with(self.dm_bit_access)?);
+                bytes.extend_from_slice(&Address::bit(spec.address, bit)?.encode(spec.area));
             } else {
                 bytes.push(spec.area.word_code());
-                bytes.push((spec.address >> 8) as u8);
-                bytes.push((spec.address & 0xFF) as u8);
-                bytes.push(0x00);
+                bytes.extend_from_slice(&Address::word(spec.address).encode(spec.area));
             }
         }
 
@@ -1129,6 +2563,43 @@ mod tests {
         assert_eq!(bytes[17], 0x0A);
     }
 
+    #[test]
+    fn test_counter_address_offset() {
+        let (dest, src) = test_addresses();
+        let cmd = ReadWordCommand::new(dest, src, 0x01, MemoryArea::CNT, 5, 1).unwrap();
+        assert_eq!(&cmd.to_bytes()[12..16], &[0x89, 0x80, 0x05, 0x00]);
+
+        let cmd = ReadWordCommand::new(dest, src, 0x01, MemoryArea::TIM, 5, 1).unwrap();
+        assert_eq!(&cmd.to_bytes()[12..16], &[0x89, 0x00, 0x05, 0x00]);
+
+        let cmd = ReadBitCommand::new(dest, src, 0x01, MemoryArea::CNT, 5, 0).unwrap();
+        assert_eq!(&cmd.to_bytes().unwrap()[12..16], &[0x09, 0x80, 0x05, 0x00]);
+    }
+
+    #[test]
+    fn test_index_register_write_count() {
+        let (dest, src) = test_addresses();
+        let cmd =
+            WriteWordCommand::new(dest, src, 0x01, MemoryArea::IR, 3, &[0x1234, 0x5678]).unwrap();
+        let bytes = cmd.to_bytes();
+        assert_eq!(&bytes[12..18], &[0xDC, 0x01, 0x03, 0x00, 0x00, 0x01]);
+        assert_eq!(&bytes[18..], &[0x12, 0x34, 0x56, 0x78]);
+
+        // Registers are two words each
+        assert!(WriteWordCommand::new(dest, src, 0x01, MemoryArea::IR, 3, &[0x1234]).is_err());
+    }
+
+    #[test]
+    fn test_condition_flags_bit_only() {
+        let (dest, src) = test_addresses();
+        let cmd = ReadBitCommand::new(dest, src, 0x01, MemoryArea::CF, 1, 3).unwrap();
+        assert_eq!(&cmd.to_bytes().unwrap()[12..16], &[0x07, 0x00, 0x01, 0x03]);
+
+        assert!(ReadWordCommand::new(dest, src, 0x01, MemoryArea::CF, 0, 1).is_err());
+        assert!(WriteWordCommand::new(dest, src, 0x01, MemoryArea::CF, 0, &[0]).is_err());
+        assert!(FillCommand::new(dest, src, 0x01, MemoryArea::CF, 0, 1, 0).is_err());
+    }
+
     #[test]
     fn test_read_word_command_invalid_count() {
         let (dest, src) = test_addresses();
@@ -1195,6 +2666,43 @@ mod tests {
         assert_eq!(bytes[17], 0x01);
     }
 
+    #[test]
+    fn test_read_bit_command_with_count() {
+        let (dest, src) = test_addresses();
+        let cmd = ReadBitCommand::new(dest, src, 0x03, MemoryArea::CIO, 100, 12)
+            .unwrap()
+            .with_count(8)
+            .unwrap();
+        assert_eq!(
+            &cmd.to_bytes().unwrap()[12..],
+            &[0x30, 0x00, 0x64, 0x0C, 0x00, 0x08]
+        );
+
+        let cmd = ReadBitCommand::new(dest, src, 0x03, MemoryArea::CIO, 100, 0).unwrap();
+        assert!(cmd.clone().with_count(0).is_err());
+        assert!(cmd.with_count(MAX_BITS_PER_COMMAND + 1).is_err());
+    }
+
+    #[test]
+    fn test_write_bit_command_with_values() {
+        let (dest, src) = test_addresses();
+        let cmd = WriteBitCommand::new(dest, src, 0x03, MemoryArea::WR, 10, 15, false)
+            .unwrap()
+            .with_values(&[true, true])
+            .unwrap();
+        // W10.15 and W11.00
+        assert_eq!(
+            &cmd.to_bytes().unwrap()[12..],
+            &[0x31, 0x00, 0x0A, 0x0F, 0x00, 0x02, 0x01, 0x01]
+        );
+
+        let cmd = WriteBitCommand::new(dest, src, 0x03, MemoryArea::WR, 10, 0, false).unwrap();
+        assert!(cmd.clone().with_values(&[]).is_err());
+        assert!(cmd
+            .with_values(&vec![true; MAX_BITS_PER_COMMAND as usize + 1])
+            .is_err());
+    }
+
     #[test]
     fn test_read_bit_command_dm_fails() {
         let (dest, src) = test_addresses();
@@ -1202,6 +2710,27 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_dm_bit_access_opt_in() {
+        let (dest, src) = test_addresses();
+        let cmd = ReadBitCommand::new_with_dm_bits(dest, src, 0x01, MemoryArea::DM, 100, 5, true)
+            .unwrap();
+        assert_eq!(&cmd.to_bytes().unwrap()[12..16], &[0x02, 0x00, 0x64, 0x05]);
+
+        let cmd =
+            WriteBitCommand::new_with_dm_bits(dest, src, 0x01, MemoryArea::DM, 100, 5, true, true)
+                .unwrap();
+        assert_eq!(
+            &cmd.to_bytes().unwrap()[12..19],
+            &[0x02, 0x00, 0x64, 0x05, 0x00, 0x01, 0x01]
+        );
+
+        assert!(
+            ReadBitCommand::new_with_dm_bits(dest, src, 0x01, MemoryArea::DM, 100, 5, false)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_write_bit_command_serialization() {
         let (dest, src) = test_addresses();
@@ -1319,6 +2848,279 @@ mod tests {
         // Check command codes
         assert_eq!(bytes[10], MRC_RUN); // 0x04
         assert_eq!(bytes[11], SRC_STOP); // 0x02
+
+        // Optional program number
+        let bytes = StopCommand::new(dest, src, 0x01)
+            .with_program_number(0x1234)
+            .to_bytes();
+        assert_eq!(bytes.len(), 14);
+        assert_eq!(&bytes[12..], &[0x12, 0x34]);
+    }
+
+    #[test]
+    fn test_controller_data_read_command_serialization() {
+        let (dest, src) = test_addresses();
+        let cmd = ControllerDataReadCommand::new(dest, src, 0x07);
+        let bytes = cmd.to_bytes();
+
+        // Header (10) + MRC + SRC + Data (1) = 13 bytes
+        assert_eq!(bytes.len(), 13);
+        assert_eq!(bytes[9], 0x07);
+        assert_eq!(bytes[10], MRC_CONTROLLER_DATA); // 0x05
+        assert_eq!(bytes[11], SRC_CONTROLLER_DATA_READ); // 0x01
+        assert_eq!(bytes[12], 0x00);
+    }
+
+    #[test]
+    fn test_error_log_read_command_serialization() {
+        let (dest, src) = test_addresses();
+        let cmd = ErrorLogReadCommand::new(dest, src, 0x03, 0x0102, 20).unwrap();
+        let bytes = cmd.to_bytes();
+
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bytes[10], MRC_ERROR_LOG);
+        assert_eq!(bytes[11], SRC_ERROR_LOG_READ);
+        assert_eq!(&bytes[12..16], &[0x01, 0x02, 0x00, 0x14]);
+
+        assert!(ErrorLogReadCommand::new(dest, src, 0x03, 0, 0).is_err());
+        assert!(ErrorLogReadCommand::new(dest, src, 0x03, 0, 21).is_err());
+    }
+
+    #[test]
+    fn test_error_log_clear_command_serialization() {
+        let (dest, src) = test_addresses();
+        let bytes = ErrorLogClearCommand::new(dest, src, 0x04).to_bytes();
+
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(bytes[9], 0x04);
+        assert_eq!(bytes[10], MRC_ERROR_LOG);
+        assert_eq!(bytes[11], SRC_ERROR_LOG_CLEAR);
+    }
+
+    #[test]
+    fn test_access_right_command_serialization() {
+        let (dest, src) = test_addresses();
+        for (action, code) in [
+            (AccessRightAction::Acquire, 0x01),
+            (AccessRightAction::ForcedAcquire, 0x02),
+            (AccessRightAction::Release, 0x03),
+        ] {
+            let bytes = AccessRightCommand::new(dest, src, 0x09, action).to_bytes();
+            assert_eq!(bytes.len(), 14);
+            assert_eq!(bytes[10], MRC_ACCESS_RIGHT);
+            assert_eq!(bytes[11], code);
+            assert_eq!(&bytes[12..14], &[0xFF, 0xFF]);
+        }
+    }
+
+    #[test]
+    fn test_message_commands_serialization() {
+        let (dest, src) = test_addresses();
+        let read = MessageReadCommand::new(dest, src, 0x01, &[0, 3, 7]).unwrap();
+        assert_eq!(
+            &read.to_bytes()[10..],
+            &[MRC_MESSAGE, SRC_MESSAGE, 0x00, 0x89]
+        );
+
+        let clear = MessageClearCommand::new(dest, src, 0x01, &[1]).unwrap();
+        assert_eq!(
+            &clear.to_bytes()[10..],
+            &[MRC_MESSAGE, SRC_MESSAGE, 0x40, 0x02]
+        );
+
+        assert!(MessageReadCommand::new(dest, src, 0x01, &[]).is_err());
+        assert!(MessageClearCommand::new(dest, src, 0x01, &[8]).is_err());
+    }
+
+    #[test]
+    fn test_reset_command_serialization() {
+        let src = NodeAddress::new(0, 1, 0);
+        let bytes = ResetCommand::new(NodeAddress::new(0, 10, 0x10), src, 0x02).to_bytes();
+
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(bytes[5], 0x10); // DA2: Ethernet unit
+        assert_eq!(bytes[10], MRC_RUN); // 0x04
+        assert_eq!(bytes[11], SRC_RESET); // 0x03
+    }
+
+    #[test]
+    fn test_encode_file_name() {
+        assert_eq!(&encode_file_name("recipe.csv").unwrap(), b"RECIPE  .CSV");
+        assert_eq!(&encode_file_name("A.B").unwrap(), b"A       .B  ");
+        assert_eq!(&encode_file_name("NOEXT").unwrap(), b"NOEXT   .   ");
+        assert!(encode_file_name("").is_err());
+        assert!(encode_file_name("TOOLONGNAME.CSV").is_err());
+        assert!(encode_file_name("A.CSVX").is_err());
+        assert!(encode_file_name("A B.CSV").is_err());
+        assert!(encode_file_name("A/B.CSV").is_err());
+    }
+
+    #[test]
+    fn test_file_write_command_serialization() {
+        let (dest, src) = test_addresses();
+        let cmd = FileWriteCommand::new(
+            dest,
+            src,
+            0x01,
+            FileDisk::MemoryCard,
+            "RECIPE.CSV",
+            FileWriteMode::Append,
+            0x0102,
+            &[0xAA, 0xBB],
+        )
+        .unwrap();
+        let bytes = cmd.to_bytes();
+
+        assert_eq!(bytes.len(), FINS_HEADER_SIZE + 24 + 2);
+        assert_eq!(&bytes[10..12], &[MRC_FILE, SRC_FILE_WRITE]);
+        assert_eq!(&bytes[12..14], &[0x80, 0x00]); // Memory card
+        assert_eq!(&bytes[14..16], &[0x00, 0x02]); // Append
+        assert_eq!(&bytes[16..28], b"RECIPE  .CSV");
+        assert_eq!(&bytes[28..32], &[0x00, 0x00, 0x01, 0x02]);
+        assert_eq!(&bytes[32..34], &[0x00, 0x02]);
+        assert_eq!(&bytes[34..], &[0xAA, 0xBB]);
+
+        let too_long = vec![0u8; MAX_FILE_CHUNK + 1];
+        assert!(FileWriteCommand::new(
+            dest,
+            src,
+            0x01,
+            FileDisk::EmFileMemory,
+            "A.BIN",
+            FileWriteMode::Create,
+            0,
+            &too_long,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_file_rename_command_serialization() {
+        let (dest, src) = test_addresses();
+        let cmd = FileRenameCommand::new(
+            dest,
+            src,
+            0x01,
+            FileDisk::EmFileMemory,
+            "RECIPE.TMP",
+            "RECIPE.CSV",
+        )
+        .unwrap();
+        let bytes = cmd.to_bytes();
+
+        assert_eq!(bytes.len(), FINS_HEADER_SIZE + 28);
+        assert_eq!(&bytes[10..14], &[MRC_FILE, SRC_FILE_RENAME, 0x80, 0x01]);
+        assert_eq!(&bytes[14..26], b"RECIPE  .TMP");
+        assert_eq!(&bytes[26..38], b"RECIPE  .CSV");
+
+        assert!(
+            FileRenameCommand::new(dest, src, 0x01, FileDisk::MemoryCard, "A.CSV", "").is_err()
+        );
+    }
+
+    #[test]
+    fn test_memory_file_transfer_command_serialization() {
+        let (dest, src) = test_addresses();
+        let cmd = MemoryFileTransferCommand::new(
+            dest,
+            src,
+            0x01,
+            FileTransferDirection::FromFile,
+            MemoryArea::DM,
+            0x0100,
+            500,
+            FileDisk::MemoryCard,
+            "DMBACKUP.IOM",
+        )
+        .unwrap();
+        let bytes = cmd.to_bytes();
+
+        assert_eq!(bytes.len(), FINS_HEADER_SIZE + 24);
+        assert_eq!(&bytes[10..12], &[MRC_FILE, SRC_MEMORY_FILE_TRANSFER]);
+        assert_eq!(&bytes[12..14], &[0x00, 0x01]); // File to memory
+        assert_eq!(&bytes[14..18], &[0x82, 0x01, 0x00, 0x00]); // DM256
+        assert_eq!(&bytes[18..20], &[0x01, 0xF4]); // 500 words
+        assert_eq!(&bytes[20..22], &[0x80, 0x00]);
+        assert_eq!(&bytes[22..34], b"DMBACKUP.IOM");
+
+        assert!(MemoryFileTransferCommand::new(
+            dest,
+            src,
+            0x01,
+            FileTransferDirection::ToFile,
+            MemoryArea::DM,
+            0,
+            0,
+            FileDisk::MemoryCard,
+            "A.IOM",
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_program_file_transfer_command_serialization() {
+        let (dest, src) = test_addresses();
+        let cmd = ProgramFileTransferCommand::new(
+            dest,
+            src,
+            0x01,
+            FileTransferDirection::ToFile,
+            FileDisk::MemoryCard,
+            "BACKUP.OBJ",
+        )
+        .unwrap();
+        let bytes = cmd.to_bytes();
+
+        assert_eq!(bytes.len(), FINS_HEADER_SIZE + 28);
+        assert_eq!(&bytes[10..12], &[MRC_FILE, SRC_PROGRAM_FILE_TRANSFER]);
+        assert_eq!(&bytes[12..14], &[0x00, 0x00]);
+        assert_eq!(&bytes[14..16], &[0xFF, 0xFF]);
+        assert_eq!(
+            &bytes[16..24],
+            &[0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+        assert_eq!(&bytes[24..26], &[0x80, 0x00]);
+        assert_eq!(&bytes[26..38], b"BACKUP  .OBJ");
+    }
+
+    #[test]
+    fn test_raw_command_serialization() {
+        let (dest, src) = test_addresses();
+        let cmd = RawCommand::new(dest, src, 0x33, 0x07, 0x01, &[0xAA]).unwrap();
+        let bytes = cmd.to_bytes();
+
+        assert_eq!(bytes[9], 0x33);
+        assert_eq!(&bytes[10..], &[0x07, 0x01, 0xAA]);
+        assert_eq!(cmd.sid(), 0x33);
+
+        let too_long = vec![0u8; MAX_PACKET_SIZE];
+        assert!(RawCommand::new(dest, src, 0x01, 0x07, 0x01, &too_long).is_err());
+    }
+
+    #[test]
+    fn test_connection_data_read_command_serialization() {
+        let (dest, src) = test_addresses();
+        let cmd = ConnectionDataReadCommand::new(dest, src, 0x01, 0x10, 64).unwrap();
+        assert_eq!(
+            &cmd.to_bytes()[10..],
+            &[MRC_CONTROLLER_DATA, SRC_CONNECTION_DATA_READ, 0x10, 0x40]
+        );
+
+        assert!(ConnectionDataReadCommand::new(dest, src, 0x01, 0, 0).is_err());
+        assert!(ConnectionDataReadCommand::new(dest, src, 0x01, 0, 65).is_err());
+    }
+
+    #[test]
+    fn test_echo_test_command_serialization() {
+        let (dest, src) = test_addresses();
+        let cmd = EchoTestCommand::new(dest, src, 0x01, b"AB").unwrap();
+        assert_eq!(
+            &cmd.to_bytes()[10..],
+            &[MRC_ECHO_TEST, SRC_ECHO_TEST, b'A', b'B']
+        );
+
+        assert!(EchoTestCommand::new(dest, src, 0x01, &[0; MAX_ECHO_DATA]).is_ok());
+        assert!(EchoTestCommand::new(dest, src, 0x01, &[0; MAX_ECHO_DATA + 1]).is_err());
     }
 
     #[test]
@@ -0,0 +1,343 @@
+//! Serde support for [`ClientConfig`](crate::ClientConfig) fields whose
+//! default representation does not read well in a configuration file:
+//! durations, the PLC address, CPU profiles and tags.
+
+use std::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::client::DEFAULT_SID_RETRIES;
+use crate::profile::CpuProfile;
+use crate::tag::{Tag, TagKind, TagTable};
+use crate::transport::{DEFAULT_FINS_PORT, DEFAULT_TIMEOUT};
+use crate::types::DataType;
+
+/// Duration units from largest to smallest, in nanoseconds.
+const UNITS: [(&str, u128); 6] = [
+    ("h", 3_600_000_000_000),
+    ("m", 60_000_000_000),
+    ("s", 1_000_000_000),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+/// CPU profiles that can be named in a configuration.
+const CPU_PROFILES: [CpuProfile; 4] = [
+    CpuProfile::CJ2M,
+    CpuProfile::CP1L,
+    CpuProfile::CS1G,
+    CpuProfile::NJ,
+];
+
+/// Data types that can be named as a tag type.
+const DATA_TYPES: [DataType; 13] = [
+    DataType::USINT,
+    DataType::UINT,
+    DataType::UDINT,
+    DataType::ULINT,
+    DataType::SINT,
+    DataType::INT,
+    DataType::DINT,
+    DataType::LINT,
+    DataType::REAL,
+    DataType::LREAL,
+    DataType::WORD,
+    DataType::DWORD,
+    DataType::LWORD,
+];
+
+pub(crate) fn default_timeout() -> Duration {
+    DEFAULT_TIMEOUT
+}
+
+pub(crate) fn default_sid_retries() -> u8 {
+    DEFAULT_SID_RETRIES
+}
+
+/// Formats a duration in the largest unit that represents it exactly.
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos == 0 {
+        return "0s".to_string();
+    }
+    UNITS
+        .iter()
+        .find(|(_, scale)| nanos % scale == 0)
+        .map(|(unit, scale)| format!("{}{}", nanos / scale, unit))
+        .unwrap_or_default()
+}
+
+/// Parses one or more `<integer><unit>` parts, e.g. `"1m30s"`.
+fn parse_duration(text: &str) -> Option<Duration> {
+    let mut rest = text.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    let mut total: u128 = 0;
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let value: u128 = rest[..digits].parse().ok()?;
+        let after = &rest[digits..];
+        let unit_len = after
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(after.len());
+        let (_, scale) = UNITS.iter().find(|(unit, _)| *unit == &after[..unit_len])?;
+        total = total.checked_add(value.checked_mul(*scale)?)?;
+        rest = &after[unit_len..];
+    }
+    let secs = u64::try_from(total / 1_000_000_000).ok()?;
+    Some(Duration::new(secs, (total % 1_000_000_000) as u32))
+}
+
+/// `#[serde(with)]` helpers for human-readable durations.
+pub(crate) mod duration {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        duration: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format_duration(*duration))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Duration, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        parse_duration(&text).ok_or_else(|| {
+            D::Error::custom(format!(
+                "invalid duration '{}', expected e.g. \"2s\", \"250ms\" or \"1m30s\"",
+                text
+            ))
+        })
+    }
+}
+
+/// `#[serde(with)]` helpers for the PLC address; the port may be left out.
+pub(crate) mod plc_addr {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        addr: &SocketAddr,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&addr.to_string())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<SocketAddr, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse::<SocketAddr>()
+            .or_else(|_| {
+                text.parse::<IpAddr>()
+                    .map(|ip| SocketAddr::new(ip, DEFAULT_FINS_PORT))
+            })
+            .map_err(|_| D::Error::custom(format!("invalid PLC address '{}'", text)))
+    }
+}
+
+/// `#[serde(with)]` helpers for CPU profiles, by preset name.
+pub(crate) mod cpu_profile {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        profile: &Option<CpuProfile>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match profile {
+            Some(profile) if CPU_PROFILES.contains(profile) => {
+                serializer.serialize_some(profile.name())
+            }
+            Some(profile) => Err(S::Error::custom(format!(
+                "custom CPU profile '{}' cannot be serialized",
+                profile.name()
+            ))),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<CpuProfile>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|name| {
+                CPU_PROFILES
+                    .into_iter()
+                    .find(|profile| profile.name().eq_ignore_ascii_case(&name))
+                    .ok_or_else(|| D::Error::custom(format!("unknown CPU profile '{}'", name)))
+            })
+            .transpose()
+    }
+}
+
+/// A tag as written in a configuration.
+#[derive(Serialize, Deserialize)]
+struct TagEntry {
+    address: String,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    words: Option<u16>,
+}
+
+impl TagEntry {
+    fn new(tag: &Tag) -> Self {
+        let (kind, words) = match tag.kind() {
+            TagKind::Bit => ("bit".to_string(), None),
+            TagKind::Value(data_type) => (format!("{:?}", data_type), None),
+            TagKind::String { words } => ("string".to_string(), Some(words)),
+        };
+        Self {
+            address: tag.address().to_string(),
+            kind,
+            words,
+        }
+    }
+
+    fn kind(&self) -> Option<TagKind> {
+        match (self.kind.as_str(), self.words) {
+            ("bit", None) => Some(TagKind::Bit),
+            ("string", Some(words)) => Some(TagKind::String { words }),
+            (name, None) => DATA_TYPES
+                .into_iter()
+                .find(|data_type| format!("{:?}", data_type) == name)
+                .map(TagKind::Value),
+            _ => None,
+        }
+    }
+}
+
+/// Serializes as a map from tag name to address and type.
+impl Serialize for TagTable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter().map(|tag| (tag.name(), TagEntry::new(tag))))
+    }
+}
+
+impl<'de> Deserialize<'de> for TagTable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = BTreeMap::<String, TagEntry>::deserialize(deserializer)?;
+        let mut tags = TagTable::new();
+        for (name, entry) in entries {
+            let kind = entry.kind().ok_or_else(|| {
+                D::Error::custom(format!(
+                    "tag '{}' has invalid type '{}' (\"bit\", \"string\" with words, or a data type such as \"REAL\")",
+                    name, entry.kind
+                ))
+            })?;
+            tags.add(name, &entry.address, kind)
+                .map_err(D::Error::custom)?;
+        }
+        Ok(tags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_durations() {
+        assert_eq!(format_duration(Duration::from_secs(2)), "2s");
+        assert_eq!(format_duration(Duration::from_millis(1500)), "1500ms");
+        assert_eq!(format_duration(Duration::from_secs(120)), "2m");
+        assert_eq!(format_duration(Duration::ZERO), "0s");
+
+        assert_eq!(parse_duration("250ms"), Some(Duration::from_millis(250)));
+        assert_eq!(parse_duration("1m30s"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_duration(" 5us "), Some(Duration::from_micros(5)));
+        assert_eq!(parse_duration("2"), None);
+        assert_eq!(parse_duration("s"), None);
+        assert_eq!(parse_duration("2 s"), None);
+        assert_eq!(parse_duration("1.5s"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+
+    #[test]
+    fn test_value_types() {
+        use crate::command::{ForceSpec, PlcMode};
+        use crate::header::NodeAddress;
+        use crate::memory::MemoryArea;
+        use crate::utils::BitInfo;
+        use serde_json::json;
+
+        let areas = [
+            MemoryArea::DM,
+            MemoryArea::Custom {
+                word_code: 0xA0,
+                bit_code: Some(0x20),
+            },
+        ];
+        let value = serde_json::to_value(areas).unwrap();
+        assert_eq!(
+            value,
+            json!(["DM", { "Custom": { "word_code": 0xA0, "bit_code": 0x20 } }])
+        );
+        assert_eq!(
+            serde_json::from_value::<[MemoryArea; 2]>(value).unwrap(),
+            areas
+        );
+
+        assert_eq!(serde_json::to_value(PlcMode::Monitor).unwrap(), "monitor");
+        assert_eq!(
+            serde_json::from_str::<Vec<ForceSpec>>(r#"["force_on", "release"]"#).unwrap(),
+            [ForceSpec::ForceOn, ForceSpec::Release]
+        );
+        assert_eq!(
+            serde_json::to_value(BitInfo::new(3, true)).unwrap(),
+            json!({ "index": 3, "value": true })
+        );
+        let node: NodeAddress = serde_json::from_str(r#"{"node": 10}"#).unwrap();
+        assert_eq!(node, NodeAddress::new(0, 10, 0));
+    }
+
+    #[test]
+    fn test_client_config() {
+        use crate::client::ClientConfig;
+        use crate::types::WordOrder;
+
+        let json = r#"{
+            "plc_addr": "192.168.1.250",
+            "source": { "node": 1 },
+            "destination": { "network": 2, "node": 10 },
+            "timeout": "500ms",
+            "word_order": "high_first",
+            "cpu_profile": "cj2m",
+            "tags": {
+                "Speed": { "address": "D100", "type": "REAL" },
+                "Name": { "address": "D200", "type": "string", "words": 4 }
+            }
+        }"#;
+        let config: ClientConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.plc_addr, "192.168.1.250:9600".parse().unwrap());
+        assert_eq!(config.destination.network, 2);
+        assert_eq!(config.timeout, Duration::from_millis(500));
+        assert_eq!(config.send_timeout, DEFAULT_TIMEOUT);
+        assert_eq!(config.word_order, Some(WordOrder::HighFirst));
+        assert_eq!(config.cpu_profile, Some(CpuProfile::CJ2M));
+        assert_eq!(config.tags.len(), 2);
+
+        let value = serde_json::to_value(&config).unwrap();
+        assert_eq!(value["timeout"], "500ms");
+        assert_eq!(value["send_timeout"], "2s");
+        assert_eq!(value["protocol"], "udp");
+        assert_eq!(value["tcp_keep_alive"], "0s");
+        assert_eq!(value["tags"]["Name"]["words"], 4);
+        let again: ClientConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(format!("{:?}", again), format!("{:?}", config));
+
+        assert!(serde_json::from_str::<ClientConfig>(r#"{"plc_addr": "1.2.3.4"}"#).is_err());
+        let bad_tag = json.replace(r#""type": "REAL""#, r#""type": "FLOAT""#);
+        assert!(serde_json::from_str::<ClientConfig>(&bad_tag).is_err());
+        let bad_profile = json.replace("cj2m", "CJ1");
+        assert!(serde_json::from_str::<ClientConfig>(&bad_profile).is_err());
+        let custom = config.with_cpu_profile(CpuProfile::new("X", 1, 1, 1, 1, 1));
+        assert!(serde_json::to_string(&custom).is_err());
+    }
+}
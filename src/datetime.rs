@@ -0,0 +1,484 @@
+//! Date and time values stored in PLC memory.
+//!
+//! Omron CPUs keep timestamps in one of two layouts, chosen with
+//! [`TimeFormat`]:
+//!
+//! * [`TimeFormat::Bcd`] - CS/CJ/CP series. Date and time use the layout of
+//!   the clock words A351-A353 (3 words); durations and times of day use the
+//!   hours-minutes-seconds layout of the `SEC`/`HMS` instructions (2 words).
+//!   Resolution is one second.
+//! * [`TimeFormat::Binary`] - NJ/NX series `DATE_AND_TIME`, `TIME` and
+//!   `TIME_OF_DAY` variables: a 64-bit count of nanoseconds (4 words, in the
+//!   client's [`WordOrder`]).
+//!
+//! Dates map to [`PlcDateTime`], durations and times of day to
+//! [`Duration`]. Use
+//! [`Client::read_date_time`](crate::Client::read_date_time) and friends, or
+//! the conversions on [`TimeFormat`] for words read some other way.
+//!
+//! # Example
+//!
+//! ```
+//! use omron_fins::{PlcDateTime, TimeFormat, WordOrder};
+//! use std::time::Duration;
+//!
+//! // A351-A353 reading 2024-03-15 08:30:05
+//! let words = [0x3005, 0x1508, 0x2403];
+//! let value = TimeFormat::Bcd.decode_date_time(&words, WordOrder::LowFirst)?;
+//! assert_eq!(value.to_string(), "2024-03-15 08:30:05");
+//!
+//! // 1 h 2 min 3 s in HMS layout
+//! let hms = TimeFormat::Bcd.encode_time(Duration::from_secs(3723), WordOrder::LowFirst)?;
+//! assert_eq!(hms, [0x0203, 0x0001]);
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use std::time::Duration;
+
+use crate::error::{FinsError, Result};
+use crate::record::FinsField;
+use crate::response::PlcDateTime;
+use crate::types::WordOrder;
+use crate::utils;
+
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+const SECS_PER_DAY: u64 = 86_400;
+
+/// How a CPU family stores date and time values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeFormat {
+    /// BCD fields with one-second resolution (CS/CJ/CP).
+    Bcd,
+    /// 64-bit nanosecond counts (NJ/NX).
+    Binary,
+}
+
+impl TimeFormat {
+    /// Returns the number of words a date and time occupies.
+    pub fn date_time_words(self) -> u16 {
+        match self {
+            TimeFormat::Bcd => 3,
+            TimeFormat::Binary => 4,
+        }
+    }
+
+    /// Returns the number of words a duration or time of day occupies.
+    pub fn time_words(self) -> u16 {
+        match self {
+            TimeFormat::Bcd => 2,
+            TimeFormat::Binary => 4,
+        }
+    }
+
+    /// Decodes a date and time.
+    ///
+    /// BCD years 70-99 are 1970-1999 and 00-69 are 2000-2069. `order` only
+    /// applies to [`TimeFormat::Binary`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidResponse` if there are fewer words than
+    /// [`date_time_words`](Self::date_time_words), a BCD digit is invalid,
+    /// a field is out of range or a binary value is before 1970.
+    pub fn decode_date_time(self, words: &[u16], order: WordOrder) -> Result<PlcDateTime> {
+        let words = take(words, self.date_time_words())?;
+        let value = match self {
+            TimeFormat::Bcd => {
+                let (minute, second) = bcd_pair(words[0])?;
+                let (day, hour) = bcd_pair(words[1])?;
+                let (year, month) = bcd_pair(words[2])?;
+                let year = year as u16;
+                PlcDateTime {
+                    year: if year < 70 { 2000 + year } else { 1900 + year },
+                    month,
+                    day,
+                    hour,
+                    minute,
+                    second,
+                }
+            }
+            TimeFormat::Binary => {
+                let nanos = i64::get(words, order);
+                if nanos < 0 {
+                    return Err(FinsError::invalid_response(format!(
+                        "DATE_AND_TIME {} is before 1970",
+                        nanos
+                    )));
+                }
+                let secs = (nanos / NANOS_PER_SEC) as u64;
+                let (year, month, day) = civil_from_days((secs / SECS_PER_DAY) as i64);
+                let time = secs % SECS_PER_DAY;
+                PlcDateTime {
+                    year: year as u16,
+                    month,
+                    day,
+                    hour: (time / 3600) as u8,
+                    minute: (time / 60 % 60) as u8,
+                    second: (time % 60) as u8,
+                }
+            }
+        };
+        check_date_time(&value).map_err(FinsError::invalid_response)?;
+        Ok(value)
+    }
+
+    /// Encodes a date and time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if a field is out of range or
+    /// the year cannot be stored: 1970-2069 for BCD, 1970-2262 for binary.
+    pub fn encode_date_time(self, value: &PlcDateTime, order: WordOrder) -> Result<Vec<u16>> {
+        check_date_time(value).map_err(|reason| FinsError::invalid_parameter("value", reason))?;
+        match self {
+            TimeFormat::Bcd => {
+                if !(1970..=2069).contains(&value.year) {
+                    return Err(FinsError::invalid_parameter(
+                        "value",
+                        format!("year {} is outside 1970-2069", value.year),
+                    ));
+                }
+                Ok(vec![
+                    to_bcd_pair(value.minute, value.second)?,
+                    to_bcd_pair(value.day, value.hour)?,
+                    to_bcd_pair((value.year % 100) as u8, value.month)?,
+                ])
+            }
+            TimeFormat::Binary => {
+                if value.year < 1970 {
+                    return Err(FinsError::invalid_parameter(
+                        "value",
+                        format!("year {} is before 1970", value.year),
+                    ));
+                }
+                let days = days_from_civil(value.year as i64, value.month, value.day);
+                let secs = days * SECS_PER_DAY as i64
+                    + value.hour as i64 * 3600
+                    + value.minute as i64 * 60
+                    + value.second as i64;
+                let nanos = secs.checked_mul(NANOS_PER_SEC).ok_or_else(|| {
+                    FinsError::invalid_parameter(
+                        "value",
+                        format!("{} is past the DATE_AND_TIME range", value),
+                    )
+                })?;
+                Ok(to_words(nanos, order))
+            }
+        }
+    }
+
+    /// Decodes a duration (`TIME`, or hours-minutes-seconds for BCD).
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidResponse` if there are fewer words than
+    /// [`time_words`](Self::time_words), a BCD digit is invalid, minutes or
+    /// seconds exceed 59, or a binary value is negative.
+    pub fn decode_time(self, words: &[u16], order: WordOrder) -> Result<Duration> {
+        let words = take(words, self.time_words())?;
+        match self {
+            TimeFormat::Bcd => {
+                let (minute, second) = bcd_pair(words[0])?;
+                if minute > 59 || second > 59 {
+                    return Err(FinsError::invalid_response(format!(
+                        "invalid minutes/seconds 0x{:04X}",
+                        words[0]
+                    )));
+                }
+                let hours = utils::bcd_to_u16(words[1]).ok_or_else(|| bcd_error(words[1]))?;
+                Ok(Duration::from_secs(
+                    hours as u64 * 3600 + minute as u64 * 60 + second as u64,
+                ))
+            }
+            TimeFormat::Binary => {
+                let nanos = i64::get(words, order);
+                u64::try_from(nanos)
+                    .map(Duration::from_nanos)
+                    .map_err(|_| FinsError::invalid_response(format!("negative TIME {} ns", nanos)))
+            }
+        }
+    }
+
+    /// Encodes a duration. BCD drops fractions of a second.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the duration is 10000 hours
+    /// or more for BCD, or does not fit in 64-bit nanoseconds for binary.
+    pub fn encode_time(self, value: Duration, order: WordOrder) -> Result<Vec<u16>> {
+        match self {
+            TimeFormat::Bcd => {
+                let secs = value.as_secs();
+                let hours = u16::try_from(secs / 3600)
+                    .ok()
+                    .and_then(utils::u16_to_bcd)
+                    .ok_or_else(|| {
+                        FinsError::invalid_parameter(
+                            "value",
+                            format!("{:?} is 10000 hours or more", value),
+                        )
+                    })?;
+                Ok(vec![
+                    to_bcd_pair((secs / 60 % 60) as u8, (secs % 60) as u8)?,
+                    hours,
+                ])
+            }
+            TimeFormat::Binary => {
+                let nanos = i64::try_from(value.as_nanos()).map_err(|_| {
+                    FinsError::invalid_parameter(
+                        "value",
+                        format!("{:?} is past the TIME range", value),
+                    )
+                })?;
+                Ok(to_words(nanos, order))
+            }
+        }
+    }
+
+    /// Decodes a time of day as the duration since midnight.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`decode_time`](Self::decode_time), and
+    /// `FinsError::InvalidResponse` if the value is 24 hours or more.
+    pub fn decode_time_of_day(self, words: &[u16], order: WordOrder) -> Result<Duration> {
+        let value = self.decode_time(words, order)?;
+        if value.as_secs() >= SECS_PER_DAY {
+            return Err(FinsError::invalid_response(format!(
+                "TIME_OF_DAY {:?} is past midnight",
+                value
+            )));
+        }
+        Ok(value)
+    }
+
+    /// Encodes a time of day given as the duration since midnight.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the value is 24 hours or
+    /// more.
+    pub fn encode_time_of_day(self, value: Duration, order: WordOrder) -> Result<Vec<u16>> {
+        if value.as_secs() >= SECS_PER_DAY {
+            return Err(FinsError::invalid_parameter(
+                "value",
+                format!("TIME_OF_DAY {:?} is past midnight", value),
+            ));
+        }
+        self.encode_time(value, order)
+    }
+}
+
+/// Returns the first `count` words, or an error if there are fewer.
+fn take(words: &[u16], count: u16) -> Result<&[u16]> {
+    words.get(..count as usize).ok_or_else(|| {
+        FinsError::invalid_response(format!("expected {} words, got {}", count, words.len()))
+    })
+}
+
+fn bcd_error(word: u16) -> FinsError {
+    FinsError::invalid_response(format!("invalid BCD value 0x{:04X}", word))
+}
+
+/// Splits a BCD word into its high and low byte values (`0x3005` -> 30, 5).
+fn bcd_pair(word: u16) -> Result<(u8, u8)> {
+    let value = utils::bcd_to_u16(word).ok_or_else(|| bcd_error(word))?;
+    Ok(((value / 100) as u8, (value % 100) as u8))
+}
+
+/// Packs two values of 0-99 into a BCD word.
+fn to_bcd_pair(high: u8, low: u8) -> Result<u16> {
+    utils::u16_to_bcd(high as u16 * 100 + low as u16).ok_or_else(|| {
+        FinsError::invalid_parameter("value", format!("{} and {} must be 0-99", high, low))
+    })
+}
+
+fn to_words(nanos: i64, order: WordOrder) -> Vec<u16> {
+    let mut words = vec![0; 4];
+    nanos.put(&mut words, order);
+    words
+}
+
+/// Checks the calendar and clock fields of a date and time.
+fn check_date_time(value: &PlcDateTime) -> std::result::Result<(), String> {
+    let days_in_month = match value.month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if value.year % 4 == 0 && (value.year % 100 != 0 || value.year % 400 == 0) => 29,
+        2 => 28,
+        _ => return Err(format!("invalid month in {:?}", value)),
+    };
+    if value.day == 0 || value.day > days_in_month {
+        return Err(format!("invalid day in {:?}", value));
+    }
+    if value.hour > 23 || value.minute > 59 || value.second > 59 {
+        return Err(format!("invalid time in {:?}", value));
+    }
+    Ok(())
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date of a day count since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u8;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leap_day() -> PlcDateTime {
+        PlcDateTime {
+            year: 2024,
+            month: 2,
+            day: 29,
+            hour: 23,
+            minute: 59,
+            second: 58,
+        }
+    }
+
+    #[test]
+    fn test_bcd_date_time() {
+        let words = TimeFormat::Bcd
+            .encode_date_time(&leap_day(), WordOrder::LowFirst)
+            .unwrap();
+        assert_eq!(words, vec![0x5958, 0x2923, 0x2402]);
+        assert_eq!(
+            TimeFormat::Bcd
+                .decode_date_time(&words, WordOrder::LowFirst)
+                .unwrap(),
+            leap_day()
+        );
+
+        let y1999 = TimeFormat::Bcd
+            .decode_date_time(&[0x0000, 0x0100, 0x9912], WordOrder::LowFirst)
+            .unwrap();
+        assert_eq!(y1999.year, 1999);
+        // 2023-02-29, 0x0A seconds, too few words
+        assert!(TimeFormat::Bcd
+            .decode_date_time(&[0x0000, 0x2900, 0x2302], WordOrder::LowFirst)
+            .is_err());
+        assert!(TimeFormat::Bcd
+            .decode_date_time(&[0x000A, 0x0100, 0x2401], WordOrder::LowFirst)
+            .is_err());
+        assert!(TimeFormat::Bcd
+            .decode_date_time(&[0x0000, 0x0100], WordOrder::LowFirst)
+            .is_err());
+
+        let mut y2072 = leap_day();
+        y2072.year = 2072;
+        assert!(TimeFormat::Bcd
+            .encode_date_time(&y2072, WordOrder::LowFirst)
+            .is_err());
+    }
+
+    #[test]
+    fn test_binary_date_time() {
+        let nanos: i64 = 1_709_251_198_000_000_000;
+        let words = TimeFormat::Binary
+            .encode_date_time(&leap_day(), WordOrder::HighFirst)
+            .unwrap();
+        assert_eq!(words, vec![0x17B8, 0x7AE8, 0x98B3, 0x6C00]);
+        assert_eq!(i64::get(&words, WordOrder::HighFirst), nanos);
+
+        for order in [WordOrder::LowFirst, WordOrder::HighFirstByteSwap] {
+            let words = to_words(nanos + 999_999_999, order);
+            assert_eq!(
+                TimeFormat::Binary.decode_date_time(&words, order).unwrap(),
+                leap_day()
+            );
+        }
+        assert_eq!(
+            TimeFormat::Binary
+                .decode_date_time(&[0; 4], WordOrder::LowFirst)
+                .unwrap()
+                .to_string(),
+            "1970-01-01 00:00:00"
+        );
+        assert!(TimeFormat::Binary
+            .decode_date_time(&to_words(-1, WordOrder::LowFirst), WordOrder::LowFirst)
+            .is_err());
+        let mut y1969 = leap_day();
+        y1969.year = 1969;
+        assert!(TimeFormat::Binary
+            .encode_date_time(&y1969, WordOrder::LowFirst)
+            .is_err());
+    }
+
+    #[test]
+    fn test_calendar_round_trip() {
+        for days in [-719_468, -1, 0, 59, 10_957, 19_782, 106_751] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_time_and_time_of_day() {
+        let order = WordOrder::LowFirst;
+        let long = Duration::from_secs(9999 * 3600 + 59 * 60 + 59);
+        let words = TimeFormat::Bcd.encode_time(long, order).unwrap();
+        assert_eq!(words, vec![0x5959, 0x9999]);
+        assert_eq!(TimeFormat::Bcd.decode_time(&words, order).unwrap(), long);
+        assert!(TimeFormat::Bcd
+            .encode_time(long + Duration::from_secs(1), order)
+            .is_err());
+        assert!(TimeFormat::Bcd.decode_time(&[0x0060, 0], order).is_err());
+
+        let precise = Duration::new(3723, 450_000_000);
+        let words = TimeFormat::Binary.encode_time(precise, order).unwrap();
+        assert_eq!(
+            TimeFormat::Binary.decode_time(&words, order).unwrap(),
+            precise
+        );
+        assert_eq!(
+            TimeFormat::Bcd
+                .decode_time(&TimeFormat::Bcd.encode_time(precise, order).unwrap(), order)
+                .unwrap(),
+            Duration::from_secs(3723)
+        );
+        assert!(TimeFormat::Binary
+            .decode_time(&to_words(-5, order), order)
+            .is_err());
+
+        let day = Duration::from_secs(SECS_PER_DAY);
+        assert!(TimeFormat::Binary.encode_time_of_day(day, order).is_err());
+        assert!(TimeFormat::Bcd
+            .decode_time_of_day(&[0x0000, 0x0024], order)
+            .is_err());
+        let evening = Duration::from_secs(20 * 3600 + 15 * 60);
+        let words = TimeFormat::Bcd.encode_time_of_day(evening, order).unwrap();
+        assert_eq!(words, vec![0x1500, 0x0020]);
+        assert_eq!(
+            TimeFormat::Bcd.decode_time_of_day(&words, order).unwrap(),
+            evening
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Broadcast-based PLC discovery.
+//!
+//! [`discover`] sends a Controller Data Read command (0x05 0x01) to node
+//! 0xFF on a broadcast address and collects every FINS node that answers
+//! before the timeout expires.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use omron_fins::{discover, DiscoveryConfig};
+//!
+//! let nodes = discover(&DiscoveryConfig::new(1))?;
+//! for node in nodes {
+//!     println!("{} -> node {}", node.ip, node.address.node);
+//! }
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use std::collections::HashSet;
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4, UdpSocket};
+use std::time::{Duration, Instant};
+
+use socket2::{Domain, Protocol, Socket, Type};
+
+use crate::command::{ControllerDataReadCommand, MRC_CONTROLLER_DATA, SRC_CONTROLLER_DATA_READ};
+use crate::error::Result;
+use crate::header::NodeAddress;
+use crate::response::{ControllerData, FinsResponse};
+use crate::transport::{SocketOptions, DEFAULT_FINS_PORT, DEFAULT_TIMEOUT, MAX_PACKET_SIZE};
+
+/// FINS node number addressing every node on the network.
+const BROADCAST_NODE: u8 = 0xFF;
+
+/// Service ID used for discovery requests.
+const DISCOVERY_SID: u8 = 0x00;
+
+/// Configuration for [`discover`].
+#[derive(Debug, Clone)]
+pub struct DiscoveryConfig {
+    /// Address the discovery request is sent to.
+    pub broadcast_addr: SocketAddr,
+    /// Source node address placed in the request header.
+    pub source: NodeAddress,
+    /// How long to wait for responses.
+    pub timeout: Duration,
+}
+
+impl DiscoveryConfig {
+    /// Creates a discovery configuration broadcasting to
+    /// `255.255.255.255:9600`.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_node` - Source node number (this client)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::DiscoveryConfig;
+    ///
+    /// let config = DiscoveryConfig::new(1);
+    /// assert_eq!(config.broadcast_addr.port(), 9600);
+    /// ```
+    pub fn new(source_node: u8) -> Self {
+        Self {
+            broadcast_addr: SocketAddr::V4(SocketAddrV4::new(
+                Ipv4Addr::BROADCAST,
+                DEFAULT_FINS_PORT,
+            )),
+            source: NodeAddress::new(0, source_node, 0),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Sets the broadcast address (e.g., a subnet-directed broadcast).
+    pub fn with_broadcast_addr(mut self, addr: SocketAddr) -> Self {
+        self.broadcast_addr = addr;
+        self
+    }
+
+    /// Sets the source network address.
+    pub fn with_source_network(mut self, network: u8) -> Self {
+        self.source.network = network;
+        self
+    }
+
+    /// Sets how long to wait for responses.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// A FINS node that answered a discovery request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredNode {
+    /// IP address the response came from.
+    pub ip: Ipv4Addr,
+    /// FINS address of the responding node.
+    pub address: NodeAddress,
+    /// Controller model and version, if the node returned them.
+    pub controller_data: Option<ControllerData>,
+}
+
+/// Discovers FINS nodes by broadcasting a Controller Data Read command.
+///
+/// Waits for the full timeout and returns every distinct node that
+/// answered, in the order the responses arrived. Nodes that reply with
+/// an error end code are still reported, without controller data.
+///
+/// # Errors
+///
+/// Returns an I/O error if the socket cannot be created or the request
+/// cannot be sent.
+///
+/// # Example
+///
+/// ```no_run
+/// use omron_fins::{discover, DiscoveryConfig};
+/// use std::time::Duration;
+///
+/// let config = DiscoveryConfig::new(1)
+///     .with_broadcast_addr("192.168.1.255:9600".parse().unwrap())
+///     .with_timeout(Duration::from_millis(500));
+/// let nodes = discover(&config)?;
+/// # Ok::<(), omron_fins::FinsError>(())
+/// ```
+pub fn discover(config: &DiscoveryConfig) -> Result<Vec<DiscoveredNode>> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    SocketOptions::new().with_broadcast(true).apply(&socket)?;
+    socket.bind(&SocketAddr::from(([0, 0, 0, 0], 0)).into())?;
+    let socket: UdpSocket = socket.into();
+
+    let destination = NodeAddress::new(0, BROADCAST_NODE, 0);
+    let cmd = ControllerDataReadCommand::new(destination, config.source, DISCOVERY_SID);
+    socket.send_to(&cmd.to_bytes(), config.broadcast_addr)?;
+
+    let deadline = Instant::now() + config.timeout;
+    let mut buffer = vec![0u8; MAX_PACKET_SIZE];
+    let mut seen = HashSet::new();
+    let mut nodes = Vec::new();
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        socket.set_read_timeout(Some(remaining))?;
+
+        let (len, from) = match socket.recv_from(&mut buffer) {
+            Ok(received) => received,
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) =>
+            {
+                break;
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let ip = match from {
+            SocketAddr::V4(addr) => *addr.ip(),
+            SocketAddr::V6(_) => continue,
+        };
+
+        // Ignore anything that is not a reply to our request
+        let response = match FinsResponse::from_bytes(&buffer[..len]) {
+            Ok(response) => response,
+            Err(_) => continue,
+        };
+        if response.header.sid != DISCOVERY_SID
+            || response.mrc != MRC_CONTROLLER_DATA
+            || response.src != SRC_CONTROLLER_DATA_READ
+        {
+            continue;
+        }
+
+        let address = response.header.source();
+        if !seen.insert((ip, address)) {
+            continue;
+        }
+
+        let controller_data = if response.is_success() {
+            response.to_controller_data().ok()
+        } else {
+            None
+        };
+
+        nodes.push(DiscoveredNode {
+            ip,
+            address,
+            controller_data,
+        });
+    }
+
+    Ok(nodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    fn controller_data_response(request: &[u8], node: u8) -> Vec<u8> {
+        let mut bytes = vec![
+            0xC0, 0x00, 0x02, request[6], request[7], request[8], 0x00, node, 0x00, request[9],
+            0x05, 0x01, 0x00, 0x00,
+        ];
+        bytes.extend_from_slice(b"CJ2M-CPU31          ");
+        bytes.extend_from_slice(b"02.01               ");
+        bytes
+    }
+
+    #[test]
+    fn test_discovery_config_builder() {
+        let config = DiscoveryConfig::new(5)
+            .with_source_network(2)
+            .with_timeout(Duration::from_millis(100));
+        assert_eq!(config.source, NodeAddress::new(2, 5, 0));
+        assert_eq!(config.timeout, Duration::from_millis(100));
+        assert_eq!(
+            config.broadcast_addr,
+            "255.255.255.255:9600".parse::<SocketAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_discover_collects_responses() {
+        let plc = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let plc_addr = plc.local_addr().unwrap();
+
+        let handle = thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            let (len, from) = plc.recv_from(&mut buf).unwrap();
+            let request = &buf[..len];
+            assert_eq!(request[4], BROADCAST_NODE);
+            assert_eq!(&request[10..12], &[0x05, 0x01]);
+
+            // Two nodes answering plus a duplicate and some noise
+            plc.send_to(&controller_data_response(request, 10), from)
+                .unwrap();
+            plc.send_to(&controller_data_response(request, 10), from)
+                .unwrap();
+            plc.send_to(&[0x00, 0x01], from).unwrap();
+            plc.send_to(&controller_data_response(request, 11), from)
+                .unwrap();
+        });
+
+        let config = DiscoveryConfig::new(1)
+            .with_broadcast_addr(plc_addr)
+            .with_timeout(Duration::from_millis(300));
+        let nodes = discover(&config).unwrap();
+        handle.join().unwrap();
+
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0].ip, Ipv4Addr::LOCALHOST);
+        assert_eq!(nodes[0].address, NodeAddress::new(0, 10, 0));
+        assert_eq!(nodes[1].address.node, 11);
+        let data = nodes[0].controller_data.as_ref().unwrap();
+        assert_eq!(data.model, "CJ2M-CPU31");
+        assert_eq!(data.version, "02.01");
+    }
+
+    #[test]
+    fn test_discover_no_responses() {
+        let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let config = DiscoveryConfig::new(1)
+            .with_broadcast_addr(silent.local_addr().unwrap())
+            .with_timeout(Duration::from_millis(50));
+        assert!(discover(&config).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,325 @@
+//! Human-readable annotation of raw FINS frames.
+//!
+//! [`describe_frame`] labels each header field, the command codes and, for
+//! responses, the end code, so a captured frame can be read without
+//! Wireshark or the FINS reference manual.
+
+use std::fmt::Write as _;
+
+use crate::error::EndCode;
+use crate::header::{FinsHeader, FINS_HEADER_SIZE};
+use crate::response::EndCodeWarnings;
+
+/// Payload bytes shown before a summary is cut short.
+const MAX_SHOWN_BYTES: usize = 16;
+
+/// Returns the name of a command, if it is a standard FINS command.
+fn command_name(mrc: u8, src: u8) -> Option<&'static str> {
+    let name = match (mrc, src) {
+        (0x01, 0x01) => "Memory Area Read",
+        (0x01, 0x02) => "Memory Area Write",
+        (0x01, 0x03) => "Memory Area Fill",
+        (0x01, 0x04) => "Multiple Memory Area Read",
+        (0x01, 0x05) => "Memory Area Transfer",
+        (0x02, 0x01) => "Parameter Area Read",
+        (0x02, 0x02) => "Parameter Area Write",
+        (0x02, 0x03) => "Parameter Area Clear",
+        (0x03, 0x06) => "Program Area Read",
+        (0x03, 0x07) => "Program Area Write",
+        (0x03, 0x08) => "Program Area Clear",
+        (0x04, 0x01) => "Run",
+        (0x04, 0x02) => "Stop",
+        (0x04, 0x03) => "Reset",
+        (0x05, 0x01) => "Controller Data Read",
+        (0x05, 0x02) => "Connection Data Read",
+        (0x06, 0x01) => "Controller Status Read",
+        (0x06, 0x20) => "Cycle Time Read",
+        (0x07, 0x01) => "Clock Read",
+        (0x07, 0x02) => "Clock Write",
+        (0x08, 0x01) => "Internode Echo Test",
+        (0x08, 0x02) => "Broadcast Test Results Read",
+        (0x08, 0x03) => "Broadcast Test Data Send",
+        (0x09, 0x20) => "Message Read/Clear",
+        (0x0C, 0x01) => "Access Right Acquire",
+        (0x0C, 0x02) => "Access Right Forced Acquire",
+        (0x0C, 0x03) => "Access Right Release",
+        (0x21, 0x01) => "Error Clear",
+        (0x21, 0x02) => "Error Log Read",
+        (0x21, 0x03) => "Error Log Clear",
+        (0x21, 0x40) => "FINS Write Access Log Read",
+        (0x21, 0x41) => "FINS Write Access Log Clear",
+        (0x22, 0x01) => "File Name Read",
+        (0x22, 0x02) => "Single File Read",
+        (0x22, 0x03) => "Single File Write",
+        (0x22, 0x04) => "File Memory Format",
+        (0x22, 0x05) => "File Delete",
+        (0x22, 0x07) => "File Copy",
+        (0x22, 0x08) => "File Name Change",
+        (0x22, 0x0A) => "Memory Area File Transfer",
+        (0x22, 0x0B) => "Parameter Area File Transfer",
+        (0x22, 0x0C) => "Program Area File Transfer",
+        (0x22, 0x15) => "Create/Delete Directory",
+        (0x23, 0x01) => "Forced Set/Reset",
+        (0x23, 0x02) => "Forced Set/Reset Cancel",
+        (0x26, 0x01) => "Name Set",
+        (0x26, 0x02) => "Name Delete",
+        (0x26, 0x03) => "Name Read",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Formats a memory area code and address as e.g. `DM 100` or `CIO 0.05`.
+///
+/// Returns the label and whether the code addresses bits.
+fn memory_address(code: u8, word: u16, bit: u8) -> (String, bool) {
+    let (name, bits) = match code {
+        0xB0 => ("CIO", false),
+        0xB1 => ("WR", false),
+        0xB2 => ("HR", false),
+        0x82 => ("DM", false),
+        0xB3 => ("AR", false),
+        0x89 if word >= 0x8000 => ("CNT", false),
+        0x89 => ("TIM", false),
+        0xDC if word >= 0x0100 => ("IR", false),
+        0x30 => ("CIO", true),
+        0x31 => ("WR", true),
+        0x32 => ("HR", true),
+        0x02 => ("DM", true),
+        0x33 => ("AR", true),
+        0x09 if word >= 0x8000 => ("CNT", true),
+        0x09 => ("TIM", true),
+        0x07 => ("CF", true),
+        _ => {
+            return (
+                format!("area 0x{:02X} word {} bit {}", code, word, bit),
+                false,
+            )
+        }
+    };
+    let word = match name {
+        "CNT" => word - 0x8000,
+        "IR" => word - 0x0100,
+        _ => word,
+    };
+    if bits {
+        (format!("{} {}.{:02}", name, word, bit), true)
+    } else {
+        (format!("{} {}", name, word), false)
+    }
+}
+
+/// Summarizes the parameters of the memory area commands, or `None` for
+/// other commands and for parameters too short to decode.
+fn command_summary(mrc: u8, src: u8, data: &[u8]) -> Option<String> {
+    if mrc != 0x01 {
+        return None;
+    }
+    if src == 0x04 {
+        return Some(format!("{} items", data.len() / 4));
+    }
+    let &[code, high, low, bit, count_high, count_low, ref rest @ ..] = data else {
+        return None;
+    };
+    let (target, bits) = memory_address(code, u16::from_be_bytes([high, low]), bit);
+    let count = u16::from_be_bytes([count_high, count_low]);
+    let unit = if bits { "bits" } else { "words" };
+    match src {
+        0x01 => Some(format!("{}, {} {}", target, count, unit)),
+        0x02 => Some(format!(
+            "{}, {} {}, {} data bytes",
+            target,
+            count,
+            unit,
+            rest.len()
+        )),
+        0x03 if rest.len() == 2 => Some(format!(
+            "{}, {} {}, value 0x{:02X}{:02X}",
+            target, count, unit, rest[0], rest[1]
+        )),
+        _ => None,
+    }
+}
+
+/// Summarizes payload bytes as their count and the first few in hex.
+fn bytes_summary(data: &[u8]) -> String {
+    let mut summary = format!("{} bytes", data.len());
+    for (index, byte) in data.iter().take(MAX_SHOWN_BYTES).enumerate() {
+        summary.push_str(if index == 0 { ": " } else { " " });
+        let _ = write!(summary, "{:02X}", byte);
+    }
+    if data.len() > MAX_SHOWN_BYTES {
+        summary.push_str(" ...");
+    }
+    summary
+}
+
+/// Describes a raw FINS command or response frame, one labelled field per
+/// line, for debugging.
+///
+/// The header fields, command codes (with the command name) and, for
+/// responses, the end code (with its description and warning flags) are
+/// listed, followed by a summary of the payload. Memory area commands show
+/// their area, address and count. Truncated frames are described as far as
+/// they go.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::describe_frame;
+///
+/// let frame = [
+///     0x80, 0x00, 0x02, 0x00, 0x0A, 0x00, 0x00, 0x01, 0x00, 0x05,
+///     0x01, 0x01, 0x82, 0x00, 0x64, 0x00, 0x00, 0x0A,
+/// ];
+/// assert_eq!(
+///     describe_frame(&frame),
+///     "ICF      0x80 command, response required\n\
+///      GCT      0x02\n\
+///      Dest     network 0, node 10, unit 0\n\
+///      Source   network 0, node 1, unit 0\n\
+///      SID      0x05\n\
+///      Command  0x0101 Memory Area Read\n\
+///      Request  DM 100, 10 words"
+/// );
+/// ```
+pub fn describe_frame(frame: &[u8]) -> String {
+    let Ok(header) = FinsHeader::from_bytes(frame) else {
+        return format!(
+            "Truncated header ({} of {} bytes): {}",
+            frame.len(),
+            FINS_HEADER_SIZE,
+            bytes_summary(frame)
+        );
+    };
+    let response = header.is_response();
+    let mut lines = Vec::new();
+
+    let kind = match (response, header.icf & 0x01 != 0) {
+        (true, _) => "response",
+        (false, false) => "command, response required",
+        (false, true) => "command, no response",
+    };
+    lines.push(format!("ICF      0x{:02X} {}", header.icf, kind));
+    if header.rsv != 0 {
+        lines.push(format!("RSV      0x{:02X} (expected 0x00)", header.rsv));
+    }
+    lines.push(format!("GCT      0x{:02X}", header.gct));
+    for (label, node) in [
+        ("Dest  ", header.destination()),
+        ("Source", header.source()),
+    ] {
+        lines.push(format!(
+            "{}   network {}, node {}, unit {}",
+            label, node.network, node.node, node.unit
+        ));
+    }
+    lines.push(format!("SID      0x{:02X}", header.sid));
+
+    let body = &frame[FINS_HEADER_SIZE..];
+    let Some(&[mrc, src]) = body.get(..2) else {
+        lines.push(format!("Command  missing ({})", bytes_summary(body)));
+        return lines.join("\n");
+    };
+    let name = command_name(mrc, src).unwrap_or("unknown command");
+    lines.push(format!("Command  0x{:02X}{:02X} {}", mrc, src, name));
+    let mut data = &body[2..];
+
+    if response {
+        let Some(&[main_code, sub_code]) = data.get(..2) else {
+            lines.push(format!("End code missing ({})", bytes_summary(data)));
+            return lines.join("\n");
+        };
+        let end_code = EndCode::from_codes(main_code & 0x7F, sub_code & 0x3F);
+        let mut line = format!(
+            "End code 0x{:02X}{:02X} {}",
+            main_code,
+            sub_code,
+            end_code.description()
+        );
+        let warnings = EndCodeWarnings::from_codes(main_code, sub_code);
+        if !warnings.is_empty() {
+            let _ = write!(line, " ({})", warnings);
+        }
+        lines.push(line);
+        data = &data[2..];
+    }
+
+    if !data.is_empty() {
+        match command_summary(mrc, src, data).filter(|_| !response) {
+            Some(summary) => lines.push(format!("Request  {}", summary)),
+            None => lines.push(format!("Data     {}", bytes_summary(data))),
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_frame() {
+        let write = [
+            0x81, 0x00, 0x07, 0x01, 0x0A, 0x00, 0x00, 0x01, 0x00, 0x2A, 0x01, 0x02, 0x30, 0x00,
+            0x02, 0x05, 0x00, 0x01, 0x01,
+        ];
+        let lines: Vec<String> = describe_frame(&write).lines().map(String::from).collect();
+        assert_eq!(lines[0], "ICF      0x81 command, no response");
+        assert_eq!(lines[2], "Dest     network 1, node 10, unit 0");
+        assert_eq!(lines[5], "Command  0x0102 Memory Area Write");
+        assert_eq!(lines[6], "Request  CIO 2.05, 1 bits, 1 data bytes");
+
+        let response = [
+            0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x05, 0x01, 0x01, 0x00, 0x40,
+            0x00, 0x0A, 0x00, 0x14,
+        ];
+        let description = describe_frame(&response);
+        assert!(description.starts_with("ICF      0xC0 response\n"));
+        assert!(description.ends_with(
+            "Command  0x0101 Memory Area Read\n\
+             End code 0x0040 Normal completion (non-fatal CPU error)\n\
+             Data     4 bytes: 00 0A 00 14"
+        ));
+
+        let error = [
+            0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x05, 0x04, 0x01, 0x22, 0x01,
+        ];
+        assert!(describe_frame(&error).ends_with(&format!(
+            "Command  0x0401 Run\nEnd code 0x2201 {}",
+            EndCode::from_codes(0x22, 0x01).description()
+        )));
+
+        let counter = [
+            0x80, 0x00, 0x02, 0x00, 0x0A, 0x00, 0x00, 0x01, 0x00, 0x05, 0x01, 0x01, 0x89, 0x80,
+            0x03, 0x00, 0x00, 0x01,
+        ];
+        assert!(describe_frame(&counter).ends_with("Request  CNT 3, 1 words"));
+
+        let index_register = [
+            0x80, 0x00, 0x02, 0x00, 0x0A, 0x00, 0x00, 0x01, 0x00, 0x05, 0x01, 0x01, 0xDC, 0x01,
+            0x02, 0x00, 0x00, 0x01,
+        ];
+        assert!(describe_frame(&index_register).ends_with("Request  IR 2, 1 words"));
+
+        let unknown = [
+            0x80, 0x00, 0x02, 0x00, 0x0A, 0x00, 0x00, 0x01, 0x00, 0x05, 0x7F, 0x01,
+        ];
+        assert!(describe_frame(&unknown).ends_with("Command  0x7F01 unknown command"));
+
+        let long_payload: Vec<u8> = [0x80, 0x00, 0x02, 0x00, 0x0A, 0x00, 0x00, 0x01, 0x00, 0x05]
+            .into_iter()
+            .chain([0x08, 0x01])
+            .chain(0..20)
+            .collect();
+        assert!(describe_frame(&long_payload)
+            .ends_with("Data     20 bytes: 00 01 02 03 04 05 06 07 08 09 0A 0B 0C 0D 0E 0F ..."));
+
+        assert_eq!(
+            describe_frame(&[0xC0, 0x00]),
+            "Truncated header (2 of 10 bytes): 2 bytes: C0 00"
+        );
+        assert!(describe_frame(&response[..11]).ends_with("Command  missing (1 bytes: 01)"));
+        assert!(describe_frame(&response[..13]).ends_with("End code missing (1 bytes: 00)"));
+    }
+}
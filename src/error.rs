@@ -15,7 +15,7 @@
 //! # Example
 //!
 //! ```no_run
-//! use omron_fins::{Client, ClientConfig, MemoryArea, FinsError};
+//! use omron_fins::{Client, ClientConfig, EndCode, MemoryArea, FinsError};
 //! use std::net::Ipv4Addr;
 //!
 //! let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0);
@@ -26,7 +26,10 @@
 //!     Err(FinsError::Timeout) => {
 //!         eprintln!("Communication timed out");
 //!     }
-//!     Err(ref e @ FinsError::PlcError { main_code, sub_code }) => {
+//!     Err(FinsError::PlcError { end_code: EndCode::AreaRangeExceeded }) => {
+//!         eprintln!("DM100-109 is past the end of DM on this CPU");
+//!     }
+//!     Err(ref e @ FinsError::PlcError { .. }) => {
 //!         // The error message now includes the description automatically:
 //!         // e.g., "PLC error (0x11:0x04): The end of specified word range exceeds acceptable range"
 //!         eprintln!("{}", e);
@@ -53,9 +56,13 @@
 //! let err = FinsError::invalid_addressing("DM area does not support bit access");
 //! ```
 
+use std::fmt;
 use std::io;
 use thiserror::Error;
 
+use crate::memory::MemoryArea;
+use crate::tcp::tcp_error_description;
+
 /// Returns a human-readable description for FINS error codes.
 ///
 /// This function maps the main and sub error codes returned by Omron PLCs
@@ -201,19 +208,370 @@ pub fn fins_error_description(main_code: u8, sub_code: u8) -> &'static str {
     }
 }
 
+/// Defines [`EndCode`] with its variants' main and sub codes.
+macro_rules! end_codes {
+    ($($(#[$doc:meta])* $name:ident = ($main:literal, $sub:literal),)*) => {
+        /// A FINS end code, naming the codes of [`fins_error_description`].
+        ///
+        /// Carried by [`FinsError::PlcError`] so callers can match named
+        /// codes instead of comparing main and sub codes.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use omron_fins::{EndCode, FinsError};
+        ///
+        /// let err = FinsError::plc_error(0x11, 0x04);
+        /// assert!(matches!(
+        ///     err,
+        ///     FinsError::PlcError { end_code: EndCode::AreaRangeExceeded }
+        /// ));
+        ///
+        /// let code = EndCode::from_codes(0x22, 0x03);
+        /// assert_eq!(code, EndCode::ProgramMode);
+        /// assert_eq!(code.to_string(), "The PC is in the PROGRAM mode (0x2203)");
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum EndCode {
+            $($(#[$doc])* $name,)*
+            /// A code without a name.
+            Other {
+                /// Main response code.
+                main_code: u8,
+                /// Sub response code.
+                sub_code: u8,
+            },
+        }
+
+        impl EndCode {
+            /// Returns the end code for a main and sub code.
+            pub fn from_codes(main_code: u8, sub_code: u8) -> Self {
+                match (main_code, sub_code) {
+                    $(($main, $sub) => Self::$name,)*
+                    (main_code, sub_code) => Self::Other { main_code, sub_code },
+                }
+            }
+
+            /// Returns the main and sub code.
+            pub fn codes(self) -> (u8, u8) {
+                match self {
+                    $(Self::$name => ($main, $sub),)*
+                    Self::Other { main_code, sub_code } => (main_code, sub_code),
+                }
+            }
+        }
+    };
+}
+
+end_codes! {
+    /// Normal completion (0x0000).
+    NormalCompletion = (0x00, 0x00),
+    /// Service was interrupted (0x0001).
+    ServiceInterrupted = (0x00, 0x01),
+    /// Local node not part of Network (0x0101).
+    LocalNodeNotInNetwork = (0x01, 0x01),
+    /// Token time-out, node number too large (0x0102).
+    TokenTimeout = (0x01, 0x02),
+    /// Number of transmit retries exceeded (0x0103).
+    RetriesExceeded = (0x01, 0x03),
+    /// Maximum number of frames exceeded (0x0104).
+    MaxFramesExceeded = (0x01, 0x04),
+    /// Node number setting error (range) (0x0105).
+    NodeNumberRange = (0x01, 0x05),
+    /// Node number duplication error (0x0106).
+    NodeNumberDuplicated = (0x01, 0x06),
+    /// Destination node not part of Network (0x0201).
+    DestinationNotInNetwork = (0x02, 0x01),
+    /// No node with the specified node number (0x0202).
+    NoSuchNode = (0x02, 0x02),
+    /// Third node not part of Network: Broadcasting was specified (0x0203).
+    ThirdNodeNotInNetwork = (0x02, 0x03),
+    /// Busy error, destination node busy (0x0204).
+    DestinationBusy = (0x02, 0x04),
+    /// Response time-out (0x0205).
+    ResponseTimeout = (0x02, 0x05),
+    /// Error occurred: ERC indicator is lit (0x0301).
+    CommunicationsControllerError = (0x03, 0x01),
+    /// CPU error occurred in the PC at the destination node (0x0302).
+    CpuUnitError = (0x03, 0x02),
+    /// A controller error has prevented a normal response (0x0303).
+    ControllerError = (0x03, 0x03),
+    /// Node number setting error (0x0304).
+    UnitNumberError = (0x03, 0x04),
+    /// An undefined command has been used (0x0401).
+    UndefinedCommand = (0x04, 0x01),
+    /// Cannot process command because the specified unit model or version is wrong (0x0402).
+    UnsupportedModelVersion = (0x04, 0x02),
+    /// Command not supported by this PLC model or version (0x0441).
+    CommandNotSupported = (0x04, 0x41),
+    /// Destination node number is not set in the routing table (0x0501).
+    DestinationNotInRoutingTable = (0x05, 0x01),
+    /// Routing table isn't registered (0x0502).
+    NoRoutingTable = (0x05, 0x02),
+    /// Routing table error (0x0503).
+    RoutingTableError = (0x05, 0x03),
+    /// Max relay nodes (2) was exceeded (0x0504).
+    TooManyRelays = (0x05, 0x04),
+    /// The command is longer than the max permissible length (0x1001).
+    CommandTooLong = (0x10, 0x01),
+    /// The command is shorter than the min permissible length (0x1002).
+    CommandTooShort = (0x10, 0x02),
+    /// The designated number of data items differs from the actual number (0x1003).
+    ItemCountMismatch = (0x10, 0x03),
+    /// An incorrect command format has been used (0x1004).
+    CommandFormatError = (0x10, 0x04),
+    /// An incorrect header has been used (0x1005).
+    HeaderError = (0x10, 0x05),
+    /// Memory area code invalid or DM is not available (0x1101).
+    AreaCodeInvalid = (0x11, 0x01),
+    /// Access size is wrong in command (0x1102).
+    AccessSizeError = (0x11, 0x02),
+    /// First address in inaccessible area (0x1103).
+    AddressRangeError = (0x11, 0x03),
+    /// The end of specified word range exceeds acceptable range (0x1104).
+    AreaRangeExceeded = (0x11, 0x04),
+    /// A non-existent program number (0x1106).
+    ProgramMissing = (0x11, 0x06),
+    /// The size of data items in command block are wrong (0x1109).
+    DataSizeError = (0x11, 0x09),
+    /// The IOM break function cannot be executed (0x110A).
+    IomBreakNotExecutable = (0x11, 0x0A),
+    /// The response block is longer than the max length (0x110B).
+    ResponseTooLong = (0x11, 0x0B),
+    /// An incorrect parameter code has been specified (0x110C).
+    ParameterCodeError = (0x11, 0x0C),
+    /// No such memory area (0x1141).
+    NoSuchMemoryArea = (0x11, 0x41),
+    /// The data is protected (0x2002).
+    ReadProtected = (0x20, 0x02),
+    /// Registered table does not exist (0x2003).
+    TableMissing = (0x20, 0x03),
+    /// Search data does not exist (0x2004).
+    SearchDataMissing = (0x20, 0x04),
+    /// Non-existent program number (0x2005).
+    ReadProgramMissing = (0x20, 0x05),
+    /// Non-existent file (0x2006).
+    ReadFileMissing = (0x20, 0x06),
+    /// Verification error (0x2007).
+    VerificationError = (0x20, 0x07),
+    /// Specified area is read-only (0x2101).
+    ReadOnly = (0x21, 0x01),
+    /// The data is protected (0x2102).
+    WriteProtected = (0x21, 0x02),
+    /// Too many files open (0x2103).
+    TooManyFilesOpen = (0x21, 0x03),
+    /// Non-existent program number (0x2105).
+    WriteProgramMissing = (0x21, 0x05),
+    /// Non-existent file (0x2106).
+    WriteFileMissing = (0x21, 0x06),
+    /// File already exists (0x2107).
+    FileExists = (0x21, 0x07),
+    /// Data cannot be changed (0x2108).
+    CannotChange = (0x21, 0x08),
+    /// The mode is wrong (executing) (0x2201).
+    ModeExecuting = (0x22, 0x01),
+    /// The mode is wrong (stopped) (0x2202).
+    ModeStopped = (0x22, 0x02),
+    /// The PC is in the PROGRAM mode (0x2203).
+    ProgramMode = (0x22, 0x03),
+    /// The PC is in the DEBUG mode (0x2204).
+    DebugMode = (0x22, 0x04),
+    /// The PC is in the MONITOR mode (0x2205).
+    MonitorMode = (0x22, 0x05),
+    /// The PC is in the RUN mode (0x2206).
+    RunMode = (0x22, 0x06),
+    /// The specified node is not the control node (0x2207).
+    NotControlNode = (0x22, 0x07),
+    /// The mode is wrong and the step cannot be executed (0x2208).
+    StepCannotExecute = (0x22, 0x08),
+    /// The file device does not exist where specified (0x2301).
+    FileDeviceMissing = (0x23, 0x01),
+    /// The specified memory does not exist (0x2302).
+    MemoryMissing = (0x23, 0x02),
+    /// No clock exists (0x2303).
+    NoClock = (0x23, 0x03),
+    /// Data link table is incorrect (0x2401).
+    DataLinkTableError = (0x24, 0x01),
+    /// Parity / checksum error occurred (0x2502).
+    ParityError = (0x25, 0x02),
+    /// I/O setting error (0x2503).
+    IoSettingError = (0x25, 0x03),
+    /// Too many I/O points (0x2504).
+    TooManyIoPoints = (0x25, 0x04),
+    /// CPU bus error (0x2505).
+    CpuBusError = (0x25, 0x05),
+    /// I/O duplication error (0x2506).
+    IoDuplication = (0x25, 0x06),
+    /// I/O bus error (0x2507).
+    IoBusError = (0x25, 0x07),
+    /// SYSMAC BUS/2 error (0x2509).
+    SysmacBus2Error = (0x25, 0x09),
+    /// Special I/O Unit error (0x250A).
+    SpecialIoUnitError = (0x25, 0x0A),
+    /// Duplication in SYSMAC BUS word allocation (0x250D).
+    SysmacBusWordDuplication = (0x25, 0x0D),
+    /// A memory error has occurred (0x250F).
+    MemoryError = (0x25, 0x0F),
+    /// Terminator not connected in SYSMAC BUS system (0x2510).
+    SysmacBusTerminatorMissing = (0x25, 0x10),
+    /// The specified area is not protected (0x2601).
+    NotProtected = (0x26, 0x01),
+    /// An incorrect password has been specified (0x2602).
+    IncorrectPassword = (0x26, 0x02),
+    /// The specified area is protected (0x2604).
+    Protected = (0x26, 0x04),
+    /// The service is being executed (0x2605).
+    ServiceExecuting = (0x26, 0x05),
+    /// The service is not being executed (0x2606).
+    ServiceNotExecuting = (0x26, 0x06),
+    /// Service cannot be executed from local node (0x2607).
+    NotExecutableFromLocalNode = (0x26, 0x07),
+    /// Service cannot be executed, settings are incorrect (0x2608).
+    SettingsIncorrect = (0x26, 0x08),
+    /// Service cannot be executed, incorrect settings in command data (0x2609).
+    CommandDataIncorrect = (0x26, 0x09),
+    /// The specified action has already been registered (0x260A).
+    AlreadyRegistered = (0x26, 0x0A),
+    /// Cannot clear error, error still exists (0x260B).
+    ErrorStillExists = (0x26, 0x0B),
+    /// The access right is held by another device (0x3001).
+    AccessRightHeld = (0x30, 0x01),
+    /// Command aborted with ABORT command (0x4001).
+    Aborted = (0x40, 0x01),
+}
+
+impl EndCode {
+    /// Returns the main response code.
+    pub fn main_code(self) -> u8 {
+        self.codes().0
+    }
+
+    /// Returns the sub response code.
+    pub fn sub_code(self) -> u8 {
+        self.codes().1
+    }
+
+    /// Returns the description from [`fins_error_description`].
+    pub fn description(self) -> &'static str {
+        let (main_code, sub_code) = self.codes();
+        fins_error_description(main_code, sub_code)
+    }
+
+    /// Returns `true` for codes that may clear on their own, such as a busy
+    /// node, a network time-out or an access right held by another device.
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::ServiceInterrupted
+                | Self::TokenTimeout
+                | Self::RetriesExceeded
+                | Self::MaxFramesExceeded
+                | Self::DestinationBusy
+                | Self::ResponseTimeout
+                | Self::ServiceExecuting
+                | Self::AccessRightHeld
+        )
+    }
+
+    /// Returns `true` if the PLC refused the command in its current
+    /// operating mode (main code 0x22).
+    pub fn is_mode_error(self) -> bool {
+        self.main_code() == 0x22
+    }
+
+    /// Returns `true` if the target is read-only, protected or held by
+    /// another device.
+    pub fn is_permission_error(self) -> bool {
+        matches!(
+            self,
+            Self::ReadProtected
+                | Self::ReadOnly
+                | Self::WriteProtected
+                | Self::CannotChange
+                | Self::IncorrectPassword
+                | Self::Protected
+                | Self::AccessRightHeld
+        )
+    }
+}
+
+/// Formats as the description followed by the code, e.g.
+/// "Specified area is read-only (0x2101)".
+impl fmt::Display for EndCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (main_code, sub_code) = self.codes();
+        write!(
+            f,
+            "{} (0x{:02X}{:02X})",
+            self.description(),
+            main_code,
+            sub_code
+        )
+    }
+}
+
+/// A word that read back differently from what was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordMismatch {
+    /// Word address.
+    pub address: u16,
+    /// Value written.
+    pub written: u16,
+    /// Value read back.
+    pub read: u16,
+}
+
+/// The request a `WithContext` error was raised by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestContext {
+    /// What was being done, e.g. "reading" or "writing".
+    pub operation: &'static str,
+    /// Memory area addressed.
+    pub area: MemoryArea,
+    /// First word address of the command.
+    pub address: u16,
+    /// Number of words in the command.
+    pub count: u16,
+    /// Service ID of the command.
+    pub sid: u8,
+}
+
+/// Formats as e.g. "reading DM 32760 len 20 (SID 0x05)".
+impl fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} len {} (SID 0x{:02X})",
+            self.operation, self.area, self.address, self.count, self.sid
+        )
+    }
+}
+
+/// Summarizes the mismatches of a `VerifyMismatch` error.
+fn mismatch_summary(mismatches: &[WordMismatch]) -> String {
+    match mismatches.first() {
+        Some(first) => format!(
+            "{} word(s) differ, first at word {} (wrote 0x{:04X}, read 0x{:04X})",
+            mismatches.len(),
+            first.address,
+            first.written,
+            first.read
+        ),
+        None => "no words differ".to_string(),
+    }
+}
+
 /// Result type alias for FINS operations.
 pub type Result<T> = std::result::Result<T, FinsError>;
 
 /// Errors that can occur during FINS communication.
 #[derive(Debug, Error)]
 pub enum FinsError {
-    /// Error returned by the PLC with main and sub codes.
-    #[error("PLC error (0x{main_code:02X}:0x{sub_code:02X}): {}", fins_error_description(*.main_code, *.sub_code))]
+    /// Error end code returned by the PLC.
+    #[error("PLC error (0x{:02X}:0x{:02X}): {}", .end_code.main_code(), .end_code.sub_code(), .end_code.description())]
     PlcError {
-        /// Main error code from PLC response.
-        main_code: u8,
-        /// Sub error code from PLC response.
-        sub_code: u8,
+        /// End code from the PLC response.
+        end_code: EndCode,
     },
 
     /// Invalid memory addressing.
@@ -255,6 +613,55 @@ pub enum FinsError {
         /// Received SID value.
         received: u8,
     },
+
+    /// Response answers a different command than the one sent.
+    #[error("Command mismatch: sent 0x{:02X}{:02X}, response is for 0x{:02X}{:02X}", .expected.0, .expected.1, .received.0, .received.1)]
+    CommandMismatch {
+        /// MRC and SRC of the command sent.
+        expected: (u8, u8),
+        /// MRC and SRC echoed by the response.
+        received: (u8, u8),
+    },
+
+    /// Error notification from a FINS/TCP server.
+    #[error("FINS/TCP error (0x{code:08X}): {}", tcp_error_description(*.code))]
+    TcpError {
+        /// FINS/TCP error code.
+        code: u32,
+    },
+
+    /// The FINS/TCP connection was lost and has been re-established.
+    ///
+    /// The request that found the connection lost may or may not have
+    /// reached the PLC; later requests use the new connection.
+    #[error("Connection reset by the PLC, reconnected")]
+    ConnectionReset,
+
+    /// A multi-command transfer failed part way through.
+    #[error("Transfer stopped after {words_done} words: {source}")]
+    TransferIncomplete {
+        /// Words transferred successfully before the failure.
+        words_done: usize,
+        /// Error that stopped the transfer.
+        source: Box<FinsError>,
+    },
+
+    /// Words read back after a verified write differ from the data written.
+    #[error("Write verification failed: {}", mismatch_summary(.mismatches))]
+    VerifyMismatch {
+        /// Every word that differs, in address order.
+        mismatches: Vec<WordMismatch>,
+    },
+
+    /// An error with the request that raised it, see
+    /// [`ClientConfig::with_error_context`](crate::ClientConfig::with_error_context).
+    #[error("{source} while {context}")]
+    WithContext {
+        /// Request being executed.
+        context: RequestContext,
+        /// Error the request failed with.
+        source: Box<FinsError>,
+    },
 }
 
 impl FinsError {
@@ -269,8 +676,7 @@ impl FinsError {
     /// ```
     pub fn plc_error(main_code: u8, sub_code: u8) -> Self {
         Self::PlcError {
-            main_code,
-            sub_code,
+            end_code: EndCode::from_codes(main_code, sub_code),
         }
     }
 
@@ -333,7 +739,186 @@ impl FinsError {
         Self::SidMismatch { expected, received }
     }
 
-    /// Returns the error description if this is a `PlcError`.
+    /// Creates a new `CommandMismatch` error from MRC/SRC pairs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsError;
+    ///
+    /// let err = FinsError::command_mismatch((0x01, 0x02), (0x01, 0x01));
+    /// ```
+    pub fn command_mismatch(expected: (u8, u8), received: (u8, u8)) -> Self {
+        Self::CommandMismatch { expected, received }
+    }
+
+    /// Creates a new `TcpError` from a FINS/TCP error code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsError;
+    ///
+    /// let err = FinsError::tcp_error(0x20);
+    /// ```
+    pub fn tcp_error(code: u32) -> Self {
+        Self::TcpError { code }
+    }
+
+    /// Creates a new `TransferIncomplete` error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsError;
+    ///
+    /// let err = FinsError::transfer_incomplete(1400, FinsError::Timeout);
+    /// assert_eq!(err.to_string(), "Transfer stopped after 1400 words: Communication timeout");
+    /// ```
+    pub fn transfer_incomplete(words_done: usize, source: FinsError) -> Self {
+        Self::TransferIncomplete {
+            words_done,
+            source: Box::new(source),
+        }
+    }
+
+    /// Creates a new `VerifyMismatch` error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{FinsError, WordMismatch};
+    ///
+    /// let err = FinsError::verify_mismatch(vec![WordMismatch {
+    ///     address: 100,
+    ///     written: 0x1234,
+    ///     read: 0x0000,
+    /// }]);
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Write verification failed: 1 word(s) differ, first at word 100 (wrote 0x1234, read 0x0000)"
+    /// );
+    /// ```
+    pub fn verify_mismatch(mismatches: Vec<WordMismatch>) -> Self {
+        Self::VerifyMismatch { mismatches }
+    }
+
+    /// Attaches the request that raised this error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{FinsError, MemoryArea, RequestContext};
+    ///
+    /// let err = FinsError::plc_error(0x11, 0x04).with_context(RequestContext {
+    ///     operation: "reading",
+    ///     area: MemoryArea::DM,
+    ///     address: 32760,
+    ///     count: 20,
+    ///     sid: 0x05,
+    /// });
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "PLC error (0x11:0x04): The end of specified word range exceeds acceptable range \
+    ///      while reading DM 32760 len 20 (SID 0x05)"
+    /// );
+    /// ```
+    pub fn with_context(self, context: RequestContext) -> Self {
+        Self::WithContext {
+            context,
+            source: Box::new(self),
+        }
+    }
+
+    /// Returns the request context, if one is attached.
+    pub fn context(&self) -> Option<&RequestContext> {
+        match self {
+            Self::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Returns the error without its request context.
+    pub fn without_context(&self) -> &FinsError {
+        match self {
+            Self::WithContext { source, .. } => source.without_context(),
+            _ => self,
+        }
+    }
+
+    /// Returns the PLC end code, looking through `TransferIncomplete` and
+    /// `WithContext`.
+    pub fn end_code(&self) -> Option<EndCode> {
+        match self {
+            Self::PlcError { end_code } => Some(*end_code),
+            Self::TransferIncomplete { source, .. } | Self::WithContext { source, .. } => {
+                source.end_code()
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if repeating the same request may succeed.
+    ///
+    /// Time-outs, stale or mismatched responses, dropped connections and
+    /// retryable end codes (see [`EndCode::is_retryable`]) qualify; invalid
+    /// parameters and end codes such as "area is read-only" do not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsError;
+    ///
+    /// assert!(FinsError::Timeout.is_retryable());
+    /// assert!(FinsError::plc_error(0x02, 0x04).is_retryable());
+    /// assert!(!FinsError::plc_error(0x21, 0x01).is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Timeout
+            | Self::ConnectionReset
+            | Self::SidMismatch { .. }
+            | Self::CommandMismatch { .. } => true,
+            Self::Io(error) => matches!(
+                error.kind(),
+                io::ErrorKind::TimedOut
+                    | io::ErrorKind::WouldBlock
+                    | io::ErrorKind::Interrupted
+                    | io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+            ),
+            Self::PlcError { end_code } => end_code.is_retryable(),
+            Self::TransferIncomplete { source, .. } | Self::WithContext { source, .. } => {
+                source.is_retryable()
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the PLC refused the command in its current
+    /// operating mode, e.g. a program change while in RUN mode.
+    pub fn is_mode_error(&self) -> bool {
+        self.end_code().is_some_and(EndCode::is_mode_error)
+    }
+
+    /// Returns `true` if the PLC refused access to a read-only or protected
+    /// target.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsError;
+    ///
+    /// assert!(FinsError::plc_error(0x21, 0x01).is_permission_error());
+    /// assert!(!FinsError::Timeout.is_permission_error());
+    /// ```
+    pub fn is_permission_error(&self) -> bool {
+        self.end_code().is_some_and(EndCode::is_permission_error)
+    }
+
+    /// Returns the error description if this is a `PlcError` or `TcpError`,
+    /// with or without context.
     ///
     /// # Example
     ///
@@ -351,10 +936,9 @@ impl FinsError {
     /// ```
     pub fn description(&self) -> Option<&'static str> {
         match self {
-            Self::PlcError {
-                main_code,
-                sub_code,
-            } => Some(fins_error_description(*main_code, *sub_code)),
+            Self::PlcError { end_code } => Some(end_code.description()),
+            Self::TcpError { code } => Some(tcp_error_description(*code)),
+            Self::WithContext { source, .. } => source.description(),
             _ => None,
         }
     }
@@ -373,6 +957,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_end_codes() {
+        assert_eq!(EndCode::from_codes(0x11, 0x04), EndCode::AreaRangeExceeded);
+        assert_eq!(EndCode::from_codes(0x40, 0x01), EndCode::Aborted);
+        assert_eq!(EndCode::ReadOnly.codes(), (0x21, 0x01));
+        assert_eq!(
+            EndCode::from_codes(0x12, 0x34),
+            EndCode::Other {
+                main_code: 0x12,
+                sub_code: 0x34
+            }
+        );
+        assert_eq!(EndCode::from_codes(0x12, 0x34).sub_code(), 0x34);
+        assert_eq!(
+            EndCode::ReadOnly.to_string(),
+            "Specified area is read-only (0x2101)"
+        );
+
+        // Every described code has a name that maps back to it
+        for main_code in 0..=0xFF {
+            for sub_code in 0..=0xFF {
+                let code = EndCode::from_codes(main_code, sub_code);
+                assert_eq!(code.codes(), (main_code, sub_code));
+                let named = !matches!(code, EndCode::Other { .. });
+                assert_eq!(named, code.description() != "Unknown error code");
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_classification() {
+        let busy = FinsError::plc_error(0x02, 0x04);
+        assert!(busy.is_retryable());
+        assert!(!busy.is_mode_error());
+        assert_eq!(busy.end_code(), Some(EndCode::DestinationBusy));
+
+        let run_mode = FinsError::plc_error(0x22, 0x06);
+        assert!(run_mode.is_mode_error());
+        assert!(!run_mode.is_retryable());
+
+        let read_only = FinsError::plc_error(0x21, 0x01);
+        assert!(read_only.is_permission_error());
+        assert!(!read_only.is_retryable());
+
+        assert!(FinsError::Timeout.is_retryable());
+        assert!(FinsError::sid_mismatch(1, 2).is_retryable());
+        assert!(FinsError::command_mismatch((1, 2), (1, 1)).is_retryable());
+        assert!(FinsError::Io(io::Error::from(io::ErrorKind::ConnectionReset)).is_retryable());
+        assert!(FinsError::ConnectionReset.is_retryable());
+        assert!(!FinsError::Io(io::Error::from(io::ErrorKind::PermissionDenied)).is_retryable());
+        assert!(!FinsError::invalid_parameter("count", "zero").is_retryable());
+        assert_eq!(FinsError::Timeout.end_code(), None);
+
+        let partial = FinsError::transfer_incomplete(100, FinsError::plc_error(0x22, 0x03));
+        assert!(partial.is_mode_error());
+        assert!(FinsError::transfer_incomplete(100, FinsError::Timeout).is_retryable());
+    }
+
+    #[test]
+    fn test_error_context() {
+        let context = RequestContext {
+            operation: "writing",
+            area: MemoryArea::HR,
+            address: 10,
+            count: 2,
+            sid: 0xA1,
+        };
+        let err = FinsError::plc_error(0x21, 0x01).with_context(context);
+        assert_eq!(
+            err.to_string(),
+            "PLC error (0x21:0x01): Specified area is read-only while writing HR 10 len 2 (SID 0xA1)"
+        );
+        assert_eq!(err.context(), Some(&context));
+        assert!(matches!(
+            err.without_context(),
+            FinsError::PlcError {
+                end_code: EndCode::ReadOnly
+            }
+        ));
+        assert!(err.is_permission_error());
+        assert_eq!(err.description(), Some("Specified area is read-only"));
+        assert!(std::error::Error::source(&err).is_some());
+
+        let timeout = FinsError::Timeout.with_context(context);
+        assert!(timeout.is_retryable());
+        assert_eq!(FinsError::Timeout.context(), None);
+    }
+
     #[test]
     fn test_plc_error_display_unknown() {
         let err = FinsError::plc_error(0xFF, 0xFF);
@@ -403,6 +1075,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tcp_error_display() {
+        let err = FinsError::tcp_error(0x20);
+        assert_eq!(
+            err.to_string(),
+            "FINS/TCP error (0x00000020): All connections are in use"
+        );
+        assert_eq!(err.description(), Some("All connections are in use"));
+    }
+
+    #[test]
+    fn test_transfer_incomplete_source() {
+        use std::error::Error as _;
+
+        let plc = FinsError::plc_error(0x11, 0x03);
+        let err = FinsError::transfer_incomplete(700, FinsError::plc_error(0x11, 0x03));
+        assert_eq!(err.source().unwrap().to_string(), plc.to_string());
+        assert_eq!(err.description(), None);
+    }
+
     #[test]
     fn test_plc_error_description_method() {
         let err = FinsError::plc_error(0x11, 0x04);
@@ -48,10 +48,21 @@ use crate::error::{FinsError, Result};
 /// FINS header size in bytes.
 pub const FINS_HEADER_SIZE: usize = 10;
 
+/// Default Gateway Count placed in command headers (max hops allowed).
+pub const DEFAULT_GCT: u8 = 0x07;
+
+/// Node address (DA1) that addresses every node on a network.
+pub const BROADCAST_NODE: u8 = 0xFF;
+
+/// ICF bit 0: set when the sender does not require a response.
+const ICF_NO_RESPONSE: u8 = 0x01;
+
 /// Node address for FINS communication.
 ///
 /// Represents a network/node/unit address in the FINS protocol.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct NodeAddress {
     /// Network address (0 = local network).
     pub network: u8,
@@ -156,10 +167,35 @@ impl FinsHeader {
     /// let header = FinsHeader::new_command(dest, src, 0x01);
     /// ```
     pub fn new_command(destination: NodeAddress, source: NodeAddress, sid: u8) -> Self {
+        Self::new_command_with_options(destination, source, sid, HeaderOptions::default())
+    }
+
+    /// Creates a new command header using the given gateway count and ICF flags.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{FinsHeader, HeaderOptions, NodeAddress};
+    ///
+    /// let options = HeaderOptions::new().with_gct(0x02);
+    /// let header = FinsHeader::new_command_with_options(
+    ///     NodeAddress::new(2, 10, 0),
+    ///     NodeAddress::new(0, 1, 0),
+    ///     0x01,
+    ///     options,
+    /// );
+    /// assert_eq!(header.gct, 0x02);
+    /// ```
+    pub fn new_command_with_options(
+        destination: NodeAddress,
+        source: NodeAddress,
+        sid: u8,
+        options: HeaderOptions,
+    ) -> Self {
         Self {
-            icf: 0x80, // Command, response required
+            icf: options.icf(),
             rsv: 0x00,
-            gct: 0x07, // Gateway count (max hops allowed)
+            gct: options.gct,
             dna: destination.network,
             da1: destination.node,
             da2: destination.unit,
@@ -246,6 +282,79 @@ impl FinsHeader {
     }
 }
 
+/// Header fields that apply to every command sent by a client.
+///
+/// The defaults (GCT 0x07, response required) suit most networks.
+/// Multi-level Controller Link routes may need a specific gateway count,
+/// and some deployments clear the "response required" ICF bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct HeaderOptions {
+    /// Gateway Count (number of bridges the frame may pass through).
+    pub gct: u8,
+    /// Whether the destination should send a response (ICF bit 0 clear).
+    ///
+    /// The client still waits for a reply to every request; use
+    /// [`Client::send_no_response`](crate::Client::send_no_response) for
+    /// fire-and-forget commands.
+    pub response_required: bool,
+}
+
+impl HeaderOptions {
+    /// Creates the default options: GCT 0x07, response required.
+    pub fn new() -> Self {
+        Self {
+            gct: DEFAULT_GCT,
+            response_required: true,
+        }
+    }
+
+    /// Sets the Gateway Count.
+    pub fn with_gct(mut self, gct: u8) -> Self {
+        self.gct = gct;
+        self
+    }
+
+    /// Sets whether the destination should send a response.
+    pub fn with_response_required(mut self, required: bool) -> Self {
+        self.response_required = required;
+        self
+    }
+
+    /// Returns the ICF byte for a command header with these options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::HeaderOptions;
+    ///
+    /// assert_eq!(HeaderOptions::new().icf(), 0x80);
+    /// assert_eq!(HeaderOptions::new().with_response_required(false).icf(), 0x81);
+    /// ```
+    pub fn icf(self) -> u8 {
+        if self.response_required {
+            0x80
+        } else {
+            0x80 | ICF_NO_RESPONSE
+        }
+    }
+
+    /// Rewrites the ICF and GCT bytes of an encoded command frame.
+    pub(crate) fn apply(self, frame: &mut [u8]) {
+        if frame.len() >= FINS_HEADER_SIZE {
+            frame[0] = self.icf();
+            frame[2] = self.gct;
+        }
+    }
+}
+
+impl Default for HeaderOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -352,4 +461,26 @@ mod tests {
         let parsed = FinsHeader::from_bytes(&bytes).unwrap();
         assert_eq!(original, parsed);
     }
+
+    #[test]
+    fn test_header_options() {
+        let options = HeaderOptions::new()
+            .with_gct(0x02)
+            .with_response_required(false);
+        let header = FinsHeader::new_command_with_options(
+            NodeAddress::new(1, 10, 0),
+            NodeAddress::new(0, 1, 0),
+            0x05,
+            options,
+        );
+        assert_eq!(header.icf, 0x81);
+        assert_eq!(header.gct, 0x02);
+
+        let mut frame =
+            FinsHeader::new_command(NodeAddress::local(), NodeAddress::local(), 1).to_bytes();
+        options.apply(&mut frame);
+        assert_eq!(frame[0], 0x81);
+        assert_eq!(frame[2], 0x02);
+        assert_eq!(HeaderOptions::default().gct, DEFAULT_GCT);
+    }
 }
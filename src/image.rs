@@ -0,0 +1,744 @@
+//! Serde mapping between Rust values and PLC word images.
+//!
+//! Requires the `serde` feature. Any type implementing `Serialize` /
+//! `Deserialize` can be laid out in consecutive words with [`to_image`] and
+//! decoded with [`from_image`], or moved in one call with
+//! [`Client::read_image`](crate::Client::read_image) and
+//! [`Client::write_image`](crate::Client::write_image). Unlike
+//! [`FinsStruct`](crate::FinsStruct), fields cannot be placed at explicit
+//! offsets; the layout follows a fixed convention:
+//!
+//! | Rust type | Words |
+//! |-----------|-------|
+//! | `bool`, `u8`, `i8`, `u16`, `i16`, `char` | 1 |
+//! | `u32`, `i32`, `f32` | 2, in the layout's [`WordOrder`] |
+//! | `u64`, `i64`, `f64` | 4, in the layout's [`WordOrder`] |
+//! | `String`, `&str` | [`ImageLayout::string_words`], 2 characters per word, zero-padded |
+//! | struct, tuple, array `[T; N]` | fields in declaration order, no padding |
+//! | unit enum variant | 1, the variant index |
+//! | `()`, unit struct | 0 |
+//!
+//! Types without a fixed size (`Vec`, maps, `Option`, enum variants with
+//! data) are rejected with `FinsError::InvalidParameter`.
+//!
+//! # Example
+//!
+//! ```
+//! use omron_fins::image::{from_image, to_image, ImageLayout};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Recipe {
+//!     batch: u16,
+//!     temperature: f32,
+//!     name: String,
+//! }
+//!
+//! let layout = ImageLayout::new().with_string_words(4);
+//! let recipe = Recipe { batch: 7, temperature: 1.5, name: "PASTA".into() };
+//!
+//! let words = to_image(&recipe, &layout)?;
+//! assert_eq!(words, vec![7, 0x0000, 0x3FC0, 0x4150, 0x5453, 0x0041, 0x0000]);
+//! assert_eq!(layout.word_count::<Recipe>()?, 7);
+//! assert_eq!(from_image::<Recipe>(&words, &layout)?, recipe);
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use std::fmt::Display;
+
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::ser::{self, Serialize};
+
+use crate::error::{FinsError, Result};
+use crate::types::WordOrder;
+use crate::utils;
+
+/// Default number of words reserved for each string (32 characters).
+pub const DEFAULT_STRING_WORDS: u16 = 16;
+
+/// Layout options for a word image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImageLayout {
+    word_order: Option<WordOrder>,
+    string_words: u16,
+}
+
+impl Default for ImageLayout {
+    fn default() -> Self {
+        Self {
+            word_order: None,
+            string_words: DEFAULT_STRING_WORDS,
+        }
+    }
+}
+
+impl ImageLayout {
+    /// Creates the default layout: [`DEFAULT_STRING_WORDS`] words per string
+    /// and the client's word order.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the word order of 32- and 64-bit values, overriding the
+    /// client's [`ClientConfig::word_order`](crate::ClientConfig::word_order).
+    pub fn with_word_order(mut self, order: WordOrder) -> Self {
+        self.word_order = Some(order);
+        self
+    }
+
+    /// Sets the number of words every string occupies.
+    pub fn with_string_words(mut self, words: u16) -> Self {
+        self.string_words = words;
+        self
+    }
+
+    /// Returns the word order set with [`with_word_order`](Self::with_word_order),
+    /// if any. Outside a client, [`WordOrder::default`] is used.
+    pub fn word_order(&self) -> Option<WordOrder> {
+        self.word_order
+    }
+
+    /// Returns the number of words every string occupies.
+    pub fn string_words(&self) -> u16 {
+        self.string_words
+    }
+
+    /// Returns the number of words a `T` occupies in this layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `T` has no fixed size.
+    pub fn word_count<T: DeserializeOwned>(&self) -> Result<u16> {
+        let mut deserializer = ImageDeserializer::sizing(self);
+        T::deserialize(&mut deserializer)?;
+        u16::try_from(deserializer.pos).map_err(|_| {
+            FinsError::invalid_parameter(
+                "value",
+                format!("{} words exceed the 65535-word limit", deserializer.pos),
+            )
+        })
+    }
+
+    fn order(&self) -> WordOrder {
+        self.word_order.unwrap_or_default()
+    }
+}
+
+/// Lays `value` out as PLC words.
+///
+/// # Errors
+///
+/// Returns `FinsError::InvalidParameter` if the value contains a type
+/// without a fixed size or a string longer than
+/// [`ImageLayout::string_words`].
+pub fn to_image<T: Serialize + ?Sized>(value: &T, layout: &ImageLayout) -> Result<Vec<u16>> {
+    let mut serializer = ImageSerializer {
+        words: Vec::new(),
+        layout,
+    };
+    value.serialize(&mut serializer)?;
+    Ok(serializer.words)
+}
+
+/// Decodes a `T` from the start of `words`.
+///
+/// Words past the end of the value are ignored.
+///
+/// # Errors
+///
+/// Returns `FinsError::InvalidResponse` if `words` is too short or holds an
+/// invalid value (e.g., an unknown enum index), or
+/// `FinsError::InvalidParameter` if `T` has no fixed size.
+pub fn from_image<T: DeserializeOwned>(words: &[u16], layout: &ImageLayout) -> Result<T> {
+    T::deserialize(&mut ImageDeserializer::new(words, layout))
+}
+
+impl ser::Error for FinsError {
+    fn custom<T: Display>(msg: T) -> Self {
+        FinsError::invalid_parameter("value", msg.to_string())
+    }
+}
+
+impl de::Error for FinsError {
+    fn custom<T: Display>(msg: T) -> Self {
+        FinsError::invalid_response(msg.to_string())
+    }
+}
+
+fn unsupported(kind: &str) -> FinsError {
+    FinsError::invalid_parameter(
+        "value",
+        format!("{} has no fixed size in a word image", kind),
+    )
+}
+
+struct ImageSerializer<'a> {
+    words: Vec<u16>,
+    layout: &'a ImageLayout,
+}
+
+impl ImageSerializer<'_> {
+    fn push_native(&mut self, mut bytes: impl AsMut<[u8]>) {
+        let bytes = bytes.as_mut();
+        self.layout.order().arrange(bytes);
+        self.words.extend(
+            bytes
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]])),
+        );
+    }
+}
+
+impl<'a> ser::Serializer for &mut ImageSerializer<'a> {
+    type Ok = ();
+    type Error = FinsError;
+    type SerializeSeq = ser::Impossible<(), FinsError>;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = ser::Impossible<(), FinsError>;
+    type SerializeMap = ser::Impossible<(), FinsError>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = ser::Impossible<(), FinsError>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.words.push(v as u16);
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.serialize_i16(v as i16)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.words.push(v as u16);
+        Ok(())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.push_native(v.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.push_native(v.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.serialize_u16(v as u16)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.words.push(v);
+        Ok(())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.push_native(v.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.push_native(v.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.push_native(v.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.push_native(v.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        let code = u16::try_from(v as u32).map_err(|_| {
+            FinsError::invalid_parameter("value", format!("character {:?} exceeds one word", v))
+        })?;
+        self.serialize_u16(code)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        let capacity = self.layout.string_words as usize * 2;
+        if v.len() > capacity {
+            return Err(FinsError::invalid_parameter(
+                "value",
+                format!(
+                    "string of {} bytes exceeds {} words",
+                    v.len(),
+                    self.layout.string_words
+                ),
+            ));
+        }
+        let mut packed = utils::pack_string_bytes(v.as_bytes());
+        packed.resize(self.layout.string_words as usize, 0);
+        self.words.extend(packed);
+        Ok(())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<()> {
+        Err(unsupported("a byte buffer"))
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        Err(unsupported("an Option"))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<()> {
+        Err(unsupported("an Option"))
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        let index = u16::try_from(variant_index)
+            .map_err(|_| FinsError::invalid_parameter("value", "enum has too many variants"))?;
+        self.serialize_u16(index)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<()> {
+        Err(unsupported("an enum variant with data"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(unsupported("a sequence"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(unsupported("an enum variant with data"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(unsupported("a map"))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(unsupported("an enum variant with data"))
+    }
+}
+
+impl ser::SerializeTuple for &mut ImageSerializer<'_> {
+    type Ok = ();
+    type Error = FinsError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut ImageSerializer<'_> {
+    type Ok = ();
+    type Error = FinsError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for &mut ImageSerializer<'_> {
+    type Ok = ();
+    type Error = FinsError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+struct ImageDeserializer<'a> {
+    words: &'a [u16],
+    pos: usize,
+    layout: &'a ImageLayout,
+    /// Reads zeros past the end instead of failing, to measure a type.
+    sizing: bool,
+}
+
+impl<'a> ImageDeserializer<'a> {
+    fn new(words: &'a [u16], layout: &'a ImageLayout) -> Self {
+        Self {
+            words,
+            pos: 0,
+            layout,
+            sizing: false,
+        }
+    }
+
+    fn sizing(layout: &'a ImageLayout) -> Self {
+        Self {
+            words: &[],
+            pos: 0,
+            layout,
+            sizing: true,
+        }
+    }
+
+    fn take(&mut self, count: usize) -> Result<Vec<u16>> {
+        let end = self.pos + count;
+        let words = if self.sizing {
+            vec![0; count]
+        } else {
+            self.words
+                .get(self.pos..end)
+                .ok_or_else(|| {
+                    FinsError::invalid_response(format!(
+                        "word image too short: need {} words, got {}",
+                        end,
+                        self.words.len()
+                    ))
+                })?
+                .to_vec()
+        };
+        self.pos = end;
+        Ok(words)
+    }
+
+    fn word(&mut self) -> Result<u16> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn native<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes: Vec<u8> = self
+            .take(N / 2)?
+            .iter()
+            .flat_map(|word| word.to_be_bytes())
+            .collect();
+        Ok(self.layout.order().native(&bytes))
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut ImageDeserializer<'_> {
+    type Error = FinsError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported("a self-describing value"))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bool(self.word()? != 0)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i16(self.word()? as i16)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i16(self.word()? as i16)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i32(i32::from_be_bytes(self.native()?))
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i64(i64::from_be_bytes(self.native()?))
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u16(self.word()?)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u16(self.word()?)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u32(u32::from_be_bytes(self.native()?))
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u64(u64::from_be_bytes(self.native()?))
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f32(f32::from_be_bytes(self.native()?))
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f64(f64::from_be_bytes(self.native()?))
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let code = self.word()?;
+        let value = char::from_u32(code as u32).ok_or_else(|| {
+            FinsError::invalid_response(format!("0x{:04X} is not a character", code))
+        })?;
+        visitor.visit_char(value)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let words = self.take(self.layout.string_words as usize)?;
+        let bytes = utils::unpack_string_bytes(&words);
+        visitor.visit_string(String::from_utf8_lossy(&bytes).to_string())
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported("a byte buffer"))
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported("a byte buffer"))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported("an Option"))
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported("a sequence"))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(Fields {
+            de: self,
+            remaining: len,
+        })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported("a map"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let index = self.word()?;
+        if index as usize >= variants.len() {
+            return Err(FinsError::invalid_response(format!(
+                "enum index {} out of range for {} variants",
+                index,
+                variants.len()
+            )));
+        }
+        visitor.visit_enum((index as u32).into_deserializer())
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported("an identifier"))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported("an ignored value"))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// Sequential access to the fields of a struct or tuple.
+struct Fields<'a, 'b> {
+    de: &'a mut ImageDeserializer<'b>,
+    remaining: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for Fields<'_, '_> {
+    type Error = FinsError;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Mode {
+        Idle,
+        Running,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Station {
+        id: u8,
+        enabled: bool,
+        mode: Mode,
+        counts: [i32; 2],
+        total: u64,
+        label: String,
+    }
+
+    fn station() -> Station {
+        Station {
+            id: 3,
+            enabled: true,
+            mode: Mode::Running,
+            counts: [-1, 2],
+            total: 0x0102_0304_0506_0708,
+            label: "ST3".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_image_layout() {
+        let layout = ImageLayout::new().with_string_words(2);
+        let words = to_image(&station(), &layout).unwrap();
+        assert_eq!(
+            words,
+            vec![3, 1, 1, 0xFFFF, 0xFFFF, 2, 0, 0x0708, 0x0506, 0x0304, 0x0102, 0x5453, 0x0033]
+        );
+        assert_eq!(layout.word_count::<Station>().unwrap(), 13);
+
+        let high = layout.with_word_order(WordOrder::HighFirst);
+        assert_eq!(
+            to_image(&station(), &high).unwrap()[7..11],
+            [0x0102, 0x0304, 0x0506, 0x0708]
+        );
+    }
+
+    #[test]
+    fn test_image_round_trip() {
+        for order in [WordOrder::LowFirst, WordOrder::HighFirstByteSwap] {
+            let layout = ImageLayout::new().with_word_order(order);
+            let words = to_image(&station(), &layout).unwrap();
+            assert_eq!(words.len(), 11 + DEFAULT_STRING_WORDS as usize);
+            assert_eq!(from_image::<Station>(&words, &layout).unwrap(), station());
+        }
+    }
+
+    #[test]
+    fn test_image_errors() {
+        let layout = ImageLayout::new().with_string_words(1);
+        assert!(matches!(
+            to_image("ABC", &layout),
+            Err(FinsError::InvalidParameter { .. })
+        ));
+        assert!(to_image(&vec![1u16], &layout).is_err());
+        assert!(to_image(&Some(1u16), &layout).is_err());
+        assert!(layout.word_count::<Vec<u16>>().is_err());
+
+        assert!(matches!(
+            from_image::<(u16, u32)>(&[1, 2], &layout),
+            Err(FinsError::InvalidResponse { .. })
+        ));
+        assert!(from_image::<Mode>(&[2], &layout).is_err());
+    }
+}
@@ -22,6 +22,10 @@ pub enum FinsMemoryArea {
     HR,
     DM,
     AR,
+    TIM,
+    CNT,
+    IR,
+    CF,
 }
 
 /// Data types supported by Omron PLCs.
@@ -68,6 +72,10 @@ fn memory_area_from_enum(area: FinsMemoryArea) -> MemoryArea {
         FinsMemoryArea::HR => MemoryArea::HR,
         FinsMemoryArea::DM => MemoryArea::DM,
         FinsMemoryArea::AR => MemoryArea::AR,
+        FinsMemoryArea::TIM => MemoryArea::TIM,
+        FinsMemoryArea::CNT => MemoryArea::CNT,
+        FinsMemoryArea::IR => MemoryArea::IR,
+        FinsMemoryArea::CF => MemoryArea::CF,
     }
 }
 
@@ -79,8 +87,12 @@ fn parse_memory_area(area: &str) -> Result<MemoryArea> {
         "HR" => Ok(MemoryArea::HR),
         "DM" => Ok(MemoryArea::DM),
         "AR" => Ok(MemoryArea::AR),
+        "TIM" => Ok(MemoryArea::TIM),
+        "CNT" => Ok(MemoryArea::CNT),
+        "IR" => Ok(MemoryArea::IR),
+        "CF" => Ok(MemoryArea::CF),
         _ => Err(Error::from_reason(format!(
-            "Área de memória inválida: '{}'. Valores válidos: CIO, WR, HR, DM, AR",
+            "Área de memória inválida: '{}'. Valores válidos: CIO, WR, HR, DM, AR, TIM, CNT, IR, CF",
             area
         ))),
     }
@@ -285,7 +297,7 @@ pub struct FinsClient {
 impl FinsClient {
     /// Creates a new FINS client.
     ///
-    /// @param host - PLC IP address (e.g., "192.168.1.250")
+    /// @param host - PLC IP address or hostname (e.g., "192.168.1.250")
     /// @param sourceNode - Source node number (this client)
     /// @param destNode - Destination node number (the PLC)
     /// @param options - Advanced options (optional)
@@ -296,11 +308,11 @@ impl FinsClient {
         dest_node: u8,
         options: Option<JsClientOptions>,
     ) -> Result<Self> {
-        let ip: std::net::Ipv4Addr = host
-            .parse()
-            .map_err(|e| Error::from_reason(format!("IP inválido '{}': {}", host, e)))?;
-
-        let mut config = ClientConfig::new(ip, source_node, dest_node);
+        let mut config = match host.parse::<std::net::Ipv4Addr>() {
+            Ok(ip) => ClientConfig::new(ip, source_node, dest_node),
+            Err(_) => ClientConfig::from_host(&host, source_node, dest_node)
+                .map_err(|e| Error::from_reason(format!("Host inválido '{}': {}", host, e)))?,
+        };
 
         if let Some(opts) = options {
             if let Some(port) = opts.port {
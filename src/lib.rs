@@ -3,8 +3,10 @@
 //! A Rust library for communicating with Omron PLCs using the FINS (Factory Interface Network Service) protocol.
 //!
 //! This is a **protocol-only** library—no business logic, polling, schedulers,
-//! or application-level features. Each call produces exactly 1 request and 1 response.
-//! No automatic retries, caching, or reconnection.
+//! or application-level features. Each call produces 1 request and 1 response,
+//! unless a block must be split into several commands. No caching, and no
+//! retries or reconnection beyond resending after a stale response and the
+//! FINS/TCP re-handshake.
 //!
 //! ## Features
 //!
@@ -74,6 +76,11 @@
 //! | [`MemoryArea::HR`] | Holding area - retentive bits/words | ✓ | ✓ |
 //! | [`MemoryArea::DM`] | Data Memory - numeric data storage | ✓ | ✗ |
 //! | [`MemoryArea::AR`] | Auxiliary Relay - system status/control | ✓ | ✓ |
+//! | [`MemoryArea::TIM`] | Timer present values / completion flags | ✓ | ✓ |
+//! | [`MemoryArea::CNT`] | Counter present values / completion flags | ✓ | ✓ |
+//! | [`MemoryArea::IR`] | Index Registers (32-bit) | ✓ | ✗ |
+//! | [`MemoryArea::CF`] | Condition Flags / Clock Pulses (read-only) | ✗ | ✓ |
+//! | [`MemoryArea::Custom`] | Any area by raw FINS codes | ✓ | optional |
 //!
 //! ## Core Operations
 //!
@@ -180,7 +187,7 @@
 //! All operations return [`Result<T, FinsError>`]. The library never panics in public code.
 //!
 //! ```no_run
-//! use omron_fins::{Client, ClientConfig, MemoryArea, FinsError};
+//! use omron_fins::{Client, ClientConfig, EndCode, MemoryArea, FinsError};
 //! use std::net::Ipv4Addr;
 //!
 //! let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0);
@@ -189,9 +196,10 @@
 //! match client.read(MemoryArea::DM, 100, 10) {
 //!     Ok(data) => println!("Data: {:?}", data),
 //!     Err(FinsError::Timeout) => println!("Communication timeout"),
-//!     Err(FinsError::PlcError { main_code, sub_code }) => {
-//!         println!("PLC error: main=0x{:02X}, sub=0x{:02X}", main_code, sub_code);
+//!     Err(FinsError::PlcError { end_code: EndCode::AreaRangeExceeded }) => {
+//!         println!("Address range exceeds the area");
 //!     }
+//!     Err(FinsError::PlcError { end_code }) => println!("PLC error: {}", end_code),
 //!     Err(FinsError::InvalidAddressing { reason }) => {
 //!         println!("Invalid addressing: {}", reason);
 //!     }
@@ -229,12 +237,37 @@
 #![warn(missing_docs)]
 #![warn(rust_2018_idioms)]
 
+mod address;
+mod batch;
+mod block;
 mod client;
 mod command;
+#[cfg(feature = "serde")]
+mod config_serde;
+mod datetime;
+mod discovery;
+mod dissect;
 mod error;
 mod header;
+#[cfg(feature = "serde")]
+pub mod image;
+mod logging;
 mod memory;
+mod metrics;
+mod multi;
+mod observer;
+mod pipeline;
+mod profile;
+pub mod record;
+mod recorder;
+mod resolver;
 mod response;
+mod route;
+mod scale;
+mod server;
+mod stats;
+mod tag;
+mod tcp;
 mod transport;
 pub mod types;
 pub mod utils;
@@ -243,15 +276,51 @@ pub mod utils;
 mod js_bindings;
 
 // Public re-exports
-pub use client::{Client, ClientConfig};
+pub use address::{AreaRange, FinsAddress};
+pub use batch::{BatchHandle, BatchRead, BatchResults, BatchValue};
+pub use block::{WordBlock, WordBlockBuilder};
+pub use client::{Client, ClientConfig, RequestOptions, DEFAULT_SID_RETRIES};
 pub use command::{
-    Address, FillCommand, ForceSpec, ForcedBit, ForcedSetResetCancelCommand, ForcedSetResetCommand,
-    MultiReadSpec, MultipleReadCommand, PlcMode, ReadBitCommand, ReadWordCommand, RunCommand,
-    StopCommand, TransferCommand, WriteBitCommand, WriteWordCommand, MAX_WORDS_PER_COMMAND,
+    AccessRightAction, AccessRightCommand, Address, ConnectionDataReadCommand,
+    ControllerDataReadCommand, EchoTestCommand, ErrorLogClearCommand, ErrorLogReadCommand,
+    FileDisk, FileRenameCommand, FileTransferDirection, FileWriteCommand, FileWriteMode,
+    FillCommand, ForceSpec, ForcedBit, ForcedSetResetCancelCommand, ForcedSetResetCommand,
+    MemoryFileTransferCommand, MessageClearCommand, MessageReadCommand, MultiReadSpec,
+    MultipleReadCommand, PlcMode, ProgramFileTransferCommand, RawCommand, ReadBitCommand,
+    ReadWordCommand, ResetCommand, RunCommand, StopCommand, TransferCommand, WriteBitCommand,
+    WriteWordCommand, MAX_BITS_PER_COMMAND, MAX_CONNECTION_DATA_UNITS, MAX_ECHO_DATA,
+    MAX_ERROR_LOG_RECORDS, MAX_FILE_CHUNK, MAX_MULTI_READ_ITEMS, MAX_WORDS_PER_COMMAND,
+    MESSAGE_COUNT,
+};
+pub use datetime::TimeFormat;
+pub use discovery::{discover, DiscoveredNode, DiscoveryConfig};
+pub use dissect::describe_frame;
+pub use error::{fins_error_description, EndCode, FinsError, RequestContext, Result, WordMismatch};
+pub use header::{
+    FinsHeader, HeaderOptions, NodeAddress, BROADCAST_NODE, DEFAULT_GCT, FINS_HEADER_SIZE,
 };
-pub use error::{fins_error_description, FinsError, Result};
-pub use header::{FinsHeader, NodeAddress, FINS_HEADER_SIZE};
 pub use memory::MemoryArea;
-pub use response::FinsResponse;
-pub use transport::{UdpTransport, DEFAULT_FINS_PORT, DEFAULT_TIMEOUT, MAX_PACKET_SIZE};
-pub use types::{DataType, PlcValue};
+pub use metrics::MetricsSink;
+pub use multi::MultiClient;
+pub use observer::Direction;
+#[cfg(feature = "derive")]
+pub use omron_fins_derive::FinsStruct;
+pub use profile::CpuProfile;
+pub use record::{FinsField, FinsStruct};
+pub use recorder::{SessionEntry, SessionRecorder};
+pub use resolver::{Resolver, SystemResolver};
+pub use response::{
+    AccessRight, ControllerData, EndCodeWarnings, ErrorLogEntry, FinsResponse, MultiReadResult,
+    PlcDateTime, UnitInfo,
+};
+pub use route::{Route, MAX_ROUTE_HOPS};
+pub use scale::Scale;
+pub use server::{FinsServer, MemoryImage, Reply, ServerHandle, SIMULATED_AREAS};
+pub use stats::ClientStats;
+pub use tag::{Tag, TagKind, TagTable, TagValue};
+pub use tcp::{tcp_error_description, TcpTransport, FINS_TCP_HEADER_SIZE, FINS_TCP_MAGIC};
+pub use transport::{
+    SocketOptions, TransportProtocol, UdpTransport, DEFAULT_FINS_PORT, DEFAULT_TIMEOUT,
+    MAX_PACKET_SIZE,
+};
+pub use types::{ByteOrder, DataType, LengthPrefix, PlcValue, StringEncoding, WordOrder};
@@ -0,0 +1,108 @@
+//! Records for the [`log`](https://docs.rs/log) crate.
+//!
+//! With the `log` feature, the client emits debug records for every frame
+//! sent and received, trace records with a hex dump of each frame, and warnings for
+//! SID mismatches and stale packets drained before a retry. Without the
+//! feature the macros compile to nothing and their arguments are not
+//! evaluated beyond borrowing.
+
+use std::fmt;
+
+use crate::utils::hexdump;
+
+/// Emits a record at `$level` if the `log` feature is enabled.
+macro_rules! log_record {
+    ($level:ident, $($arg:tt)+) => {{
+        #[cfg(feature = "log")]
+        ::log::$level!(target: "omron_fins", $($arg)+);
+        #[cfg(not(feature = "log"))]
+        let _ = format_args!($($arg)+);
+    }};
+}
+
+macro_rules! log_trace {
+    ($($arg:tt)+) => { $crate::logging::log_record!(trace, $($arg)+) };
+}
+
+macro_rules! log_debug {
+    ($($arg:tt)+) => { $crate::logging::log_record!(debug, $($arg)+) };
+}
+
+macro_rules! log_warn {
+    ($($arg:tt)+) => { $crate::logging::log_record!(warn, $($arg)+) };
+}
+
+pub(crate) use {log_debug, log_record, log_trace, log_warn};
+
+/// Formats a frame with [`hexdump`], only when a record is actually
+/// written.
+pub(crate) struct Hex<'a>(pub(crate) &'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hexdump(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(
+            Hex(&[0x80, 0x00, 0x0A]).to_string(),
+            format!("0000  80 00 0A{}  |...|", " ".repeat(40))
+        );
+        assert_eq!(Hex(&[]).to_string(), "");
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_log_records() {
+        use crate::client::{Client, ClientConfig};
+        use crate::memory::MemoryArea;
+        use crate::server::FinsServer;
+        use std::net::Ipv4Addr;
+        use std::sync::Mutex;
+
+        static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        struct Capture;
+
+        impl log::Log for Capture {
+            fn enabled(&self, _: &log::Metadata<'_>) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record<'_>) {
+                RECORDS
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .push(format!("{} {}", record.level(), record.args()));
+            }
+
+            fn flush(&self) {}
+        }
+
+        log::set_logger(&Capture).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let server = FinsServer::bind("127.0.0.1:0").unwrap().spawn().unwrap();
+        let config =
+            ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(server.local_addr().port());
+        let client = Client::new(config).unwrap();
+        client.read(MemoryArea::DM, 0, 1).unwrap();
+
+        let records = RECORDS.lock().unwrap();
+        assert!(records
+            .iter()
+            .any(|r| r.starts_with("DEBUG sending 18 bytes")));
+        assert!(records
+            .iter()
+            .any(|r| r.starts_with("DEBUG received 16 bytes")));
+        assert!(records
+            .iter()
+            .any(|r| r.starts_with("TRACE sent:\n0000  80 00 ")));
+    }
+}
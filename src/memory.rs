@@ -13,6 +13,11 @@
 //! | HR | Holding area - retentive bits/words | ✓ | ✓ |
 //! | DM | Data Memory - numeric data storage | ✓ | ✗ |
 //! | AR | Auxiliary Relay - system status/control | ✓ | ✓ |
+//! | TIM | Timer present values / completion flags | ✓ | ✓ |
+//! | CNT | Counter present values / completion flags | ✓ | ✓ |
+//! | IR | Index Registers (32-bit) | ✓ | ✗ |
+//! | CF | Condition Flags / Clock Pulses (read-only) | ✗ | ✓ |
+//! | Custom | Any area by raw FINS codes | ✓ | optional |
 //!
 //! # Example
 //!
@@ -58,6 +63,7 @@ use crate::error::{FinsError, Result};
 /// }
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryArea {
     /// CIO (Core I/O) area - general purpose I/O and internal relays.
     CIO,
@@ -69,6 +75,48 @@ pub enum MemoryArea {
     DM,
     /// AR (Auxiliary Relay) area - system status and control bits/words.
     AR,
+    /// TIM (Timer) area - timer present values (words) and completion
+    /// flags (bits), addressed by timer number.
+    TIM,
+    /// CNT (Counter) area - counter present values (words) and completion
+    /// flags (bits), addressed by counter number.
+    ///
+    /// Counters share the timer area codes; the counter number is offset
+    /// by 0x8000 on the wire (CS/CJ/CP addressing).
+    CNT,
+    /// IR (Index Register) area - 32-bit registers IR0-IR15.
+    ///
+    /// Addresses and counts are in registers, and each register occupies
+    /// two words (high word first). IR0 is address 0x0100 on the wire. Prefer
+    /// [`Client::read_ir`](crate::Client::read_ir) and
+    /// [`Client::write_ir`](crate::Client::write_ir).
+    IR,
+    /// CF (Condition Flags) area - clock pulses and CPU condition flags
+    /// (P_On, P_1s, P_ER, P_CY, ...). Bit access only, read-only.
+    ///
+    /// Addresses are the PLC's own: clock pulses are in word 0 and
+    /// condition flags in words 0x1000-0x1001 (4096-4097).
+    CF,
+    /// An area addressed by raw FINS area codes, for vendor- or
+    /// firmware-specific areas not modelled above.
+    ///
+    /// No capacity is known, so only the 16-bit address range is checked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::MemoryArea;
+    ///
+    /// // EM bank 0 (word code 0xA0, bit code 0x20)
+    /// let em0 = MemoryArea::Custom { word_code: 0xA0, bit_code: Some(0x20) };
+    /// assert!(em0.supports_bit_access());
+    /// ```
+    Custom {
+        /// Area code for word access.
+        word_code: u8,
+        /// Area code for bit access, if the area supports it.
+        bit_code: Option<u8>,
+    },
 }
 
 impl MemoryArea {
@@ -82,6 +130,11 @@ impl MemoryArea {
             MemoryArea::HR => 0xB2,
             MemoryArea::DM => 0x82,
             MemoryArea::AR => 0xB3,
+            MemoryArea::TIM | MemoryArea::CNT => 0x89,
+            MemoryArea::IR => 0xDC,
+            // Bit-only; word commands reject CF before encoding
+            MemoryArea::CF => 0x07,
+            MemoryArea::Custom { word_code, .. } => word_code,
         }
     }
 
@@ -100,11 +153,75 @@ impl MemoryArea {
                 "DM area does not support bit access",
             )),
             MemoryArea::AR => Ok(0x33),
+            MemoryArea::TIM | MemoryArea::CNT => Ok(0x09),
+            MemoryArea::IR => Err(FinsError::invalid_addressing(
+                "IR area does not support bit access",
+            )),
+            MemoryArea::CF => Ok(0x07),
+            MemoryArea::Custom { bit_code, .. } => bit_code.ok_or_else(|| {
+                FinsError::invalid_addressing(format!("{} does not support bit access", self))
+            }),
+        }
+    }
+
+    /// Checks that this memory area supports word access.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidAddressing` for bit-only areas (CF).
+    pub(crate) fn check_word_access(self) -> Result<()> {
+        if self.supports_word_access() {
+            Ok(())
+        } else {
+            Err(FinsError::invalid_addressing(format!(
+                "{} area does not support word access",
+                self
+            )))
+        }
+    }
+
+    /// Returns the bit access code, optionally allowing DM bit access.
+    ///
+    /// CS/CJ-series and newer CPUs accept bit access to DM (code 0x02);
+    /// older families reject it, so it is opt-in per client.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`bit_code`](Self::bit_code) for every other case.
+    pub(crate) fn bit_code_with(self, dm_bit_access: bool) -> Result<u8> {
+        match self {
+            MemoryArea::DM if dm_bit_access => Ok(0x02),
+            _ => self.bit_code(),
+        }
+    }
+
+    /// Returns the offset added to addresses in this area on the wire.
+    ///
+    /// Counters live in the timer area code range starting at 0x8000, and
+    /// index registers start at 0x0100.
+    pub(crate) fn address_offset(self) -> u16 {
+        match self {
+            MemoryArea::CNT => 0x8000,
+            MemoryArea::IR => 0x0100,
+            _ => 0,
+        }
+    }
+
+    /// Returns the number of 16-bit words per addressable item.
+    ///
+    /// Index registers are 32 bits wide; every other area is word-addressed.
+    pub(crate) fn words_per_item(self) -> u16 {
+        match self {
+            MemoryArea::IR => 2,
+            _ => 1,
         }
     }
 
     /// Returns whether this memory area supports bit access.
     ///
+    /// DM reports `false`; its bits are only reachable on CPUs that accept
+    /// them, with [`ClientConfig::dm_bit_access`](crate::ClientConfig::dm_bit_access).
+    ///
     /// # Example
     ///
     /// ```
@@ -114,7 +231,55 @@ impl MemoryArea {
     /// assert!(!MemoryArea::DM.supports_bit_access());
     /// ```
     pub fn supports_bit_access(self) -> bool {
-        !matches!(self, MemoryArea::DM)
+        match self {
+            MemoryArea::DM | MemoryArea::IR => false,
+            MemoryArea::Custom { bit_code, .. } => bit_code.is_some(),
+            _ => true,
+        }
+    }
+
+    /// Returns whether this memory area supports word access.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::MemoryArea;
+    ///
+    /// assert!(MemoryArea::DM.supports_word_access());
+    /// assert!(!MemoryArea::CF.supports_word_access());
+    /// ```
+    pub fn supports_word_access(self) -> bool {
+        !matches!(self, MemoryArea::CF)
+    }
+
+    /// Returns whether this memory area can be written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::MemoryArea;
+    ///
+    /// assert!(MemoryArea::CIO.is_writable());
+    /// assert!(!MemoryArea::CF.is_writable());
+    /// ```
+    pub fn is_writable(self) -> bool {
+        !matches!(self, MemoryArea::CF)
+    }
+
+    /// Checks that this memory area can be written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` for read-only areas (CF).
+    pub(crate) fn check_writable(self) -> Result<()> {
+        if self.is_writable() {
+            Ok(())
+        } else {
+            Err(FinsError::invalid_parameter(
+                "area",
+                format!("{} area is read-only", self),
+            ))
+        }
     }
 
     /// Returns the maximum number of words supported by this memory area.
@@ -125,6 +290,11 @@ impl MemoryArea {
     /// - HR: 512 words
     /// - DM: 4096 words
     /// - AR: 1024 words
+    /// - TIM: 4096 timers
+    /// - CNT: 4096 counters
+    /// - IR: 16 registers
+    /// - CF: 2 words (the condition flags; see [`MemoryArea::CF`])
+    /// - Custom: 65535 words (unknown capacity)
     ///
     /// # Example
     ///
@@ -141,6 +311,11 @@ impl MemoryArea {
             MemoryArea::HR => 512,
             MemoryArea::DM => 4096,
             MemoryArea::AR => 1024,
+            MemoryArea::TIM => 4096,
+            MemoryArea::CNT => 4096,
+            MemoryArea::IR => 16,
+            MemoryArea::CF => 2,
+            MemoryArea::Custom { .. } => u16::MAX,
         }
     }
 
@@ -162,8 +337,12 @@ impl MemoryArea {
     ///
     /// assert!(MemoryArea::CIO.check_bounds(0, 10).is_ok());
     /// assert!(MemoryArea::WR.check_bounds(500, 20).is_err());
+    /// assert!(MemoryArea::CF.check_bounds(0x1000, 2).is_ok());
     /// ```
     pub fn check_bounds(self, address: u16, count: u16) -> Result<()> {
+        if self == MemoryArea::CF {
+            return check_condition_flag_bounds(address, count);
+        }
         let max = self.max_words();
         // Check if address + count - 1 exceeds max-1, which simplifies to address + count > max.
         // Also safeguard against overflow
@@ -188,6 +367,27 @@ impl MemoryArea {
     }
 }
 
+/// Checks a range in the CF area, which holds the clock pulses at word 0 and
+/// the condition flags at words 0x1000-0x1001.
+fn check_condition_flag_bounds(address: u16, count: u16) -> Result<()> {
+    let fits = |start: u16, words: u16| {
+        address >= start
+            && address
+                .checked_add(count)
+                .is_some_and(|end| end <= start + words)
+    };
+    if fits(0x0000, 1) || fits(0x1000, 2) {
+        return Ok(());
+    }
+    Err(FinsError::invalid_parameter(
+        "address/count",
+        format!(
+            "CF {}+{} is outside the clock pulses (word 0) and condition flags (words 4096-4097)",
+            address, count
+        ),
+    ))
+}
+
 impl std::fmt::Display for MemoryArea {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -196,6 +396,11 @@ impl std::fmt::Display for MemoryArea {
             MemoryArea::HR => write!(f, "HR"),
             MemoryArea::DM => write!(f, "DM"),
             MemoryArea::AR => write!(f, "AR"),
+            MemoryArea::TIM => write!(f, "TIM"),
+            MemoryArea::CNT => write!(f, "CNT"),
+            MemoryArea::IR => write!(f, "IR"),
+            MemoryArea::CF => write!(f, "CF"),
+            MemoryArea::Custom { word_code, .. } => write!(f, "Custom(0x{:02X})", word_code),
         }
     }
 }
@@ -211,6 +416,9 @@ mod tests {
         assert_eq!(MemoryArea::HR.word_code(), 0xB2);
         assert_eq!(MemoryArea::DM.word_code(), 0x82);
         assert_eq!(MemoryArea::AR.word_code(), 0xB3);
+        assert_eq!(MemoryArea::TIM.word_code(), 0x89);
+        assert_eq!(MemoryArea::CNT.word_code(), 0x89);
+        assert_eq!(MemoryArea::IR.word_code(), 0xDC);
     }
 
     #[test]
@@ -220,6 +428,43 @@ mod tests {
         assert_eq!(MemoryArea::HR.bit_code().unwrap(), 0x32);
         assert!(MemoryArea::DM.bit_code().is_err());
         assert_eq!(MemoryArea::AR.bit_code().unwrap(), 0x33);
+        assert_eq!(MemoryArea::TIM.bit_code().unwrap(), 0x09);
+        assert_eq!(MemoryArea::CNT.bit_code().unwrap(), 0x09);
+        assert!(MemoryArea::IR.bit_code().is_err());
+        assert_eq!(MemoryArea::CF.bit_code().unwrap(), 0x07);
+    }
+
+    #[test]
+    fn test_custom_area() {
+        let area = MemoryArea::Custom {
+            word_code: 0xA0,
+            bit_code: Some(0x20),
+        };
+        assert_eq!(area.word_code(), 0xA0);
+        assert_eq!(area.bit_code().unwrap(), 0x20);
+        assert!(area.supports_bit_access());
+        assert!(area.supports_word_access());
+        assert_eq!(area.to_string(), "Custom(0xA0)");
+        assert!(area.check_bounds(0xFF00, 0xFF).is_ok());
+        assert!(area.check_bounds(0xFFFF, 1).is_err());
+
+        let word_only = MemoryArea::Custom {
+            word_code: 0xA1,
+            bit_code: None,
+        };
+        assert!(!word_only.supports_bit_access());
+        assert!(matches!(
+            word_only.bit_code(),
+            Err(FinsError::InvalidAddressing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_dm_bit_access_opt_in() {
+        assert!(MemoryArea::DM.bit_code_with(false).is_err());
+        assert_eq!(MemoryArea::DM.bit_code_with(true).unwrap(), 0x02);
+        assert_eq!(MemoryArea::CIO.bit_code_with(true).unwrap(), 0x30);
+        assert!(MemoryArea::IR.bit_code_with(true).is_err());
     }
 
     #[test]
@@ -229,6 +474,32 @@ mod tests {
         assert!(MemoryArea::HR.supports_bit_access());
         assert!(!MemoryArea::DM.supports_bit_access());
         assert!(MemoryArea::AR.supports_bit_access());
+        assert!(MemoryArea::TIM.supports_bit_access());
+        assert!(MemoryArea::CNT.supports_bit_access());
+        assert!(!MemoryArea::IR.supports_bit_access());
+        assert!(MemoryArea::CF.supports_bit_access());
+    }
+
+    #[test]
+    fn test_supports_word_access() {
+        assert!(MemoryArea::DM.supports_word_access());
+        assert!(MemoryArea::CIO.check_word_access().is_ok());
+        assert!(!MemoryArea::CF.supports_word_access());
+        assert!(matches!(
+            MemoryArea::CF.check_word_access(),
+            Err(FinsError::InvalidAddressing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_is_writable() {
+        assert!(MemoryArea::DM.is_writable());
+        assert!(MemoryArea::HR.check_writable().is_ok());
+        assert!(!MemoryArea::CF.is_writable());
+        assert!(matches!(
+            MemoryArea::CF.check_writable(),
+            Err(FinsError::InvalidParameter { .. })
+        ));
     }
 
     #[test]
@@ -238,6 +509,10 @@ mod tests {
         assert_eq!(MemoryArea::HR.to_string(), "HR");
         assert_eq!(MemoryArea::DM.to_string(), "DM");
         assert_eq!(MemoryArea::AR.to_string(), "AR");
+        assert_eq!(MemoryArea::TIM.to_string(), "TIM");
+        assert_eq!(MemoryArea::CNT.to_string(), "CNT");
+        assert_eq!(MemoryArea::IR.to_string(), "IR");
+        assert_eq!(MemoryArea::CF.to_string(), "CF");
     }
 
     #[test]
@@ -247,6 +522,37 @@ mod tests {
         assert_eq!(MemoryArea::HR.max_words(), 512);
         assert_eq!(MemoryArea::DM.max_words(), 4096);
         assert_eq!(MemoryArea::AR.max_words(), 1024);
+        assert_eq!(MemoryArea::TIM.max_words(), 4096);
+        assert_eq!(MemoryArea::CNT.max_words(), 4096);
+        assert_eq!(MemoryArea::IR.max_words(), 16);
+        assert_eq!(MemoryArea::CF.max_words(), 2);
+    }
+
+    #[test]
+    fn test_address_offset() {
+        assert_eq!(MemoryArea::TIM.address_offset(), 0);
+        assert_eq!(MemoryArea::CNT.address_offset(), 0x8000);
+        assert_eq!(MemoryArea::DM.address_offset(), 0);
+        assert_eq!(MemoryArea::IR.address_offset(), 0x0100);
+    }
+
+    #[test]
+    fn test_words_per_item() {
+        assert_eq!(MemoryArea::IR.words_per_item(), 2);
+        assert_eq!(MemoryArea::DM.words_per_item(), 1);
+    }
+
+    #[test]
+    fn test_check_bounds_condition_flags() {
+        assert!(MemoryArea::CF.check_bounds(0, 1).is_ok());
+        assert!(MemoryArea::CF.check_bounds(0x1000, 1).is_ok());
+        assert!(MemoryArea::CF.check_bounds(0x1001, 1).is_ok());
+        assert!(MemoryArea::CF.check_bounds(0x1000, 2).is_ok());
+        assert!(MemoryArea::CF.check_bounds(1, 1).is_err());
+        assert!(MemoryArea::CF.check_bounds(0, 2).is_err());
+        assert!(MemoryArea::CF.check_bounds(0x0FFF, 2).is_err());
+        assert!(MemoryArea::CF.check_bounds(0x1001, 2).is_err());
+        assert!(MemoryArea::CF.check_bounds(0xFFFF, 2).is_err());
     }
 
     #[test]
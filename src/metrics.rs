@@ -0,0 +1,92 @@
+//! Hooks for exporting request metrics.
+//!
+//! A [`MetricsSink`] installed with
+//! [`Client::set_metrics_sink`](crate::Client::set_metrics_sink) is told
+//! about every command the client issues and how it ended, so counters and
+//! latency histograms can be fed to Prometheus, statsd or any other system
+//! without this crate depending on it.
+//!
+//! # Example
+//!
+//! ```
+//! use omron_fins::{EndCode, MetricsSink};
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//! use std::time::Duration;
+//!
+//! #[derive(Default)]
+//! struct Counters {
+//!     requests: AtomicU64,
+//!     plc_errors: AtomicU64,
+//! }
+//!
+//! impl MetricsSink for Counters {
+//!     fn on_request(&self, _mrc: u8, _src: u8) {
+//!         self.requests.fetch_add(1, Ordering::Relaxed);
+//!     }
+//!
+//!     fn on_response(&self, _mrc: u8, _src: u8, end_code: EndCode, _elapsed: Duration) {
+//!         if end_code != EndCode::NormalCompletion {
+//!             self.plc_errors.fetch_add(1, Ordering::Relaxed);
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::error::{EndCode, FinsError};
+
+/// Receives one call per command when it is sent and one when it ends.
+///
+/// Every command is reported by `on_request`, followed by exactly one of
+/// `on_response`, `on_timeout` or `on_error`. Command codes identify the
+/// operation, e.g. MRC 0x01 / SRC 0x01 for a memory read. Retransmissions
+/// after a SID mismatch belong to the same command, and commands sent with
+/// [`Client::send_no_response`](crate::Client::send_no_response) only
+/// report `on_request`.
+///
+/// Calls run synchronously on the thread issuing the request, so they
+/// should be cheap. All methods do nothing by default.
+pub trait MetricsSink: Send + Sync {
+    /// A command is about to be sent.
+    fn on_request(&self, mrc: u8, src: u8) {
+        let _ = (mrc, src);
+    }
+
+    /// A response arrived after `elapsed`. The PLC may still have rejected
+    /// the command; `end_code` is [`EndCode::NormalCompletion`] if not.
+    fn on_response(&self, mrc: u8, src: u8, end_code: EndCode, elapsed: Duration) {
+        let _ = (mrc, src, end_code, elapsed);
+    }
+
+    /// No response arrived within the timeout.
+    fn on_timeout(&self, mrc: u8, src: u8, elapsed: Duration) {
+        let _ = (mrc, src, elapsed);
+    }
+
+    /// The command failed without a usable response, e.g. an I/O error, a
+    /// malformed response or repeated SID mismatches.
+    fn on_error(&self, mrc: u8, src: u8, error: &FinsError) {
+        let _ = (mrc, src, error);
+    }
+}
+
+/// Lets one sink be shared by several clients.
+impl<T: MetricsSink + ?Sized> MetricsSink for Arc<T> {
+    fn on_request(&self, mrc: u8, src: u8) {
+        (**self).on_request(mrc, src);
+    }
+
+    fn on_response(&self, mrc: u8, src: u8, end_code: EndCode, elapsed: Duration) {
+        (**self).on_response(mrc, src, end_code, elapsed);
+    }
+
+    fn on_timeout(&self, mrc: u8, src: u8, elapsed: Duration) {
+        (**self).on_timeout(mrc, src, elapsed);
+    }
+
+    fn on_error(&self, mrc: u8, src: u8, error: &FinsError) {
+        (**self).on_error(mrc, src, error);
+    }
+}
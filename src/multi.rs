@@ -0,0 +1,246 @@
+//! Several PLCs addressed by name.
+//!
+//! A [`MultiClient`] owns one [`Client`] per PLC, e.g. per line or station
+//! of a cell, and routes calls by name. Operations that must reach every PLC,
+//! such as broadcasting a recipe number, run on all clients at once and
+//! report each PLC's result separately, so one unreachable station does not
+//! hide the outcome of the others.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use omron_fins::{ClientConfig, MemoryArea, MultiClient};
+//! use std::net::Ipv4Addr;
+//!
+//! let mut cell = MultiClient::new();
+//! cell.connect("filler", ClientConfig::new(Ipv4Addr::new(192, 168, 1, 10), 1, 10))?;
+//! cell.connect("capper", ClientConfig::new(Ipv4Addr::new(192, 168, 1, 11), 1, 11))?;
+//!
+//! let counts = cell.read("filler", MemoryArea::DM, 100, 2)?;
+//!
+//! for (name, result) in cell.write_all(MemoryArea::DM, 0, &[42]) {
+//!     if let Err(error) = result {
+//!         eprintln!("{}: recipe not set: {}", name, error);
+//!     }
+//! }
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::client::{Client, ClientConfig};
+use crate::error::{FinsError, Result};
+use crate::memory::MemoryArea;
+
+/// A set of named clients.
+#[derive(Debug, Default)]
+pub struct MultiClient {
+    clients: BTreeMap<String, Client>,
+}
+
+impl MultiClient {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a client under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the name is already taken.
+    pub fn insert(&mut self, name: impl Into<String>, client: Client) -> Result<()> {
+        let name = name.into();
+        if self.clients.contains_key(&name) {
+            return Err(FinsError::invalid_parameter(
+                "name",
+                format!("PLC '{}' is already defined", name),
+            ));
+        }
+        self.clients.insert(name, client);
+        Ok(())
+    }
+
+    /// Creates a client from `config` and adds it under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the name is already taken,
+    /// or the errors of [`Client::new`].
+    pub fn connect(&mut self, name: impl Into<String>, config: ClientConfig) -> Result<()> {
+        let name = name.into();
+        if self.clients.contains_key(&name) {
+            return Err(FinsError::invalid_parameter(
+                "name",
+                format!("PLC '{}' is already defined", name),
+            ));
+        }
+        self.insert(name, Client::new(config)?)
+    }
+
+    /// Removes a client, returning it if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<Client> {
+        self.clients.remove(name)
+    }
+
+    /// Returns the client called `name`.
+    pub fn get(&self, name: &str) -> Option<&Client> {
+        self.clients.get(name)
+    }
+
+    /// Returns the client called `name`, or `FinsError::InvalidParameter`
+    /// if there is none.
+    pub fn client(&self, name: &str) -> Result<&Client> {
+        self.get(name)
+            .ok_or_else(|| FinsError::invalid_parameter("plc", format!("unknown PLC '{}'", name)))
+    }
+
+    /// Returns the number of clients.
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Returns `true` if there are no clients.
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Iterates over the clients in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Client)> {
+        self.clients
+            .iter()
+            .map(|(name, client)| (name.as_str(), client))
+    }
+
+    /// Reads words from the PLC called `plc`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` for an unknown PLC, or the
+    /// errors of [`Client::read`].
+    pub fn read(&self, plc: &str, area: MemoryArea, address: u16, count: u16) -> Result<Vec<u16>> {
+        self.client(plc)?.read(area, address, count)
+    }
+
+    /// Writes words to the PLC called `plc`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` for an unknown PLC, or the
+    /// errors of [`Client::write`].
+    pub fn write(&self, plc: &str, area: MemoryArea, address: u16, data: &[u16]) -> Result<()> {
+        self.client(plc)?.write(area, address, data)
+    }
+
+    /// Runs `f` against every client at once, one thread per PLC, and
+    /// returns each result by name.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use omron_fins::{MemoryArea, MultiClient};
+    /// # let cell = MultiClient::new();
+    /// let speeds = cell.for_each(|client| client.read_f32(MemoryArea::DM, 200));
+    /// for (name, speed) in &speeds {
+    ///     println!("{}: {:?}", name, speed);
+    /// }
+    /// ```
+    pub fn for_each<T, F>(&self, f: F) -> BTreeMap<String, Result<T>>
+    where
+        T: Send,
+        F: Fn(&Client) -> Result<T> + Sync,
+    {
+        let f = &f;
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .clients
+                .iter()
+                .map(|(name, client)| (name, scope.spawn(move || f(client))))
+                .collect();
+            handles
+                .into_iter()
+                .map(|(name, handle)| {
+                    let result = handle.join().unwrap_or_else(|_| {
+                        Err(FinsError::invalid_response(format!(
+                            "operation on PLC '{}' panicked",
+                            name
+                        )))
+                    });
+                    (name.clone(), result)
+                })
+                .collect()
+        })
+    }
+
+    /// Reads the same words from every PLC.
+    pub fn read_all(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        count: u16,
+    ) -> BTreeMap<String, Result<Vec<u16>>> {
+        self.for_each(|client| client.read(area, address, count))
+    }
+
+    /// Writes the same words to every PLC, e.g. to broadcast a recipe number.
+    pub fn write_all(
+        &self,
+        area: MemoryArea,
+        address: u16,
+        data: &[u16],
+    ) -> BTreeMap<String, Result<()>> {
+        self.for_each(|client| client.write(area, address, data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    /// Starts a fake PLC that answers `requests` commands, reads with
+    /// `value`, and returns its config.
+    fn fake_plc(node: u8, value: u16, requests: usize) -> ClientConfig {
+        let plc = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = plc.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            for _ in 0..requests {
+                let (_, from) = plc.recv_from(&mut buf).unwrap();
+                let mut response = buf[..10].to_vec();
+                response[0] = 0xC0;
+                response.extend_from_slice(&[buf[10], buf[11], 0x00, 0x00]);
+                if buf[11] == 0x01 {
+                    response.extend_from_slice(&value.to_be_bytes());
+                }
+                plc.send_to(&response, from).unwrap();
+            }
+        });
+        ClientConfig::new(Ipv4Addr::LOCALHOST, node, 100)
+            .with_port(port)
+            .with_timeout(std::time::Duration::from_millis(200))
+    }
+
+    #[test]
+    fn test_multi_client_routing() {
+        let mut cell = MultiClient::new();
+        cell.connect("a", fake_plc(1, 0x1111, 2)).unwrap();
+        cell.connect("b", fake_plc(2, 0x2222, 2)).unwrap();
+        assert!(cell.connect("a", fake_plc(3, 0, 0)).is_err());
+        assert_eq!(cell.len(), 2);
+
+        assert_eq!(cell.read("b", MemoryArea::DM, 0, 1).unwrap(), vec![0x2222]);
+        assert!(cell.read("c", MemoryArea::DM, 0, 1).is_err());
+
+        let all = cell.read_all(MemoryArea::DM, 0, 1);
+        assert_eq!(all["a"].as_ref().unwrap(), &vec![0x1111]);
+        assert_eq!(all["b"].as_ref().unwrap(), &vec![0x2222]);
+
+        let written = cell.write_all(MemoryArea::DM, 0, &[7]);
+        assert!(written["a"].is_ok());
+        assert!(written["b"].is_err());
+
+        let names: Vec<&str> = cell.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, ["a", "b"]);
+    }
+}
@@ -0,0 +1,18 @@
+//! Hooks for observing raw FINS traffic.
+//!
+//! A frame observer installed with
+//! [`Client::set_frame_observer`](crate::Client::set_frame_observer) sees
+//! every frame exactly as it goes over the wire, tagged with a
+//! [`Direction`].
+
+/// Direction of a frame relative to this client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Command frame sent to the PLC.
+    Sent,
+    /// Response frame received from the PLC.
+    Received,
+}
+
+/// Boxed frame observer callback.
+pub(crate) type FrameObserver = Box<dyn Fn(Direction, &[u8]) + Send + Sync>;
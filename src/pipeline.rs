@@ -0,0 +1,274 @@
+//! SID-based response demultiplexing for concurrent requests.
+//!
+//! In pipelined mode a background thread owns the receive side of the
+//! socket and routes every incoming response to the request waiting for
+//! its Service ID. This lets several threads share one [`Client`] with
+//! multiple requests in flight instead of serializing on the socket.
+//!
+//! [`Client`]: crate::Client
+
+use std::collections::HashMap;
+use std::io;
+use std::net::UdpSocket;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::error::{FinsError, Result};
+use crate::header::FINS_HEADER_SIZE;
+use crate::logging::log_debug;
+use crate::stats::StatsCounters;
+use crate::transport::{UdpTransport, MAX_PACKET_SIZE};
+
+/// How often the receiver thread checks for shutdown while idle.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+type Waiters = Arc<Mutex<HashMap<u8, SyncSender<Result<Vec<u8>>>>>>;
+
+/// Routes responses to in-flight requests by SID.
+pub(crate) struct Demux {
+    socket: UdpSocket,
+    waiters: Waiters,
+    shutdown: Arc<AtomicBool>,
+    receiver: Option<JoinHandle<()>>,
+}
+
+impl Demux {
+    /// Starts the receiver thread on a clone of the transport's socket.
+    ///
+    /// The socket's read timeout is replaced by a short poll interval, so
+    /// the transport must not be used for direct receives afterwards.
+    /// Responses nobody claims are counted as stale in `stats`.
+    pub(crate) fn new(transport: &UdpTransport, stats: Arc<StatsCounters>) -> Result<Self> {
+        let socket = transport.socket().try_clone()?;
+        let rx_socket = transport.socket().try_clone()?;
+        rx_socket.set_read_timeout(Some(POLL_INTERVAL))?;
+
+        let waiters: Waiters = Arc::new(Mutex::new(HashMap::new()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let receiver = {
+            let waiters = Arc::clone(&waiters);
+            let shutdown = Arc::clone(&shutdown);
+            thread::Builder::new()
+                .name("fins-demux".into())
+                .spawn(move || receive_loop(rx_socket, waiters, shutdown, stats))?
+        };
+
+        Ok(Self {
+            socket,
+            waiters,
+            shutdown,
+            receiver: Some(receiver),
+        })
+    }
+
+    /// Sends a frame and waits up to `timeout` for the response carrying `sid`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::Timeout` if no matching response arrives in
+    /// time, `FinsError::Io` if the socket fails while waiting, or
+    /// `InvalidParameter` if a request with the same SID is already in
+    /// flight (more than 256 outstanding requests).
+    pub(crate) fn send_receive(&self, data: &[u8], sid: u8, timeout: Duration) -> Result<Vec<u8>> {
+        let (tx, rx) = mpsc::sync_channel(1);
+        {
+            let mut waiters = self.waiters.lock().unwrap_or_else(|e| e.into_inner());
+            if waiters.contains_key(&sid) {
+                return Err(FinsError::invalid_parameter(
+                    "sid",
+                    format!("SID 0x{:02X} is already in flight", sid),
+                ));
+            }
+            waiters.insert(sid, tx);
+        }
+
+        if let Err(e) = self.socket.send(data) {
+            self.remove_waiter(sid);
+            return Err(e.into());
+        }
+
+        match rx.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => {
+                self.remove_waiter(sid);
+                Err(FinsError::Timeout)
+            }
+        }
+    }
+
+    fn remove_waiter(&self, sid: u8) {
+        self.waiters
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&sid);
+    }
+}
+
+impl Drop for Demux {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        if let Some(receiver) = self.receiver.take() {
+            let _ = receiver.join();
+        }
+    }
+}
+
+/// Receives datagrams until shutdown and hands each to its waiter.
+///
+/// Responses nobody is waiting for (late replies to timed-out requests,
+/// stray packets) are discarded. A socket error other than the poll
+/// timeout fails every pending request and pauses for one poll interval,
+/// so a persistent error cannot spin the thread.
+fn receive_loop(
+    socket: UdpSocket,
+    waiters: Waiters,
+    shutdown: Arc<AtomicBool>,
+    stats: Arc<StatsCounters>,
+) {
+    let mut buffer = [0u8; MAX_PACKET_SIZE];
+
+    while !shutdown.load(Ordering::Relaxed) {
+        let size = match socket.recv(&mut buffer) {
+            Ok(size) => size,
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) =>
+            {
+                continue
+            }
+            Err(e) => {
+                log_debug!("receive failed: {}", e);
+                fail_waiters(&waiters, &e);
+                thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+        };
+        if size < FINS_HEADER_SIZE {
+            stats.record_stale(1);
+            continue;
+        }
+
+        let sid = buffer[FINS_HEADER_SIZE - 1];
+        let waiter = waiters
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&sid);
+        match waiter {
+            Some(waiter) => {
+                let _ = waiter.try_send(Ok(buffer[..size].to_vec()));
+            }
+            None => {
+                log_debug!("discarded response for SID 0x{:02X} with no waiter", sid);
+                stats.record_stale(1);
+            }
+        }
+    }
+}
+
+/// Fails every request waiting for a response with `error`.
+fn fail_waiters(waiters: &Waiters, error: &io::Error) {
+    let pending: Vec<_> = waiters
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .drain()
+        .collect();
+    for (_, waiter) in pending {
+        let error = io::Error::new(error.kind(), error.to_string());
+        let _ = waiter.try_send(Err(FinsError::Io(error)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::SocketAddr;
+
+    fn response_for(request: &[u8], payload: u8) -> Vec<u8> {
+        let mut response = request[..FINS_HEADER_SIZE].to_vec();
+        response[0] = 0xC0;
+        response.extend_from_slice(&[request[10], request[11], 0x00, 0x00, payload]);
+        response
+    }
+
+    #[test]
+    fn test_demux_routes_out_of_order_responses() {
+        let plc = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let plc_addr: SocketAddr = plc.local_addr().unwrap();
+        let transport = UdpTransport::new(plc_addr, Duration::from_secs(1)).unwrap();
+        let demux = Demux::new(&transport, Arc::default()).unwrap();
+
+        let responder = thread::spawn(move || {
+            let mut requests = Vec::new();
+            let mut buf = [0u8; 64];
+            for _ in 0..2 {
+                let (len, from) = plc.recv_from(&mut buf).unwrap();
+                requests.push((buf[..len].to_vec(), from));
+            }
+            // Answer in reverse order, tagging each reply with its SID
+            for (request, from) in requests.iter().rev() {
+                plc.send_to(&response_for(request, request[9]), from)
+                    .unwrap();
+            }
+        });
+
+        let frame = |sid: u8| {
+            let mut frame = vec![0x80, 0x00, 0x02, 0, 0, 0, 0, 0, 0, sid];
+            frame.extend_from_slice(&[0x01, 0x01]);
+            frame
+        };
+
+        thread::scope(|s| {
+            let a = s.spawn(|| demux.send_receive(&frame(0x11), 0x11, Duration::from_secs(1)));
+            let b = s.spawn(|| demux.send_receive(&frame(0x22), 0x22, Duration::from_secs(1)));
+            assert_eq!(*a.join().unwrap().unwrap().last().unwrap(), 0x11);
+            assert_eq!(*b.join().unwrap().unwrap().last().unwrap(), 0x22);
+        });
+        responder.join().unwrap();
+    }
+
+    #[test]
+    fn test_demux_timeout_releases_sid() {
+        let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let transport =
+            UdpTransport::new(silent.local_addr().unwrap(), Duration::from_secs(1)).unwrap();
+        let demux = Demux::new(&transport, Arc::default()).unwrap();
+        let timeout = Duration::from_millis(50);
+
+        let frame = [0x80, 0x00, 0x02, 0, 0, 0, 0, 0, 0, 0x05, 0x01, 0x01];
+        assert!(matches!(
+            demux.send_receive(&frame, 0x05, timeout),
+            Err(FinsError::Timeout)
+        ));
+        // The SID is free again after the timeout
+        assert!(matches!(
+            demux.send_receive(&frame, 0x05, timeout),
+            Err(FinsError::Timeout)
+        ));
+    }
+
+    #[test]
+    fn test_demux_socket_error_fails_waiters() {
+        // Nothing listens on this port, so the receive fails with the
+        // ICMP port-unreachable error instead of timing out
+        let closed = UdpSocket::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let transport = UdpTransport::new(closed, Duration::from_secs(1)).unwrap();
+        let demux = Demux::new(&transport, Arc::default()).unwrap();
+
+        let frame = [0x80, 0x00, 0x02, 0, 0, 0, 0, 0, 0, 0x07, 0x01, 0x01];
+        for _ in 0..2 {
+            assert!(matches!(
+                demux.send_receive(&frame, 0x07, Duration::from_secs(2)),
+                Err(FinsError::Io(_))
+            ));
+        }
+    }
+}
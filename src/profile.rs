@@ -0,0 +1,187 @@
+//! CPU model profiles with per-area capacities.
+//!
+//! [`MemoryArea::max_words`] only knows conservative capacities common to
+//! every Ethernet PLC. A [`CpuProfile`] set with
+//! [`ClientConfig::with_cpu_profile`](crate::ClientConfig::with_cpu_profile)
+//! replaces them with the real sizes of one CPU family, so reads and writes
+//! past the end of an area fail locally with `FinsError::InvalidAddressing`
+//! instead of an "address range exceeded" (0x1103/0x1104) end code from the
+//! PLC.
+//!
+//! # Example
+//!
+//! ```
+//! use omron_fins::{ClientConfig, CpuProfile, MemoryArea};
+//! use std::net::Ipv4Addr;
+//!
+//! let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+//!     .with_cpu_profile(CpuProfile::CJ2M);
+//!
+//! assert!(CpuProfile::CJ2M.check_range(MemoryArea::DM, 32000, 768).is_ok());
+//! assert!(CpuProfile::CJ2M.check_range(MemoryArea::DM, 32000, 769).is_err());
+//! ```
+
+use crate::error::{FinsError, Result};
+use crate::memory::MemoryArea;
+
+/// Memory area capacities of a CPU family, in words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CpuProfile {
+    name: &'static str,
+    cio: u16,
+    wr: u16,
+    hr: u16,
+    dm: u16,
+    ar: u16,
+    tim: u16,
+    cnt: u16,
+}
+
+impl CpuProfile {
+    /// CJ2M CPU units (CIO 6144, W 512, H 512, D 32768, A 960 words).
+    pub const CJ2M: Self = Self::new("CJ2M", 6144, 512, 512, 32768, 960);
+
+    /// CP1L-M CPU units (CIO 6144, W 512, H 512, D 32768, A 960 words).
+    ///
+    /// CP1L-L units only have D0-D10239; use
+    /// [`with_area_words`](Self::with_area_words) to narrow DM.
+    pub const CP1L: Self = Self::new("CP1L", 6144, 512, 512, 32768, 960);
+
+    /// CS1G/CS1H CPU units (CIO 6144, W 512, H 512, D 32768, A 960 words).
+    pub const CS1G: Self = Self::new("CS1G", 6144, 512, 512, 32768, 960);
+
+    /// NJ-series CPU units, CJ-compatible memory used through FINS
+    /// (CIO 6144, W 512, H 1536, D 32768 words, no AR area).
+    pub const NJ: Self = Self::new("NJ", 6144, 512, 1536, 32768, 0);
+
+    /// Creates a profile from area capacities in words.
+    ///
+    /// Timers and counters default to 4096 each; use
+    /// [`with_area_words`](Self::with_area_words) to change them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{CpuProfile, MemoryArea};
+    ///
+    /// let profile = CpuProfile::new("CP1E-N", 6144, 100, 50, 8192, 754);
+    /// assert_eq!(profile.area_words(MemoryArea::DM), Some(8192));
+    /// ```
+    pub const fn new(name: &'static str, cio: u16, wr: u16, hr: u16, dm: u16, ar: u16) -> Self {
+        Self {
+            name,
+            cio,
+            wr,
+            hr,
+            dm,
+            ar,
+            tim: 4096,
+            cnt: 4096,
+        }
+    }
+
+    /// Overrides the capacity of one area.
+    ///
+    /// IR, CF and custom areas have no per-model capacity and are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{CpuProfile, MemoryArea};
+    ///
+    /// let cp1l_l = CpuProfile::CP1L.with_area_words(MemoryArea::DM, 10240);
+    /// assert_eq!(cp1l_l.area_words(MemoryArea::DM), Some(10240));
+    /// ```
+    pub fn with_area_words(mut self, area: MemoryArea, words: u16) -> Self {
+        match area {
+            MemoryArea::CIO => self.cio = words,
+            MemoryArea::WR => self.wr = words,
+            MemoryArea::HR => self.hr = words,
+            MemoryArea::DM => self.dm = words,
+            MemoryArea::AR => self.ar = words,
+            MemoryArea::TIM => self.tim = words,
+            MemoryArea::CNT => self.cnt = words,
+            MemoryArea::IR | MemoryArea::CF | MemoryArea::Custom { .. } => {}
+        }
+        self
+    }
+
+    /// Returns the profile name (e.g., "CJ2M").
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the capacity of `area` in words, or `None` if the profile
+    /// does not know it (CF and custom areas).
+    pub fn area_words(&self, area: MemoryArea) -> Option<u16> {
+        match area {
+            MemoryArea::CIO => Some(self.cio),
+            MemoryArea::WR => Some(self.wr),
+            MemoryArea::HR => Some(self.hr),
+            MemoryArea::DM => Some(self.dm),
+            MemoryArea::AR => Some(self.ar),
+            MemoryArea::TIM => Some(self.tim),
+            MemoryArea::CNT => Some(self.cnt),
+            MemoryArea::IR => Some(area.max_words()),
+            MemoryArea::CF | MemoryArea::Custom { .. } => None,
+        }
+    }
+
+    /// Checks that `count` items starting at `address` fit in `area`.
+    ///
+    /// Areas without a known capacity fall back to
+    /// [`MemoryArea::check_bounds`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidAddressing` if the range runs past the end
+    /// of the area on this CPU.
+    pub fn check_range(&self, area: MemoryArea, address: u16, count: u16) -> Result<()> {
+        let words = match self.area_words(area) {
+            Some(words) => words,
+            None => return area.check_bounds(address, count),
+        };
+
+        match address.checked_add(count) {
+            Some(end) if end <= words => Ok(()),
+            _ => Err(FinsError::invalid_addressing(format!(
+                "{} {}+{} exceeds the {} {} area of {} words",
+                area, address, count, self.name, area, words
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_area_words() {
+        assert_eq!(CpuProfile::CJ2M.name(), "CJ2M");
+        assert_eq!(CpuProfile::CJ2M.area_words(MemoryArea::DM), Some(32768));
+        assert_eq!(CpuProfile::NJ.area_words(MemoryArea::HR), Some(1536));
+        assert_eq!(CpuProfile::CS1G.area_words(MemoryArea::IR), Some(16));
+        let custom = MemoryArea::Custom {
+            word_code: 0xA0,
+            bit_code: None,
+        };
+        assert_eq!(CpuProfile::CJ2M.area_words(custom), None);
+    }
+
+    #[test]
+    fn test_profile_check_range() {
+        let profile = CpuProfile::CJ2M;
+        assert!(profile.check_range(MemoryArea::DM, 32767, 1).is_ok());
+        assert!(matches!(
+            profile.check_range(MemoryArea::DM, 32767, 2),
+            Err(FinsError::InvalidAddressing { .. })
+        ));
+        assert!(profile.check_range(MemoryArea::AR, 960, 1).is_err());
+        assert!(CpuProfile::NJ.check_range(MemoryArea::AR, 0, 1).is_err());
+        assert!(profile.check_range(MemoryArea::DM, u16::MAX, 2).is_err());
+
+        let narrowed = profile.with_area_words(MemoryArea::DM, 10240);
+        assert!(narrowed.check_range(MemoryArea::DM, 10240, 1).is_err());
+    }
+}
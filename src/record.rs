@@ -0,0 +1,263 @@
+//! Typed structs mapped onto word blocks.
+//!
+//! A type implementing [`FinsStruct`] knows its own layout in PLC memory, so
+//! [`Client::read_typed`](crate::Client::read_typed) and
+//! [`Client::write_typed`](crate::Client::write_typed) move the whole struct
+//! in one read or write. With the `derive` feature, the layout is declared on
+//! the struct itself:
+//!
+//! ```toml
+//! [dependencies]
+//! omron-fins = { version = "0.6", features = ["derive"] }
+//! ```
+//!
+//! ```ignore
+//! use omron_fins::FinsStruct;
+//!
+//! #[derive(FinsStruct)]
+//! struct Recipe {
+//!     #[fins(offset = 0)]
+//!     batch: u16,
+//!     #[fins(offset = 2)]
+//!     temperature: f32,
+//!     // Follows the previous field (offset 4)
+//!     setpoints: [i16; 3],
+//!     #[fins(offset = 10, words = 8)]
+//!     name: String,
+//! }
+//!
+//! let recipe: Recipe = client.read_typed(MemoryArea::DM, 500)?;
+//! ```
+//!
+//! A field without `offset` starts right after the previous field. `String`
+//! fields need `words`, the fixed number of words they occupy; they are
+//! packed 2 characters per word like
+//! [`Client::write_string`](crate::Client::write_string) and truncated to
+//! fit. Gaps between fields are written as zero.
+//!
+//! Field types implement [`FinsField`]: integers, `f32`/`f64`, `bool` (one
+//! word, nonzero is `true`) and fixed-size arrays of them. Multi-word values
+//! use the client's [`WordOrder`].
+
+use crate::error::{FinsError, Result};
+use crate::types::WordOrder;
+use crate::utils;
+
+/// A struct with a fixed layout in PLC memory.
+///
+/// Usually derived; see the [module documentation](self).
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::{FinsField, FinsStruct, Result, WordOrder};
+///
+/// struct Axis {
+///     position: i32,
+///     speed: u16,
+/// }
+///
+/// impl FinsStruct for Axis {
+///     const WORD_COUNT: u16 = 3;
+///
+///     fn to_words(&self, order: WordOrder) -> Vec<u16> {
+///         let mut words = vec![0; 3];
+///         self.position.put(&mut words[0..2], order);
+///         self.speed.put(&mut words[2..3], order);
+///         words
+///     }
+///
+///     fn from_words(words: &[u16], order: WordOrder) -> Result<Self> {
+///         omron_fins::record::check_len::<Self>(words)?;
+///         Ok(Self {
+///             position: i32::get(&words[0..2], order),
+///             speed: u16::get(&words[2..3], order),
+///         })
+///     }
+/// }
+///
+/// let words = Axis { position: -2, speed: 50 }.to_words(WordOrder::LowFirst);
+/// assert_eq!(words, vec![0xFFFE, 0xFFFF, 50]);
+/// # Ok::<(), omron_fins::FinsError>(())
+/// ```
+pub trait FinsStruct: Sized {
+    /// Number of words the struct occupies.
+    const WORD_COUNT: u16;
+
+    /// Lays the struct out as [`WORD_COUNT`](Self::WORD_COUNT) PLC words.
+    fn to_words(&self, order: WordOrder) -> Vec<u16>;
+
+    /// Decodes the struct from the first [`WORD_COUNT`](Self::WORD_COUNT)
+    /// words of `words`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidResponse` if `words` is too short.
+    fn from_words(words: &[u16], order: WordOrder) -> Result<Self>;
+}
+
+/// A value stored in a fixed number of words.
+///
+/// `put` and `get` receive exactly [`WORDS`](Self::WORDS) words.
+pub trait FinsField: Sized {
+    /// Number of words the value occupies.
+    const WORDS: u16;
+
+    /// Encodes the value into `words`.
+    fn put(&self, words: &mut [u16], order: WordOrder);
+
+    /// Decodes the value from `words`.
+    fn get(words: &[u16], order: WordOrder) -> Self;
+}
+
+impl FinsField for u16 {
+    const WORDS: u16 = 1;
+
+    fn put(&self, words: &mut [u16], _order: WordOrder) {
+        words[0] = *self;
+    }
+
+    fn get(words: &[u16], _order: WordOrder) -> Self {
+        words[0]
+    }
+}
+
+impl FinsField for i16 {
+    const WORDS: u16 = 1;
+
+    fn put(&self, words: &mut [u16], _order: WordOrder) {
+        words[0] = *self as u16;
+    }
+
+    fn get(words: &[u16], _order: WordOrder) -> Self {
+        words[0] as i16
+    }
+}
+
+impl FinsField for bool {
+    const WORDS: u16 = 1;
+
+    fn put(&self, words: &mut [u16], _order: WordOrder) {
+        words[0] = *self as u16;
+    }
+
+    fn get(words: &[u16], _order: WordOrder) -> Self {
+        words[0] != 0
+    }
+}
+
+macro_rules! impl_multi_word_field {
+    ($($ty:ty),*) => {
+        $(
+            impl FinsField for $ty {
+                const WORDS: u16 = (std::mem::size_of::<$ty>() / 2) as u16;
+
+                fn put(&self, words: &mut [u16], order: WordOrder) {
+                    let mut bytes = self.to_be_bytes();
+                    order.arrange(&mut bytes);
+                    for (word, pair) in words.iter_mut().zip(bytes.chunks_exact(2)) {
+                        *word = u16::from_be_bytes([pair[0], pair[1]]);
+                    }
+                }
+
+                fn get(words: &[u16], order: WordOrder) -> Self {
+                    let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_be_bytes()).collect();
+                    Self::from_be_bytes(order.native(&bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_multi_word_field!(u32, i32, f32, u64, i64, f64);
+
+impl<T: FinsField + Default + Copy, const N: usize> FinsField for [T; N] {
+    const WORDS: u16 = T::WORDS * N as u16;
+
+    fn put(&self, words: &mut [u16], order: WordOrder) {
+        let len = T::WORDS as usize;
+        for (value, slot) in self.iter().zip(words.chunks_exact_mut(len)) {
+            value.put(slot, order);
+        }
+    }
+
+    fn get(words: &[u16], order: WordOrder) -> Self {
+        let len = T::WORDS as usize;
+        let mut values = [T::default(); N];
+        for (value, slot) in values.iter_mut().zip(words.chunks_exact(len)) {
+            *value = T::get(slot, order);
+        }
+        values
+    }
+}
+
+/// Checks that `words` holds at least `T::WORD_COUNT` words.
+///
+/// # Errors
+///
+/// Returns `FinsError::InvalidResponse` if `words` is too short.
+pub fn check_len<T: FinsStruct>(words: &[u16]) -> Result<()> {
+    if words.len() < T::WORD_COUNT as usize {
+        return Err(FinsError::invalid_response(format!(
+            "expected {} words, got {}",
+            T::WORD_COUNT,
+            words.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Packs `value` into `words`, truncating it to fit and zeroing the rest.
+/// Used by the derive for `String` fields.
+#[doc(hidden)]
+pub fn put_string(value: &str, words: &mut [u16]) {
+    words.fill(0);
+    let bytes = value.as_bytes();
+    let bytes = &bytes[..bytes.len().min(words.len() * 2)];
+    for (word, packed) in words.iter_mut().zip(utils::pack_string_bytes(bytes)) {
+        *word = packed;
+    }
+}
+
+/// Unpacks a `String` field written by [`put_string`].
+#[doc(hidden)]
+pub fn get_string(words: &[u16]) -> String {
+    String::from_utf8_lossy(&utils::unpack_string_bytes(words)).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_layouts() {
+        let mut words = [0u16; 4];
+        0x1122_3344u32.put(&mut words[..2], WordOrder::LowFirst);
+        assert_eq!(words[..2], [0x3344, 0x1122]);
+        (-1.5f32).put(&mut words[2..], WordOrder::HighFirst);
+        assert_eq!(words[2..], [0xBFC0, 0x0000]);
+        assert_eq!(u32::get(&words[..2], WordOrder::LowFirst), 0x1122_3344);
+        assert_eq!(f32::get(&words[2..], WordOrder::HighFirst), -1.5);
+
+        assert_eq!(<[i32; 3]>::WORDS, 6);
+        let mut words = [0u16; 6];
+        [1i32, -1, 2].put(&mut words, WordOrder::LowFirst);
+        assert_eq!(words, [1, 0, 0xFFFF, 0xFFFF, 2, 0]);
+        assert_eq!(<[i32; 3]>::get(&words, WordOrder::LowFirst), [1, -1, 2]);
+
+        assert!(bool::get(&[2], WordOrder::LowFirst));
+        assert_eq!(i16::get(&[0xFFFF], WordOrder::LowFirst), -1);
+    }
+
+    #[test]
+    fn test_string_field() {
+        let mut words = [0xFFFFu16; 2];
+        put_string("ABCDE", &mut words);
+        assert_eq!(words, [0x4241, 0x4443]);
+        assert_eq!(get_string(&words), "ABCD");
+
+        let mut words = [0xFFFFu16; 3];
+        put_string("AB", &mut words);
+        assert_eq!(words, [0x4241, 0x0000, 0x0000]);
+    }
+}
@@ -0,0 +1,419 @@
+//! Session recording for post-mortem analysis.
+//!
+//! A [`SessionRecorder`] installed with
+//! [`Client::set_session_recorder`](crate::Client::set_session_recorder)
+//! captures every request/response exchange into a bounded ring buffer and,
+//! optionally, writes one line per exchange to any [`Write`] sink.
+//!
+//! Logs written that way can be read back with
+//! [`SessionRecorder::read_log`] and replayed by a
+//! [`FinsServer`](crate::FinsServer), so a session captured in the field
+//! can be reproduced against a client on a developer machine.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use omron_fins::{Client, ClientConfig, MemoryArea, SessionRecorder};
+//! use std::net::Ipv4Addr;
+//!
+//! let mut client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+//! client.set_session_recorder(SessionRecorder::new(1000));
+//!
+//! let _ = client.read(MemoryArea::DM, 100, 10);
+//!
+//! for entry in client.session_log() {
+//!     println!("{}", entry);
+//! }
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::{FinsError, Result};
+use crate::header::FINS_HEADER_SIZE;
+use crate::response::FinsResponse;
+
+/// One recorded request/response exchange.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionEntry {
+    /// When the request was sent.
+    pub timestamp: SystemTime,
+    /// Service ID of the request.
+    pub sid: u8,
+    /// Raw request frame.
+    pub request: Vec<u8>,
+    /// Raw response frame, if one was received.
+    pub response: Option<Vec<u8>>,
+    /// Time between sending and the response (or failure).
+    pub elapsed: Duration,
+    /// Short human-readable description of the exchange.
+    pub summary: String,
+}
+
+impl fmt::Display for SessionEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let millis = self
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        write!(
+            f,
+            "{} sid=0x{:02X} {} ({} us) req={}",
+            millis,
+            self.sid,
+            self.summary,
+            self.elapsed.as_micros(),
+            hex(&self.request)
+        )?;
+        if let Some(response) = &self.response {
+            write!(f, " resp={}", hex(response))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a line written by the `Display` implementation.
+///
+/// The timestamp is kept to the millisecond and the elapsed time to the
+/// microsecond, as written.
+impl FromStr for SessionEntry {
+    type Err = FinsError;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let invalid =
+            || FinsError::invalid_parameter("line", format!("not a session log line: '{}'", line));
+        let (millis, rest) = line.split_once(' ').ok_or_else(invalid)?;
+        let (sid, rest) = rest
+            .strip_prefix("sid=0x")
+            .and_then(|rest| rest.split_once(' '))
+            .ok_or_else(invalid)?;
+        let (rest, frames) = rest.rsplit_once(" us) req=").ok_or_else(invalid)?;
+        let (summary, micros) = rest.rsplit_once(" (").ok_or_else(invalid)?;
+        let (request, response) = match frames.split_once(" resp=") {
+            Some((request, response)) => (request, Some(response)),
+            None => (frames, None),
+        };
+
+        Ok(Self {
+            timestamp: UNIX_EPOCH + Duration::from_millis(millis.parse().map_err(|_| invalid())?),
+            sid: u8::from_str_radix(sid, 16).map_err(|_| invalid())?,
+            request: unhex(request).ok_or_else(invalid)?,
+            response: response
+                .map(|response| unhex(response).ok_or_else(invalid))
+                .transpose()?,
+            elapsed: Duration::from_micros(micros.parse().map_err(|_| invalid())?),
+            summary: summary.to_string(),
+        })
+    }
+}
+
+/// Records request/response exchanges into a ring buffer.
+pub struct SessionRecorder {
+    capacity: usize,
+    entries: Mutex<VecDeque<SessionEntry>>,
+    writer: Option<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl SessionRecorder {
+    /// Creates a recorder keeping the most recent `capacity` exchanges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::SessionRecorder;
+    ///
+    /// let recorder = SessionRecorder::new(500);
+    /// assert_eq!(recorder.capacity(), 500);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+            writer: None,
+        }
+    }
+
+    /// Also writes every exchange as one line to `writer` (e.g., a log file).
+    ///
+    /// Write errors are ignored so recording never fails a PLC operation.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::SessionRecorder;
+    /// use std::fs::File;
+    ///
+    /// let file = File::create("fins-session.log")?;
+    /// let recorder = SessionRecorder::new(1000).with_writer(file);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn with_writer<W: Write + Send + 'static>(mut self, writer: W) -> Self {
+        self.writer = Some(Mutex::new(Box::new(writer)));
+        self
+    }
+
+    /// Reads the exchanges of a log written through
+    /// [`with_writer`](Self::with_writer), oldest first. Blank lines are
+    /// skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::Io` if reading fails, or
+    /// `FinsError::InvalidParameter` for a line that is not a log entry.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::SessionRecorder;
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    ///
+    /// let log = SessionRecorder::read_log(BufReader::new(File::open("fins-session.log")?))?;
+    /// println!("{} exchanges", log.len());
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn read_log<R: BufRead>(reader: R) -> Result<Vec<SessionEntry>> {
+        let mut entries = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                entries.push(line.trim_end().parse()?);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Returns the ring buffer capacity.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns a copy of the recorded exchanges, oldest first.
+    pub fn entries(&self) -> Vec<SessionEntry> {
+        self.lock_entries().iter().cloned().collect()
+    }
+
+    /// Discards all recorded exchanges.
+    pub fn clear(&self) {
+        self.lock_entries().clear();
+    }
+
+    /// Records one exchange.
+    pub(crate) fn record(
+        &self,
+        timestamp: SystemTime,
+        sid: u8,
+        request: &[u8],
+        result: &Result<Vec<u8>>,
+        elapsed: Duration,
+    ) {
+        let entry = SessionEntry {
+            timestamp,
+            sid,
+            request: request.to_vec(),
+            response: result.as_ref().ok().cloned(),
+            elapsed,
+            summary: summarize(request, result),
+        };
+
+        if let Some(writer) = &self.writer {
+            let mut writer = writer.lock().unwrap_or_else(|e| e.into_inner());
+            let _ = writeln!(writer, "{}", entry);
+        }
+
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.lock_entries();
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    fn lock_entries(&self) -> std::sync::MutexGuard<'_, VecDeque<SessionEntry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl fmt::Debug for SessionRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionRecorder")
+            .field("capacity", &self.capacity)
+            .field("entries", &self.lock_entries().len())
+            .field("writer", &self.writer.is_some())
+            .finish()
+    }
+}
+
+/// Describes an exchange as "MRC/SRC: outcome".
+fn summarize(request: &[u8], result: &Result<Vec<u8>>) -> String {
+    let command = match request.get(FINS_HEADER_SIZE..FINS_HEADER_SIZE + 2) {
+        Some(codes) => format!("cmd {:02X}{:02X}", codes[0], codes[1]),
+        None => "cmd ????".to_string(),
+    };
+
+    let outcome = match result {
+        Ok(bytes) => match FinsResponse::from_bytes(bytes) {
+            Ok(response) => format!(
+                "end {:02X}{:02X}, {} data bytes",
+                response.main_code,
+                response.sub_code,
+                response.data.len()
+            ),
+            Err(e) => e.to_string(),
+        },
+        Err(FinsError::Timeout) => "timeout".to_string(),
+        Err(e) => e.to_string(),
+    };
+
+    format!("{}: {}", command, outcome)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+fn unhex(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 2 != 0 || !text.is_ascii() {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&text[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    const REQUEST: [u8; 18] = [
+        0x80, 0x00, 0x02, 0x00, 0x0A, 0x00, 0x00, 0x01, 0x00, 0x07, 0x01, 0x01, 0x82, 0x00, 0x64,
+        0x00, 0x00, 0x01,
+    ];
+    const RESPONSE: [u8; 16] = [
+        0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x07, 0x01, 0x01, 0x00, 0x00, 0x12,
+        0x34,
+    ];
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_record_success_and_timeout() {
+        let recorder = SessionRecorder::new(10);
+        let now = SystemTime::now();
+        recorder.record(
+            now,
+            0x07,
+            &REQUEST,
+            &Ok(RESPONSE.to_vec()),
+            Duration::from_millis(3),
+        );
+        recorder.record(
+            now,
+            0x08,
+            &REQUEST,
+            &Err(FinsError::Timeout),
+            Duration::from_secs(2),
+        );
+
+        let entries = recorder.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].sid, 0x07);
+        assert_eq!(entries[0].response.as_deref(), Some(&RESPONSE[..]));
+        assert_eq!(entries[0].summary, "cmd 0101: end 0000, 2 data bytes");
+        assert_eq!(entries[1].response, None);
+        assert_eq!(entries[1].summary, "cmd 0101: timeout");
+    }
+
+    #[test]
+    fn test_ring_buffer_drops_oldest() {
+        let recorder = SessionRecorder::new(2);
+        for sid in 0..5 {
+            recorder.record(
+                SystemTime::now(),
+                sid,
+                &REQUEST,
+                &Err(FinsError::Timeout),
+                Duration::ZERO,
+            );
+        }
+        let sids: Vec<u8> = recorder.entries().iter().map(|e| e.sid).collect();
+        assert_eq!(sids, vec![3, 4]);
+
+        recorder.clear();
+        assert!(recorder.entries().is_empty());
+    }
+
+    #[test]
+    fn test_writer_receives_lines() {
+        let buffer = SharedBuffer::default();
+        let recorder = SessionRecorder::new(0).with_writer(buffer.clone());
+        recorder.record(
+            UNIX_EPOCH + Duration::from_millis(1500),
+            0x07,
+            &REQUEST,
+            &Ok(RESPONSE.to_vec()),
+            Duration::from_micros(250),
+        );
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(output.starts_with("1500 sid=0x07 cmd 0101: end 0000"));
+        assert!(output.contains("(250 us)"));
+        assert!(output.contains(" resp=C000"));
+        assert!(output.ends_with('\n'));
+        // Capacity 0 keeps nothing in memory
+        assert!(recorder.entries().is_empty());
+    }
+
+    #[test]
+    fn test_read_log() {
+        let buffer = SharedBuffer::default();
+        let recorder = SessionRecorder::new(2).with_writer(buffer.clone());
+        let start = UNIX_EPOCH + Duration::from_millis(1500);
+        recorder.record(
+            start,
+            0x07,
+            &REQUEST,
+            &Ok(RESPONSE.to_vec()),
+            Duration::from_micros(250),
+        );
+        recorder.record(
+            start,
+            0x08,
+            &REQUEST,
+            &Err(FinsError::Timeout),
+            Duration::from_secs(2),
+        );
+
+        let output = buffer.0.lock().unwrap().clone();
+        let log = SessionRecorder::read_log(&output[..]).unwrap();
+        assert_eq!(log, recorder.entries());
+
+        assert!(SessionRecorder::read_log(&b"1500 sid=0x07 cmd 0101 req=80"[..]).is_err());
+        assert!("1500 sid=0x07 x (1 us) req=8"
+            .parse::<SessionEntry>()
+            .is_err());
+        assert_eq!(SessionRecorder::read_log(&b"\n\n"[..]).unwrap(), vec![]);
+    }
+}
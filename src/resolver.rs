@@ -0,0 +1,130 @@
+//! Hostname resolution for PLC addresses.
+//!
+//! This module provides the [`Resolver`] trait used by
+//! [`ClientConfig::from_host`](crate::ClientConfig::from_host) to turn a DNS
+//! name into an IPv4 address, plus the default [`SystemResolver`] backed by
+//! the operating system's resolver. The lookup happens once, when the
+//! configuration is built; reconnecting does not resolve the name again.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use omron_fins::ClientConfig;
+//!
+//! // Resolve using the system resolver (DNS, /etc/hosts, ...)
+//! let config = ClientConfig::from_host("plc-line3.factory.local", 1, 0)?;
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+//!
+//! A custom resolver can be any closure returning the candidate addresses:
+//!
+//! ```
+//! use omron_fins::ClientConfig;
+//! use std::net::{IpAddr, Ipv4Addr};
+//!
+//! let resolver = |_host: &str| Ok(vec![IpAddr::V4(Ipv4Addr::new(10, 0, 3, 20))]);
+//! let config = ClientConfig::from_host_with_resolver("plc-line3", 1, 0, &resolver)?;
+//! assert_eq!(config.plc_addr.ip(), Ipv4Addr::new(10, 0, 3, 20));
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs};
+
+use crate::error::{FinsError, Result};
+
+/// Resolves a hostname into a list of IP addresses.
+///
+/// Implemented for [`SystemResolver`] and for any
+/// `Fn(&str) -> io::Result<Vec<IpAddr>>` closure, so tests and deployments
+/// with their own service discovery can plug in a custom lookup.
+pub trait Resolver {
+    /// Returns all addresses known for `host`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the lookup fails.
+    fn resolve(&self, host: &str) -> io::Result<Vec<IpAddr>>;
+}
+
+/// Resolver backed by the operating system (`getaddrinfo`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemResolver;
+
+impl Resolver for SystemResolver {
+    fn resolve(&self, host: &str) -> io::Result<Vec<IpAddr>> {
+        Ok((host, 0).to_socket_addrs()?.map(|addr| addr.ip()).collect())
+    }
+}
+
+impl<F> Resolver for F
+where
+    F: Fn(&str) -> io::Result<Vec<IpAddr>>,
+{
+    fn resolve(&self, host: &str) -> io::Result<Vec<IpAddr>> {
+        self(host)
+    }
+}
+
+/// Resolves `host` and returns the first IPv4 address.
+///
+/// FINS/UDP only runs over IPv4, so IPv6 results are skipped.
+pub(crate) fn resolve_ipv4(host: &str, resolver: &dyn Resolver) -> Result<Ipv4Addr> {
+    if host.is_empty() {
+        return Err(FinsError::invalid_parameter("host", "must not be empty"));
+    }
+
+    resolver
+        .resolve(host)?
+        .into_iter()
+        .find_map(|ip| match ip {
+            IpAddr::V4(v4) => Some(v4),
+            IpAddr::V6(_) => None,
+        })
+        .ok_or_else(|| {
+            FinsError::invalid_parameter("host", format!("'{}' has no IPv4 address", host))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv6Addr;
+
+    #[test]
+    fn test_system_resolver_ip_literal() {
+        let ips = SystemResolver.resolve("127.0.0.1").unwrap();
+        assert!(ips.contains(&IpAddr::V4(Ipv4Addr::LOCALHOST)));
+    }
+
+    #[test]
+    fn test_resolve_ipv4_skips_ipv6() {
+        let resolver = |_: &str| {
+            Ok(vec![
+                IpAddr::V6(Ipv6Addr::LOCALHOST),
+                IpAddr::V4(Ipv4Addr::new(192, 168, 1, 250)),
+            ])
+        };
+        let ip = resolve_ipv4("plc", &resolver).unwrap();
+        assert_eq!(ip, Ipv4Addr::new(192, 168, 1, 250));
+    }
+
+    #[test]
+    fn test_resolve_ipv4_no_ipv4() {
+        let resolver = |_: &str| Ok(vec![IpAddr::V6(Ipv6Addr::LOCALHOST)]);
+        let err = resolve_ipv4("plc", &resolver).unwrap_err();
+        assert!(matches!(err, FinsError::InvalidParameter { .. }));
+    }
+
+    #[test]
+    fn test_resolve_ipv4_lookup_error() {
+        let resolver = |_: &str| Err(io::Error::new(io::ErrorKind::NotFound, "no such host"));
+        let err = resolve_ipv4("plc", &resolver).unwrap_err();
+        assert!(matches!(err, FinsError::Io(_)));
+    }
+
+    #[test]
+    fn test_resolve_ipv4_empty_host() {
+        assert!(resolve_ipv4("", &SystemResolver).is_err());
+    }
+}
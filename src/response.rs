@@ -41,12 +41,162 @@
 //! assert_eq!(words, vec![0x1234, 0x5678]);
 //! ```
 
+use std::collections::BTreeMap;
+
+use crate::command::MultiReadSpec;
 use crate::error::{FinsError, Result};
-use crate::header::{FinsHeader, FINS_HEADER_SIZE};
+use crate::header::{FinsHeader, NodeAddress, FINS_HEADER_SIZE};
+use crate::types::ByteOrder;
+use crate::utils;
 
 /// Minimum response size: header (10) + MRC (1) + SRC (1) + main code (1) + sub code (1) = 14 bytes.
 pub const MIN_RESPONSE_SIZE: usize = FINS_HEADER_SIZE + 4;
 
+/// Controller model and version returned by Controller Data Read (0x05 0x01).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControllerData {
+    /// Controller model (e.g., "CJ2M-CPU31").
+    pub model: String,
+    /// Controller version (e.g., "02.01").
+    pub version: String,
+}
+
+/// A unit reported by Connection Data Read (0x05 0x02).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitInfo {
+    /// Unit address (0x00 = CPU, 0x10-0x1F = CPU Bus Units, ...).
+    pub unit_address: u8,
+    /// Unit model (e.g., "CJ1W-ETN21").
+    pub model: String,
+}
+
+/// Date and time as reported by the PLC clock (local time, no time zone).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PlcDateTime {
+    /// Year (1970-2069).
+    pub year: u16,
+    /// Month (1-12).
+    pub month: u8,
+    /// Day of month (1-31).
+    pub day: u8,
+    /// Hour (0-23).
+    pub hour: u8,
+    /// Minute (0-59).
+    pub minute: u8,
+    /// Second (0-59).
+    pub second: u8,
+}
+
+impl std::fmt::Display for PlcDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+/// One record of the controller error log (Error Log Read, 0x21 0x02).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorLogEntry {
+    /// Error code (e.g., 0x80F1 for a memory error).
+    pub error_code: u16,
+    /// Detail code qualifying the error.
+    pub detail_code: u16,
+    /// When the error occurred.
+    pub timestamp: PlcDateTime,
+}
+
+/// One value returned by a Multiple Memory Area Read (0x01 0x04).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultiReadResult {
+    /// Word read (spec without a bit position).
+    Word(u16),
+    /// Bit read (spec with a bit position).
+    Bit(bool),
+}
+
+impl MultiReadResult {
+    /// Returns the value as a word: bits map to 0x0000 (OFF) or 0x0001 (ON).
+    pub fn as_u16(self) -> u16 {
+        match self {
+            MultiReadResult::Word(word) => word,
+            MultiReadResult::Bit(bit) => bit as u16,
+        }
+    }
+}
+
+/// Warning flags carried in the end code of a response.
+///
+/// Bit 7 of the main code flags a relay error on the route to the PLC; bits
+/// 7 and 6 of the sub code flag a fatal or non-fatal error in the CPU Unit.
+/// The command itself may still have completed and returned data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EndCodeWarnings {
+    /// Network relay error (main code bit 7).
+    pub relay_error: bool,
+    /// Fatal CPU Unit error (sub code bit 7).
+    pub fatal_cpu_error: bool,
+    /// Non-fatal CPU Unit error (sub code bit 6).
+    pub non_fatal_cpu_error: bool,
+}
+
+impl EndCodeWarnings {
+    /// Reads the flags of an end code.
+    pub fn from_codes(main_code: u8, sub_code: u8) -> Self {
+        Self {
+            relay_error: main_code & 0x80 != 0,
+            fatal_cpu_error: sub_code & 0x80 != 0,
+            non_fatal_cpu_error: sub_code & 0x40 != 0,
+        }
+    }
+
+    /// Returns `true` if no flag is set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Combines the flags of several responses, e.g. the chunks of one read.
+impl std::ops::BitOrAssign for EndCodeWarnings {
+    fn bitor_assign(&mut self, other: Self) {
+        self.relay_error |= other.relay_error;
+        self.fatal_cpu_error |= other.fatal_cpu_error;
+        self.non_fatal_cpu_error |= other.non_fatal_cpu_error;
+    }
+}
+
+/// Lists the set flags, e.g. "relay error, non-fatal CPU error".
+impl std::fmt::Display for EndCodeWarnings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let flags = [
+            (self.relay_error, "relay error"),
+            (self.fatal_cpu_error, "fatal CPU error"),
+            (self.non_fatal_cpu_error, "non-fatal CPU error"),
+        ];
+        let names: Vec<&str> = flags
+            .iter()
+            .filter(|(set, _)| *set)
+            .map(|(_, name)| *name)
+            .collect();
+        if names.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&names.join(", "))
+        }
+    }
+}
+
+/// Outcome of an Access Right Acquire request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessRight {
+    /// This client now holds the access right.
+    Acquired,
+    /// Another device holds the access right.
+    HeldBy(NodeAddress),
+}
+
 /// Parsed FINS response.
 #[derive(Debug, Clone)]
 pub struct FinsResponse {
@@ -163,6 +313,86 @@ impl FinsResponse {
         }
     }
 
+    /// Like [`check_error`](Self::check_error), but also rejects the 0x0040
+    /// routing warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::PlcError` unless the end code is 0x0000.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsResponse;
+    ///
+    /// let warning_bytes = [
+    ///     0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x01,
+    ///     0x01, 0x01, 0x00, 0x40, 0x12, 0x34,
+    /// ];
+    /// let response = FinsResponse::from_bytes(&warning_bytes).unwrap();
+    /// assert!(response.check_error().is_ok());
+    /// assert!(response.check_error_strict().is_err());
+    /// ```
+    pub fn check_error_strict(&self) -> Result<()> {
+        if self.is_success() {
+            Ok(())
+        } else {
+            Err(FinsError::plc_error(self.main_code, self.sub_code))
+        }
+    }
+
+    /// Returns the warning flags set in the end code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsResponse;
+    ///
+    /// let bytes = [
+    ///     0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x01,
+    ///     0x01, 0x01, 0x00, 0x40, 0x12, 0x34,
+    /// ];
+    /// let response = FinsResponse::from_bytes(&bytes).unwrap();
+    /// assert!(response.warnings().non_fatal_cpu_error);
+    /// assert!(!response.warnings().relay_error);
+    /// ```
+    pub fn warnings(&self) -> EndCodeWarnings {
+        EndCodeWarnings::from_codes(self.main_code, self.sub_code)
+    }
+
+    /// Validates the response, accepting a normal completion that carries
+    /// warning flags and returning those flags, if any.
+    ///
+    /// Unlike [`check_error`](Self::check_error), which accepts only the
+    /// 0x0040 warning and drops it, every flag combination on a normal
+    /// completion (e.g. 0x0080 or 0x8040) is accepted and reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::PlcError` if the end code without its flags is
+    /// not a normal completion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsResponse;
+    ///
+    /// let bytes = [
+    ///     0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x01,
+    ///     0x01, 0x01, 0x00, 0x80, 0x12, 0x34,
+    /// ];
+    /// let response = FinsResponse::from_bytes(&bytes).unwrap();
+    /// let warnings = response.check_warnings().unwrap().unwrap();
+    /// assert!(warnings.fatal_cpu_error);
+    /// ```
+    pub fn check_warnings(&self) -> Result<Option<EndCodeWarnings>> {
+        if self.main_code & 0x7F != 0 || self.sub_code & 0x3F != 0 {
+            return Err(FinsError::plc_error(self.main_code, self.sub_code));
+        }
+        let warnings = self.warnings();
+        Ok((!warnings.is_empty()).then_some(warnings))
+    }
+
     /// Validates the Service ID matches the expected value.
     ///
     /// # Errors
@@ -217,11 +447,7 @@ impl FinsResponse {
             ));
         }
 
-        Ok(self
-            .data
-            .chunks_exact(2)
-            .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]))
-            .collect())
+        Ok(utils::bytes_to_words(&self.data, ByteOrder::BigEndian))
     }
 
     /// Converts response data to a single bit value.
@@ -250,11 +476,388 @@ impl FinsResponse {
 
         Ok(self.data[0] != 0)
     }
+
+    /// Converts response data to bit values, one byte per bit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsResponse;
+    ///
+    /// let bytes = [
+    ///     0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x01,
+    ///     0x01, 0x01, 0x00, 0x00,
+    ///     0x01, 0x00, 0x01, // bits: true, false, true
+    /// ];
+    /// let response = FinsResponse::from_bytes(&bytes).unwrap();
+    /// assert_eq!(response.to_bits(), vec![true, false, true]);
+    /// ```
+    pub fn to_bits(&self) -> Vec<bool> {
+        self.data.iter().map(|&byte| byte != 0).collect()
+    }
+
+    /// Parses the response data of a Controller Data Read command.
+    ///
+    /// The model and version are 20-byte ASCII fields; trailing spaces and
+    /// NUL bytes are trimmed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is shorter than 40 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsResponse;
+    ///
+    /// let mut bytes = vec![
+    ///     0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x01,
+    ///     0x05, 0x01, 0x00, 0x00,
+    /// ];
+    /// bytes.extend_from_slice(b"CJ2M-CPU31          ");
+    /// bytes.extend_from_slice(b"02.01               ");
+    /// let response = FinsResponse::from_bytes(&bytes).unwrap();
+    /// let data = response.to_controller_data().unwrap();
+    /// assert_eq!(data.model, "CJ2M-CPU31");
+    /// assert_eq!(data.version, "02.01");
+    /// ```
+    pub fn to_controller_data(&self) -> Result<ControllerData> {
+        const FIELD_SIZE: usize = 20;
+
+        if self.data.len() < FIELD_SIZE * 2 {
+            return Err(FinsError::invalid_response(format!(
+                "controller data too short: expected at least {} bytes, got {}",
+                FIELD_SIZE * 2,
+                self.data.len()
+            )));
+        }
+
+        let field = |bytes: &[u8]| {
+            String::from_utf8_lossy(bytes)
+                .trim_end_matches([' ', '\0'])
+                .to_string()
+        };
+
+        Ok(ControllerData {
+            model: field(&self.data[..FIELD_SIZE]),
+            version: field(&self.data[FIELD_SIZE..FIELD_SIZE * 2]),
+        })
+    }
+
+    /// Parses the response data of a Connection Data Read command.
+    ///
+    /// The data holds the number of units followed by, for each unit, its
+    /// unit address and a 20-byte ASCII model name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is shorter than the unit count
+    /// announces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsResponse;
+    ///
+    /// let mut bytes = vec![
+    ///     0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x01,
+    ///     0x05, 0x02, 0x00, 0x00,
+    ///     0x01, 0x10,
+    /// ];
+    /// bytes.extend_from_slice(b"CJ1W-ETN21          ");
+    /// let response = FinsResponse::from_bytes(&bytes).unwrap();
+    /// let units = response.to_connection_data().unwrap();
+    /// assert_eq!(units[0].unit_address, 0x10);
+    /// assert_eq!(units[0].model, "CJ1W-ETN21");
+    /// ```
+    pub fn to_connection_data(&self) -> Result<Vec<UnitInfo>> {
+        const RECORD_SIZE: usize = 21;
+
+        let count = match self.data.first() {
+            Some(&count) => count as usize,
+            None => {
+                return Err(FinsError::invalid_response(
+                    "connection data missing unit count",
+                ))
+            }
+        };
+        let records = &self.data[1..];
+        if records.len() < count * RECORD_SIZE {
+            return Err(FinsError::invalid_response(format!(
+                "connection data truncated: expected {} units, got {} bytes",
+                count,
+                records.len()
+            )));
+        }
+
+        Ok(records
+            .chunks_exact(RECORD_SIZE)
+            .take(count)
+            .map(|record| UnitInfo {
+                unit_address: record[0],
+                model: String::from_utf8_lossy(&record[1..])
+                    .trim_end_matches([' ', '\0'])
+                    .to_string(),
+            })
+            .collect())
+    }
+
+    /// Parses the response data of a Multiple Memory Area Read command.
+    ///
+    /// Each item is preceded by its memory area code; word items carry 2
+    /// data bytes and bit items 1 byte. `specs` must be the specs the
+    /// request was built from, in the same order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is truncated or an item's area code
+    /// does not match its spec.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{FinsResponse, MemoryArea, MultiReadResult, MultiReadSpec};
+    ///
+    /// let bytes = [
+    ///     0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x01,
+    ///     0x01, 0x04, 0x00, 0x00,
+    ///     0x82, 0x12, 0x34, // DM word
+    ///     0x30, 0x01,       // CIO bit
+    /// ];
+    /// let specs = [
+    ///     MultiReadSpec { area: MemoryArea::DM, address: 100, bit: None },
+    ///     MultiReadSpec { area: MemoryArea::CIO, address: 0, bit: Some(5) },
+    /// ];
+    /// let response = FinsResponse::from_bytes(&bytes).unwrap();
+    /// assert_eq!(
+    ///     response.to_multi_read(&specs).unwrap(),
+    ///     vec![MultiReadResult::Word(0x1234), MultiReadResult::Bit(true)]
+    /// );
+    /// ```
+    pub fn to_multi_read(&self, specs: &[MultiReadSpec]) -> Result<Vec<MultiReadResult>> {
+        self.to_multi_read_with(specs, false)
+    }
+
+    /// Like [`to_multi_read`](Self::to_multi_read), optionally expecting
+    /// DM bit items.
+    pub(crate) fn to_multi_read_with(
+        &self,
+        specs: &[MultiReadSpec],
+        dm_bit_access: bool,
+    ) -> Result<Vec<MultiReadResult>> {
+        let mut data = self.data.as_slice();
+        let mut values = Vec::with_capacity(specs.len());
+
+        for (index, spec) in specs.iter().enumerate() {
+            let (expected_code, size) = match spec.bit {
+                Some(_) => (spec.area.bit_code_with(dm_bit_access)?, 1),
+                None => (spec.area.word_code(), 2),
+            };
+            if data.len() < 1 + size {
+                return Err(FinsError::invalid_response(format!(
+                    "multiple read data truncated at item {}",
+                    index
+                )));
+            }
+            if data[0] != expected_code {
+                return Err(FinsError::invalid_response(format!(
+                    "multiple read item {}: expected area code 0x{:02X}, got 0x{:02X}",
+                    index, expected_code, data[0]
+                )));
+            }
+
+            values.push(match spec.bit {
+                Some(_) => MultiReadResult::Bit(data[1] != 0),
+                None => MultiReadResult::Word(u16::from_be_bytes([data[1], data[2]])),
+            });
+            data = &data[1 + size..];
+        }
+
+        Ok(values)
+    }
+
+    /// Interprets the response to an Access Right Acquire command.
+    ///
+    /// End code 0x3001 means another device holds the access right; its
+    /// network, node and unit address are returned in the data.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::PlcError` for any other error end code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{AccessRight, FinsResponse, NodeAddress};
+    ///
+    /// let bytes = [
+    ///     0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x01,
+    ///     0x0C, 0x01, 0x30, 0x01, 0x00, 0x05, 0x00,
+    /// ];
+    /// let response = FinsResponse::from_bytes(&bytes).unwrap();
+    /// assert_eq!(
+    ///     response.to_access_right().unwrap(),
+    ///     AccessRight::HeldBy(NodeAddress::new(0, 5, 0))
+    /// );
+    /// ```
+    pub fn to_access_right(&self) -> Result<AccessRight> {
+        if self.main_code == 0x30 && self.sub_code == 0x01 && self.data.len() >= 3 {
+            return Ok(AccessRight::HeldBy(NodeAddress::new(
+                self.data[0],
+                self.data[1],
+                self.data[2],
+            )));
+        }
+        self.check_error()?;
+        Ok(AccessRight::Acquired)
+    }
+
+    /// Parses the response data of a Message Read command.
+    ///
+    /// The data starts with the message bit mask (2 bytes), followed by a
+    /// 32-byte ASCII text for every requested message in ascending order.
+    /// Trailing spaces and NUL bytes are trimmed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is shorter than the mask announces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsResponse;
+    ///
+    /// let mut bytes = vec![
+    ///     0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x01,
+    ///     0x09, 0x20, 0x00, 0x00,
+    ///     0x00, 0x04, // message 2
+    /// ];
+    /// bytes.extend_from_slice(b"TANK 3 LEVEL LOW                ");
+    /// let response = FinsResponse::from_bytes(&bytes).unwrap();
+    /// let messages = response.to_messages().unwrap();
+    /// assert_eq!(messages[&2], "TANK 3 LEVEL LOW");
+    /// ```
+    pub fn to_messages(&self) -> Result<BTreeMap<u8, String>> {
+        const MESSAGE_SIZE: usize = 32;
+
+        if self.data.len() < 2 {
+            return Err(FinsError::invalid_response(format!(
+                "message data too short: expected at least 2 bytes, got {}",
+                self.data.len()
+            )));
+        }
+
+        let mask = self.data[1];
+        let numbers: Vec<u8> = (0..8).filter(|n| mask & (1 << n) != 0).collect();
+        let texts = &self.data[2..];
+        if texts.len() < numbers.len() * MESSAGE_SIZE {
+            return Err(FinsError::invalid_response(format!(
+                "message data truncated: expected {} messages, got {} bytes",
+                numbers.len(),
+                texts.len()
+            )));
+        }
+
+        Ok(numbers
+            .into_iter()
+            .zip(texts.chunks_exact(MESSAGE_SIZE))
+            .map(|(number, text)| {
+                let text = String::from_utf8_lossy(text)
+                    .trim_end_matches([' ', '\0'])
+                    .to_string();
+                (number, text)
+            })
+            .collect())
+    }
+
+    /// Parses the response data of an Error Log Read command.
+    ///
+    /// The data starts with the maximum, stored and returned record counts
+    /// (2 bytes each), followed by 10-byte records: error code, detail code,
+    /// then minute, second, day, hour, year and month in BCD. Two-digit
+    /// years below 70 are in the 2000s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is truncated or a timestamp byte is
+    /// not valid BCD.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::FinsResponse;
+    ///
+    /// let bytes = [
+    ///     0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x01,
+    ///     0x21, 0x02, 0x00, 0x00,
+    ///     0x00, 0x14, 0x00, 0x01, 0x00, 0x01, // max, stored, returned
+    ///     0x80, 0xF1, 0x00, 0x01, 0x30, 0x15, 0x07, 0x09, 0x24, 0x03,
+    /// ];
+    /// let response = FinsResponse::from_bytes(&bytes).unwrap();
+    /// let log = response.to_error_log().unwrap();
+    /// assert_eq!(log[0].error_code, 0x80F1);
+    /// assert_eq!(log[0].timestamp.to_string(), "2024-03-07 09:30:15");
+    /// ```
+    pub fn to_error_log(&self) -> Result<Vec<ErrorLogEntry>> {
+        const COUNTS_SIZE: usize = 6;
+        const RECORD_SIZE: usize = 10;
+
+        if self.data.len() < COUNTS_SIZE {
+            return Err(FinsError::invalid_response(format!(
+                "error log data too short: expected at least {} bytes, got {}",
+                COUNTS_SIZE,
+                self.data.len()
+            )));
+        }
+
+        let returned = u16::from_be_bytes([self.data[4], self.data[5]]) as usize;
+        let records = &self.data[COUNTS_SIZE..];
+        if records.len() < returned * RECORD_SIZE {
+            return Err(FinsError::invalid_response(format!(
+                "error log truncated: expected {} records, got {} bytes",
+                returned,
+                records.len()
+            )));
+        }
+
+        records
+            .chunks_exact(RECORD_SIZE)
+            .take(returned)
+            .map(|record| {
+                let year = bcd_byte(record[8])? as u16;
+                Ok(ErrorLogEntry {
+                    error_code: u16::from_be_bytes([record[0], record[1]]),
+                    detail_code: u16::from_be_bytes([record[2], record[3]]),
+                    timestamp: PlcDateTime {
+                        year: if year < 70 { 2000 + year } else { 1900 + year },
+                        month: bcd_byte(record[9])?,
+                        day: bcd_byte(record[6])?,
+                        hour: bcd_byte(record[7])?,
+                        minute: bcd_byte(record[4])?,
+                        second: bcd_byte(record[5])?,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Decodes a two-digit BCD byte.
+fn bcd_byte(byte: u8) -> Result<u8> {
+    let (high, low) = (byte >> 4, byte & 0x0F);
+    if high > 9 || low > 9 {
+        return Err(FinsError::invalid_response(format!(
+            "invalid BCD byte 0x{:02X}",
+            byte
+        )));
+    }
+    Ok(high * 10 + low)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::EndCode;
 
     fn make_response(main_code: u8, sub_code: u8, data: &[u8]) -> Vec<u8> {
         let mut bytes = vec![
@@ -299,6 +902,38 @@ mod tests {
         assert!(!error2.is_success());
     }
 
+    #[test]
+    fn test_warnings() {
+        let clean = FinsResponse::from_bytes(&make_response(0x00, 0x00, &[])).unwrap();
+        assert!(clean.warnings().is_empty());
+        assert_eq!(clean.check_warnings().unwrap(), None);
+
+        let flagged = FinsResponse::from_bytes(&make_response(0x80, 0xC0, &[0, 1])).unwrap();
+        let warnings = flagged.check_warnings().unwrap().unwrap();
+        assert_eq!(
+            warnings,
+            EndCodeWarnings {
+                relay_error: true,
+                fatal_cpu_error: true,
+                non_fatal_cpu_error: true,
+            }
+        );
+        assert_eq!(
+            warnings.to_string(),
+            "relay error, fatal CPU error, non-fatal CPU error"
+        );
+        assert!(flagged.check_error().is_err());
+
+        let mut merged = EndCodeWarnings::from_codes(0x00, 0x40);
+        merged |= EndCodeWarnings::from_codes(0x80, 0x00);
+        assert!(merged.relay_error && merged.non_fatal_cpu_error && !merged.fatal_cpu_error);
+
+        // A flagged error is still an error
+        let error = FinsResponse::from_bytes(&make_response(0x21, 0x41, &[])).unwrap();
+        assert!(error.check_warnings().is_err());
+        assert!(error.warnings().non_fatal_cpu_error);
+    }
+
     #[test]
     fn test_check_error() {
         let success = FinsResponse::from_bytes(&make_response(0x00, 0x00, &[])).unwrap();
@@ -307,12 +942,10 @@ mod tests {
         let error = FinsResponse::from_bytes(&make_response(0x02, 0x03, &[])).unwrap();
         let err = error.check_error().unwrap_err();
         match err {
-            FinsError::PlcError {
-                main_code,
-                sub_code,
-            } => {
-                assert_eq!(main_code, 0x02);
-                assert_eq!(sub_code, 0x03);
+            FinsError::PlcError { end_code } => {
+                assert_eq!(end_code, EndCode::ThirdNodeNotInNetwork);
+                assert_eq!(end_code.main_code(), 0x02);
+                assert_eq!(end_code.sub_code(), 0x03);
             }
             _ => panic!("Expected PlcError"),
         }
@@ -362,10 +995,177 @@ mod tests {
         assert!(!response.to_bit().unwrap());
     }
 
+    #[test]
+    fn test_to_controller_data() {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"CS1G-CPU45H\0\0\0\0\0\0\0\0\0");
+        data.extend_from_slice(b"04.00               ");
+        data.extend_from_slice(&[0u8; 12]); // trailing area data is ignored
+        let response = FinsResponse::from_bytes(&make_response(0x00, 0x00, &data)).unwrap();
+        let controller = response.to_controller_data().unwrap();
+        assert_eq!(controller.model, "CS1G-CPU45H");
+        assert_eq!(controller.version, "04.00");
+    }
+
+    #[test]
+    fn test_to_controller_data_too_short() {
+        let response = FinsResponse::from_bytes(&make_response(0x00, 0x00, &[0x41; 39])).unwrap();
+        assert!(response.to_controller_data().is_err());
+    }
+
     #[test]
     fn test_to_bit_empty() {
         let bytes = make_response(0x00, 0x00, &[]);
         let response = FinsResponse::from_bytes(&bytes).unwrap();
         assert!(response.to_bit().is_err());
     }
+
+    #[test]
+    fn test_response_to_error_log() {
+        let data = [
+            0x00, 0x14, 0x00, 0x02, 0x00, 0x02, // max 20, stored 2, returned 2
+            0x80, 0xF1, 0x00, 0x01, 0x30, 0x15, 0x07, 0x09, 0x24, 0x03, // 2024-03-07
+            0x00, 0x0A, 0x12, 0x34, 0x59, 0x00, 0x31, 0x23, 0x99, 0x12, // 1999-12-31
+        ];
+        let response = FinsResponse::from_bytes(&make_response(0x00, 0x00, &data)).unwrap();
+        let log = response.to_error_log().unwrap();
+
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0].error_code, 0x80F1);
+        assert_eq!(log[0].detail_code, 0x0001);
+        assert_eq!(
+            log[0].timestamp,
+            PlcDateTime {
+                year: 2024,
+                month: 3,
+                day: 7,
+                hour: 9,
+                minute: 30,
+                second: 15,
+            }
+        );
+        assert_eq!(log[1].timestamp.to_string(), "1999-12-31 23:59:00");
+    }
+
+    #[test]
+    fn test_response_to_error_log_invalid() {
+        // Claims one record but carries none
+        let truncated = [0x00, 0x14, 0x00, 0x01, 0x00, 0x01];
+        let response = FinsResponse::from_bytes(&make_response(0x00, 0x00, &truncated)).unwrap();
+        assert!(response.to_error_log().is_err());
+
+        let bad_bcd = [
+            0x00, 0x14, 0x00, 0x01, 0x00, 0x01, 0x80, 0xF1, 0x00, 0x01, 0x3A, 0x15, 0x07, 0x09,
+            0x24, 0x03,
+        ];
+        let response = FinsResponse::from_bytes(&make_response(0x00, 0x00, &bad_bcd)).unwrap();
+        assert!(response.to_error_log().is_err());
+    }
+
+    #[test]
+    fn test_response_to_access_right() {
+        let acquired = FinsResponse::from_bytes(&make_response(0x00, 0x00, &[])).unwrap();
+        assert_eq!(acquired.to_access_right().unwrap(), AccessRight::Acquired);
+
+        let held_bytes = make_response(0x30, 0x01, &[0x01, 0x22, 0x00]);
+        let held = FinsResponse::from_bytes(&held_bytes).unwrap();
+        assert_eq!(
+            held.to_access_right().unwrap(),
+            AccessRight::HeldBy(NodeAddress::new(1, 0x22, 0))
+        );
+
+        let failed = FinsResponse::from_bytes(&make_response(0x11, 0x01, &[])).unwrap();
+        assert!(failed.to_access_right().is_err());
+    }
+
+    #[test]
+    fn test_response_to_messages() {
+        let mut data = vec![0x00, 0x81]; // messages 0 and 7
+        data.extend_from_slice(&[b' '; 32]);
+        let mut text = b"PUMP FAULT".to_vec();
+        text.resize(32, 0);
+        data.extend_from_slice(&text);
+
+        let response = FinsResponse::from_bytes(&make_response(0x00, 0x00, &data)).unwrap();
+        let messages = response.to_messages().unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[&0], "");
+        assert_eq!(messages[&7], "PUMP FAULT");
+
+        data.truncate(40);
+        let response = FinsResponse::from_bytes(&make_response(0x00, 0x00, &data)).unwrap();
+        assert!(response.to_messages().is_err());
+    }
+
+    #[test]
+    fn test_response_to_multi_read() {
+        use crate::memory::MemoryArea;
+
+        let specs = [
+            MultiReadSpec {
+                area: MemoryArea::DM,
+                address: 100,
+                bit: None,
+            },
+            MultiReadSpec {
+                area: MemoryArea::CIO,
+                address: 0,
+                bit: Some(5),
+            },
+            MultiReadSpec {
+                area: MemoryArea::WR,
+                address: 3,
+                bit: None,
+            },
+        ];
+        let data = [0x82, 0x12, 0x34, 0x30, 0x00, 0xB1, 0xAB, 0xCD];
+        let response = FinsResponse::from_bytes(&make_response(0x00, 0x00, &data)).unwrap();
+        let values = response.to_multi_read(&specs).unwrap();
+        assert_eq!(
+            values,
+            vec![
+                MultiReadResult::Word(0x1234),
+                MultiReadResult::Bit(false),
+                MultiReadResult::Word(0xABCD),
+            ]
+        );
+        assert_eq!(values[1].as_u16(), 0);
+
+        // Truncated data
+        let response = FinsResponse::from_bytes(&make_response(0x00, 0x00, &data[..6])).unwrap();
+        assert!(response.to_multi_read(&specs).is_err());
+
+        // Area code does not match the spec
+        let response =
+            FinsResponse::from_bytes(&make_response(0x00, 0x00, &[0xB0, 0x00, 0x01])).unwrap();
+        assert!(response.to_multi_read(&specs[..1]).is_err());
+    }
+
+    #[test]
+    fn test_response_to_connection_data() {
+        let mut data = vec![0x02, 0x00];
+        data.extend_from_slice(b"CJ2M-CPU31\0\0\0\0\0\0\0\0\0\0");
+        data.push(0x11);
+        data.extend_from_slice(b"CJ1W-ETN21          ");
+        let response = FinsResponse::from_bytes(&make_response(0x00, 0x00, &data)).unwrap();
+        let units = response.to_connection_data().unwrap();
+
+        assert_eq!(
+            units,
+            vec![
+                UnitInfo {
+                    unit_address: 0x00,
+                    model: "CJ2M-CPU31".to_string(),
+                },
+                UnitInfo {
+                    unit_address: 0x11,
+                    model: "CJ1W-ETN21".to_string(),
+                },
+            ]
+        );
+
+        data.truncate(30);
+        let response = FinsResponse::from_bytes(&make_response(0x00, 0x00, &data)).unwrap();
+        assert!(response.to_connection_data().is_err());
+    }
 }
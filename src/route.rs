@@ -0,0 +1,190 @@
+//! Multi-network routing helper.
+//!
+//! A FINS frame only carries the final destination (DNA/DA1/DA2); the
+//! routing tables in each PLC forward it through the intermediate
+//! gateways. [`Route`] records those gateways alongside the destination so
+//! the header fields are derived in one place and the path is validated
+//! against the FINS limit of two network bridges.
+//!
+//! # Example
+//!
+//! ```
+//! use omron_fins::{ClientConfig, NodeAddress, Route};
+//! use std::net::Ipv4Addr;
+//!
+//! // Ethernet -> Controller Link network 2, node 5
+//! let route = Route::new(NodeAddress::new(2, 5, 0), &[NodeAddress::new(1, 10, 0)])?;
+//! let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 10).with_route(&route);
+//! assert_eq!(config.destination, NodeAddress::new(2, 5, 0));
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use crate::error::{FinsError, Result};
+use crate::header::NodeAddress;
+
+/// Maximum number of network bridges a FINS frame may cross.
+pub const MAX_ROUTE_HOPS: usize = 2;
+
+/// Highest valid FINS network address.
+const MAX_NETWORK: u8 = 127;
+
+/// A path to a FINS node through zero or more gateway nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    gateways: Vec<NodeAddress>,
+    destination: NodeAddress,
+}
+
+impl Route {
+    /// Creates a route to `destination` through `gateways`, in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - Final node the commands are addressed to
+    /// * `gateways` - Bridge nodes crossed on the way, nearest first
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if:
+    /// - more than [`MAX_ROUTE_HOPS`] gateways are given
+    /// - a network address is above 127
+    /// - a remote destination uses network 0 (local network)
+    /// - two consecutive hops are on the same network
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{NodeAddress, Route};
+    ///
+    /// let route = Route::new(NodeAddress::new(3, 1, 0), &[
+    ///     NodeAddress::new(1, 10, 0),
+    ///     NodeAddress::new(2, 20, 0),
+    /// ])?;
+    /// assert_eq!(route.hops(), 2);
+    ///
+    /// let too_long = Route::new(NodeAddress::new(4, 1, 0), &[
+    ///     NodeAddress::new(1, 10, 0),
+    ///     NodeAddress::new(2, 20, 0),
+    ///     NodeAddress::new(3, 30, 0),
+    /// ]);
+    /// assert!(too_long.is_err());
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn new(destination: NodeAddress, gateways: &[NodeAddress]) -> Result<Self> {
+        if gateways.len() > MAX_ROUTE_HOPS {
+            return Err(FinsError::invalid_parameter(
+                "gateways",
+                format!(
+                    "route has {} hops, FINS allows at most {}",
+                    gateways.len(),
+                    MAX_ROUTE_HOPS
+                ),
+            ));
+        }
+
+        for node in gateways.iter().chain(std::iter::once(&destination)) {
+            if node.network > MAX_NETWORK {
+                return Err(FinsError::invalid_parameter(
+                    "network",
+                    format!("network {} exceeds maximum {}", node.network, MAX_NETWORK),
+                ));
+            }
+        }
+
+        if !gateways.is_empty() && destination.network == 0 {
+            return Err(FinsError::invalid_parameter(
+                "destination",
+                "a routed destination needs an explicit network address",
+            ));
+        }
+
+        for pair in gateways.windows(2) {
+            if pair[0].network == pair[1].network {
+                return Err(FinsError::invalid_parameter(
+                    "gateways",
+                    format!("consecutive gateways share network {}", pair[0].network),
+                ));
+            }
+        }
+        if let Some(last) = gateways.last() {
+            if last.network == destination.network {
+                return Err(FinsError::invalid_parameter(
+                    "gateways",
+                    format!(
+                        "last gateway is already on destination network {}",
+                        destination.network
+                    ),
+                ));
+            }
+        }
+
+        Ok(Self {
+            gateways: gateways.to_vec(),
+            destination,
+        })
+    }
+
+    /// Creates a route to a node on the local network (no gateways).
+    pub fn local(node: u8, unit: u8) -> Self {
+        Self {
+            gateways: Vec::new(),
+            destination: NodeAddress::new(0, node, unit),
+        }
+    }
+
+    /// Returns the gateway nodes, nearest first.
+    pub fn gateways(&self) -> &[NodeAddress] {
+        &self.gateways
+    }
+
+    /// Returns the number of network bridges crossed.
+    pub fn hops(&self) -> usize {
+        self.gateways.len()
+    }
+
+    /// Returns the address to place in the header's DNA/DA1/DA2 fields.
+    pub fn destination(&self) -> NodeAddress {
+        self.destination
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_local() {
+        let route = Route::local(10, 0);
+        assert_eq!(route.hops(), 0);
+        assert_eq!(route.destination(), NodeAddress::new(0, 10, 0));
+        assert!(route.gateways().is_empty());
+    }
+
+    #[test]
+    fn test_route_valid_two_hops() {
+        let gateways = [NodeAddress::new(1, 10, 0), NodeAddress::new(2, 20, 0)];
+        let route = Route::new(NodeAddress::new(3, 5, 0), &gateways).unwrap();
+        assert_eq!(route.hops(), 2);
+        assert_eq!(route.gateways(), &gateways);
+        assert_eq!(route.destination(), NodeAddress::new(3, 5, 0));
+    }
+
+    #[test]
+    fn test_route_validation_errors() {
+        let gw = NodeAddress::new(1, 10, 0);
+        // Too many hops
+        assert!(Route::new(
+            NodeAddress::new(4, 1, 0),
+            &[gw, NodeAddress::new(2, 1, 0), NodeAddress::new(3, 1, 0)]
+        )
+        .is_err());
+        // Network out of range
+        assert!(Route::new(NodeAddress::new(128, 1, 0), &[]).is_err());
+        // Routed destination on the local network
+        assert!(Route::new(NodeAddress::new(0, 1, 0), &[gw]).is_err());
+        // Gateway already on the destination network
+        assert!(Route::new(NodeAddress::new(1, 5, 0), &[gw]).is_err());
+        // Consecutive gateways on the same network
+        assert!(Route::new(NodeAddress::new(3, 5, 0), &[gw, NodeAddress::new(1, 11, 0)]).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+//! Linear scaling between raw counts and engineering units.
+//!
+//! Analog I/O units report values as counts, e.g. 0-4000 for 4-20 mA. A
+//! [`Scale`] maps that range onto engineering units so
+//! [`Client::read_scaled`](crate::Client::read_scaled) and
+//! [`Client::write_scaled`](crate::Client::write_scaled) work in bar, °C
+//! or % directly.
+//!
+//! # Example
+//!
+//! ```
+//! use omron_fins::Scale;
+//!
+//! // 0-4000 counts for 0-10 bar
+//! let pressure = Scale::new(0, 4000, 0.0, 10.0);
+//! assert_eq!(pressure.to_eu(1000), 2.5);
+//! assert_eq!(pressure.to_raw(7.5), Some(3000));
+//! ```
+
+use crate::error::{FinsError, Result};
+
+/// A linear mapping from a raw count range to an engineering unit range.
+///
+/// Values outside the ranges are extrapolated, not clamped, so over- and
+/// under-range readings stay visible. Either range may be inverted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scale {
+    /// Raw count at `eu_min`.
+    pub raw_min: i16,
+    /// Raw count at `eu_max`.
+    pub raw_max: i16,
+    /// Engineering value at `raw_min`.
+    pub eu_min: f64,
+    /// Engineering value at `raw_max`.
+    pub eu_max: f64,
+}
+
+impl Scale {
+    /// Creates a scale.
+    pub fn new(raw_min: i16, raw_max: i16, eu_min: f64, eu_max: f64) -> Self {
+        Self {
+            raw_min,
+            raw_max,
+            eu_min,
+            eu_max,
+        }
+    }
+
+    /// Converts a raw count to engineering units.
+    ///
+    /// Returns a non-finite value if `raw_min` equals `raw_max`.
+    pub fn to_eu(&self, raw: i16) -> f64 {
+        let span = self.raw_max as f64 - self.raw_min as f64;
+        self.eu_min + (raw as f64 - self.raw_min as f64) * (self.eu_max - self.eu_min) / span
+    }
+
+    /// Converts engineering units to the nearest raw count, or `None` if it
+    /// does not fit in an i16 or the engineering range is empty.
+    pub fn to_raw(&self, eu: f64) -> Option<i16> {
+        let span = self.eu_max - self.eu_min;
+        let raw = (self.raw_min as f64
+            + (eu - self.eu_min) * (self.raw_max as f64 - self.raw_min as f64) / span)
+            .round();
+        if raw.is_finite() && raw >= i16::MIN as f64 && raw <= i16::MAX as f64 {
+            Some(raw as i16)
+        } else {
+            None
+        }
+    }
+
+    /// Checks that both ranges are non-empty and finite.
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.raw_min == self.raw_max {
+            return Err(FinsError::invalid_parameter(
+                "scale",
+                format!("raw range {}..{} is empty", self.raw_min, self.raw_max),
+            ));
+        }
+        if !self.eu_min.is_finite() || !self.eu_max.is_finite() || self.eu_min == self.eu_max {
+            return Err(FinsError::invalid_parameter(
+                "scale",
+                format!(
+                    "engineering range {}..{} is empty or not finite",
+                    self.eu_min, self.eu_max
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_conversions() {
+        // -2000..2000 counts for -10..10 V
+        let volts = Scale::new(-2000, 2000, -10.0, 10.0);
+        assert_eq!(volts.to_eu(0), 0.0);
+        assert_eq!(volts.to_eu(-2000), -10.0);
+        // Over range is extrapolated
+        assert_eq!(volts.to_eu(2100), 10.5);
+        assert_eq!(volts.to_raw(2.5), Some(500));
+        assert_eq!(volts.to_raw(1.0e6), None);
+
+        let inverted = Scale::new(0, 4000, 100.0, 0.0);
+        assert_eq!(inverted.to_eu(1000), 75.0);
+        assert_eq!(inverted.to_raw(75.0), Some(1000));
+        assert!(inverted.check().is_ok());
+    }
+
+    #[test]
+    fn test_scale_check() {
+        assert!(Scale::new(100, 100, 0.0, 1.0).check().is_err());
+        assert!(Scale::new(0, 4000, 5.0, 5.0).check().is_err());
+        assert!(Scale::new(0, 4000, 0.0, f64::NAN).check().is_err());
+        assert_eq!(Scale::new(0, 4000, 5.0, 5.0).to_raw(5.0), None);
+    }
+}
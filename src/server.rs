@@ -0,0 +1,1063 @@
+//! In-process PLC simulator.
+//!
+//! A [`FinsServer`] answers FINS/UDP commands from an in-memory
+//! [`MemoryImage`] of the CIO, WR, HR, DM and AR areas, so integration tests
+//! and applications can run against a [`Client`](crate::Client) without
+//! hardware. It serves Memory Area Read, Write and Fill and Multiple Memory
+//! Area Read, for words and bits (including DM bits); any other command gets
+//! the end code for an undefined command (0x04 0x01).
+//!
+//! Handlers registered with [`FinsServer::with_handler`] answer further
+//! commands, e.g. Controller Status or the clock, or replace a built-in one
+//! to emulate unusual PLC behavior such as the 0x00 0x40 routing warning.
+//!
+//! Area sizes follow [`MemoryArea::max_words`]. Out-of-range addresses and
+//! malformed commands are answered with the end codes a CPU would use.
+//!
+//! A session log captured with a [`SessionRecorder`](crate::SessionRecorder)
+//! can be replayed with [`FinsServer::with_replay`], answering requests
+//! with the recorded responses and delays to reproduce field issues exactly.
+//!
+//! Images can be saved to and loaded from files with [`MemoryImage::save`]
+//! and [`MemoryImage::load`], so simulated state survives restarts and test
+//! fixtures can ship known contents. With the `serde` feature, images also
+//! serialize as a map from area name to words, e.g. for JSON fixtures.
+//!
+//! # Example
+//!
+//! ```
+//! use omron_fins::{Client, ClientConfig, FinsServer, MemoryArea};
+//! use std::net::Ipv4Addr;
+//!
+//! let server = FinsServer::bind("127.0.0.1:0")?.spawn()?;
+//! server.image().write(MemoryArea::DM, 100, &[1234])?;
+//!
+//! let config = ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(server.local_addr().port());
+//! let client = Client::new(config)?;
+//! assert_eq!(client.read(MemoryArea::DM, 100, 1)?, vec![1234]);
+//!
+//! client.write_bit(MemoryArea::WR, 3, 12, true)?;
+//! assert_eq!(server.image().read(MemoryArea::WR, 3, 1)?, vec![0x1000]);
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::error::{FinsError, Result};
+use crate::header::{FinsHeader, FINS_HEADER_SIZE};
+use crate::memory::MemoryArea;
+use crate::recorder::SessionEntry;
+use crate::transport::MAX_PACKET_SIZE;
+
+/// Areas held by a [`MemoryImage`].
+pub const SIMULATED_AREAS: [MemoryArea; 5] = [
+    MemoryArea::CIO,
+    MemoryArea::WR,
+    MemoryArea::HR,
+    MemoryArea::DM,
+    MemoryArea::AR,
+];
+
+/// Leading bytes of the binary image format.
+const IMAGE_MAGIC: [u8; 8] = *b"FINSIMG1";
+
+/// How often a spawned server checks whether it should stop.
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A FINS end code (main, sub).
+type EndCode = (u8, u8);
+
+const UNDEFINED_COMMAND: EndCode = (0x04, 0x01);
+const COMMAND_TOO_SHORT: EndCode = (0x10, 0x02);
+const DATA_MISMATCH: EndCode = (0x10, 0x03);
+const NO_SUCH_AREA: EndCode = (0x11, 0x01);
+const ADDRESS_OUT_OF_RANGE: EndCode = (0x11, 0x03);
+const RANGE_EXCEEDED: EndCode = (0x11, 0x04);
+
+/// Word contents of the simulated memory areas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryImage {
+    areas: HashMap<MemoryArea, Vec<u16>>,
+}
+
+impl Default for MemoryImage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryImage {
+    /// Creates an image of [`SIMULATED_AREAS`] with every word zero.
+    pub fn new() -> Self {
+        Self {
+            areas: SIMULATED_AREAS
+                .iter()
+                .map(|&area| (area, vec![0; area.max_words() as usize]))
+                .collect(),
+        }
+    }
+
+    /// Returns all words of an area, or `None` if it is not simulated.
+    pub fn area(&self, area: MemoryArea) -> Option<&[u16]> {
+        self.areas.get(&area).map(Vec::as_slice)
+    }
+
+    /// Reads `count` words starting at `address`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the area is not simulated or
+    /// the range runs past its end.
+    pub fn read(&self, area: MemoryArea, address: u16, count: u16) -> Result<Vec<u16>> {
+        let words = self.words(area)?;
+        let range = range(words.len(), address, count as usize)?;
+        Ok(words[range].to_vec())
+    }
+
+    /// Writes words starting at `address`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the area is not simulated or
+    /// the range runs past its end.
+    pub fn write(&mut self, area: MemoryArea, address: u16, data: &[u16]) -> Result<()> {
+        let words = self.words_mut(area)?;
+        let range = range(words.len(), address, data.len())?;
+        words[range].copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Encodes the image in the binary image format.
+    ///
+    /// The format is the magic `FINSIMG1`, then for each area its word code,
+    /// its word count as a big-endian u16 and its words, big-endian. Trailing
+    /// zero words are left out.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = IMAGE_MAGIC.to_vec();
+        for area in SIMULATED_AREAS {
+            let words = trim_zeros(&self.areas[&area]);
+            bytes.push(area.word_code());
+            bytes.extend_from_slice(&(words.len() as u16).to_be_bytes());
+            bytes.extend(words.iter().flat_map(|word| word.to_be_bytes()));
+        }
+        bytes
+    }
+
+    /// Decodes an image written by [`to_bytes`](Self::to_bytes).
+    ///
+    /// Areas may be missing or shorter than their size; the remaining words
+    /// are zero, so fixtures only need to hold the words they set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the data is not an image, is
+    /// truncated, or holds an unknown or oversized area.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let invalid = |reason: String| FinsError::invalid_parameter("data", reason);
+        let mut rest = data
+            .strip_prefix(&IMAGE_MAGIC)
+            .ok_or_else(|| invalid("not a FINS memory image".to_string()))?;
+        let mut image = Self::new();
+        while let [code, high, low, tail @ ..] = rest {
+            let area = SIMULATED_AREAS
+                .into_iter()
+                .find(|area| area.word_code() == *code)
+                .ok_or_else(|| invalid(format!("unknown area code 0x{:02X}", code)))?;
+            let count = u16::from_be_bytes([*high, *low]) as usize;
+            let bytes = tail
+                .get(..count * 2)
+                .ok_or_else(|| invalid(format!("{} words are truncated", area)))?;
+            let words: Vec<u16> = bytes
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            image.write(area, 0, &words).map_err(|_| {
+                invalid(format!(
+                    "{} holds more than {} words",
+                    area,
+                    area.max_words()
+                ))
+            })?;
+            rest = &tail[count * 2..];
+        }
+        if !rest.is_empty() {
+            return Err(invalid(format!("{} trailing bytes", rest.len())));
+        }
+        Ok(image)
+    }
+
+    /// Writes the image to a file in the binary image format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::Io` if the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(std::fs::write(path, self.to_bytes())?)
+    }
+
+    /// Reads an image from a file written by [`save`](Self::save).
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::Io` if the file cannot be read, or the errors of
+    /// [`from_bytes`](Self::from_bytes).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    fn words(&self, area: MemoryArea) -> Result<&Vec<u16>> {
+        self.areas.get(&area).ok_or_else(|| not_simulated(area))
+    }
+
+    fn words_mut(&mut self, area: MemoryArea) -> Result<&mut Vec<u16>> {
+        self.areas.get_mut(&area).ok_or_else(|| not_simulated(area))
+    }
+}
+
+fn not_simulated(area: MemoryArea) -> FinsError {
+    FinsError::invalid_parameter("area", format!("{} is not simulated", area))
+}
+
+/// Returns `words` without its trailing zeros.
+fn trim_zeros(words: &[u16]) -> &[u16] {
+    let len = words
+        .iter()
+        .rposition(|&word| word != 0)
+        .map_or(0, |last| last + 1);
+    &words[..len]
+}
+
+/// Serializes as a map from area name to words, without trailing zeros,
+/// e.g. `{"DM": [0, 0, 1234]}` in JSON.
+#[cfg(feature = "serde")]
+impl serde::Serialize for MemoryImage {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(SIMULATED_AREAS.len()))?;
+        for area in SIMULATED_AREAS {
+            map.serialize_entry(&area.to_string(), trim_zeros(&self.areas[&area]))?;
+        }
+        map.end()
+    }
+}
+
+/// Deserializes the map written by `Serialize`. Areas may be missing or
+/// shorter than their size, as in [`MemoryImage::from_bytes`].
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MemoryImage {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let areas = <HashMap<String, Vec<u16>>>::deserialize(deserializer)?;
+        let mut image = Self::new();
+        for (name, words) in areas {
+            let area = SIMULATED_AREAS
+                .into_iter()
+                .find(|area| area.to_string() == name)
+                .ok_or_else(|| D::Error::custom(format!("unknown area '{}'", name)))?;
+            image.write(area, 0, &words).map_err(D::Error::custom)?;
+        }
+        Ok(image)
+    }
+}
+
+/// Returns the index range of `count` words at `address`, if it fits in `len`.
+fn range(len: usize, address: u16, count: usize) -> Result<std::ops::Range<usize>> {
+    let start = address as usize;
+    if start + count > len {
+        return Err(FinsError::invalid_parameter(
+            "address/count",
+            format!("{} words from {} run past {} words", count, address, len),
+        ));
+    }
+    Ok(start..start + count)
+}
+
+/// The answer of a custom command handler: an end code and response data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    /// Main end code.
+    pub main_code: u8,
+    /// Sub end code.
+    pub sub_code: u8,
+    /// Response data following the end code.
+    pub data: Vec<u8>,
+}
+
+impl Reply {
+    /// Creates a reply with the given end code and data.
+    pub fn new(main_code: u8, sub_code: u8, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            main_code,
+            sub_code,
+            data: data.into(),
+        }
+    }
+
+    /// Creates a normal completion (0x00 0x00) reply.
+    pub fn ok(data: impl Into<Vec<u8>>) -> Self {
+        Self::new(0x00, 0x00, data)
+    }
+
+    /// Creates an error reply without data.
+    pub fn error(main_code: u8, sub_code: u8) -> Self {
+        Self::new(main_code, sub_code, Vec::new())
+    }
+}
+
+/// Custom command handlers by (MRC, SRC).
+type Handlers = HashMap<(u8, u8), Box<dyn Fn(&mut MemoryImage, &[u8]) -> Reply + Send + Sync>>;
+
+/// A simulated PLC answering FINS/UDP commands.
+pub struct FinsServer {
+    socket: UdpSocket,
+    image: Arc<Mutex<MemoryImage>>,
+    handlers: Handlers,
+    replay: Arc<Mutex<VecDeque<SessionEntry>>>,
+}
+
+impl FinsServer {
+    /// Binds a server with an all-zero image, e.g. to `"0.0.0.0:9600"` or to
+    /// `"127.0.0.1:0"` for an ephemeral test port.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::Io` if the socket cannot be bound.
+    pub fn bind(addr: impl ToSocketAddrs) -> Result<Self> {
+        Ok(Self {
+            socket: UdpSocket::bind(addr)?,
+            image: Arc::new(Mutex::new(MemoryImage::new())),
+            handlers: Handlers::new(),
+            replay: Arc::default(),
+        })
+    }
+
+    /// Replaces the memory image, e.g. with prepared fixture contents.
+    pub fn with_image(self, image: MemoryImage) -> Self {
+        *lock(&self.image) = image;
+        self
+    }
+
+    /// Answers the command `mrc`/`src` with `handler` instead of the
+    /// built-in behavior.
+    ///
+    /// The handler gets the memory image and the command data after the
+    /// command code, and returns the end code and data to send back. A later
+    /// handler for the same command replaces an earlier one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::{FinsServer, Reply};
+    ///
+    /// let server = FinsServer::bind("127.0.0.1:0")?
+    ///     // Controller Status: running, no errors
+    ///     .with_handler(0x06, 0x01, |_, _| Reply::ok([0x01, 0x00, 0x00, 0x00]))
+    ///     // Every word read succeeds with the routing table warning
+    ///     .with_handler(0x01, 0x01, |_, _| Reply::new(0x00, 0x40, [0x00, 0x00]));
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn with_handler<F>(mut self, mrc: u8, src: u8, handler: F) -> Self
+    where
+        F: Fn(&mut MemoryImage, &[u8]) -> Reply + Send + Sync + 'static,
+    {
+        self.handlers.insert((mrc, src), Box::new(handler));
+        self
+    }
+
+    /// Replays recorded exchanges, e.g. from
+    /// [`SessionRecorder::read_log`](crate::SessionRecorder::read_log).
+    ///
+    /// While a request matches the command and data of the next recorded
+    /// request, it is answered with the recorded response after the recorded
+    /// delay, with the addresses and SID of the new request. Exchanges
+    /// recorded without a response, such as timeouts, are not answered.
+    /// Other requests are served as usual.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{FinsServer, SessionRecorder};
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    ///
+    /// let log = SessionRecorder::read_log(BufReader::new(File::open("field.log")?))?;
+    /// let server = FinsServer::bind("127.0.0.1:9600")?.with_replay(log).spawn()?;
+    /// // run the client code under test against 127.0.0.1
+    /// assert_eq!(server.replay_pending(), 0);
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn with_replay(self, entries: impl IntoIterator<Item = SessionEntry>) -> Self {
+        lock(&self.replay).extend(entries);
+        self
+    }
+
+    /// Returns the number of recorded exchanges not replayed yet.
+    pub fn replay_pending(&self) -> usize {
+        lock(&self.replay).len()
+    }
+
+    /// Returns the address the server is bound to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::Io` if the socket address cannot be read.
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    /// Locks the memory image for inspection or changes.
+    pub fn image(&self) -> MutexGuard<'_, MemoryImage> {
+        lock(&self.image)
+    }
+
+    /// Receives one datagram and answers it.
+    ///
+    /// Datagrams that are not FINS commands are ignored, as are commands
+    /// that do not require a response once they are executed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::Io` if receiving or sending fails, including a
+    /// receive timeout set on the socket.
+    pub fn serve_one(&self) -> Result<()> {
+        let mut buf = [0u8; MAX_PACKET_SIZE];
+        let (len, from) = self.socket.recv_from(&mut buf)?;
+        let frame = &buf[..len];
+        let replayed = next_replay(&mut lock(&self.replay), frame);
+        if let Some(entry) = replayed {
+            if let (Some(recorded), Ok(header)) = (entry.response, FinsHeader::from_bytes(frame)) {
+                std::thread::sleep(entry.elapsed);
+                let mut response = response_header(header).to_bytes().to_vec();
+                response.extend_from_slice(recorded.get(FINS_HEADER_SIZE..).unwrap_or_default());
+                self.socket.send_to(&response, from)?;
+            }
+            return Ok(());
+        }
+        if let Some(response) = handle(&mut lock(&self.image), &self.handlers, frame) {
+            self.socket.send_to(&response, from)?;
+        }
+        Ok(())
+    }
+
+    /// Serves requests until an I/O error occurs.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error that stopped the server.
+    pub fn run(&self) -> Result<()> {
+        loop {
+            self.serve_one()?;
+        }
+    }
+
+    /// Serves requests on a background thread until the returned handle is
+    /// stopped or dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::Io` if the socket cannot be configured.
+    pub fn spawn(self) -> Result<ServerHandle> {
+        self.socket.set_read_timeout(Some(STOP_POLL_INTERVAL))?;
+        let addr = self.local_addr()?;
+        let image = Arc::clone(&self.image);
+        let replay = Arc::clone(&self.replay);
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let stop = Arc::clone(&stop);
+            std::thread::spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    // Timeouts only wake the loop up; other errors come from
+                    // single datagrams (e.g. an unreachable client) and must
+                    // not take the simulator down
+                    let _ = self.serve_one();
+                }
+            })
+        };
+        Ok(ServerHandle {
+            addr,
+            image,
+            replay,
+            stop,
+            thread: Some(thread),
+        })
+    }
+}
+
+impl std::fmt::Debug for FinsServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FinsServer")
+            .field("socket", &self.socket)
+            .field("handlers", &self.handlers.len())
+            .field("replay_pending", &self.replay_pending())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A [`FinsServer`] running on a background thread.
+///
+/// Dropping the handle stops the server.
+#[derive(Debug)]
+pub struct ServerHandle {
+    addr: SocketAddr,
+    image: Arc<Mutex<MemoryImage>>,
+    replay: Arc<Mutex<VecDeque<SessionEntry>>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ServerHandle {
+    /// Returns the address the server is bound to.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Locks the memory image for inspection or changes.
+    pub fn image(&self) -> MutexGuard<'_, MemoryImage> {
+        lock(&self.image)
+    }
+
+    /// Returns the number of recorded exchanges not replayed yet.
+    pub fn replay_pending(&self) -> usize {
+        lock(&self.replay).len()
+    }
+
+    /// Stops the server and waits for its thread to finish.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for ServerHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Locks shared state, recovering it if a thread panicked while holding it.
+fn lock<T>(state: &Mutex<T>) -> MutexGuard<'_, T> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Takes the next recorded exchange if `frame` repeats its command and data.
+fn next_replay(replay: &mut VecDeque<SessionEntry>, frame: &[u8]) -> Option<SessionEntry> {
+    let command = frame.get(FINS_HEADER_SIZE..)?;
+    let recorded = replay.front()?.request.get(FINS_HEADER_SIZE..)?;
+    if command == recorded {
+        replay.pop_front()
+    } else {
+        None
+    }
+}
+
+/// Returns the header answering `request`: addresses swapped, same SID.
+fn response_header(request: FinsHeader) -> FinsHeader {
+    FinsHeader {
+        icf: 0xC0,
+        rsv: 0x00,
+        gct: 0x02,
+        dna: request.sna,
+        da1: request.sa1,
+        da2: request.sa2,
+        sna: request.dna,
+        sa1: request.da1,
+        sa2: request.da2,
+        sid: request.sid,
+    }
+}
+
+/// Executes a command frame and returns the response frame, if one is due.
+fn handle(image: &mut MemoryImage, handlers: &Handlers, frame: &[u8]) -> Option<Vec<u8>> {
+    if frame.len() < FINS_HEADER_SIZE + 2 {
+        return None;
+    }
+    let header = FinsHeader::from_bytes(frame).ok()?;
+    if header.is_response() {
+        return None;
+    }
+    let (mrc, src) = (frame[10], frame[11]);
+    let body = &frame[12..];
+    let reply = match handlers.get(&(mrc, src)) {
+        Some(handler) => handler(image, body),
+        None => {
+            let result = match (mrc, src) {
+                (0x01, 0x01) => memory_read(image, body),
+                (0x01, 0x02) => memory_write(image, body),
+                (0x01, 0x03) => memory_fill(image, body),
+                (0x01, 0x04) => multiple_read(image, body),
+                _ => Err(UNDEFINED_COMMAND),
+            };
+            result.map_or_else(|(main, sub)| Reply::error(main, sub), Reply::ok)
+        }
+    };
+    if header.icf & 0x01 != 0 {
+        return None;
+    }
+
+    let mut response = response_header(header).to_bytes().to_vec();
+    response.extend_from_slice(&[mrc, src, reply.main_code, reply.sub_code]);
+    response.extend_from_slice(&reply.data);
+    Some(response)
+}
+
+/// Where a command reads or writes: an area, a word and a bit.
+#[derive(Debug, Clone, Copy)]
+struct Target {
+    area: MemoryArea,
+    bits: bool,
+    address: u16,
+    bit: u8,
+}
+
+impl Target {
+    /// Parses the area code, word address and bit number of a command.
+    fn parse(bytes: &[u8]) -> std::result::Result<Self, EndCode> {
+        let Some(&[code, high, low, bit]) = bytes.get(..4) else {
+            return Err(COMMAND_TOO_SHORT);
+        };
+        let (area, bits) = match code {
+            0xB0 => (MemoryArea::CIO, false),
+            0xB1 => (MemoryArea::WR, false),
+            0xB2 => (MemoryArea::HR, false),
+            0x82 => (MemoryArea::DM, false),
+            0xB3 => (MemoryArea::AR, false),
+            0x30 => (MemoryArea::CIO, true),
+            0x31 => (MemoryArea::WR, true),
+            0x32 => (MemoryArea::HR, true),
+            0x02 => (MemoryArea::DM, true),
+            0x33 => (MemoryArea::AR, true),
+            _ => return Err(NO_SUCH_AREA),
+        };
+        if (!bits && bit != 0) || bit > 15 {
+            return Err(ADDRESS_OUT_OF_RANGE);
+        }
+        Ok(Self {
+            area,
+            bits,
+            address: u16::from_be_bytes([high, low]),
+            bit,
+        })
+    }
+
+    /// Returns the words of the target area from the target word on.
+    fn words(self, image: &mut MemoryImage) -> std::result::Result<&mut [u16], EndCode> {
+        let words = image.words_mut(self.area).map_err(|_| NO_SUCH_AREA)?;
+        words
+            .get_mut(self.address as usize..)
+            .filter(|rest| !rest.is_empty())
+            .ok_or(ADDRESS_OUT_OF_RANGE)
+    }
+
+    /// Returns the word index and bit mask of the `offset`-th item.
+    fn bit_position(self, offset: usize) -> (usize, u16) {
+        let position = self.bit as usize + offset;
+        (position / 16, 1 << (position % 16))
+    }
+}
+
+/// Parses the item count that follows a target.
+fn count(bytes: &[u8]) -> std::result::Result<usize, EndCode> {
+    match bytes.get(4..6) {
+        Some(&[high, low]) => Ok(u16::from_be_bytes([high, low]) as usize),
+        _ => Err(COMMAND_TOO_SHORT),
+    }
+}
+
+fn memory_read(image: &mut MemoryImage, body: &[u8]) -> std::result::Result<Vec<u8>, EndCode> {
+    let target = Target::parse(body)?;
+    let count = count(body)?;
+    let words = target.words(image)?;
+    if target.bits {
+        let (last, _) = target.bit_position(count.saturating_sub(1));
+        if last >= words.len() {
+            return Err(RANGE_EXCEEDED);
+        }
+        Ok((0..count)
+            .map(|offset| {
+                let (index, mask) = target.bit_position(offset);
+                u8::from(words[index] & mask != 0)
+            })
+            .collect())
+    } else {
+        let words = words.get(..count).ok_or(RANGE_EXCEEDED)?;
+        Ok(words.iter().flat_map(|word| word.to_be_bytes()).collect())
+    }
+}
+
+fn memory_write(image: &mut MemoryImage, body: &[u8]) -> std::result::Result<Vec<u8>, EndCode> {
+    let target = Target::parse(body)?;
+    let count = count(body)?;
+    let data = &body[6..];
+    let words = target.words(image)?;
+    if target.bits {
+        if data.len() != count {
+            return Err(DATA_MISMATCH);
+        }
+        let (last, _) = target.bit_position(count.saturating_sub(1));
+        if last >= words.len() {
+            return Err(RANGE_EXCEEDED);
+        }
+        for (offset, &value) in data.iter().enumerate() {
+            let (index, mask) = target.bit_position(offset);
+            if value != 0 {
+                words[index] |= mask;
+            } else {
+                words[index] &= !mask;
+            }
+        }
+    } else {
+        if data.len() != count * 2 {
+            return Err(DATA_MISMATCH);
+        }
+        let words = words.get_mut(..count).ok_or(RANGE_EXCEEDED)?;
+        for (word, bytes) in words.iter_mut().zip(data.chunks_exact(2)) {
+            *word = u16::from_be_bytes([bytes[0], bytes[1]]);
+        }
+    }
+    Ok(Vec::new())
+}
+
+fn memory_fill(image: &mut MemoryImage, body: &[u8]) -> std::result::Result<Vec<u8>, EndCode> {
+    let target = Target::parse(body)?;
+    let count = count(body)?;
+    let value = match body.get(6..8) {
+        Some(&[high, low]) => u16::from_be_bytes([high, low]),
+        _ => return Err(COMMAND_TOO_SHORT),
+    };
+    if target.bits {
+        return Err(NO_SUCH_AREA);
+    }
+    let words = target.words(image)?;
+    words.get_mut(..count).ok_or(RANGE_EXCEEDED)?.fill(value);
+    Ok(Vec::new())
+}
+
+fn multiple_read(image: &mut MemoryImage, body: &[u8]) -> std::result::Result<Vec<u8>, EndCode> {
+    if body.is_empty() || body.len() % 4 != 0 {
+        return Err(COMMAND_TOO_SHORT);
+    }
+    let mut data = Vec::new();
+    for item in body.chunks_exact(4) {
+        let target = Target::parse(item)?;
+        let words = target.words(image)?;
+        data.push(item[0]);
+        if target.bits {
+            let (_, mask) = target.bit_position(0);
+            data.push(u8::from(words[0] & mask != 0));
+        } else {
+            data.extend_from_slice(&words[0].to_be_bytes());
+        }
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{Client, ClientConfig};
+    use crate::command::MultiReadSpec;
+    use crate::error::EndCode;
+    use crate::recorder::SessionRecorder;
+    use crate::response::MultiReadResult;
+    use std::net::Ipv4Addr;
+
+    fn frame(mrc: u8, src: u8, body: &[u8]) -> Vec<u8> {
+        let mut frame = vec![0x80, 0x00, 0x07, 0x00, 0x0A, 0x00, 0x00, 0x01, 0x00, 0x2A];
+        frame.extend_from_slice(&[mrc, src]);
+        frame.extend_from_slice(body);
+        frame
+    }
+
+    #[test]
+    fn test_memory_image() {
+        let mut image = MemoryImage::new();
+        image.write(MemoryArea::HR, 510, &[1, 2]).unwrap();
+        assert_eq!(image.read(MemoryArea::HR, 509, 3).unwrap(), vec![0, 1, 2]);
+        assert!(image.write(MemoryArea::HR, 511, &[1, 2]).is_err());
+        assert!(image.read(MemoryArea::TIM, 0, 1).is_err());
+        assert_eq!(image.area(MemoryArea::DM).unwrap().len(), 4096);
+    }
+
+    #[test]
+    fn test_memory_image_bytes() {
+        let mut image = MemoryImage::new();
+        image.write(MemoryArea::DM, 2, &[0x1234]).unwrap();
+        image.write(MemoryArea::AR, 1023, &[1]).unwrap();
+
+        let bytes = image.to_bytes();
+        assert_eq!(&bytes[..8], b"FINSIMG1");
+        // Zero areas are empty, DM keeps words up to the last non-zero one
+        assert_eq!(&bytes[8..17], [0xB0, 0, 0, 0xB1, 0, 0, 0xB2, 0, 0]);
+        assert_eq!(&bytes[17..26], [0x82, 0, 3, 0, 0, 0, 0, 0x12, 0x34]);
+        assert_eq!(MemoryImage::from_bytes(&bytes).unwrap(), image);
+
+        // Fixtures may leave areas out
+        let fixture = MemoryImage::from_bytes(b"FINSIMG1\x82\x00\x01\x00\x07").unwrap();
+        assert_eq!(fixture.read(MemoryArea::DM, 0, 2).unwrap(), vec![7, 0]);
+
+        assert!(MemoryImage::from_bytes(b"FINSIMG2").is_err());
+        assert!(MemoryImage::from_bytes(b"FINSIMG1\x82\x00\x02\x00\x07").is_err());
+        assert!(MemoryImage::from_bytes(b"FINSIMG1\x89\x00\x00").is_err());
+        assert!(MemoryImage::from_bytes(b"FINSIMG1\xB1\x02\x01").is_err());
+        assert!(MemoryImage::from_bytes(b"FINSIMG1\x82").is_err());
+
+        let path =
+            std::env::temp_dir().join(format!("omron-fins-image-{}.bin", std::process::id()));
+        image.save(&path).unwrap();
+        let loaded = MemoryImage::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), image);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_memory_image_serde() {
+        use serde::de::value::{Error, MapDeserializer};
+        use serde::Deserialize;
+
+        let entries = vec![("DM".to_string(), vec![0u16, 42])];
+        let image = MemoryImage::deserialize(MapDeserializer::<_, Error>::new(entries.into_iter()))
+            .unwrap();
+        assert_eq!(image.read(MemoryArea::DM, 0, 3).unwrap(), vec![0, 42, 0]);
+
+        let unknown = vec![("TIM".to_string(), vec![1u16])];
+        assert!(
+            MemoryImage::deserialize(MapDeserializer::<_, Error>::new(unknown.into_iter()))
+                .is_err()
+        );
+        let oversized = vec![("WR".to_string(), vec![0u16; 513])];
+        assert!(
+            MemoryImage::deserialize(MapDeserializer::<_, Error>::new(oversized.into_iter()))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_server_frames() {
+        let mut image = MemoryImage::new();
+        let handlers = Handlers::new();
+
+        // Response header swaps the addresses and keeps the SID
+        let response = handle(
+            &mut image,
+            &handlers,
+            &frame(
+                0x01,
+                0x02,
+                &[0x82, 0x00, 0x0A, 0x00, 0x00, 0x01, 0x12, 0x34],
+            ),
+        )
+        .unwrap();
+        assert_eq!(
+            response,
+            [0xC0, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x0A, 0x00, 0x2A, 0x01, 0x02, 0x00, 0x00]
+        );
+        assert_eq!(image.read(MemoryArea::DM, 10, 1).unwrap(), vec![0x1234]);
+
+        // Bits 15 of W0 and 0 of W1
+        handle(
+            &mut image,
+            &handlers,
+            &frame(
+                0x01,
+                0x02,
+                &[0x31, 0x00, 0x00, 0x0F, 0x00, 0x02, 0x01, 0x01],
+            ),
+        );
+        assert_eq!(
+            image.read(MemoryArea::WR, 0, 2).unwrap(),
+            vec![0x8000, 0x0001]
+        );
+
+        let end_code = |frame: &[u8], image: &mut MemoryImage| {
+            let response = handle(image, &handlers, frame).unwrap();
+            (response[12], response[13])
+        };
+        assert_eq!(
+            end_code(
+                &frame(0x01, 0x01, &[0xB1, 0x01, 0xFF, 0x00, 0x00, 0x02]),
+                &mut image
+            ),
+            RANGE_EXCEEDED
+        );
+        assert_eq!(
+            end_code(
+                &frame(0x01, 0x01, &[0xB1, 0x02, 0x00, 0x00, 0x00, 0x01]),
+                &mut image
+            ),
+            ADDRESS_OUT_OF_RANGE
+        );
+        assert_eq!(
+            end_code(
+                &frame(0x01, 0x01, &[0x89, 0x00, 0x00, 0x00, 0x00, 0x01]),
+                &mut image
+            ),
+            NO_SUCH_AREA
+        );
+        assert_eq!(
+            end_code(
+                &frame(0x01, 0x02, &[0x82, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00]),
+                &mut image
+            ),
+            DATA_MISMATCH
+        );
+        assert_eq!(
+            end_code(&frame(0x01, 0x01, &[0x82, 0x00]), &mut image),
+            COMMAND_TOO_SHORT
+        );
+        assert_eq!(
+            end_code(&frame(0x06, 0x01, &[]), &mut image),
+            UNDEFINED_COMMAND
+        );
+
+        // No response required, but still executed
+        let mut quiet = frame(
+            0x01,
+            0x03,
+            &[0xB2, 0x00, 0x00, 0x00, 0x00, 0x02, 0xAB, 0xCD],
+        );
+        quiet[0] |= 0x01;
+        assert!(handle(&mut image, &handlers, &quiet).is_none());
+        assert_eq!(
+            image.read(MemoryArea::HR, 0, 3).unwrap(),
+            vec![0xABCD, 0xABCD, 0]
+        );
+        assert!(handle(&mut image, &handlers, &[0xC0; 12]).is_none());
+    }
+
+    #[test]
+    fn test_server_handlers() {
+        let server = FinsServer::bind("127.0.0.1:0")
+            .unwrap()
+            .with_handler(0x06, 0x01, |_, _| Reply::ok([0x01, 0x00]))
+            .with_handler(0x01, 0x01, |image, _| {
+                let words = image.read(MemoryArea::DM, 0, 1).unwrap();
+                Reply::new(0x00, 0x40, words[0].to_be_bytes())
+            })
+            .with_handler(0x01, 0x02, |_, _| Reply::error(0x21, 0x01))
+            .spawn()
+            .unwrap();
+        server.image().write(MemoryArea::DM, 0, &[0x0102]).unwrap();
+
+        let config =
+            ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(server.local_addr().port());
+        let client = Client::new(config).unwrap();
+
+        let status = client.execute_raw(0x06, 0x01, &[]).unwrap();
+        assert_eq!(status.data, vec![0x01, 0x00]);
+        // The routing warning is accepted with data
+        assert_eq!(client.read(MemoryArea::DM, 0, 1).unwrap(), vec![0x0102]);
+        assert!(matches!(
+            client.write(MemoryArea::DM, 0, &[1]),
+            Err(FinsError::PlcError {
+                end_code: EndCode::ReadOnly
+            })
+        ));
+        // Built-in commands without a handler still work
+        client.fill(MemoryArea::DM, 0, 1, 7).unwrap();
+        assert_eq!(server.image().read(MemoryArea::DM, 0, 1).unwrap(), vec![7]);
+    }
+
+    #[test]
+    fn test_server_replay() {
+        // Record a session against one server...
+        let recording = FinsServer::bind("127.0.0.1:0").unwrap().spawn().unwrap();
+        recording
+            .image()
+            .write(MemoryArea::DM, 0, &[0xBEEF])
+            .unwrap();
+        let config =
+            ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(recording.local_addr().port());
+        let mut client = Client::new(config).unwrap();
+        client.set_session_recorder(SessionRecorder::new(16));
+        assert_eq!(client.read(MemoryArea::DM, 0, 1).unwrap(), vec![0xBEEF]);
+        assert!(client.read(MemoryArea::TIM, 0, 1).is_err());
+        let log = client.session_log();
+        recording.stop();
+
+        // ...and replay it with an empty image and a new client
+        let replay = FinsServer::bind("127.0.0.1:0")
+            .unwrap()
+            .with_replay(log)
+            .spawn()
+            .unwrap();
+        assert_eq!(replay.replay_pending(), 2);
+        let config =
+            ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 20).with_port(replay.local_addr().port());
+        let client = Client::new(config).unwrap();
+        assert_eq!(client.read(MemoryArea::DM, 0, 1).unwrap(), vec![0xBEEF]);
+        // Out of sequence requests come from the image
+        assert_eq!(client.read(MemoryArea::DM, 1, 1).unwrap(), vec![0]);
+        assert!(matches!(
+            client.read(MemoryArea::TIM, 0, 1),
+            Err(FinsError::PlcError {
+                end_code: EndCode::AreaCodeInvalid
+            })
+        ));
+        assert_eq!(replay.replay_pending(), 0);
+        // Once the log is used up the image answers again
+        assert_eq!(client.read(MemoryArea::DM, 0, 1).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_server_with_client() {
+        let server = FinsServer::bind("127.0.0.1:0").unwrap().spawn().unwrap();
+        server.image().write(MemoryArea::CIO, 0, &[0x0020]).unwrap();
+
+        let config =
+            ClientConfig::new(Ipv4Addr::LOCALHOST, 1, 10).with_port(server.local_addr().port());
+        let client = Client::new(config).unwrap();
+
+        client.write(MemoryArea::DM, 100, &[1, 2, 3]).unwrap();
+        assert_eq!(client.read(MemoryArea::DM, 100, 3).unwrap(), vec![1, 2, 3]);
+        client.fill(MemoryArea::HR, 0, 4, 0x00FF).unwrap();
+        assert_eq!(client.read(MemoryArea::HR, 0, 4).unwrap(), vec![0x00FF; 4]);
+        assert!(client.read_bit(MemoryArea::CIO, 0, 5).unwrap());
+
+        let results = client
+            .read_multiple_typed(&[
+                MultiReadSpec {
+                    area: MemoryArea::DM,
+                    address: 101,
+                    bit: None,
+                },
+                MultiReadSpec {
+                    area: MemoryArea::CIO,
+                    address: 0,
+                    bit: Some(5),
+                },
+            ])
+            .unwrap();
+        assert_eq!(
+            results,
+            vec![MultiReadResult::Word(2), MultiReadResult::Bit(true)]
+        );
+
+        assert!(matches!(
+            client.read(MemoryArea::TIM, 0, 1),
+            Err(FinsError::PlcError {
+                end_code: EndCode::AreaCodeInvalid
+            })
+        ));
+        server.stop();
+    }
+}
@@ -0,0 +1,141 @@
+//! Link health counters.
+//!
+//! Every [`Client`](crate::Client) keeps a set of counters that can be read
+//! at any time with [`Client::stats`](crate::Client::stats), e.g. to feed a
+//! monitoring dashboard.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use omron_fins::{Client, ClientConfig};
+//! use std::net::Ipv4Addr;
+//!
+//! let client = Client::new(ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0))?;
+//! let stats = client.stats();
+//! println!("{} requests, {} timeouts", stats.requests_sent, stats.timeouts);
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Snapshot of a client's communication counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClientStats {
+    /// Command frames sent, including retransmits.
+    pub requests_sent: u64,
+    /// Response frames received.
+    pub responses_received: u64,
+    /// Requests that timed out waiting for a response.
+    pub timeouts: u64,
+    /// Responses discarded because their SID did not match.
+    pub sid_mismatches: u64,
+    /// Commands re-sent after a SID mismatch.
+    pub retransmits: u64,
+    /// Stale datagrams discarded (drained or unclaimed in pipelined mode).
+    pub stale_packets: u64,
+    /// Total bytes sent.
+    pub bytes_sent: u64,
+    /// Total bytes received.
+    pub bytes_received: u64,
+}
+
+/// Lock-free counters behind [`ClientStats`].
+#[derive(Debug, Default)]
+pub(crate) struct StatsCounters {
+    requests_sent: AtomicU64,
+    responses_received: AtomicU64,
+    timeouts: AtomicU64,
+    sid_mismatches: AtomicU64,
+    retransmits: AtomicU64,
+    stale_packets: AtomicU64,
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+}
+
+impl StatsCounters {
+    pub(crate) fn record_sent(&self, bytes: usize) {
+        self.requests_sent.fetch_add(1, Ordering::Relaxed);
+        self.bytes_sent.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_received(&self, bytes: usize) {
+        self.responses_received.fetch_add(1, Ordering::Relaxed);
+        self.bytes_received
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_timeout(&self) {
+        self.timeouts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_sid_mismatch(&self) {
+        self.sid_mismatches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_retransmit(&self) {
+        self.retransmits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_stale(&self, count: usize) {
+        self.stale_packets
+            .fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> ClientStats {
+        ClientStats {
+            requests_sent: self.requests_sent.load(Ordering::Relaxed),
+            responses_received: self.responses_received.load(Ordering::Relaxed),
+            timeouts: self.timeouts.load(Ordering::Relaxed),
+            sid_mismatches: self.sid_mismatches.load(Ordering::Relaxed),
+            retransmits: self.retransmits.load(Ordering::Relaxed),
+            stale_packets: self.stale_packets.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        for counter in [
+            &self.requests_sent,
+            &self.responses_received,
+            &self.timeouts,
+            &self.sid_mismatches,
+            &self.retransmits,
+            &self.stale_packets,
+            &self.bytes_sent,
+            &self.bytes_received,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counters_snapshot_and_reset() {
+        let counters = StatsCounters::default();
+        counters.record_sent(18);
+        counters.record_sent(18);
+        counters.record_received(16);
+        counters.record_timeout();
+        counters.record_sid_mismatch();
+        counters.record_retransmit();
+        counters.record_stale(4);
+
+        let stats = counters.snapshot();
+        assert_eq!(stats.requests_sent, 2);
+        assert_eq!(stats.bytes_sent, 36);
+        assert_eq!(stats.responses_received, 1);
+        assert_eq!(stats.bytes_received, 16);
+        assert_eq!(stats.timeouts, 1);
+        assert_eq!(stats.sid_mismatches, 1);
+        assert_eq!(stats.retransmits, 1);
+        assert_eq!(stats.stale_packets, 4);
+
+        counters.reset();
+        assert_eq!(counters.snapshot(), ClientStats::default());
+    }
+}
@@ -0,0 +1,266 @@
+//! Named tags for PLC addresses.
+//!
+//! A [`TagTable`] maps names such as `"LineSpeed"` to an address and a data
+//! type, so application code refers to PLC data by name and the address
+//! bookkeeping lives in one place. Set the table with
+//! [`ClientConfig::with_tags`](crate::ClientConfig::with_tags), then use
+//! [`Client::read_tag`](crate::Client::read_tag) and
+//! [`Client::write_tag`](crate::Client::write_tag). Each call is a single
+//! read or write; nothing is polled or cached.
+//!
+//! # Example
+//!
+//! ```
+//! use omron_fins::{DataType, TagKind, TagTable};
+//!
+//! let mut tags = TagTable::new();
+//! tags.add("LineSpeed", "D100", TagKind::Value(DataType::REAL))?;
+//! tags.add("EStop", "CIO0.05", TagKind::Bit)?;
+//! tags.add("Operator", "D200", TagKind::String { words: 10 })?;
+//!
+//! assert_eq!(tags.get("LineSpeed").unwrap().address().word, 100);
+//! assert!(tags.add("EStop", "CIO0.06", TagKind::Bit).is_err());
+//! # Ok::<(), omron_fins::FinsError>(())
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::address::FinsAddress;
+use crate::error::{FinsError, Result};
+use crate::types::{DataType, PlcValue};
+
+/// What a tag holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagKind {
+    /// A single bit; the tag address must name a bit.
+    Bit,
+    /// A numeric value of the given type, starting at the tag's word.
+    Value(DataType),
+    /// A string of fixed length, 2 characters per word.
+    String {
+        /// Number of words the string occupies.
+        words: u16,
+    },
+}
+
+/// A value read from or written to a tag.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TagValue {
+    /// Value of a [`TagKind::Bit`] tag.
+    Bit(bool),
+    /// Value of a [`TagKind::Value`] tag.
+    Value(PlcValue),
+    /// Value of a [`TagKind::String`] tag.
+    String(String),
+}
+
+impl From<bool> for TagValue {
+    fn from(value: bool) -> Self {
+        TagValue::Bit(value)
+    }
+}
+
+impl From<PlcValue> for TagValue {
+    fn from(value: PlcValue) -> Self {
+        TagValue::Value(value)
+    }
+}
+
+impl From<String> for TagValue {
+    fn from(value: String) -> Self {
+        TagValue::String(value)
+    }
+}
+
+impl From<&str> for TagValue {
+    fn from(value: &str) -> Self {
+        TagValue::String(value.to_string())
+    }
+}
+
+/// A named PLC address with its data type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    name: String,
+    address: FinsAddress,
+    kind: TagKind,
+}
+
+impl Tag {
+    /// Creates a tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if a [`TagKind::Bit`] tag has a
+    /// word address, any other tag has a bit address, or a string tag has
+    /// zero words.
+    pub fn new(name: impl Into<String>, address: FinsAddress, kind: TagKind) -> Result<Self> {
+        let name = name.into();
+        match (kind, address.bit) {
+            (TagKind::Bit, None) => {
+                return Err(FinsError::invalid_parameter(
+                    "address",
+                    format!("bit tag '{}' needs a bit address", name),
+                ))
+            }
+            (TagKind::Value(_) | TagKind::String { .. }, Some(_)) => {
+                return Err(FinsError::invalid_parameter(
+                    "address",
+                    format!("tag '{}' needs a word address", name),
+                ))
+            }
+            (TagKind::String { words: 0 }, _) => {
+                return Err(FinsError::invalid_parameter(
+                    "words",
+                    format!("string tag '{}' needs at least 1 word", name),
+                ))
+            }
+            _ => {}
+        }
+        Ok(Self {
+            name,
+            address,
+            kind,
+        })
+    }
+
+    /// Returns the tag name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the tag address.
+    pub fn address(&self) -> FinsAddress {
+        self.address
+    }
+
+    /// Returns what the tag holds.
+    pub fn kind(&self) -> TagKind {
+        self.kind
+    }
+
+    /// Returns the error for a value that does not match the tag's kind.
+    pub(crate) fn mismatch(&self, value: &TagValue) -> FinsError {
+        FinsError::invalid_parameter(
+            "value",
+            format!(
+                "{:?} does not match tag '{}' ({:?})",
+                value, self.name, self.kind
+            ),
+        )
+    }
+}
+
+/// A set of tags looked up by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagTable {
+    tags: BTreeMap<String, Tag>,
+}
+
+impl TagTable {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a tag at an address given in Omron notation (see
+    /// [`FinsAddress`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the name is already taken,
+    /// the address does not parse or it does not suit `kind` (see
+    /// [`Tag::new`]).
+    pub fn add(&mut self, name: impl Into<String>, address: &str, kind: TagKind) -> Result<()> {
+        self.insert(Tag::new(name, address.parse()?, kind)?)
+    }
+
+    /// Adds a tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if the name is already taken.
+    pub fn insert(&mut self, tag: Tag) -> Result<()> {
+        if self.tags.contains_key(&tag.name) {
+            return Err(FinsError::invalid_parameter(
+                "name",
+                format!("tag '{}' is already defined", tag.name),
+            ));
+        }
+        self.tags.insert(tag.name.clone(), tag);
+        Ok(())
+    }
+
+    /// Removes a tag, returning it if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<Tag> {
+        self.tags.remove(name)
+    }
+
+    /// Returns the tag called `name`.
+    pub fn get(&self, name: &str) -> Option<&Tag> {
+        self.tags.get(name)
+    }
+
+    /// Returns the tag called `name`, or `FinsError::InvalidParameter` if
+    /// there is none.
+    pub(crate) fn lookup(&self, name: &str) -> Result<&Tag> {
+        self.get(name)
+            .ok_or_else(|| FinsError::invalid_parameter("tag", format!("unknown tag '{}'", name)))
+    }
+
+    /// Returns the number of tags.
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    /// Returns `true` if the table has no tags.
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Iterates over the tags in name order.
+    pub fn iter(&self) -> impl Iterator<Item = &Tag> {
+        self.tags.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MemoryArea;
+
+    #[test]
+    fn test_tag_validation() {
+        let word = FinsAddress::word(MemoryArea::DM, 10);
+        let bit = FinsAddress::bit(MemoryArea::CIO, 0, 5).unwrap();
+        assert!(Tag::new("A", bit, TagKind::Bit).is_ok());
+        assert!(Tag::new("A", word, TagKind::Bit).is_err());
+        assert!(Tag::new("A", bit, TagKind::Value(DataType::INT)).is_err());
+        assert!(Tag::new("A", word, TagKind::String { words: 0 }).is_err());
+
+        let tag = Tag::new("Speed", word, TagKind::Value(DataType::REAL)).unwrap();
+        assert!(matches!(
+            tag.mismatch(&PlcValue::Dint(1).into()),
+            FinsError::InvalidParameter { .. }
+        ));
+    }
+
+    #[test]
+    fn test_tag_table() {
+        let mut tags = TagTable::new();
+        tags.add("Speed", "D100", TagKind::Value(DataType::REAL))
+            .unwrap();
+        tags.add("Alarm", "W3.12", TagKind::Bit).unwrap();
+        assert!(tags
+            .add("Speed", "D102", TagKind::Value(DataType::INT))
+            .is_err());
+        assert!(tags.add("Bad", "X1", TagKind::Bit).is_err());
+
+        assert_eq!(tags.len(), 2);
+        let names: Vec<&str> = tags.iter().map(Tag::name).collect();
+        assert_eq!(names, ["Alarm", "Speed"]);
+        assert!(tags.lookup("Missing").is_err());
+        assert!(tags.remove("Alarm").is_some());
+        assert!(tags.get("Alarm").is_none());
+    }
+}
@@ -0,0 +1,757 @@
+//! FINS/TCP transport.
+//!
+//! FINS/TCP wraps every FINS frame in a 16-byte header and starts each
+//! connection with a node-address handshake, in which the PLC's Ethernet
+//! unit assigns (or confirms) the client's FINS node number.
+//!
+//! # Frame Structure
+//!
+//! | Field | Size | Description |
+//! |-------|------|-------------|
+//! | Magic | 4 bytes | ASCII `"FINS"` |
+//! | Length | 4 bytes | Bytes following this field (big-endian) |
+//! | Command | 4 bytes | 0 = node address (client), 1 = node address (server), 2 = FINS frame, 3 = error |
+//! | Error code | 4 bytes | 0 on success |
+//! | Data | Variable | Node addresses or a FINS frame |
+//!
+//! If the PLC drops the connection, e.g. after a power cycle or an idle
+//! timeout, the next request re-runs the handshake on a new connection and
+//! fails with `FinsError::ConnectionReset`; later requests use the new
+//! connection. A receive timeout or a garbled frame also replaces the
+//! connection, so a late response can never be read as the answer to a
+//! later request. TCP keep-alive probes
+//! ([`set_keep_alive`](TcpTransport::set_keep_alive)) detect a dead
+//! connection between requests.
+//!
+//! Most applications use FINS/TCP through
+//! [`ClientConfig::with_tcp`](crate::ClientConfig::with_tcp) rather than
+//! this transport directly.
+
+use std::io::{self, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpStream};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use socket2::{SockRef, TcpKeepalive};
+
+use crate::error::{FinsError, Result};
+use crate::logging::{log_debug, log_warn};
+use crate::transport::MAX_PACKET_SIZE;
+
+/// Magic bytes at the start of every FINS/TCP frame.
+pub const FINS_TCP_MAGIC: [u8; 4] = *b"FINS";
+
+/// FINS/TCP header size: magic (4) + length (4) + command (4) + error code (4).
+pub const FINS_TCP_HEADER_SIZE: usize = 16;
+
+/// Node address data send, client to server.
+const CMD_NODE_ADDRESS_CLIENT: u32 = 0x0000_0000;
+/// Node address data send, server to client.
+const CMD_NODE_ADDRESS_SERVER: u32 = 0x0000_0001;
+/// FINS frame send.
+const CMD_FINS_FRAME: u32 = 0x0000_0002;
+/// Error notification.
+const CMD_ERROR: u32 = 0x0000_0003;
+
+/// Returns a human-readable description for FINS/TCP error codes.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::tcp_error_description;
+///
+/// assert_eq!(tcp_error_description(0x20), "All connections are in use");
+/// ```
+pub fn tcp_error_description(code: u32) -> &'static str {
+    match code {
+        0x00 => "Normal",
+        0x01 => "The header is not 'FINS' (ASCII code)",
+        0x02 => "The data length is too long",
+        0x03 => "The command is not supported",
+        0x20 => "All connections are in use",
+        0x21 => "The specified node is already connected",
+        0x22 => "Attempt to access a protected node from an unspecified IP address",
+        0x23 => "The client FINS node address is out of range",
+        0x24 => "The same FINS node address is being used by the client and server",
+        0x25 => "All the node addresses available for allocation have been used",
+        _ => "Unknown error code",
+    }
+}
+
+/// TCP transport for FINS communication.
+///
+/// Performs the node-address handshake on connect and then exchanges
+/// FINS frames wrapped in FINS/TCP headers. Requests are serialized on the
+/// stream, one request and one response at a time.
+///
+/// A request that finds the connection closed or reset reconnects, repeats
+/// the handshake for the same client node and returns
+/// `FinsError::ConnectionReset`. The request itself is not resent, as it
+/// may already have reached the PLC.
+pub struct TcpTransport {
+    stream: Mutex<TcpStream>,
+    remote_addr: SocketAddr,
+    timeout: Duration,
+    connect_timeout: Duration,
+    keep_alive: Duration,
+    client_node: u8,
+    server_node: u8,
+}
+
+impl TcpTransport {
+    /// Connects to the PLC and performs the node-address handshake.
+    ///
+    /// # Arguments
+    ///
+    /// * `plc_addr` - Socket address of the PLC (IP:port)
+    /// * `connect_timeout` - Timeout for the TCP connect and the handshake
+    /// * `timeout` - Read/write timeout for subsequent requests
+    /// * `client_node` - Requested client node number (0 = let the PLC assign one)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The connection cannot be established (`FinsError::Io` or `FinsError::Timeout`)
+    /// - The PLC rejects the handshake (`FinsError::TcpError`)
+    /// - The handshake response is malformed (`FinsError::InvalidResponse`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::TcpTransport;
+    /// use std::time::Duration;
+    ///
+    /// let transport = TcpTransport::connect(
+    ///     "192.168.1.10:9600".parse().unwrap(),
+    ///     Duration::from_secs(10),
+    ///     Duration::from_secs(2),
+    ///     0,
+    /// ).unwrap();
+    /// println!("assigned node {}", transport.client_node());
+    /// ```
+    pub fn connect(
+        plc_addr: SocketAddr,
+        connect_timeout: Duration,
+        timeout: Duration,
+        client_node: u8,
+    ) -> Result<Self> {
+        let (stream, client_node, server_node) = handshake(plc_addr, connect_timeout, client_node)?;
+        stream.set_read_timeout(Some(timeout))?;
+        stream.set_write_timeout(Some(timeout))?;
+
+        Ok(Self {
+            stream: Mutex::new(stream),
+            remote_addr: plc_addr,
+            timeout,
+            connect_timeout,
+            keep_alive: Duration::ZERO,
+            client_node,
+            server_node,
+        })
+    }
+
+    /// Enables TCP keep-alive probes after `interval` without traffic.
+    ///
+    /// Detects a PLC that disappeared without closing the connection while
+    /// the client is idle, so the next request reconnects instead of
+    /// waiting for its timeout. Zero disables the probes (the default).
+    /// The setting survives automatic reconnects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the operating system rejects the setting.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::TcpTransport;
+    /// use std::time::Duration;
+    ///
+    /// let mut transport = TcpTransport::connect(
+    ///     "192.168.1.10:9600".parse().unwrap(),
+    ///     Duration::from_secs(10),
+    ///     Duration::from_secs(2),
+    ///     0,
+    /// )?;
+    /// transport.set_keep_alive(Duration::from_secs(30))?;
+    /// # Ok::<(), omron_fins::FinsError>(())
+    /// ```
+    pub fn set_keep_alive(&mut self, interval: Duration) -> Result<()> {
+        let stream = self.stream.get_mut().unwrap_or_else(|e| e.into_inner());
+        apply_keep_alive(stream, interval)?;
+        self.keep_alive = interval;
+        Ok(())
+    }
+
+    /// Returns the keep-alive interval, zero if disabled.
+    pub fn keep_alive(&self) -> Duration {
+        self.keep_alive
+    }
+
+    /// Sends a FINS frame and receives the response.
+    ///
+    /// Frames whose SID (FINS header byte 9) differs from the request's are
+    /// discarded until the matching response arrives. After a timeout or a
+    /// malformed frame the connection is replaced, since the stream may
+    /// still hold the late response or the rest of the frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The send fails
+    /// - The receive times out (`FinsError::Timeout`)
+    /// - The PLC reports a FINS/TCP error (`FinsError::TcpError`)
+    /// - The connection was lost and has been re-established
+    ///   (`FinsError::ConnectionReset`)
+    /// - Other I/O errors occur, including a failed reconnect
+    pub fn send_receive(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut stream = self.stream.lock().unwrap_or_else(|e| e.into_inner());
+        self.exchange(&mut stream, data, self.timeout)
+    }
+
+    /// Exchanges one request and response on the locked stream.
+    fn exchange(&self, stream: &mut TcpStream, data: &[u8], timeout: Duration) -> Result<Vec<u8>> {
+        let result = write_frame(stream, CMD_FINS_FRAME, data)
+            .and_then(|()| read_response(stream, data, timeout));
+        match self.recover(stream, result) {
+            Err(error @ (FinsError::Timeout | FinsError::InvalidResponse { .. })) => {
+                self.resync(stream);
+                Err(error)
+            }
+            other => other,
+        }
+    }
+
+    /// Sends a FINS frame without waiting for a response.
+    ///
+    /// For commands sent with the ICF "response not required" bit set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the send fails, or
+    /// `FinsError::ConnectionReset` if the connection was lost and has been
+    /// re-established.
+    pub fn send(&self, data: &[u8]) -> Result<()> {
+        let mut stream = self.stream.lock().unwrap_or_else(|e| e.into_inner());
+        let result = write_frame(&mut stream, CMD_FINS_FRAME, data);
+        self.recover(&mut stream, result)
+    }
+
+    /// Sends a FINS frame and waits up to `timeout` for the response.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`send_receive`](Self::send_receive).
+    pub fn send_receive_with_timeout(&self, data: &[u8], timeout: Duration) -> Result<Vec<u8>> {
+        let mut stream = self.stream.lock().unwrap_or_else(|e| e.into_inner());
+        self.exchange(&mut stream, data, timeout)
+    }
+
+    /// Sets the send timeout independently of the receive timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the timeout is zero or rejected by the
+    /// operating system.
+    pub fn set_send_timeout(&self, timeout: Duration) -> Result<()> {
+        let stream = self.stream.lock().unwrap_or_else(|e| e.into_inner());
+        stream.set_write_timeout(Some(timeout))?;
+        Ok(())
+    }
+
+    /// Returns the client node number confirmed by the PLC.
+    pub fn client_node(&self) -> u8 {
+        self.client_node
+    }
+
+    /// Returns the PLC's (server) node number.
+    pub fn server_node(&self) -> u8 {
+        self.server_node
+    }
+
+    /// Returns the remote PLC address.
+    pub fn remote_addr(&self) -> SocketAddr {
+        self.remote_addr
+    }
+
+    /// Returns the configured receive timeout.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Changes the configured receive timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the timeout is zero or rejected by the
+    /// operating system.
+    pub fn set_timeout(&mut self, timeout: Duration) -> Result<()> {
+        self.set_read_timeout(timeout)?;
+        self.timeout = timeout;
+        Ok(())
+    }
+
+    fn set_read_timeout(&self, timeout: Duration) -> Result<()> {
+        let stream = self.stream.lock().unwrap_or_else(|e| e.into_inner());
+        stream.set_read_timeout(Some(timeout))?;
+        Ok(())
+    }
+
+    /// Replaces a lost connection and turns its error into
+    /// `FinsError::ConnectionReset`; other results pass through.
+    fn recover<T>(&self, stream: &mut TcpStream, result: Result<T>) -> Result<T> {
+        match result {
+            Err(FinsError::Io(error)) if is_connection_lost(&error) => {
+                self.rehandshake(stream)?;
+                Err(FinsError::ConnectionReset)
+            }
+            other => other,
+        }
+    }
+
+    /// Replaces a connection that may be out of step with the PLC.
+    ///
+    /// If the new connection fails, the old one is left shut down, so the
+    /// next request reconnects through [`recover`](Self::recover).
+    fn resync(&self, stream: &mut TcpStream) {
+        let _ = stream.shutdown(Shutdown::Both);
+        if let Err(error) = self.rehandshake(stream) {
+            log_warn!(
+                "FINS/TCP reconnect to {} failed: {}",
+                self.remote_addr,
+                error
+            );
+        }
+    }
+
+    /// Connects again, asking for the node the PLC assigned before so the
+    /// source address of later requests stays valid.
+    fn rehandshake(&self, stream: &mut TcpStream) -> Result<()> {
+        let (new_stream, client_node, _) =
+            handshake(self.remote_addr, self.connect_timeout, self.client_node)?;
+        if client_node != self.client_node {
+            return Err(FinsError::invalid_response(format!(
+                "PLC assigned node {} on reconnect instead of {}",
+                client_node, self.client_node
+            )));
+        }
+        new_stream.set_read_timeout(Some(self.timeout))?;
+        new_stream.set_write_timeout(stream.write_timeout()?)?;
+        apply_keep_alive(&new_stream, self.keep_alive)?;
+        *stream = new_stream;
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for TcpTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TcpTransport")
+            .field("remote_addr", &self.remote_addr)
+            .field("client_node", &self.client_node)
+            .field("server_node", &self.server_node)
+            .finish()
+    }
+}
+
+/// Connects and performs the node-address handshake, returning the stream
+/// with the client and server node numbers.
+fn handshake(
+    plc_addr: SocketAddr,
+    connect_timeout: Duration,
+    client_node: u8,
+) -> Result<(TcpStream, u8, u8)> {
+    let mut stream =
+        TcpStream::connect_timeout(&plc_addr, connect_timeout).map_err(map_io_error)?;
+    stream.set_nodelay(true)?;
+    stream.set_read_timeout(Some(connect_timeout))?;
+    stream.set_write_timeout(Some(connect_timeout))?;
+
+    write_frame(
+        &mut stream,
+        CMD_NODE_ADDRESS_CLIENT,
+        &(client_node as u32).to_be_bytes(),
+    )?;
+    let (command, data) = read_frame(&mut stream)?;
+    if command != CMD_NODE_ADDRESS_SERVER || data.len() < 8 {
+        return Err(FinsError::invalid_response(format!(
+            "unexpected FINS/TCP handshake response (command 0x{:08X}, {} bytes)",
+            command,
+            data.len()
+        )));
+    }
+    Ok((stream, data[3], data[7]))
+}
+
+/// Enables keep-alive probes after `interval` of idle time, or disables
+/// them for zero.
+fn apply_keep_alive(stream: &TcpStream, interval: Duration) -> Result<()> {
+    let socket = SockRef::from(stream);
+    if interval.is_zero() {
+        socket.set_keepalive(false)?;
+    } else {
+        socket.set_tcp_keepalive(&TcpKeepalive::new().with_time(interval))?;
+    }
+    Ok(())
+}
+
+/// Returns `true` if the peer closed or reset the connection.
+fn is_connection_lost(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::BrokenPipe
+            | io::ErrorKind::UnexpectedEof
+    )
+}
+
+/// Maps socket timeouts to `FinsError::Timeout`.
+fn map_io_error(e: io::Error) -> FinsError {
+    match e.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => FinsError::Timeout,
+        _ => FinsError::Io(e),
+    }
+}
+
+/// Writes one FINS/TCP frame.
+fn write_frame(stream: &mut TcpStream, command: u32, data: &[u8]) -> Result<()> {
+    let mut frame = Vec::with_capacity(FINS_TCP_HEADER_SIZE + data.len());
+    frame.extend_from_slice(&FINS_TCP_MAGIC);
+    frame.extend_from_slice(&((8 + data.len()) as u32).to_be_bytes());
+    frame.extend_from_slice(&command.to_be_bytes());
+    frame.extend_from_slice(&0u32.to_be_bytes());
+    frame.extend_from_slice(data);
+    stream.write_all(&frame).map_err(map_io_error)
+}
+
+/// Reads frames until the response to `request` arrives or `timeout`
+/// elapses, discarding late responses to earlier requests.
+fn read_response(stream: &mut TcpStream, request: &[u8], timeout: Duration) -> Result<Vec<u8>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(FinsError::Timeout);
+        }
+        stream.set_read_timeout(Some(remaining))?;
+        let (command, response) = read_frame(stream)?;
+        if command != CMD_FINS_FRAME {
+            return Err(FinsError::invalid_response(format!(
+                "unexpected FINS/TCP command 0x{:08X}",
+                command
+            )));
+        }
+        if response.get(9) == request.get(9) {
+            return Ok(response);
+        }
+        log_debug!("discarded a late FINS/TCP response for another SID");
+    }
+}
+
+/// Reads one FINS/TCP frame, returning its command and data.
+///
+/// Error notifications (and non-zero error codes) become `FinsError::TcpError`.
+fn read_frame(stream: &mut TcpStream) -> Result<(u32, Vec<u8>)> {
+    let mut header = [0u8; FINS_TCP_HEADER_SIZE];
+    stream.read_exact(&mut header).map_err(map_io_error)?;
+
+    if header[0..4] != FINS_TCP_MAGIC {
+        return Err(FinsError::invalid_response("FINS/TCP header is not 'FINS'"));
+    }
+    let length = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+    if !(8..=MAX_PACKET_SIZE + 8).contains(&length) {
+        return Err(FinsError::invalid_response(format!(
+            "invalid FINS/TCP length {}",
+            length
+        )));
+    }
+    let command = u32::from_be_bytes([header[8], header[9], header[10], header[11]]);
+    let error_code = u32::from_be_bytes([header[12], header[13], header[14], header[15]]);
+
+    let mut data = vec![0u8; length - 8];
+    stream.read_exact(&mut data).map_err(map_io_error)?;
+
+    if command == CMD_ERROR || error_code != 0 {
+        return Err(FinsError::tcp_error(error_code));
+    }
+    Ok((command, data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn frame(command: u32, error_code: u32, data: &[u8]) -> Vec<u8> {
+        let mut frame = FINS_TCP_MAGIC.to_vec();
+        frame.extend_from_slice(&((8 + data.len()) as u32).to_be_bytes());
+        frame.extend_from_slice(&command.to_be_bytes());
+        frame.extend_from_slice(&error_code.to_be_bytes());
+        frame.extend_from_slice(data);
+        frame
+    }
+
+    fn read_request(stream: &mut TcpStream) -> (u32, Vec<u8>) {
+        let mut header = [0u8; FINS_TCP_HEADER_SIZE];
+        stream.read_exact(&mut header).unwrap();
+        assert_eq!(&header[0..4], b"FINS");
+        let length = u32::from_be_bytes(header[4..8].try_into().unwrap()) as usize;
+        let command = u32::from_be_bytes(header[8..12].try_into().unwrap());
+        let mut data = vec![0u8; length - 8];
+        stream.read_exact(&mut data).unwrap();
+        (command, data)
+    }
+
+    #[test]
+    fn test_tcp_handshake_and_exchange() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let (command, data) = read_request(&mut stream);
+            assert_eq!(command, CMD_NODE_ADDRESS_CLIENT);
+            assert_eq!(data, vec![0, 0, 0, 0]);
+            stream
+                .write_all(&frame(
+                    CMD_NODE_ADDRESS_SERVER,
+                    0,
+                    &[0, 0, 0, 0x22, 0, 0, 0, 0x0A],
+                ))
+                .unwrap();
+
+            let (command, data) = read_request(&mut stream);
+            assert_eq!(command, CMD_FINS_FRAME);
+            assert_eq!(data, vec![0x80, 0x01]);
+            stream
+                .write_all(&frame(CMD_FINS_FRAME, 0, &[0xC0, 0x01]))
+                .unwrap();
+        });
+
+        let transport =
+            TcpTransport::connect(addr, Duration::from_secs(1), Duration::from_secs(1), 0).unwrap();
+        assert_eq!(transport.client_node(), 0x22);
+        assert_eq!(transport.server_node(), 0x0A);
+        assert_eq!(transport.remote_addr(), addr);
+        assert_eq!(
+            transport.send_receive(&[0x80, 0x01]).unwrap(),
+            vec![0xC0, 0x01]
+        );
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_tcp_handshake_rejected() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let (_, data) = read_request(&mut stream);
+            assert_eq!(data, vec![0, 0, 0, 5]);
+            stream.write_all(&frame(CMD_ERROR, 0x21, &[])).unwrap();
+        });
+
+        let result = TcpTransport::connect(addr, Duration::from_secs(1), Duration::from_secs(1), 5);
+        assert!(matches!(result, Err(FinsError::TcpError { code: 0x21 })));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_tcp_receive_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            read_request(&mut stream);
+            stream
+                .write_all(&frame(
+                    CMD_NODE_ADDRESS_SERVER,
+                    0,
+                    &[0, 0, 0, 1, 0, 0, 0, 2],
+                ))
+                .unwrap();
+            // Swallow the FINS frame without answering
+            read_request(&mut stream);
+            thread::sleep(Duration::from_millis(200));
+        });
+
+        let transport =
+            TcpTransport::connect(addr, Duration::from_secs(1), Duration::from_secs(1), 0).unwrap();
+        let result = transport.send_receive_with_timeout(&[0x80], Duration::from_millis(50));
+        assert!(matches!(result, Err(FinsError::Timeout)));
+        server.join().unwrap();
+    }
+
+    /// Answers the handshake on `stream`, assigning client node `node`.
+    fn accept_handshake(stream: &mut TcpStream, node: u8) -> Vec<u8> {
+        let (command, data) = read_request(stream);
+        assert_eq!(command, CMD_NODE_ADDRESS_CLIENT);
+        stream
+            .write_all(&frame(
+                CMD_NODE_ADDRESS_SERVER,
+                0,
+                &[0, 0, 0, node, 0, 0, 0, 0x0A],
+            ))
+            .unwrap();
+        data
+    }
+
+    #[test]
+    fn test_tcp_rehandshake_after_reset() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            accept_handshake(&mut stream, 0x22);
+            // Drop the connection as a power-cycled PLC would
+            read_request(&mut stream);
+            drop(stream);
+
+            let (mut stream, _) = listener.accept().unwrap();
+            let requested = accept_handshake(&mut stream, 0x22);
+            let (_, data) = read_request(&mut stream);
+            assert_eq!(data, vec![0x80, 0x02]);
+            stream
+                .write_all(&frame(CMD_FINS_FRAME, 0, &[0xC0, 0x02]))
+                .unwrap();
+            requested
+        });
+
+        let mut transport =
+            TcpTransport::connect(addr, Duration::from_secs(1), Duration::from_secs(1), 0).unwrap();
+        transport.set_keep_alive(Duration::from_secs(30)).unwrap();
+        let result = transport.send_receive(&[0x80, 0x01]);
+        assert!(matches!(result, Err(FinsError::ConnectionReset)));
+        assert_eq!(
+            transport.send_receive(&[0x80, 0x02]).unwrap(),
+            vec![0xC0, 0x02]
+        );
+        assert_eq!(transport.client_node(), 0x22);
+
+        // The handshake asks for the node assigned on the first connect
+        assert_eq!(server.join().unwrap(), vec![0, 0, 0, 0x22]);
+        let stream = transport.stream.lock().unwrap();
+        assert!(SockRef::from(&*stream).keepalive().unwrap());
+        assert_eq!(stream.read_timeout().unwrap(), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_tcp_late_response_reconnects() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let request = |sid: u8| vec![0x80, 0, 2, 0, 1, 0, 0, 0x22, 0, sid, 0x01, 0x01];
+        let response = |sid: u8| vec![0xC0, 0, 2, 0, 0x22, 0, 0, 1, 0, sid, 0x01, 0x01, 0, 0];
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            accept_handshake(&mut stream, 0x22);
+            // Answer the first request after the client gave up on it
+            let (_, data) = read_request(&mut stream);
+            assert_eq!(data, request(1));
+            thread::sleep(Duration::from_millis(150));
+            let _ = stream.write_all(&frame(CMD_FINS_FRAME, 0, &response(1)));
+
+            let (mut stream, _) = listener.accept().unwrap();
+            accept_handshake(&mut stream, 0x22);
+            let (_, data) = read_request(&mut stream);
+            assert_eq!(data, request(2));
+            stream
+                .write_all(&frame(CMD_FINS_FRAME, 0, &response(2)))
+                .unwrap();
+            // The second request was sent once
+            stream
+                .set_read_timeout(Some(Duration::from_millis(100)))
+                .unwrap();
+            let mut byte = [0u8; 1];
+            assert!(!matches!(stream.read(&mut byte), Ok(n) if n > 0));
+        });
+
+        let transport =
+            TcpTransport::connect(addr, Duration::from_secs(1), Duration::from_secs(1), 0).unwrap();
+        let result = transport.send_receive_with_timeout(&request(1), Duration::from_millis(50));
+        assert!(matches!(result, Err(FinsError::Timeout)));
+        assert_eq!(transport.send_receive(&request(2)).unwrap(), response(2));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_tcp_discards_other_sid() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            accept_handshake(&mut stream, 0x22);
+            read_request(&mut stream);
+            let mut stale = vec![0xC0, 0, 2, 0, 0x22, 0, 0, 1, 0, 0x07, 0x01, 0x01, 0, 0];
+            stream.write_all(&frame(CMD_FINS_FRAME, 0, &stale)).unwrap();
+            stale[9] = 0x08;
+            stream.write_all(&frame(CMD_FINS_FRAME, 0, &stale)).unwrap();
+        });
+
+        let transport =
+            TcpTransport::connect(addr, Duration::from_secs(1), Duration::from_secs(1), 0).unwrap();
+        let response = transport
+            .send_receive(&[0x80, 0, 2, 0, 1, 0, 0, 0x22, 0, 0x08, 0x01, 0x01])
+            .unwrap();
+        assert_eq!(response[9], 0x08);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_tcp_reconnect_failure() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            accept_handshake(&mut stream, 0x22);
+        });
+
+        let transport =
+            TcpTransport::connect(addr, Duration::from_secs(1), Duration::from_secs(1), 0).unwrap();
+        server.join().unwrap();
+        // The listener is gone, so the new connection is refused
+        let result = transport.send_receive(&[0x80, 0x01]);
+        assert!(matches!(result, Err(FinsError::Io(_))));
+    }
+
+    #[test]
+    fn test_tcp_keep_alive() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            accept_handshake(&mut stream, 1);
+        });
+
+        let mut transport =
+            TcpTransport::connect(addr, Duration::from_secs(1), Duration::from_secs(1), 0).unwrap();
+        server.join().unwrap();
+        assert_eq!(transport.keep_alive(), Duration::ZERO);
+        transport.set_keep_alive(Duration::from_secs(45)).unwrap();
+        assert_eq!(transport.keep_alive(), Duration::from_secs(45));
+        assert!(SockRef::from(&*transport.stream.lock().unwrap())
+            .keepalive()
+            .unwrap());
+        transport.set_keep_alive(Duration::ZERO).unwrap();
+        assert!(!SockRef::from(&*transport.stream.lock().unwrap())
+            .keepalive()
+            .unwrap());
+    }
+
+    #[test]
+    fn test_tcp_error_description() {
+        assert_eq!(tcp_error_description(0x00), "Normal");
+        assert_eq!(
+            tcp_error_description(0x25),
+            "All the node addresses available for allocation have been used"
+        );
+        assert_eq!(tcp_error_description(0xFF), "Unknown error code");
+    }
+}
@@ -37,10 +37,14 @@
 //! let response = transport.send_receive(&request);
 //! ```
 
+use std::io;
 use std::net::{SocketAddr, UdpSocket};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+use socket2::{Domain, Protocol, Socket, Type};
 
 use crate::error::{FinsError, Result};
+use crate::tcp::TcpTransport;
 
 /// Default FINS UDP port.
 pub const DEFAULT_FINS_PORT: u16 = 9600;
@@ -51,6 +55,164 @@ pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
 /// Maximum UDP packet size for FINS.
 pub const MAX_PACKET_SIZE: usize = 2048;
 
+/// Read timeout of the UDP socket. Receives wait in slices of this length
+/// until their own deadline, so a per-request timeout never changes the
+/// socket options other users of the socket see.
+const RECV_SLICE: Duration = Duration::from_millis(20);
+
+/// Transport protocol used by a [`Client`](crate::Client).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum TransportProtocol {
+    /// FINS over UDP (default).
+    #[default]
+    Udp,
+    /// FINS/TCP with node-address handshake.
+    Tcp,
+}
+
+/// Low-level socket tuning applied when the transport socket is created.
+///
+/// Every option defaults to the operating system's behavior; only the
+/// options that are set are applied.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::SocketOptions;
+///
+/// // Tag FINS traffic as CS3 and enlarge the receive buffer
+/// let options = SocketOptions::new()
+///     .with_dscp(24)
+///     .with_recv_buffer_size(256 * 1024);
+/// assert_eq!(options.tos, Some(96));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SocketOptions {
+    /// Receive buffer size in bytes (`SO_RCVBUF`).
+    pub recv_buffer_size: Option<usize>,
+    /// Send buffer size in bytes (`SO_SNDBUF`).
+    pub send_buffer_size: Option<usize>,
+    /// IP time-to-live for outgoing datagrams.
+    pub ttl: Option<u32>,
+    /// IPv4 type-of-service byte (`IP_TOS`), i.e. DSCP in the upper 6 bits.
+    pub tos: Option<u8>,
+    /// Allows sending to broadcast addresses (`SO_BROADCAST`).
+    pub broadcast: bool,
+    /// Local port to bind (`None` lets the OS pick an ephemeral port).
+    pub local_port: Option<u16>,
+    /// Allows several sockets to bind the same local port (`SO_REUSEADDR`).
+    pub reuse_address: bool,
+}
+
+impl SocketOptions {
+    /// Creates options that leave every setting at the OS default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the receive buffer size (`SO_RCVBUF`).
+    pub fn with_recv_buffer_size(mut self, size: usize) -> Self {
+        self.recv_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the send buffer size (`SO_SNDBUF`).
+    pub fn with_send_buffer_size(mut self, size: usize) -> Self {
+        self.send_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the IP time-to-live.
+    pub fn with_ttl(mut self, ttl: u32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Sets the raw IPv4 type-of-service byte.
+    pub fn with_tos(mut self, tos: u8) -> Self {
+        self.tos = Some(tos);
+        self
+    }
+
+    /// Sets the DSCP code point (0-63); only the low 6 bits are used.
+    ///
+    /// This is a shorthand for `with_tos(dscp << 2)`.
+    pub fn with_dscp(mut self, dscp: u8) -> Self {
+        self.tos = Some((dscp & 0x3F) << 2);
+        self
+    }
+
+    /// Enables or disables `SO_BROADCAST`.
+    pub fn with_broadcast(mut self, enabled: bool) -> Self {
+        self.broadcast = enabled;
+        self
+    }
+
+    /// Binds the local socket to a fixed port instead of an ephemeral one.
+    pub fn with_local_port(mut self, port: u16) -> Self {
+        self.local_port = Some(port);
+        self
+    }
+
+    /// Enables or disables `SO_REUSEADDR`.
+    pub fn with_reuse_address(mut self, enabled: bool) -> Self {
+        self.reuse_address = enabled;
+        self
+    }
+
+    /// Binds the local socket to the FINS port (9600) with `SO_REUSEADDR`.
+    ///
+    /// Some Ethernet units only reply to source port 9600 (e.g., in
+    /// automatic node-number mode, where the reply goes back to the FINS
+    /// port of the sender's IP).
+    ///
+    /// Several clients on one host can share the port this way as long as
+    /// each talks to a different PLC: the OS delivers each datagram to the
+    /// socket connected to its sender. Two clients for the *same* PLC
+    /// would compete for its responses and must not both use this option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use omron_fins::SocketOptions;
+    ///
+    /// let options = SocketOptions::new().with_fins_source_port();
+    /// assert_eq!(options.local_port, Some(9600));
+    /// assert!(options.reuse_address);
+    /// ```
+    pub fn with_fins_source_port(self) -> Self {
+        self.with_local_port(DEFAULT_FINS_PORT)
+            .with_reuse_address(true)
+    }
+
+    /// Applies the configured options to a socket.
+    pub(crate) fn apply(&self, socket: &Socket) -> Result<()> {
+        if let Some(size) = self.recv_buffer_size {
+            socket.set_recv_buffer_size(size)?;
+        }
+        if let Some(size) = self.send_buffer_size {
+            socket.set_send_buffer_size(size)?;
+        }
+        if let Some(ttl) = self.ttl {
+            socket.set_ttl_v4(ttl)?;
+        }
+        if let Some(tos) = self.tos {
+            socket.set_tos_v4(tos as u32)?;
+        }
+        if self.broadcast {
+            socket.set_broadcast(true)?;
+        }
+        if self.reuse_address {
+            socket.set_reuse_address(true)?;
+        }
+        Ok(())
+    }
+}
+
 /// UDP transport for FINS communication.
 ///
 /// Handles synchronous UDP communication with configurable timeout.
@@ -58,6 +220,7 @@ pub const MAX_PACKET_SIZE: usize = 2048;
 pub struct UdpTransport {
     socket: UdpSocket,
     remote_addr: SocketAddr,
+    timeout: Duration,
 }
 
 impl UdpTransport {
@@ -84,16 +247,55 @@ impl UdpTransport {
     /// ).unwrap();
     /// ```
     pub fn new(plc_addr: SocketAddr, timeout: Duration) -> Result<Self> {
-        // Bind to any available local port
-        let socket = UdpSocket::bind("0.0.0.0:0")?;
+        Self::with_options(plc_addr, timeout, &SocketOptions::default())
+    }
+
+    /// Creates a new UDP transport with custom socket options.
+    ///
+    /// # Arguments
+    ///
+    /// * `plc_addr` - Socket address of the PLC (IP:port)
+    /// * `timeout` - Read/write timeout duration
+    /// * `options` - Socket tuning applied before binding
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the socket cannot be created or an option
+    /// is rejected by the operating system.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{SocketOptions, UdpTransport};
+    /// use std::time::Duration;
+    ///
+    /// let transport = UdpTransport::with_options(
+    ///     "192.168.1.10:9600".parse().unwrap(),
+    ///     Duration::from_secs(2),
+    ///     &SocketOptions::new().with_dscp(46),
+    /// ).unwrap();
+    /// ```
+    pub fn with_options(
+        plc_addr: SocketAddr,
+        timeout: Duration,
+        options: &SocketOptions,
+    ) -> Result<Self> {
+        check_timeout(timeout)?;
+        let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+        options.apply(&socket)?;
+        // Bind to the requested local port, or any available one
+        let local_port = options.local_port.unwrap_or(0);
+        socket.bind(&SocketAddr::from(([0, 0, 0, 0], local_port)).into())?;
+        let socket: UdpSocket = socket.into();
         // Connect to the PLC (required for proper FINS communication)
         socket.connect(plc_addr)?;
-        socket.set_read_timeout(Some(timeout))?;
+        socket.set_read_timeout(Some(RECV_SLICE))?;
         socket.set_write_timeout(Some(timeout))?;
 
         Ok(Self {
             socket,
             remote_addr: plc_addr,
+            timeout,
         })
     }
 
@@ -151,22 +353,145 @@ impl UdpTransport {
     /// let response = transport.send_receive(&request).unwrap();
     /// ```
     pub fn send_receive(&self, data: &[u8]) -> Result<Vec<u8>> {
+        // Receive on the stack; only the exact response size is allocated
+        let mut buffer = [0u8; MAX_PACKET_SIZE];
+        let size = self.send_receive_into(data, &mut buffer)?;
+        Ok(buffer[..size].to_vec())
+    }
+
+    /// Sends a FINS frame and receives the response into a caller buffer.
+    ///
+    /// Avoids any allocation per call, for high-frequency polling loops.
+    /// Returns the response length. A buffer of [`MAX_PACKET_SIZE`] bytes
+    /// fits any FINS response; with a smaller buffer an oversized
+    /// datagram is truncated by the operating system.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`send_receive`](Self::send_receive).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::{UdpTransport, MAX_PACKET_SIZE};
+    /// use std::time::Duration;
+    ///
+    /// let transport = UdpTransport::new(
+    ///     "192.168.1.10:9600".parse().unwrap(),
+    ///     Duration::from_secs(2),
+    /// ).unwrap();
+    ///
+    /// let request = vec![0x80, 0x00, 0x02, /* ... */];
+    /// let mut buffer = [0u8; MAX_PACKET_SIZE];
+    /// loop {
+    ///     let len = transport.send_receive_into(&request, &mut buffer).unwrap();
+    ///     let response = &buffer[..len];
+    ///     # break;
+    /// }
+    /// ```
+    pub fn send_receive_into(&self, data: &[u8], buffer: &mut [u8]) -> Result<usize> {
+        self.exchange(data, buffer, self.timeout)
+    }
+
+    /// Sends a request and waits until `timeout` has passed for a datagram.
+    fn exchange(&self, data: &[u8], buffer: &mut [u8], timeout: Duration) -> Result<usize> {
         // Send the request (socket is already connected)
         self.socket.send(data)?;
 
-        // Receive the response
-        let mut buffer = vec![0u8; MAX_PACKET_SIZE];
-        match self.socket.recv(&mut buffer) {
-            Ok(size) => {
-                buffer.truncate(size);
-                Ok(buffer)
+        // Receive the response, one slice at a time
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.socket.recv(buffer) {
+                Ok(size) => return Ok(size),
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        io::ErrorKind::WouldBlock
+                            | io::ErrorKind::TimedOut
+                            | io::ErrorKind::Interrupted
+                    ) =>
+                {
+                    if Instant::now() >= deadline {
+                        return Err(FinsError::Timeout);
+                    }
+                }
+                Err(e) => return Err(FinsError::Io(e)),
             }
-            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Err(FinsError::Timeout),
-            Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Err(FinsError::Timeout),
-            Err(e) => Err(FinsError::Io(e)),
         }
     }
 
+    /// Sends a FINS frame without waiting for a response.
+    ///
+    /// For commands sent with the ICF "response not required" bit set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the send fails.
+    pub fn send(&self, data: &[u8]) -> Result<()> {
+        self.socket.send(data)?;
+        Ok(())
+    }
+
+    /// Sends a FINS frame and waits up to `timeout` for the response.
+    ///
+    /// Only this call waits longer or shorter; the socket options are left
+    /// alone, so concurrent calls on the same transport keep the configured
+    /// timeout.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`send_receive`](Self::send_receive).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use omron_fins::UdpTransport;
+    /// use std::time::Duration;
+    ///
+    /// let transport = UdpTransport::new(
+    ///     "192.168.1.10:9600".parse().unwrap(),
+    ///     Duration::from_secs(2),
+    /// ).unwrap();
+    ///
+    /// let request = vec![0x80, 0x00, 0x02, /* ... */];
+    /// let response = transport
+    ///     .send_receive_with_timeout(&request, Duration::from_secs(30))
+    ///     .unwrap();
+    /// ```
+    pub fn send_receive_with_timeout(&self, data: &[u8], timeout: Duration) -> Result<Vec<u8>> {
+        let mut buffer = [0u8; MAX_PACKET_SIZE];
+        let size = self.exchange(data, &mut buffer, timeout)?;
+        Ok(buffer[..size].to_vec())
+    }
+
+    /// Returns the configured receive timeout.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Changes the configured receive timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the timeout is zero or rejected by the
+    /// operating system.
+    pub fn set_timeout(&mut self, timeout: Duration) -> Result<()> {
+        check_timeout(timeout)?;
+        self.timeout = timeout;
+        Ok(())
+    }
+
+    /// Sets the send timeout independently of the receive timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the timeout is zero or rejected by the
+    /// operating system.
+    pub fn set_send_timeout(&self, timeout: Duration) -> Result<()> {
+        self.socket.set_write_timeout(Some(timeout))?;
+        Ok(())
+    }
+
     /// Returns the remote PLC address.
     pub fn remote_addr(&self) -> SocketAddr {
         self.remote_addr
@@ -183,6 +508,8 @@ impl UdpTransport {
     /// operation sequence, especially after connection issues or
     /// when resuming communication.
     ///
+    /// Returns the number of datagrams discarded.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -195,21 +522,36 @@ impl UdpTransport {
     /// ).unwrap();
     ///
     /// // Clear any stale data before operations
-    /// transport.drain_pending();
+    /// let discarded = transport.drain_pending();
     /// ```
-    pub fn drain_pending(&self) {
+    pub fn drain_pending(&self) -> usize {
         // Set socket to non-blocking temporarily
         let _ = self.socket.set_nonblocking(true);
 
         let mut buffer = [0u8; MAX_PACKET_SIZE];
+        let mut drained = 0;
         // Read and discard all pending data
-        while self.socket.recv(&mut buffer).is_ok() {}
+        while self.socket.recv(&mut buffer).is_ok() {
+            drained += 1;
+        }
 
         // Restore blocking mode with original timeout
         let _ = self.socket.set_nonblocking(false);
+        drained
     }
 }
 
+/// Rejects a zero timeout, as the socket options would.
+fn check_timeout(timeout: Duration) -> Result<()> {
+    if timeout.is_zero() {
+        return Err(FinsError::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "cannot set a 0 duration timeout",
+        )));
+    }
+    Ok(())
+}
+
 impl std::fmt::Debug for UdpTransport {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("UdpTransport")
@@ -219,6 +561,64 @@ impl std::fmt::Debug for UdpTransport {
     }
 }
 
+/// Transport used by [`Client`](crate::Client): UDP or FINS/TCP.
+#[derive(Debug)]
+pub(crate) enum Transport {
+    Udp(UdpTransport),
+    Tcp(TcpTransport),
+}
+
+impl Transport {
+    pub(crate) fn send_receive(&self, data: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            Self::Udp(udp) => udp.send_receive(data),
+            Self::Tcp(tcp) => tcp.send_receive(data),
+        }
+    }
+
+    pub(crate) fn send_receive_with_timeout(
+        &self,
+        data: &[u8],
+        timeout: Duration,
+    ) -> Result<Vec<u8>> {
+        match self {
+            Self::Udp(udp) => udp.send_receive_with_timeout(data, timeout),
+            Self::Tcp(tcp) => tcp.send_receive_with_timeout(data, timeout),
+        }
+    }
+
+    pub(crate) fn send(&self, data: &[u8]) -> Result<()> {
+        match self {
+            Self::Udp(udp) => udp.send(data),
+            Self::Tcp(tcp) => tcp.send(data),
+        }
+    }
+
+    /// Changes the receive timeout.
+    pub(crate) fn set_timeout(&mut self, timeout: Duration) -> Result<()> {
+        match self {
+            Self::Udp(udp) => udp.set_timeout(timeout),
+            Self::Tcp(tcp) => tcp.set_timeout(timeout),
+        }
+    }
+
+    /// Changes the send timeout.
+    pub(crate) fn set_send_timeout(&self, timeout: Duration) -> Result<()> {
+        match self {
+            Self::Udp(udp) => udp.set_send_timeout(timeout),
+            Self::Tcp(tcp) => tcp.set_send_timeout(timeout),
+        }
+    }
+
+    /// Drains stale datagrams; a TCP stream never holds unclaimed responses.
+    pub(crate) fn drain_pending(&self) -> usize {
+        match self {
+            Self::Udp(udp) => udp.drain_pending(),
+            Self::Tcp(_) => 0,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,6 +640,43 @@ mod tests {
 
         let transport = transport.unwrap();
         assert_eq!(transport.remote_addr(), addr);
+        assert_eq!(transport.timeout(), Duration::from_millis(100));
+
+        transport
+            .set_send_timeout(Duration::from_millis(500))
+            .unwrap();
+        assert_eq!(
+            transport.socket().write_timeout().unwrap(),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(transport.socket().read_timeout().unwrap(), Some(RECV_SLICE));
+        assert!(UdpTransport::new(addr, Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_send_receive_with_timeout_leaves_socket_alone() {
+        let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let transport =
+            UdpTransport::new(silent.local_addr().unwrap(), Duration::from_millis(100)).unwrap();
+
+        // A long per-request timeout neither stretches a concurrent request
+        // that uses the configured timeout nor is cut short by it
+        std::thread::scope(|scope| {
+            let long = scope.spawn(|| {
+                let start = Instant::now();
+                let result = transport.send_receive_with_timeout(&[0x80], Duration::from_secs(1));
+                assert!(matches!(result, Err(FinsError::Timeout)));
+                start.elapsed()
+            });
+            std::thread::sleep(Duration::from_millis(50));
+            let start = Instant::now();
+            let result = transport.send_receive(&[0x80]);
+            assert!(matches!(result, Err(FinsError::Timeout)));
+            assert!(start.elapsed() < Duration::from_millis(500));
+            assert!(long.join().unwrap() >= Duration::from_secs(1));
+        });
+        assert_eq!(transport.socket().read_timeout().unwrap(), Some(RECV_SLICE));
+        assert_eq!(transport.timeout(), Duration::from_millis(100));
     }
 
     #[test]
@@ -249,6 +686,96 @@ mod tests {
         assert!(transport.is_ok());
     }
 
+    #[test]
+    fn test_transport_with_options() {
+        let addr: SocketAddr = "127.0.0.1:9600".parse().unwrap();
+        let options = SocketOptions::new()
+            .with_ttl(16)
+            .with_dscp(46)
+            .with_broadcast(true)
+            .with_recv_buffer_size(64 * 1024);
+        let transport =
+            UdpTransport::with_options(addr, Duration::from_millis(100), &options).unwrap();
+
+        let sock = socket2::SockRef::from(transport.socket());
+        assert_eq!(sock.ttl_v4().unwrap(), 16);
+        assert!(sock.broadcast().unwrap());
+        assert!(sock.recv_buffer_size().unwrap() >= 64 * 1024);
+    }
+
+    #[test]
+    fn test_send_receive_into_reuses_buffer() {
+        let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let transport =
+            UdpTransport::new(peer.local_addr().unwrap(), Duration::from_secs(1)).unwrap();
+
+        let echo = std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            for _ in 0..2 {
+                let (len, from) = peer.recv_from(&mut buf).unwrap();
+                peer.send_to(&buf[..len], from).unwrap();
+            }
+        });
+
+        let mut buffer = [0u8; MAX_PACKET_SIZE];
+        let len = transport
+            .send_receive_into(&[1, 2, 3], &mut buffer)
+            .unwrap();
+        assert_eq!(&buffer[..len], &[1, 2, 3]);
+        assert_eq!(transport.send_receive(&[4, 5]).unwrap(), vec![4, 5]);
+        echo.join().unwrap();
+    }
+
+    #[test]
+    fn test_transports_share_local_port() {
+        let port = UdpSocket::bind("0.0.0.0:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let options = SocketOptions::new()
+            .with_local_port(port)
+            .with_reuse_address(true);
+
+        let a = UdpTransport::with_options(
+            "127.0.0.1:9600".parse().unwrap(),
+            Duration::from_millis(100),
+            &options,
+        )
+        .unwrap();
+        let b = UdpTransport::with_options(
+            "127.0.0.1:9601".parse().unwrap(),
+            Duration::from_millis(100),
+            &options,
+        )
+        .unwrap();
+        assert_eq!(a.socket().local_addr().unwrap().port(), port);
+        assert_eq!(b.socket().local_addr().unwrap().port(), port);
+    }
+
+    #[test]
+    fn test_drain_pending_counts_datagrams() {
+        let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let transport =
+            UdpTransport::new(peer.local_addr().unwrap(), Duration::from_millis(100)).unwrap();
+        let local = transport.socket().local_addr().unwrap();
+
+        assert_eq!(transport.drain_pending(), 0);
+        for _ in 0..3 {
+            peer.send_to(&[0xC0], local).unwrap();
+        }
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(transport.drain_pending(), 3);
+        assert_eq!(transport.drain_pending(), 0);
+    }
+
+    #[test]
+    fn test_socket_options_dscp() {
+        assert_eq!(SocketOptions::new().with_dscp(46).tos, Some(0xB8));
+        assert_eq!(SocketOptions::new().with_dscp(0xFF).tos, Some(0xFC));
+        assert_eq!(SocketOptions::default().tos, None);
+    }
+
     #[test]
     fn test_transport_debug() {
         let addr: SocketAddr = "127.0.0.1:9600".parse().unwrap();
@@ -100,28 +100,53 @@ impl PlcValue {
     }
 
     /// Converts the value into bytes suitable for PLC memory.
+    ///
+    /// Multi-word values use the default [`WordOrder`] (least significant
+    /// word first).
     pub fn to_plc_bytes(&self) -> Vec<u8> {
-        match self {
-            PlcValue::USint(v) => vec![0, *v],
-            PlcValue::Sint(v) => vec![0, *v as u8],
-            PlcValue::Uint(v) => v.to_be_bytes().to_vec(),
-            PlcValue::Int(v) => v.to_be_bytes().to_vec(),
-            PlcValue::Word(v) => v.to_be_bytes().to_vec(),
-            PlcValue::Udint(v) => swap_words_32(&v.to_be_bytes()),
-            PlcValue::Dint(v) => swap_words_32(&v.to_be_bytes()),
-            PlcValue::Dword(v) => swap_words_32(&v.to_be_bytes()),
-            PlcValue::Real(v) => swap_words_32(&v.to_be_bytes()),
-            PlcValue::Ulint(v) => reverse_words_64(&v.to_be_bytes()),
-            PlcValue::Lint(v) => reverse_words_64(&v.to_be_bytes()),
-            PlcValue::Lword(v) => reverse_words_64(&v.to_be_bytes()),
-            PlcValue::Lreal(v) => reverse_words_64(&v.to_be_bytes()),
-        }
+        self.to_plc_bytes_with(WordOrder::default())
+    }
+
+    /// Converts the value into bytes suitable for PLC memory, laying out
+    /// multi-word values in `order`.
+    pub fn to_plc_bytes_with(&self, order: WordOrder) -> Vec<u8> {
+        let mut bytes = match self {
+            PlcValue::USint(v) => return vec![0, *v],
+            PlcValue::Sint(v) => return vec![0, *v as u8],
+            PlcValue::Uint(v) => return v.to_be_bytes().to_vec(),
+            PlcValue::Int(v) => return v.to_be_bytes().to_vec(),
+            PlcValue::Word(v) => return v.to_be_bytes().to_vec(),
+            PlcValue::Udint(v) => v.to_be_bytes().to_vec(),
+            PlcValue::Dint(v) => v.to_be_bytes().to_vec(),
+            PlcValue::Dword(v) => v.to_be_bytes().to_vec(),
+            PlcValue::Real(v) => v.to_be_bytes().to_vec(),
+            PlcValue::Ulint(v) => v.to_be_bytes().to_vec(),
+            PlcValue::Lint(v) => v.to_be_bytes().to_vec(),
+            PlcValue::Lword(v) => v.to_be_bytes().to_vec(),
+            PlcValue::Lreal(v) => v.to_be_bytes().to_vec(),
+        };
+        order.arrange(&mut bytes);
+        bytes
     }
 
     /// Parses a value from bytes received from the PLC.
+    ///
+    /// Multi-word values are expected in the default [`WordOrder`].
     pub fn from_plc_bytes(data_type: DataType, bytes: &[u8]) -> Result<Self> {
+        Self::from_plc_bytes_with(data_type, bytes, WordOrder::default())
+    }
+
+    /// Parses a value from bytes received from the PLC, with multi-word
+    /// values laid out in `order`.
+    pub fn from_plc_bytes_with(
+        data_type: DataType,
+        bytes: &[u8],
+        order: WordOrder,
+    ) -> Result<Self> {
         if bytes.len() < data_type.size() {
-            return Err(FinsError::invalid_response("Insufficient bytes for data type"));
+            return Err(FinsError::invalid_response(
+                "Insufficient bytes for data type",
+            ));
         }
 
         match data_type {
@@ -130,51 +155,426 @@ impl PlcValue {
             DataType::UINT => Ok(PlcValue::Uint(u16::from_be_bytes([bytes[0], bytes[1]]))),
             DataType::INT => Ok(PlcValue::Int(i16::from_be_bytes([bytes[0], bytes[1]]))),
             DataType::WORD => Ok(PlcValue::Word(u16::from_be_bytes([bytes[0], bytes[1]]))),
-            DataType::UDINT => {
-                let swapped = swap_words_32(bytes);
-                Ok(PlcValue::Udint(u32::from_be_bytes(swapped.try_into().unwrap())))
-            }
-            DataType::DINT => {
-                let swapped = swap_words_32(bytes);
-                Ok(PlcValue::Dint(i32::from_be_bytes(swapped.try_into().unwrap())))
+            DataType::UDINT => Ok(PlcValue::Udint(u32::from_be_bytes(order.native(bytes)))),
+            DataType::DINT => Ok(PlcValue::Dint(i32::from_be_bytes(order.native(bytes)))),
+            DataType::DWORD => Ok(PlcValue::Dword(u32::from_be_bytes(order.native(bytes)))),
+            DataType::REAL => Ok(PlcValue::Real(f32::from_be_bytes(order.native(bytes)))),
+            DataType::ULINT => Ok(PlcValue::Ulint(u64::from_be_bytes(order.native(bytes)))),
+            DataType::LINT => Ok(PlcValue::Lint(i64::from_be_bytes(order.native(bytes)))),
+            DataType::LWORD => Ok(PlcValue::Lword(u64::from_be_bytes(order.native(bytes)))),
+            DataType::LREAL => Ok(PlcValue::Lreal(f64::from_be_bytes(order.native(bytes)))),
+        }
+    }
+}
+
+/// Layout of 32- and 64-bit values across consecutive PLC words.
+///
+/// Letters name the bytes of a value from most to least significant, e.g.
+/// `0x11223344` is `ABCD`. Omron CPUs store the least significant word
+/// first (`CDAB`); some gateways and third-party devices differ. Single-word
+/// values are never rearranged.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::{ClientConfig, WordOrder};
+/// use std::net::Ipv4Addr;
+///
+/// let config = ClientConfig::new(Ipv4Addr::new(192, 168, 1, 250), 1, 0)
+///     .with_word_order(WordOrder::HighFirst);
+/// assert_eq!(config.word_order, Some(WordOrder::HighFirst));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum WordOrder {
+    /// Least significant word first, big-endian words (`CDAB`). Omron default.
+    #[default]
+    LowFirst,
+    /// Most significant word first, big-endian words (`ABCD`).
+    HighFirst,
+    /// Least significant word first, byte-swapped words (`DCBA`).
+    LowFirstByteSwap,
+    /// Most significant word first, byte-swapped words (`BADC`).
+    HighFirstByteSwap,
+}
+
+impl WordOrder {
+    /// Converts between big-endian bytes and PLC memory layout, in place.
+    ///
+    /// Every layout is its own inverse, so the same call encodes and decodes.
+    pub(crate) fn arrange(self, bytes: &mut [u8]) {
+        if matches!(self, WordOrder::LowFirst | WordOrder::LowFirstByteSwap) {
+            // Reversing all bytes reverses the words and swaps each word's bytes
+            bytes.reverse();
+        }
+        if matches!(self, WordOrder::LowFirst | WordOrder::HighFirstByteSwap) {
+            for word in bytes.chunks_exact_mut(2) {
+                word.swap(0, 1);
             }
-            DataType::DWORD => {
-                let swapped = swap_words_32(bytes);
-                Ok(PlcValue::Dword(u32::from_be_bytes(swapped.try_into().unwrap())))
+        }
+    }
+
+    /// Returns the first `N` bytes of PLC memory as a big-endian value.
+    pub(crate) fn native<const N: usize>(self, bytes: &[u8]) -> [u8; N] {
+        let mut value = [0u8; N];
+        value.copy_from_slice(&bytes[..N]);
+        self.arrange(&mut value);
+        value
+    }
+}
+
+/// Order of the two bytes within a word.
+///
+/// Words travel big-endian in FINS frames, so [`Client::read`] returns
+/// `0x1234` for the bytes `12 34`. Strings are packed the other way, first
+/// character in the low byte.
+///
+/// [`Client::read`]: crate::Client::read
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ByteOrder {
+    /// High byte first, as in FINS frames.
+    #[default]
+    BigEndian,
+    /// Low byte first, as strings are packed into words.
+    LittleEndian,
+}
+
+/// Character encoding of strings stored in PLC memory.
+///
+/// Characters are packed 2 per word, first character in the low byte.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::StringEncoding;
+///
+/// assert_eq!(StringEncoding::Ascii.encode("AB")?, b"AB");
+/// assert!(StringEncoding::Ascii.encode("°C").is_err());
+/// assert_eq!(StringEncoding::Utf8.decode(&[0x41, 0xFF], false)?, "A\u{FFFD}");
+/// assert!(StringEncoding::Utf8.decode(&[0x41, 0xFF], true).is_err());
+/// # Ok::<(), omron_fins::FinsError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum StringEncoding {
+    /// UTF-8, the behavior of [`Client::read_string`](crate::Client::read_string).
+    #[default]
+    Utf8,
+    /// 7-bit ASCII only.
+    Ascii,
+    /// Shift-JIS, used by Japanese HMI and PLC programs (feature `encoding`).
+    #[cfg(feature = "encoding")]
+    ShiftJis,
+}
+
+impl StringEncoding {
+    /// Encodes `text` into bytes for PLC memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `text` has characters the
+    /// encoding cannot represent.
+    pub fn encode(self, text: &str) -> Result<Vec<u8>> {
+        match self {
+            StringEncoding::Utf8 => Ok(text.as_bytes().to_vec()),
+            StringEncoding::Ascii => {
+                if !text.is_ascii() {
+                    return Err(FinsError::invalid_parameter(
+                        "value",
+                        format!("'{}' is not ASCII", text),
+                    ));
+                }
+                Ok(text.as_bytes().to_vec())
             }
-            DataType::REAL => {
-                let swapped = swap_words_32(bytes);
-                Ok(PlcValue::Real(f32::from_be_bytes(swapped.try_into().unwrap())))
+            #[cfg(feature = "encoding")]
+            StringEncoding::ShiftJis => {
+                let (bytes, _, had_errors) = encoding_rs::SHIFT_JIS.encode(text);
+                if had_errors {
+                    return Err(FinsError::invalid_parameter(
+                        "value",
+                        format!("'{}' cannot be encoded as Shift-JIS", text),
+                    ));
+                }
+                Ok(bytes.into_owned())
             }
-            DataType::ULINT => {
-                let reversed = reverse_words_64(bytes);
-                Ok(PlcValue::Ulint(u64::from_be_bytes(reversed.try_into().unwrap())))
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            StringEncoding::Utf8 => "UTF-8",
+            StringEncoding::Ascii => "ASCII",
+            #[cfg(feature = "encoding")]
+            StringEncoding::ShiftJis => "Shift-JIS",
+        }
+    }
+
+    /// Decodes bytes read from PLC memory.
+    ///
+    /// Invalid sequences become U+FFFD, or an error when `strict` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidResponse` in strict mode if `bytes` are
+    /// not valid in this encoding, naming the offset of the first invalid
+    /// byte.
+    pub fn decode(self, bytes: &[u8], strict: bool) -> Result<String> {
+        let invalid = |offset: usize| {
+            FinsError::invalid_response(format!(
+                "string is not valid {} at byte {}",
+                self.name(),
+                offset
+            ))
+        };
+        match self {
+            StringEncoding::Utf8 if strict => String::from_utf8(bytes.to_vec())
+                .map_err(|error| invalid(error.utf8_error().valid_up_to())),
+            StringEncoding::Utf8 => Ok(String::from_utf8_lossy(bytes).to_string()),
+            StringEncoding::Ascii => {
+                if strict {
+                    if let Some(offset) = bytes.iter().position(|byte| !byte.is_ascii()) {
+                        return Err(invalid(offset));
+                    }
+                }
+                Ok(bytes
+                    .iter()
+                    .map(|&byte| {
+                        if byte.is_ascii() {
+                            byte as char
+                        } else {
+                            '\u{FFFD}'
+                        }
+                    })
+                    .collect())
             }
-            DataType::LINT => {
-                let reversed = reverse_words_64(bytes);
-                Ok(PlcValue::Lint(i64::from_be_bytes(reversed.try_into().unwrap())))
+            #[cfg(feature = "encoding")]
+            StringEncoding::ShiftJis if strict => {
+                use encoding_rs::DecoderResult;
+
+                let mut decoder = encoding_rs::SHIFT_JIS.new_decoder_without_bom_handling();
+                let capacity = decoder
+                    .max_utf8_buffer_length_without_replacement(bytes.len())
+                    .unwrap_or(bytes.len() * 3);
+                let mut text = String::with_capacity(capacity);
+                match decoder.decode_to_string_without_replacement(bytes, &mut text, true) {
+                    (DecoderResult::InputEmpty, _) => Ok(text),
+                    (DecoderResult::Malformed(length, consumed), read) => {
+                        Err(invalid(read - length as usize - consumed as usize))
+                    }
+                    (DecoderResult::OutputFull, read) => Err(invalid(read)),
+                }
             }
-            DataType::LWORD => {
-                let reversed = reverse_words_64(bytes);
-                Ok(PlcValue::Lword(u64::from_be_bytes(reversed.try_into().unwrap())))
+            #[cfg(feature = "encoding")]
+            StringEncoding::ShiftJis => Ok(encoding_rs::SHIFT_JIS
+                .decode_without_bom_handling(bytes)
+                .0
+                .into_owned()),
+        }
+    }
+}
+
+/// Length header of a length-prefixed string.
+///
+/// NJ/NX programs and some function block libraries store a string as its
+/// byte count followed by the characters, instead of NUL padding. The
+/// characters are packed 2 per word, first character in the low byte.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::LengthPrefix;
+///
+/// // "AB": count word, then the characters
+/// assert_eq!(LengthPrefix::Word.pack(b"AB")?, [0x0002, 0x4241]);
+/// // "AB": count in the low byte, 'A' in the high byte, then 'B'
+/// assert_eq!(LengthPrefix::Byte.pack(b"AB")?, [0x4102, 0x0042]);
+/// assert_eq!(LengthPrefix::Byte.unpack(&[0x4102, 0x0042])?, b"AB");
+/// # Ok::<(), omron_fins::FinsError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthPrefix {
+    /// One byte count (up to 255 bytes) in the low byte of the first word.
+    Byte,
+    /// A whole word holding the byte count.
+    Word,
+}
+
+impl LengthPrefix {
+    /// Returns the largest byte count the prefix can hold.
+    pub fn max_len(self) -> usize {
+        match self {
+            LengthPrefix::Byte => u8::MAX as usize,
+            LengthPrefix::Word => u16::MAX as usize,
+        }
+    }
+
+    /// Packs `bytes` behind a length prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidParameter` if `bytes` is longer than
+    /// [`max_len`](Self::max_len).
+    pub fn pack(self, bytes: &[u8]) -> Result<Vec<u16>> {
+        if bytes.len() > self.max_len() {
+            return Err(FinsError::invalid_parameter(
+                "value",
+                format!(
+                    "{} bytes do not fit a {:?} length prefix (max {})",
+                    bytes.len(),
+                    self,
+                    self.max_len()
+                ),
+            ));
+        }
+        Ok(match self {
+            LengthPrefix::Byte => {
+                let mut prefixed = Vec::with_capacity(bytes.len() + 1);
+                prefixed.push(bytes.len() as u8);
+                prefixed.extend_from_slice(bytes);
+                crate::utils::pack_string_bytes(&prefixed)
             }
-            DataType::LREAL => {
-                let reversed = reverse_words_64(bytes);
-                Ok(PlcValue::Lreal(f64::from_be_bytes(reversed.try_into().unwrap())))
+            LengthPrefix::Word => {
+                let mut words = vec![bytes.len() as u16];
+                words.extend(crate::utils::pack_string_bytes(bytes));
+                words
             }
+        })
+    }
+
+    /// Returns the bytes counted by the prefix at the start of `words`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinsError::InvalidResponse` if `words` is empty or shorter
+    /// than the prefix says.
+    pub fn unpack(self, words: &[u16]) -> Result<Vec<u8>> {
+        let (&first, rest) = words
+            .split_first()
+            .ok_or_else(|| FinsError::invalid_response("no length prefix"))?;
+        // A byte prefix shares its word with the first character
+        let (len, data, skip) = match self {
+            LengthPrefix::Byte => ((first & 0xFF) as usize, words, 1),
+            LengthPrefix::Word => (first as usize, rest, 0),
+        };
+        let bytes: Vec<u8> = data
+            .iter()
+            .flat_map(|word| [(word & 0xFF) as u8, (word >> 8) as u8])
+            .skip(skip)
+            .collect();
+        if len > bytes.len() {
+            return Err(FinsError::invalid_response(format!(
+                "length prefix {} exceeds the {} bytes read",
+                len,
+                bytes.len()
+            )));
         }
+        Ok(bytes[..len].to_vec())
     }
 }
 
-fn swap_words_32(bytes: &[u8]) -> Vec<u8> {
-    vec![bytes[2], bytes[3], bytes[0], bytes[1]]
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_order_layouts() {
+        let value = PlcValue::Dint(0x1122_3344);
+        let layouts = [
+            (WordOrder::LowFirst, [0x33, 0x44, 0x11, 0x22]),
+            (WordOrder::HighFirst, [0x11, 0x22, 0x33, 0x44]),
+            (WordOrder::LowFirstByteSwap, [0x44, 0x33, 0x22, 0x11]),
+            (WordOrder::HighFirstByteSwap, [0x22, 0x11, 0x44, 0x33]),
+        ];
+        for (order, bytes) in layouts {
+            assert_eq!(value.to_plc_bytes_with(order), bytes);
+            assert_eq!(
+                PlcValue::from_plc_bytes_with(DataType::DINT, &bytes, order).unwrap(),
+                value
+            );
+        }
+
+        // Default keeps the Omron layout; single words are untouched
+        assert_eq!(
+            PlcValue::Ulint(0x0001_0002_0003_0004).to_plc_bytes(),
+            [0x00, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x01]
+        );
+        assert_eq!(
+            PlcValue::Int(-2).to_plc_bytes_with(WordOrder::LowFirstByteSwap),
+            [0xFF, 0xFE]
+        );
+    }
 
-fn reverse_words_64(bytes: &[u8]) -> Vec<u8> {
-    vec![
-        bytes[6], bytes[7], 
-        bytes[4], bytes[5], 
-        bytes[2], bytes[3], 
-        bytes[0], bytes[1]
-    ]
+    #[test]
+    fn test_string_encoding_strict_and_lossy() {
+        assert_eq!(
+            StringEncoding::Ascii.decode(b"A\x80", false).unwrap(),
+            "A\u{FFFD}"
+        );
+        assert!(matches!(
+            StringEncoding::Ascii.decode(b"A\x80", true),
+            Err(FinsError::InvalidResponse { .. })
+        ));
+        assert_eq!(
+            StringEncoding::Utf8
+                .decode(b"LOT\xFF1", true)
+                .unwrap_err()
+                .to_string(),
+            "Invalid response: string is not valid UTF-8 at byte 3"
+        );
+        assert!(StringEncoding::Ascii
+            .decode(b"AB\x80", true)
+            .unwrap_err()
+            .to_string()
+            .ends_with("at byte 2"));
+        assert_eq!(StringEncoding::Utf8.encode("°C").unwrap(), "°C".as_bytes());
+    }
+
+    #[test]
+    fn test_length_prefix() {
+        for prefix in [LengthPrefix::Byte, LengthPrefix::Word] {
+            for text in [&b""[..], b"A", b"ABC", b"ABCD"] {
+                let words = prefix.pack(text).unwrap();
+                assert_eq!(prefix.unpack(&words).unwrap(), text);
+            }
+        }
+        assert_eq!(LengthPrefix::Byte.pack(b"ABC").unwrap(), [0x4103, 0x4342]);
+        assert_eq!(
+            LengthPrefix::Word
+                .unpack(&[0x0003, 0x4241, 0x4443])
+                .unwrap(),
+            b"ABC"
+        );
+        // High byte of a byte prefix is the first character, not the count
+        assert_eq!(LengthPrefix::Byte.unpack(&[0x4101]).unwrap(), b"A");
+
+        assert!(LengthPrefix::Byte.pack(&[b'X'; 256]).is_err());
+        assert!(LengthPrefix::Word.unpack(&[]).is_err());
+        assert!(matches!(
+            LengthPrefix::Word.unpack(&[0x0005, 0x4241]),
+            Err(FinsError::InvalidResponse { .. })
+        ));
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn test_string_encoding_shift_jis() {
+        let bytes = StringEncoding::ShiftJis.encode("温度").unwrap();
+        assert_eq!(bytes, [0x89, 0xB7, 0x93, 0x78]);
+        assert_eq!(
+            StringEncoding::ShiftJis.decode(&bytes, true).unwrap(),
+            "温度"
+        );
+        // Lead byte without a trail byte
+        assert!(StringEncoding::ShiftJis.decode(&[0x89], true).is_err());
+        assert!(StringEncoding::ShiftJis
+            .decode(&[0x89, 0xB7, 0x41, 0x89], true)
+            .unwrap_err()
+            .to_string()
+            .ends_with("at byte 3"));
+        assert_eq!(
+            StringEncoding::ShiftJis.decode(&[0x89], false).unwrap(),
+            "\u{FFFD}"
+        );
+        assert!(StringEncoding::ShiftJis.encode("€").is_err());
+    }
 }
@@ -26,8 +26,16 @@
 //! }
 //! ```
 
+use std::fmt::Write as _;
+
+use crate::address::FinsAddress;
+use crate::error::{FinsError, Result};
+use crate::memory::MemoryArea;
+use crate::types::{ByteOrder, WordOrder};
+
 /// Represents a single bit with its index and value.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BitInfo {
     /// Bit position (0-15 for u16).
     pub index: u8,
@@ -429,6 +437,96 @@ pub fn extract_bits(value: u16, start_bit: u8, end_bit: u8) -> u16 {
     (value >> start_bit) & mask
 }
 
+/// Reads a field of `len` bits starting at bit `start_bit` of `words`,
+/// which may span word boundaries.
+///
+/// Bits are numbered across the slice: bit 16 is bit 0 of `words[1]`. The
+/// field's lowest bit is `start_bit`, as when a PLC program packs e.g. a
+/// 10-bit counter into bits 12-21.
+///
+/// # Errors
+///
+/// Returns `FinsError::InvalidParameter` if `len` is not 1-32 or the field
+/// extends past the end of `words`.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::get_bit_range;
+///
+/// // Bits 12-15 of D100 hold the low nibble, bits 0-5 of D101 the rest
+/// let words = [0x5000, 0x0023];
+/// assert_eq!(get_bit_range(&words, 12, 10)?, 0x235);
+/// # Ok::<(), omron_fins::FinsError>(())
+/// ```
+pub fn get_bit_range(words: &[u16], start_bit: usize, len: u8) -> Result<u32> {
+    check_bit_range(words, start_bit, len)?;
+    let value = (start_bit..start_bit + len as usize)
+        .rev()
+        .fold(0u32, |value, bit| {
+            value << 1 | u32::from(words[bit / 16] >> (bit % 16) & 1)
+        });
+    Ok(value)
+}
+
+/// Writes `value` into a field of `len` bits starting at bit `start_bit`
+/// of `words`, leaving the other bits unchanged. Inverse of
+/// [`get_bit_range`].
+///
+/// # Errors
+///
+/// Returns `FinsError::InvalidParameter` if `len` is not 1-32, the field
+/// extends past the end of `words`, or `value` does not fit in `len` bits.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::set_bit_range;
+///
+/// let mut words = [0x0FFF, 0xFFC0];
+/// set_bit_range(&mut words, 12, 10, 0x235)?;
+/// assert_eq!(words, [0x5FFF, 0xFFE3]);
+/// # Ok::<(), omron_fins::FinsError>(())
+/// ```
+pub fn set_bit_range(words: &mut [u16], start_bit: usize, len: u8, value: u32) -> Result<()> {
+    check_bit_range(words, start_bit, len)?;
+    if len < 32 && value >> len != 0 {
+        return Err(FinsError::invalid_parameter(
+            "value",
+            format!("0x{:X} does not fit in {} bits", value, len),
+        ));
+    }
+    for offset in 0..len as usize {
+        let bit = start_bit + offset;
+        let mask = 1u16 << (bit % 16);
+        if value >> offset & 1 != 0 {
+            words[bit / 16] |= mask;
+        } else {
+            words[bit / 16] &= !mask;
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a bit field of `len` bits at `start_bit` lies within `words`.
+fn check_bit_range(words: &[u16], start_bit: usize, len: u8) -> Result<()> {
+    if !(1..=32).contains(&len) {
+        return Err(FinsError::invalid_parameter("len", "must be 1-32"));
+    }
+    if start_bit.saturating_add(len as usize) > words.len() * 16 {
+        return Err(FinsError::invalid_parameter(
+            "start_bit",
+            format!(
+                "{} bits from bit {} exceed {} words",
+                len,
+                start_bit,
+                words.len()
+            ),
+        ));
+    }
+    Ok(())
+}
+
 /// Checks if all specified bits are ON.
 ///
 /// # Arguments
@@ -477,6 +575,548 @@ pub fn any_bit_on(value: u16, bits: &[u8]) -> bool {
     bits.iter().any(|&b| get_bit(value, b))
 }
 
+/// Reinterprets a word as a signed INT, e.g. a value read with
+/// [`Client::read`](crate::Client::read). `0xFFFF` becomes `-1`.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::as_i16;
+///
+/// assert_eq!(as_i16(0xFFFE), -2);
+/// assert_eq!(as_i16(0x7FFF), 32767);
+/// ```
+pub fn as_i16(word: u16) -> i16 {
+    word as i16
+}
+
+/// Reinterprets a signed INT as the word stored in PLC memory. Inverse of
+/// [`as_i16`].
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::as_u16;
+///
+/// assert_eq!(as_u16(-2), 0xFFFE);
+/// ```
+pub fn as_u16(value: i16) -> u16 {
+    value as u16
+}
+
+/// Reinterprets each word of a read as a signed INT.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::as_i16_slice;
+///
+/// assert_eq!(as_i16_slice(&[0x0001, 0xFFFF, 0x8000]), [1, -1, i16::MIN]);
+/// ```
+pub fn as_i16_slice(words: &[u16]) -> Vec<i16> {
+    words.iter().map(|&word| as_i16(word)).collect()
+}
+
+/// Reinterprets signed INTs as words, e.g. for [`Client::write`](crate::Client::write).
+/// Inverse of [`as_i16_slice`].
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::as_u16_slice;
+///
+/// assert_eq!(as_u16_slice(&[1, -1, i16::MIN]), [0x0001, 0xFFFF, 0x8000]);
+/// ```
+pub fn as_u16_slice(values: &[i16]) -> Vec<u16> {
+    values.iter().map(|&value| as_u16(value)).collect()
+}
+
+/// Decodes a BCD-coded word (e.g., `0x1234`) into its value (`1234`).
+///
+/// # Returns
+///
+/// `None` if any nibble is above 9.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::bcd_to_u16;
+///
+/// assert_eq!(bcd_to_u16(0x1234), Some(1234));
+/// assert_eq!(bcd_to_u16(0x12A4), None);
+/// ```
+pub fn bcd_to_u16(value: u16) -> Option<u16> {
+    bcd_to_u32(value as u32).map(|decoded| decoded as u16)
+}
+
+/// Encodes a value from 0 to 9999 as a BCD-coded word.
+///
+/// # Returns
+///
+/// `None` if `value` has more than 4 digits.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::u16_to_bcd;
+///
+/// assert_eq!(u16_to_bcd(1234), Some(0x1234));
+/// assert_eq!(u16_to_bcd(10000), None);
+/// ```
+pub fn u16_to_bcd(value: u16) -> Option<u16> {
+    if value > 9999 {
+        return None;
+    }
+    u32_to_bcd(value as u32).map(|encoded| encoded as u16)
+}
+
+/// Decodes an 8-digit BCD value (e.g., `0x12345678`) into its value.
+///
+/// # Returns
+///
+/// `None` if any nibble is above 9.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::bcd_to_u32;
+///
+/// assert_eq!(bcd_to_u32(0x0012_3456), Some(123456));
+/// assert_eq!(bcd_to_u32(0xF000_0000), None);
+/// ```
+pub fn bcd_to_u32(value: u32) -> Option<u32> {
+    let mut decoded = 0u32;
+    for shift in (0..8).rev() {
+        let digit = (value >> (shift * 4)) & 0x0F;
+        if digit > 9 {
+            return None;
+        }
+        decoded = decoded * 10 + digit;
+    }
+    Some(decoded)
+}
+
+/// Encodes a value from 0 to 99999999 as an 8-digit BCD value.
+///
+/// # Returns
+///
+/// `None` if `value` has more than 8 digits.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::u32_to_bcd;
+///
+/// assert_eq!(u32_to_bcd(123456), Some(0x0012_3456));
+/// assert_eq!(u32_to_bcd(100_000_000), None);
+/// ```
+pub fn u32_to_bcd(value: u32) -> Option<u32> {
+    if value > 99_999_999 {
+        return None;
+    }
+    let mut remaining = value;
+    let mut encoded = 0u32;
+    for shift in 0..8 {
+        encoded |= (remaining % 10) << (shift * 4);
+        remaining /= 10;
+    }
+    Some(encoded)
+}
+
+/// Swaps the two bytes of a word.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::swap_bytes_u16;
+///
+/// assert_eq!(swap_bytes_u16(0x1234), 0x3412);
+/// ```
+pub fn swap_bytes_u16(value: u16) -> u16 {
+    value.swap_bytes()
+}
+
+/// Swaps the high and low words of a double word.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::swap_words_u32;
+///
+/// assert_eq!(swap_words_u32(0x1234_5678), 0x5678_1234);
+/// ```
+pub fn swap_words_u32(value: u32) -> u32 {
+    value.rotate_left(16)
+}
+
+/// Splits a double word into the two words stored in PLC memory, in the
+/// given word order, as the typed client helpers write it.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::u32_to_words;
+/// use omron_fins::WordOrder;
+///
+/// assert_eq!(u32_to_words(0x1234_5678, WordOrder::LowFirst), [0x5678, 0x1234]);
+/// assert_eq!(u32_to_words(0x1234_5678, WordOrder::HighFirst), [0x1234, 0x5678]);
+/// assert_eq!(u32_to_words(0x1234_5678, WordOrder::LowFirstByteSwap), [0x7856, 0x3412]);
+/// ```
+pub fn u32_to_words(value: u32, order: WordOrder) -> [u16; 2] {
+    let mut bytes = value.to_be_bytes();
+    order.arrange(&mut bytes);
+    [
+        u16::from_be_bytes([bytes[0], bytes[1]]),
+        u16::from_be_bytes([bytes[2], bytes[3]]),
+    ]
+}
+
+/// Joins two words read from PLC memory into a double word, in the given
+/// word order. Inverse of [`u32_to_words`].
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::words_to_u32;
+/// use omron_fins::WordOrder;
+///
+/// assert_eq!(words_to_u32([0x5678, 0x1234], WordOrder::LowFirst), 0x1234_5678);
+/// assert_eq!(words_to_u32([0x3412, 0x7856], WordOrder::HighFirstByteSwap), 0x1234_5678);
+/// ```
+pub fn words_to_u32(words: [u16; 2], order: WordOrder) -> u32 {
+    u32::from_be_bytes(words_to_native(&words, order))
+}
+
+/// Decodes an f32 (REAL) from the first two words of `words`, as
+/// [`Client::read_f32_with_order`](crate::Client::read_f32_with_order)
+/// does, e.g. for values taken out of a bulk read.
+///
+/// Returns `None` if there are fewer than two words.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::decode_f32;
+/// use omron_fins::WordOrder;
+///
+/// let words = [0x0000, 0x4049, 0x0000, 0x4120];
+/// assert_eq!(decode_f32(&words, WordOrder::LowFirst), Some(3.140625));
+/// assert_eq!(decode_f32(&words[2..], WordOrder::LowFirst), Some(10.0));
+/// assert_eq!(decode_f32(&words[3..], WordOrder::LowFirst), None);
+/// ```
+pub fn decode_f32(words: &[u16], order: WordOrder) -> Option<f32> {
+    (words.len() >= 2).then(|| f32::from_be_bytes(words_to_native(words, order)))
+}
+
+/// Encodes an f32 (REAL) as the two words stored in PLC memory. Inverse of
+/// [`decode_f32`].
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::encode_f32;
+/// use omron_fins::WordOrder;
+///
+/// assert_eq!(encode_f32(10.0, WordOrder::LowFirst), [0x0000, 0x4120]);
+/// assert_eq!(encode_f32(10.0, WordOrder::HighFirst), [0x4120, 0x0000]);
+/// ```
+pub fn encode_f32(value: f32, order: WordOrder) -> [u16; 2] {
+    u32_to_words(value.to_bits(), order)
+}
+
+/// Decodes an f64 (LREAL) from the first four words of `words`.
+///
+/// Returns `None` if there are fewer than four words.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::decode_f64;
+/// use omron_fins::WordOrder;
+///
+/// let words = [0x0000, 0x0000, 0x0000, 0x4024];
+/// assert_eq!(decode_f64(&words, WordOrder::LowFirst), Some(10.0));
+/// assert_eq!(decode_f64(&words[1..], WordOrder::LowFirst), None);
+/// ```
+pub fn decode_f64(words: &[u16], order: WordOrder) -> Option<f64> {
+    (words.len() >= 4).then(|| f64::from_be_bytes(words_to_native(words, order)))
+}
+
+/// Encodes an f64 (LREAL) as the four words stored in PLC memory. Inverse
+/// of [`decode_f64`].
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::encode_f64;
+/// use omron_fins::WordOrder;
+///
+/// assert_eq!(encode_f64(10.0, WordOrder::HighFirst), [0x4024, 0x0000, 0x0000, 0x0000]);
+/// ```
+pub fn encode_f64(value: f64, order: WordOrder) -> [u16; 4] {
+    let mut words = [0; 4];
+    words.copy_from_slice(&native_to_words(value.to_be_bytes(), order));
+    words
+}
+
+/// Decodes an i32 (DINT) from the first two words of `words`.
+///
+/// Returns `None` if there are fewer than two words.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::decode_i32;
+/// use omron_fins::WordOrder;
+///
+/// assert_eq!(decode_i32(&[0xFFFE, 0xFFFF], WordOrder::LowFirst), Some(-2));
+/// assert_eq!(decode_i32(&[0x0001], WordOrder::LowFirst), None);
+/// ```
+pub fn decode_i32(words: &[u16], order: WordOrder) -> Option<i32> {
+    (words.len() >= 2).then(|| i32::from_be_bytes(words_to_native(words, order)))
+}
+
+/// Encodes an i32 (DINT) as the two words stored in PLC memory. Inverse of
+/// [`decode_i32`].
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::encode_i32;
+/// use omron_fins::WordOrder;
+///
+/// assert_eq!(encode_i32(-2, WordOrder::LowFirst), [0xFFFE, 0xFFFF]);
+/// ```
+pub fn encode_i32(value: i32, order: WordOrder) -> [u16; 2] {
+    u32_to_words(value as u32, order)
+}
+
+/// Parses Omron address notation such as `"D2000"` or `"H12.07"` into its
+/// area, word and optional bit, e.g. for configuration loaders that run
+/// before any client exists.
+///
+/// Accepts the same notation as [`FinsAddress`]'s `FromStr`
+/// implementation, which this returns as a tuple.
+///
+/// # Errors
+///
+/// Returns `FinsError::InvalidParameter` for an unknown area prefix, a
+/// malformed word or bit number, or a bit above 15.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::parse_address;
+/// use omron_fins::MemoryArea;
+///
+/// assert_eq!(parse_address("H12.07")?, (MemoryArea::HR, 12, Some(7)));
+/// assert_eq!(parse_address("D2000")?, (MemoryArea::DM, 2000, None));
+/// assert!(parse_address("X10").is_err());
+/// # Ok::<(), omron_fins::FinsError>(())
+/// ```
+pub fn parse_address(text: &str) -> Result<(MemoryArea, u16, Option<u8>)> {
+    let address: FinsAddress = text.parse()?;
+    Ok((address.area, address.word, address.bit))
+}
+
+/// Formats bytes as an offset/hex/ASCII dump, 16 bytes per line, e.g. for
+/// attaching a captured frame to a support ticket.
+///
+/// Bytes outside printable ASCII are shown as `.`. Lines are separated by
+/// `\n` with no trailing newline; an empty slice gives an empty string.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::hexdump;
+///
+/// let frame = [0x80, 0x00, 0x02, 0x00, 0x0A, 0x00, 0x00, 0x01, 0x00, 0x05, 0x01, 0x01, 0x82];
+/// assert_eq!(
+///     hexdump(&frame),
+///     "0000  80 00 02 00 0A 00 00 01  00 05 01 01 82           |.............|"
+/// );
+/// ```
+pub fn hexdump(bytes: &[u8]) -> String {
+    let mut dump = String::new();
+    for (line, chunk) in bytes.chunks(16).enumerate() {
+        if line > 0 {
+            dump.push('\n');
+        }
+        let _ = write!(dump, "{:04X} ", line * 16);
+        for index in 0..16 {
+            if index == 8 {
+                dump.push(' ');
+            }
+            match chunk.get(index) {
+                Some(byte) => {
+                    let _ = write!(dump, " {:02X}", byte);
+                }
+                None => dump.push_str("   "),
+            }
+        }
+        dump.push_str("  |");
+        dump.extend(chunk.iter().map(|&byte| {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                byte as char
+            } else {
+                '.'
+            }
+        }));
+        dump.push('|');
+    }
+    dump
+}
+
+/// Computes the Host Link frame check sequence: the XOR of every byte from
+/// the `@` through the end of the text.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::hostlink_fcs;
+///
+/// assert_eq!(hostlink_fcs(b"@00RD00000001"), 0x57);
+/// ```
+pub fn hostlink_fcs(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |fcs, byte| fcs ^ byte)
+}
+
+/// Completes a Host Link frame by appending the FCS, as two uppercase hex
+/// characters, and the `*` CR terminator to `body`.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::hostlink_frame;
+///
+/// assert_eq!(hostlink_frame(b"@00RD00000001"), b"@00RD0000000157*\r");
+/// ```
+pub fn hostlink_frame(body: &[u8]) -> Vec<u8> {
+    let mut frame = body.to_vec();
+    frame.extend_from_slice(format!("{:02X}*\r", hostlink_fcs(body)).as_bytes());
+    frame
+}
+
+/// Checks the terminator and FCS of a received Host Link frame and returns
+/// the body, from the `@` up to the FCS. The trailing CR is optional.
+///
+/// # Errors
+///
+/// Returns `FinsError::InvalidResponse` if the frame does not end in `*`,
+/// is too short to hold an FCS, or its FCS does not match.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::hostlink_verify;
+///
+/// assert_eq!(hostlink_verify(b"@00RD0000000157*\r")?, b"@00RD00000001");
+/// assert!(hostlink_verify(b"@00RD0000000158*\r").is_err());
+/// # Ok::<(), omron_fins::FinsError>(())
+/// ```
+pub fn hostlink_verify(frame: &[u8]) -> Result<&[u8]> {
+    let frame = frame.strip_suffix(b"\r").unwrap_or(frame);
+    let frame = frame
+        .strip_suffix(b"*")
+        .ok_or_else(|| FinsError::invalid_response("Host Link frame does not end in '*'"))?;
+    let Some(split) = frame.len().checked_sub(2) else {
+        return Err(FinsError::invalid_response(
+            "Host Link frame too short for an FCS",
+        ));
+    };
+    let (body, fcs) = frame.split_at(split);
+    let received = std::str::from_utf8(fcs)
+        .ok()
+        .and_then(|fcs| u8::from_str_radix(fcs, 16).ok())
+        .ok_or_else(|| FinsError::invalid_response("Host Link FCS is not hexadecimal"))?;
+    let expected = hostlink_fcs(body);
+    if received != expected {
+        return Err(FinsError::invalid_response(format!(
+            "Host Link FCS mismatch: frame has {:02X}, expected {:02X}",
+            received, expected
+        )));
+    }
+    Ok(body)
+}
+
+/// Decodes PLC words into the big-endian bytes of an `N`-byte value. The
+/// caller must pass at least `N / 2` words.
+pub(crate) fn words_to_native<const N: usize>(words: &[u16], order: WordOrder) -> [u8; N] {
+    let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_be_bytes()).collect();
+    order.native(&bytes)
+}
+
+/// Encodes the big-endian bytes of a value as PLC words.
+pub(crate) fn native_to_words<const N: usize>(mut bytes: [u8; N], order: WordOrder) -> Vec<u16> {
+    order.arrange(&mut bytes);
+    bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+        .collect()
+}
+
+/// Splits words into bytes, two per word in the given byte order.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::words_to_bytes;
+/// use omron_fins::ByteOrder;
+///
+/// assert_eq!(words_to_bytes(&[0x1234], ByteOrder::BigEndian), [0x12, 0x34]);
+/// // "AB" as written by write_string
+/// assert_eq!(words_to_bytes(&[0x4241], ByteOrder::LittleEndian), b"AB");
+/// ```
+pub fn words_to_bytes(words: &[u16], order: ByteOrder) -> Vec<u8> {
+    words
+        .iter()
+        .flat_map(|word| match order {
+            ByteOrder::BigEndian => word.to_be_bytes(),
+            ByteOrder::LittleEndian => word.to_le_bytes(),
+        })
+        .collect()
+}
+
+/// Joins bytes into words, two per word in the given byte order. An odd
+/// trailing byte is padded with 0x00.
+///
+/// # Example
+///
+/// ```
+/// use omron_fins::utils::bytes_to_words;
+/// use omron_fins::ByteOrder;
+///
+/// assert_eq!(bytes_to_words(&[0x12, 0x34], ByteOrder::BigEndian), [0x1234]);
+/// assert_eq!(bytes_to_words(b"ABC", ByteOrder::LittleEndian), [0x4241, 0x0043]);
+/// ```
+pub fn bytes_to_words(bytes: &[u8], order: ByteOrder) -> Vec<u16> {
+    bytes
+        .chunks(2)
+        .map(|chunk| {
+            let pair = [chunk[0], chunk.get(1).copied().unwrap_or(0)];
+            match order {
+                ByteOrder::BigEndian => u16::from_be_bytes(pair),
+                ByteOrder::LittleEndian => u16::from_le_bytes(pair),
+            }
+        })
+        .collect()
+}
+
+/// Packs string bytes into words, first character in the low byte of each
+/// word, padding an odd length with 0x00.
+pub(crate) fn pack_string_bytes(bytes: &[u8]) -> Vec<u16> {
+    bytes_to_words(bytes, ByteOrder::LittleEndian)
+}
+
+/// Unpacks words written by [`pack_string_bytes`], trimming trailing NULs.
+pub(crate) fn unpack_string_bytes(words: &[u16]) -> Vec<u8> {
+    let mut bytes = words_to_bytes(words, ByteOrder::LittleEndian);
+    while bytes.last() == Some(&0) {
+        bytes.pop();
+    }
+    bytes
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -513,8 +1153,8 @@ mod tests {
         assert!(bits[0]);
         assert!(bits[1]);
         assert!(!bits[2]);
-        for i in 3..16 {
-            assert!(!bits[i]);
+        for bit in bits.iter().skip(3) {
+            assert!(!bit);
         }
     }
 
@@ -616,4 +1256,169 @@ mod tests {
         let bit = BitInfo::new(0, false);
         assert_eq!(bit.to_string(), "Bit 0: OFF");
     }
+
+    #[test]
+    fn test_word_swaps() {
+        assert_eq!(swap_bytes_u16(0x00FF), 0xFF00);
+        assert_eq!(swap_words_u32(swap_words_u32(0xDEAD_BEEF)), 0xDEAD_BEEF);
+
+        let orders = [
+            (WordOrder::LowFirst, [0x5678, 0x1234]),
+            (WordOrder::HighFirst, [0x1234, 0x5678]),
+            (WordOrder::LowFirstByteSwap, [0x7856, 0x3412]),
+            (WordOrder::HighFirstByteSwap, [0x3412, 0x7856]),
+        ];
+        for (order, words) in orders {
+            assert_eq!(u32_to_words(0x1234_5678, order), words);
+            assert_eq!(words_to_u32(words, order), 0x1234_5678);
+        }
+    }
+
+    #[test]
+    fn test_codecs() {
+        let orders = [
+            WordOrder::LowFirst,
+            WordOrder::HighFirst,
+            WordOrder::LowFirstByteSwap,
+            WordOrder::HighFirstByteSwap,
+        ];
+        for order in orders {
+            assert_eq!(decode_f32(&encode_f32(-1.5, order), order), Some(-1.5));
+            assert_eq!(decode_f64(&encode_f64(1e300, order), order), Some(1e300));
+            assert_eq!(
+                decode_i32(&encode_i32(i32::MIN, order), order),
+                Some(i32::MIN)
+            );
+        }
+        assert_eq!(
+            encode_f32(1.0, WordOrder::HighFirstByteSwap),
+            [0x803F, 0x0000]
+        );
+        assert_eq!(
+            encode_f64(1.0, WordOrder::LowFirst),
+            [0x0000, 0x0000, 0x0000, 0x3FF0]
+        );
+        assert_eq!(decode_f32(&[], WordOrder::HighFirst), None);
+        assert_eq!(decode_f64(&[0; 3], WordOrder::HighFirst), None);
+        assert_eq!(
+            decode_i32(&[0x0000, 0x0007, 0xFFFF], WordOrder::HighFirst),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn test_hexdump() {
+        let bytes: Vec<u8> = (0x30..0x48).chain([0x00, 0x7F]).collect();
+        assert_eq!(
+            hexdump(&bytes),
+            "0000  30 31 32 33 34 35 36 37  38 39 3A 3B 3C 3D 3E 3F  |0123456789:;<=>?|\n\
+             0010  40 41 42 43 44 45 46 47  00 7F                    |@ABCDEFG..|"
+        );
+        assert_eq!(hexdump(b" "), format!("0000  20{}  | |", " ".repeat(46)));
+        assert_eq!(hexdump(&[]), "");
+    }
+
+    #[test]
+    fn test_parse_address() {
+        assert_eq!(
+            parse_address("CIO2.05").unwrap(),
+            (MemoryArea::CIO, 2, Some(5))
+        );
+        assert_eq!(
+            parse_address(" a500 ").unwrap(),
+            (MemoryArea::AR, 500, None)
+        );
+        assert_eq!(
+            parse_address("E1_10").unwrap(),
+            (
+                MemoryArea::Custom {
+                    word_code: 0xA1,
+                    bit_code: Some(0x21)
+                },
+                10,
+                None
+            )
+        );
+        assert!(parse_address("D100.16").is_err());
+        assert!(parse_address("D").is_err());
+    }
+
+    #[test]
+    fn test_hostlink() {
+        let body = b"@00FA0000000000101820000000A0000";
+        assert_eq!(hostlink_fcs(body), 0x0C);
+        let frame = hostlink_frame(body);
+        assert!(frame.ends_with(b"0C*\r"));
+        assert_eq!(hostlink_verify(&frame).unwrap(), body);
+        assert_eq!(hostlink_verify(&frame[..frame.len() - 1]).unwrap(), body);
+
+        assert_eq!(hostlink_fcs(b""), 0);
+        assert!(hostlink_verify(b"@00RD0000000157").is_err());
+        assert!(hostlink_verify(b"5*\r").is_err());
+        assert!(hostlink_verify(b"@00RD000000015G*").is_err());
+        assert!(hostlink_verify(b"@00RD0000000157*\r\r").is_err());
+    }
+
+    #[test]
+    fn test_bit_ranges() {
+        let mut words = [0u16; 3];
+        set_bit_range(&mut words, 14, 32, 0xDEAD_BEEF).unwrap();
+        assert_eq!(get_bit_range(&words, 14, 32).unwrap(), 0xDEAD_BEEF);
+        assert_eq!(words, [0xC000, 0x6FBB, 0x37AB]);
+
+        set_bit_range(&mut words, 0, 1, 1).unwrap();
+        assert_eq!(get_bit_range(&words, 0, 16).unwrap(), 0xC001);
+        set_bit_range(&mut words, 15, 2, 0b00).unwrap();
+        assert_eq!(words[..2], [0x4001, 0x6FBA]);
+        assert_eq!(get_bit_range(&words, 47, 1).unwrap(), 0);
+
+        assert!(get_bit_range(&words, 40, 9).is_err());
+        assert!(get_bit_range(&words, 0, 0).is_err());
+        assert!(get_bit_range(&words, 0, 33).is_err());
+        assert!(get_bit_range(&words, usize::MAX, 1).is_err());
+        assert!(set_bit_range(&mut words, 0, 4, 0x10).is_err());
+    }
+
+    #[test]
+    fn test_signed_words() {
+        for value in [i16::MIN, -1, 0, 1, i16::MAX] {
+            assert_eq!(as_i16(as_u16(value)), value);
+        }
+        assert_eq!(as_u16(i16::MIN), 0x8000);
+        let words = [0x0000, 0x8001, 0xFF9C];
+        assert_eq!(as_i16_slice(&words), [0, -32767, -100]);
+        assert_eq!(as_u16_slice(&as_i16_slice(&words)), words);
+        assert!(as_i16_slice(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_word_bytes() {
+        let words = [0x1234, 0xABCD];
+        for order in [ByteOrder::BigEndian, ByteOrder::LittleEndian] {
+            assert_eq!(bytes_to_words(&words_to_bytes(&words, order), order), words);
+        }
+        assert_eq!(
+            words_to_bytes(&words, ByteOrder::LittleEndian),
+            [0x34, 0x12, 0xCD, 0xAB]
+        );
+        assert_eq!(bytes_to_words(&[0xFF], ByteOrder::BigEndian), [0xFF00]);
+        assert!(bytes_to_words(&[], ByteOrder::BigEndian).is_empty());
+        assert_eq!(pack_string_bytes(b"Hi!"), [0x6948, 0x0021]);
+        assert_eq!(unpack_string_bytes(&[0x6948, 0x0021]), b"Hi!");
+    }
+
+    #[test]
+    fn test_bcd_conversions() {
+        assert_eq!(bcd_to_u16(0x0000), Some(0));
+        assert_eq!(bcd_to_u16(0x9999), Some(9999));
+        assert_eq!(bcd_to_u16(0x000A), None);
+        assert_eq!(u16_to_bcd(9999), Some(0x9999));
+        assert_eq!(u16_to_bcd(42), Some(0x0042));
+        assert_eq!(u16_to_bcd(10000), None);
+
+        assert_eq!(bcd_to_u32(0x9999_9999), Some(99_999_999));
+        assert_eq!(bcd_to_u32(0x1234_5B78), None);
+        assert_eq!(u32_to_bcd(12_345_678), Some(0x1234_5678));
+        assert_eq!(u32_to_bcd(u32::MAX), None);
+    }
 }